@@ -2,9 +2,15 @@
 //! # Calculus module
 
 /// dual numbers - for automatic differentiation
+#[cfg(feature = "alloc")]
 pub mod dual;
 
+/// reverse-mode tape - for automatic differentiation of scalar-valued maps with many inputs
+#[cfg(feature = "std")]
+pub mod tape;
+
 /// curves, scalar-valued, vector-valued, and matrix-valued maps
+#[cfg(feature = "alloc")]
 pub mod maps;
 
 /// intervals and regions
@@ -15,6 +21,19 @@ pub use crate::calculus::region::Interval;
 pub use crate::calculus::region::Region;
 
 /// splines
+#[cfg(feature = "alloc")]
 pub mod spline;
+#[cfg(feature = "alloc")]
+pub use crate::calculus::spline::spline_fit::SplineFitError;
+#[cfg(feature = "alloc")]
+pub use crate::calculus::spline::spline_fit::SplineFitParams;
+#[cfg(feature = "alloc")]
+pub use crate::calculus::spline::spline_fit::SplineFitStats;
+#[cfg(feature = "alloc")]
+pub use crate::calculus::spline::BSpline;
+#[cfg(feature = "alloc")]
+pub use crate::calculus::spline::BSplineParams;
+#[cfg(feature = "alloc")]
 pub use crate::calculus::spline::CubicBSpline;
+#[cfg(feature = "alloc")]
 pub use crate::calculus::spline::CubicBSplineParams;
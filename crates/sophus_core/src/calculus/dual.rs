@@ -2,28 +2,46 @@
 pub mod dual_matrix;
 pub use crate::calculus::dual::dual_matrix::DualMatrix;
 
-#[cfg(feature = "simd")]
 /// Dual batch matrix.
 pub mod dual_batch_matrix;
-#[cfg(feature = "simd")]
 pub use crate::calculus::dual::dual_batch_matrix::DualBatchMatrix;
 
 /// Dual scalar.
 pub mod dual_scalar;
 pub use crate::calculus::dual::dual_scalar::DualScalar;
 
-#[cfg(feature = "simd")]
 /// Dual batch scalar.
 pub mod dual_batch_scalar;
-#[cfg(feature = "simd")]
 pub use crate::calculus::dual::dual_batch_scalar::DualBatchScalar;
 
+/// Hyper-dual matrix.
+pub mod hyperdual_matrix;
+pub use crate::calculus::dual::hyperdual_matrix::HyperDualMatrix;
+
+/// Hyper-dual scalar.
+pub mod hyperdual_scalar;
+pub use crate::calculus::dual::hyperdual_scalar::HyperDualScalar;
+
+/// Hyper-dual vector.
+pub mod hyperdual_vector;
+pub use crate::calculus::dual::hyperdual_vector::HyperDualVector;
+
+/// Jet matrix.
+pub mod jet_matrix;
+pub use crate::calculus::dual::jet_matrix::JetMatrix;
+
+/// Jet scalar.
+pub mod jet_scalar;
+pub use crate::calculus::dual::jet_scalar::JetScalar;
+
+/// Jet vector.
+pub mod jet_vector;
+pub use crate::calculus::dual::jet_vector::JetVector;
+
 /// Dual vector.
 pub mod dual_vector;
 pub use crate::calculus::dual::dual_vector::DualVector;
 
-#[cfg(feature = "simd")]
 /// Dual batch vector.
 pub mod dual_batch_vector;
-#[cfg(feature = "simd")]
 pub use crate::calculus::dual::dual_batch_vector::DualBatchVector;
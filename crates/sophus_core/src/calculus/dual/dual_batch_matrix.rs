@@ -1,6 +1,9 @@
 use crate::calculus::dual::dual_batch_scalar::DualBatchScalar;
 use crate::calculus::dual::dual_matrix::DijPairMV;
 use crate::calculus::dual::DualBatchVector;
+use crate::linalg::batch_backend::LaneCount;
+use crate::linalg::batch_backend::Mask;
+use crate::linalg::batch_backend::SupportedLaneCount;
 use crate::linalg::BatchMatF64;
 use crate::linalg::BatchScalarF64;
 use crate::linalg::BatchVecF64;
@@ -10,15 +13,12 @@ use crate::tensor::mut_tensor::MutTensorDDR;
 use crate::tensor::mut_tensor::MutTensorDDRC;
 use approx::AbsDiffEq;
 use approx::RelativeEq;
+use core::fmt::Debug;
+use core::ops::Add;
+use core::ops::Mul;
+use core::ops::Neg;
+use core::ops::Sub;
 use num_traits::Zero;
-use std::fmt::Debug;
-use std::ops::Add;
-use std::ops::Mul;
-use std::ops::Neg;
-use std::ops::Sub;
-use std::simd::LaneCount;
-use std::simd::Mask;
-use std::simd::SupportedLaneCount;
 
 use crate::calculus::dual::dual_matrix::DijPairM;
 
@@ -679,7 +679,7 @@ impl<const ROWS: usize, const COLS: usize, const BATCH: usize> Debug
 where
     LaneCount<BATCH>: SupportedLaneCount,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         if self.dij_part.is_some() {
             f.debug_struct("DualScalarLike")
                 .field("val", &self.real_part)
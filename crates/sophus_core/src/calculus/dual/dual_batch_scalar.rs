@@ -1,6 +1,9 @@
 use super::dual_scalar::DualScalar;
 pub use crate::calculus::dual::dual_batch_matrix::DualBatchMatrix;
 pub use crate::calculus::dual::dual_batch_vector::DualBatchVector;
+use crate::linalg::batch_backend::LaneCount;
+use crate::linalg::batch_backend::Mask;
+use crate::linalg::batch_backend::SupportedLaneCount;
 use crate::linalg::scalar::NumberCategory;
 use crate::linalg::BatchMatF64;
 use crate::linalg::BatchScalarF64;
@@ -8,22 +11,20 @@ use crate::linalg::BatchVecF64;
 use crate::prelude::*;
 use crate::tensor::mut_tensor::InnerScalarToVec;
 use crate::tensor::mut_tensor::MutTensorDD;
+use alloc::vec::Vec;
 use approx::assert_abs_diff_eq;
 use approx::AbsDiffEq;
 use approx::RelativeEq;
+use core::fmt::Debug;
+use core::ops::Add;
+use core::ops::AddAssign;
+use core::ops::Div;
+use core::ops::Mul;
+use core::ops::Neg;
+use core::ops::Sub;
+use core::ops::SubAssign;
 use num_traits::One;
 use num_traits::Zero;
-use std::fmt::Debug;
-use std::ops::Add;
-use std::ops::AddAssign;
-use std::ops::Div;
-use std::ops::Mul;
-use std::ops::Neg;
-use std::ops::Sub;
-use std::ops::SubAssign;
-use std::simd::LaneCount;
-use std::simd::Mask;
-use std::simd::SupportedLaneCount;
 
 /// Dual number - a real number and an infinitesimal number (batch version)
 #[derive(Clone)]
@@ -266,7 +267,7 @@ where
     BatchScalarF64<BATCH>: IsCoreScalar,
     LaneCount<BATCH>: SupportedLaneCount,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         if self.dij_part.is_some() {
             f.debug_struct("DualScalar")
                 .field("val", &self.real_part)
@@ -431,6 +432,39 @@ where
         }
     }
 
+    fn exp(self) -> Self {
+        let exp = self.real_part.exp();
+        Self {
+            real_part: exp,
+            dij_part: match self.dij_part {
+                Some(dij) => {
+                    let out_dij =
+                        MutTensorDD::from_map(&dij.view(), |dij: &BatchScalarF64<BATCH>| {
+                            *dij * exp
+                        });
+                    Some(out_dij)
+                }
+                None => None,
+            },
+        }
+    }
+
+    fn ln(self) -> Self {
+        Self {
+            real_part: self.real_part.ln(),
+            dij_part: match self.dij_part {
+                Some(dij) => {
+                    let out_dij =
+                        MutTensorDD::from_map(&dij.view(), |dij: &BatchScalarF64<BATCH>| {
+                            *dij / self.real_part
+                        });
+                    Some(out_dij)
+                }
+                None => None,
+            },
+        }
+    }
+
     fn to_vec(self) -> DualBatchVector<1, BATCH> {
         DualBatchVector::<1, BATCH> {
             real_part: self.real_part.real_part().to_vec(),
@@ -542,6 +576,8 @@ where
 
     type DualScalar = Self;
 
+    type Float = f64;
+
     fn ones() -> Self {
         Self::from_f64(1.0)
     }
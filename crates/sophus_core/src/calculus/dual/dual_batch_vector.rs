@@ -1,6 +1,9 @@
 pub use crate::calculus::dual::dual_batch_scalar::DualBatchScalar;
 use crate::calculus::dual::dual_vector::DijPair;
 use crate::calculus::dual::DualBatchMatrix;
+use crate::linalg::batch_backend::LaneCount;
+use crate::linalg::batch_backend::Mask;
+use crate::linalg::batch_backend::SupportedLaneCount;
 use crate::linalg::BatchScalarF64;
 use crate::linalg::BatchVecF64;
 use crate::prelude::*;
@@ -9,13 +12,10 @@ use crate::tensor::mut_tensor::MutTensorDD;
 use crate::tensor::mut_tensor::MutTensorDDR;
 use approx::AbsDiffEq;
 use approx::RelativeEq;
-use std::fmt::Debug;
-use std::ops::Add;
-use std::ops::Neg;
-use std::ops::Sub;
-use std::simd::LaneCount;
-use std::simd::Mask;
-use std::simd::SupportedLaneCount;
+use core::fmt::Debug;
+use core::ops::Add;
+use core::ops::Neg;
+use core::ops::Sub;
 
 /// Dual vector (batch version)
 #[derive(Clone, Debug)]
@@ -1,5 +1,6 @@
 use super::dual_matrix::DualMatrix;
 use super::dual_vector::DualVector;
+use alloc::vec::Vec;
 
 use crate::linalg::scalar::NumberCategory;
 
@@ -14,14 +15,14 @@ use approx::RelativeEq;
 use num_traits::One;
 use num_traits::Zero;
 
-use std::fmt::Debug;
-use std::ops::Add;
-use std::ops::AddAssign;
-use std::ops::Div;
-use std::ops::Mul;
-use std::ops::Neg;
-use std::ops::Sub;
-use std::ops::SubAssign;
+use core::fmt::Debug;
+use core::ops::Add;
+use core::ops::AddAssign;
+use core::ops::Div;
+use core::ops::Mul;
+use core::ops::Neg;
+use core::ops::Sub;
+use core::ops::SubAssign;
 
 /// Trait for dual numbers
 pub trait IsDual {}
@@ -233,7 +234,7 @@ impl PartialEq for DualScalar {
 }
 
 impl PartialOrd for DualScalar {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
         self.real_part.partial_cmp(&other.real_part)
     }
 }
@@ -245,7 +246,7 @@ impl From<f64> for DualScalar {
 }
 
 impl Debug for DualScalar {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         if self.dij_part.is_some() {
             f.debug_struct("DualScalar")
                 .field("val", &self.real_part)
@@ -367,6 +368,35 @@ impl IsScalar<1> for DualScalar {
         }
     }
 
+    fn exp(self) -> Self {
+        let exp = self.real_part.exp();
+        Self {
+            real_part: exp,
+            dij_part: match self.dij_part {
+                Some(dij) => {
+                    let out_dij = <MutTensorDD<f64>>::from_map(&dij.view(), |dij: &f64| *dij * exp);
+                    Some(out_dij)
+                }
+                None => None,
+            },
+        }
+    }
+
+    fn ln(self) -> Self {
+        Self {
+            real_part: self.real_part.ln(),
+            dij_part: match self.dij_part {
+                Some(dij) => {
+                    let out_dij = <MutTensorDD<f64>>::from_map(&dij.view(), |dij: &f64| {
+                        *dij / self.real_part
+                    });
+                    Some(out_dij)
+                }
+                None => None,
+            },
+        }
+    }
+
     fn to_vec(self) -> DualVector<1> {
         DualVector::<1> {
             real_part: self.real_part.real_part().to_vec(),
@@ -483,6 +513,8 @@ impl IsScalar<1> for DualScalar {
 
     type DualScalar = Self;
 
+    type Float = f64;
+
     type DualVector<const ROWS: usize> = DualVector<ROWS>;
 
     type DualMatrix<const ROWS: usize, const COLS: usize> = DualMatrix<ROWS, COLS>;
@@ -611,9 +643,7 @@ impl Sub<&DualScalar> for DualScalar {
 fn dual_scalar_tests() {
     use crate::calculus::maps::curves::ScalarValuedCurve;
 
-    #[cfg(feature = "simd")]
     use crate::calculus::dual::DualBatchScalar;
-    #[cfg(feature = "simd")]
     use crate::linalg::BatchScalarF64;
 
     trait DualScalarTest {
@@ -755,18 +785,12 @@ fn dual_scalar_tests() {
     }
 
     def_dual_scalar_test_template!(1, f64, DualScalar);
-    #[cfg(feature = "simd")]
     def_dual_scalar_test_template!(2, BatchScalarF64<2>, DualBatchScalar<2>);
-    #[cfg(feature = "simd")]
     def_dual_scalar_test_template!(4, BatchScalarF64<4>, DualBatchScalar<4>);
-    #[cfg(feature = "simd")]
     def_dual_scalar_test_template!(8, BatchScalarF64<8>, DualBatchScalar<8>);
 
     DualScalar::run_dual_scalar_test();
-    #[cfg(feature = "simd")]
     DualBatchScalar::<2>::run_dual_scalar_test();
-    #[cfg(feature = "simd")]
     DualBatchScalar::<4>::run_dual_scalar_test();
-    #[cfg(feature = "simd")]
     DualBatchScalar::<8>::run_dual_scalar_test();
 }
@@ -0,0 +1,343 @@
+use super::dual_matrix::DualMatrix;
+use super::hyperdual_scalar::HyperDualScalar;
+use super::hyperdual_vector::HyperDualVector;
+use crate::linalg::MatF64;
+use crate::prelude::*;
+use approx::AbsDiffEq;
+use approx::RelativeEq;
+use core::ops::Add;
+use core::ops::Mul;
+use core::ops::Neg;
+use core::ops::Sub;
+
+/// Hyper-dual matrix
+///
+/// Matrix of hyper-dual numbers, stored as four real matrices - one for each part.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HyperDualMatrix<const ROWS: usize, const COLS: usize> {
+    /// real part
+    pub real_part: MatF64<ROWS, COLS>,
+    /// first infinitesimal part - represents derivative in ϵ₁ direction
+    pub eps1_part: MatF64<ROWS, COLS>,
+    /// second infinitesimal part - represents derivative in ϵ₂ direction
+    pub eps2_part: MatF64<ROWS, COLS>,
+    /// mixed infinitesimal part - represents second derivative in ϵ₁ and ϵ₂ direction
+    pub eps1eps2_part: MatF64<ROWS, COLS>,
+}
+
+impl<const ROWS: usize, const COLS: usize> HyperDualMatrix<ROWS, COLS> {
+    /// Applies the real-valued linear operation to all four parts
+    fn map<const R: usize, const C: usize, F: Fn(&MatF64<ROWS, COLS>) -> MatF64<R, C>>(
+        &self,
+        op: F,
+    ) -> HyperDualMatrix<R, C> {
+        HyperDualMatrix {
+            real_part: op(&self.real_part),
+            eps1_part: op(&self.eps1_part),
+            eps2_part: op(&self.eps2_part),
+            eps1eps2_part: op(&self.eps1eps2_part),
+        }
+    }
+}
+
+impl<const ROWS: usize, const COLS: usize> IsSingleMatrix<HyperDualScalar, ROWS, COLS>
+    for HyperDualMatrix<ROWS, COLS>
+{
+}
+
+impl<const ROWS: usize, const COLS: usize> AbsDiffEq for HyperDualMatrix<ROWS, COLS> {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> Self::Epsilon {
+        f64::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.real_part.abs_diff_eq(&other.real_part, epsilon)
+    }
+}
+
+impl<const ROWS: usize, const COLS: usize> RelativeEq for HyperDualMatrix<ROWS, COLS> {
+    fn default_max_relative() -> Self::Epsilon {
+        f64::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.real_part
+            .relative_eq(&other.real_part, epsilon, max_relative)
+    }
+}
+
+impl<const ROWS: usize, const COLS: usize> IsMatrix<HyperDualScalar, ROWS, COLS, 1>
+    for HyperDualMatrix<ROWS, COLS>
+{
+    fn from_scalar(val: HyperDualScalar) -> Self {
+        HyperDualMatrix {
+            real_part: MatF64::from_element(val.real_part),
+            eps1_part: MatF64::from_element(val.eps1_part),
+            eps2_part: MatF64::from_element(val.eps2_part),
+            eps1eps2_part: MatF64::from_element(val.eps1eps2_part),
+        }
+    }
+
+    fn from_real_matrix(val: MatF64<ROWS, COLS>) -> Self {
+        Self {
+            real_part: val,
+            eps1_part: MatF64::zeros(),
+            eps2_part: MatF64::zeros(),
+            eps1eps2_part: MatF64::zeros(),
+        }
+    }
+
+    fn from_array2(vals: [[HyperDualScalar; COLS]; ROWS]) -> Self {
+        let mut out = Self::zeros();
+        for r in 0..ROWS {
+            for c in 0..COLS {
+                out.set_elem([r, c], vals[r][c]);
+            }
+        }
+        out
+    }
+
+    fn from_real_scalar_array2(vals: [[f64; COLS]; ROWS]) -> Self {
+        Self::from_real_matrix(MatF64::from_real_scalar_array2(vals))
+    }
+
+    fn from_f64_array2(vals: [[f64; COLS]; ROWS]) -> Self {
+        Self::from_real_matrix(MatF64::from_f64_array2(vals))
+    }
+
+    fn from_f64(val: f64) -> Self {
+        Self::from_real_matrix(MatF64::from_element(val))
+    }
+
+    fn identity() -> Self {
+        Self::from_real_matrix(MatF64::identity())
+    }
+
+    fn real_matrix(&self) -> &MatF64<ROWS, COLS> {
+        &self.real_part
+    }
+
+    fn get_elem(&self, idx: [usize; 2]) -> HyperDualScalar {
+        HyperDualScalar {
+            real_part: self.real_part[(idx[0], idx[1])],
+            eps1_part: self.eps1_part[(idx[0], idx[1])],
+            eps2_part: self.eps2_part[(idx[0], idx[1])],
+            eps1eps2_part: self.eps1eps2_part[(idx[0], idx[1])],
+        }
+    }
+
+    fn set_elem(&mut self, idx: [usize; 2], val: HyperDualScalar) {
+        self.real_part[(idx[0], idx[1])] = val.real_part;
+        self.eps1_part[(idx[0], idx[1])] = val.eps1_part;
+        self.eps2_part[(idx[0], idx[1])] = val.eps2_part;
+        self.eps1eps2_part[(idx[0], idx[1])] = val.eps1eps2_part;
+    }
+
+    fn mat_mul<const C2: usize>(
+        &self,
+        other: HyperDualMatrix<COLS, C2>,
+    ) -> HyperDualMatrix<ROWS, C2> {
+        HyperDualMatrix {
+            real_part: self.real_part * other.real_part,
+            eps1_part: self.eps1_part * other.real_part + self.real_part * other.eps1_part,
+            eps2_part: self.eps2_part * other.real_part + self.real_part * other.eps2_part,
+            eps1eps2_part: self.eps1eps2_part * other.real_part
+                + self.eps1_part * other.eps2_part
+                + self.eps2_part * other.eps1_part
+                + self.real_part * other.eps1eps2_part,
+        }
+    }
+
+    fn block_mat2x1<const R0: usize, const R1: usize>(
+        top_row: HyperDualMatrix<R0, COLS>,
+        bot_row: HyperDualMatrix<R1, COLS>,
+    ) -> Self {
+        HyperDualMatrix {
+            real_part: MatF64::block_mat2x1(top_row.real_part, bot_row.real_part),
+            eps1_part: MatF64::block_mat2x1(top_row.eps1_part, bot_row.eps1_part),
+            eps2_part: MatF64::block_mat2x1(top_row.eps2_part, bot_row.eps2_part),
+            eps1eps2_part: MatF64::block_mat2x1(top_row.eps1eps2_part, bot_row.eps1eps2_part),
+        }
+    }
+
+    fn block_mat1x2<const C0: usize, const C1: usize>(
+        left_col: HyperDualMatrix<ROWS, C0>,
+        righ_col: HyperDualMatrix<ROWS, C1>,
+    ) -> Self {
+        HyperDualMatrix {
+            real_part: MatF64::block_mat1x2(left_col.real_part, righ_col.real_part),
+            eps1_part: MatF64::block_mat1x2(left_col.eps1_part, righ_col.eps1_part),
+            eps2_part: MatF64::block_mat1x2(left_col.eps2_part, righ_col.eps2_part),
+            eps1eps2_part: MatF64::block_mat1x2(left_col.eps1eps2_part, righ_col.eps1eps2_part),
+        }
+    }
+
+    fn block_mat2x2<const R0: usize, const R1: usize, const C0: usize, const C1: usize>(
+        top_row: (HyperDualMatrix<R0, C0>, HyperDualMatrix<R0, C1>),
+        bot_row: (HyperDualMatrix<R1, C0>, HyperDualMatrix<R1, C1>),
+    ) -> Self {
+        assert_eq!(ROWS, R0 + R1);
+        assert_eq!(COLS, C0 + C1);
+        Self::block_mat2x1(
+            HyperDualMatrix::<R0, COLS>::block_mat1x2(top_row.0, top_row.1),
+            HyperDualMatrix::<R1, COLS>::block_mat1x2(bot_row.0, bot_row.1),
+        )
+    }
+
+    fn get_fixed_submat<const R: usize, const C: usize>(
+        &self,
+        start_r: usize,
+        start_c: usize,
+    ) -> HyperDualMatrix<R, C> {
+        self.map(|m| m.fixed_view::<R, C>(start_r, start_c).into())
+    }
+
+    fn get_col_vec(&self, c: usize) -> HyperDualVector<ROWS> {
+        let col = self.map(|m| m.fixed_view::<ROWS, 1>(0, c).into());
+        HyperDualVector {
+            real_part: col.real_part,
+            eps1_part: col.eps1_part,
+            eps2_part: col.eps2_part,
+            eps1eps2_part: col.eps1eps2_part,
+        }
+    }
+
+    fn get_row_vec(&self, r: usize) -> HyperDualVector<COLS> {
+        let row = self.map(|m| m.fixed_view::<1, COLS>(r, 0).transpose());
+        HyperDualVector {
+            real_part: row.real_part,
+            eps1_part: row.eps1_part,
+            eps2_part: row.eps2_part,
+            eps1eps2_part: row.eps1eps2_part,
+        }
+    }
+
+    fn scaled(&self, s: HyperDualScalar) -> Self {
+        HyperDualMatrix {
+            real_part: self.real_part * s.real_part,
+            eps1_part: self.eps1_part * s.real_part + self.real_part * s.eps1_part,
+            eps2_part: self.eps2_part * s.real_part + self.real_part * s.eps2_part,
+            eps1eps2_part: self.eps1eps2_part * s.real_part
+                + self.eps1_part * s.eps2_part
+                + self.eps2_part * s.eps1_part
+                + self.real_part * s.eps1eps2_part,
+        }
+    }
+
+    fn set_col_vec(&mut self, c: usize, v: HyperDualVector<ROWS>) {
+        self.real_part
+            .fixed_columns_mut::<1>(c)
+            .copy_from(&v.real_part);
+        self.eps1_part
+            .fixed_columns_mut::<1>(c)
+            .copy_from(&v.eps1_part);
+        self.eps2_part
+            .fixed_columns_mut::<1>(c)
+            .copy_from(&v.eps2_part);
+        self.eps1eps2_part
+            .fixed_columns_mut::<1>(c)
+            .copy_from(&v.eps1eps2_part);
+    }
+
+    /// Returns the real part as a dual matrix - the infinitesimal parts are dropped.
+    fn to_dual(self) -> DualMatrix<ROWS, COLS> {
+        DualMatrix::from_real_matrix(self.real_part)
+    }
+
+    fn select(self, mask: &bool, other: Self) -> Self {
+        if *mask {
+            self
+        } else {
+            other
+        }
+    }
+}
+
+impl<const ROWS: usize, const COLS: usize> Add for HyperDualMatrix<ROWS, COLS> {
+    type Output = HyperDualMatrix<ROWS, COLS>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        HyperDualMatrix {
+            real_part: self.real_part + rhs.real_part,
+            eps1_part: self.eps1_part + rhs.eps1_part,
+            eps2_part: self.eps2_part + rhs.eps2_part,
+            eps1eps2_part: self.eps1eps2_part + rhs.eps1eps2_part,
+        }
+    }
+}
+
+impl<const ROWS: usize, const COLS: usize> Sub for HyperDualMatrix<ROWS, COLS> {
+    type Output = HyperDualMatrix<ROWS, COLS>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        HyperDualMatrix {
+            real_part: self.real_part - rhs.real_part,
+            eps1_part: self.eps1_part - rhs.eps1_part,
+            eps2_part: self.eps2_part - rhs.eps2_part,
+            eps1eps2_part: self.eps1eps2_part - rhs.eps1eps2_part,
+        }
+    }
+}
+
+impl<const ROWS: usize, const COLS: usize> Neg for HyperDualMatrix<ROWS, COLS> {
+    type Output = HyperDualMatrix<ROWS, COLS>;
+
+    fn neg(self) -> Self::Output {
+        self.map(|m| -m)
+    }
+}
+
+impl<const ROWS: usize, const COLS: usize> Mul<HyperDualVector<COLS>>
+    for HyperDualMatrix<ROWS, COLS>
+{
+    type Output = HyperDualVector<ROWS>;
+
+    fn mul(self, rhs: HyperDualVector<COLS>) -> Self::Output {
+        HyperDualVector {
+            real_part: self.real_part * rhs.real_part,
+            eps1_part: self.eps1_part * rhs.real_part + self.real_part * rhs.eps1_part,
+            eps2_part: self.eps2_part * rhs.real_part + self.real_part * rhs.eps2_part,
+            eps1eps2_part: self.eps1eps2_part * rhs.real_part
+                + self.eps1_part * rhs.eps2_part
+                + self.eps2_part * rhs.eps1_part
+                + self.real_part * rhs.eps1eps2_part,
+        }
+    }
+}
+
+#[test]
+fn hyperdual_matrix_tests() {
+    use crate::calculus::dual::DualScalar;
+    use crate::calculus::maps::scalar_valued_maps::ScalarValuedMapFromVector;
+    use crate::calculus::maps::vector_valued_maps::VectorValuedMapFromVector;
+    use crate::linalg::VecF64;
+
+    // f(x) = xᵀ·A(x)·x + |B·x|, with A(x) depending on x
+    fn f<S: IsScalar<1>>(x: S::Vector<3>) -> S {
+        let b = S::Matrix::<2, 3>::from_f64_array2([[1.0, 0.5, -0.4], [0.2, -1.3, 0.7]]);
+        let mut a = S::Matrix::<3, 3>::identity().scaled(x.get_elem(0).sin());
+        a.set_elem([0, 2], x.get_elem(1).clone() * x.get_elem(2));
+        let a = a.mat_mul(S::Matrix::<3, 3>::from_f64_array2([
+            [0.1, 2.0, 0.0],
+            [-0.3, 1.0, 0.4],
+            [0.0, 0.2, 1.5],
+        ]));
+        let a_x = a * x.clone();
+        x.clone().dot(a_x) + (b * x).norm()
+    }
+    fn grad(x: VecF64<3>) -> VecF64<3> {
+        ScalarValuedMapFromVector::<DualScalar, 1>::fw_autodiff(f::<DualScalar>, x)
+    }
+
+    let x = VecF64::<3>::new(0.6, 0.2, -1.1);
+    let hessian = ScalarValuedMapFromVector::<HyperDualScalar, 1>::fw_hessian(f, x);
+    let finite_diff = VectorValuedMapFromVector::<f64, 1>::static_sym_diff_quotient(grad, x, 1e-6);
+    approx::assert_abs_diff_eq!(hessian, finite_diff, epsilon = 1e-5);
+}
@@ -0,0 +1,452 @@
+use super::dual_matrix::DualMatrix;
+use super::dual_scalar::DualScalar;
+use super::dual_vector::DualVector;
+use super::hyperdual_matrix::HyperDualMatrix;
+use super::hyperdual_vector::HyperDualVector;
+use alloc::vec::Vec;
+
+use crate::linalg::scalar::NumberCategory;
+use crate::linalg::MatF64;
+use crate::linalg::VecF64;
+use crate::prelude::*;
+
+use approx::AbsDiffEq;
+use approx::RelativeEq;
+use num_traits::One;
+use num_traits::Zero;
+
+use core::ops::Add;
+use core::ops::AddAssign;
+use core::ops::Div;
+use core::ops::Mul;
+use core::ops::Neg;
+use core::ops::Sub;
+use core::ops::SubAssign;
+
+/// Hyper-dual number - a real number and three infinitesimal numbers
+///
+///   x = a + b ϵ₁ + c ϵ₂ + d ϵ₁ϵ₂,  with ϵ₁² = ϵ₂² = 0, but ϵ₁ϵ₂ ≠ 0
+///
+/// Seeding ϵ₁ in direction i and ϵ₂ in direction j, the ϵ₁ϵ₂ part of f(x) is the second
+/// derivative ∂²f/∂xᵢ∂xⱼ, free of any truncation error. See
+/// ScalarValuedMapFromVector::fw_hessian(...).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HyperDualScalar {
+    /// real part
+    pub real_part: f64,
+    /// first infinitesimal part - represents derivative in ϵ₁ direction
+    pub eps1_part: f64,
+    /// second infinitesimal part - represents derivative in ϵ₂ direction
+    pub eps2_part: f64,
+    /// mixed infinitesimal part - represents second derivative in ϵ₁ and ϵ₂ direction
+    pub eps1eps2_part: f64,
+}
+
+impl HyperDualScalar {
+    /// Create a new hyper-dual scalar from its four parts
+    pub fn new(real_part: f64, eps1_part: f64, eps2_part: f64, eps1eps2_part: f64) -> Self {
+        Self {
+            real_part,
+            eps1_part,
+            eps2_part,
+            eps1eps2_part,
+        }
+    }
+
+    /// Create a new hyper-dual scalar for second order auto-differentiation with respect to self
+    ///
+    /// Both first order parts are set to one: (val, 1ϵ₁, 1ϵ₂, 0ϵ₁ϵ₂)
+    pub fn new_with_eps(val: f64) -> Self {
+        Self::new(val, 1.0, 1.0, 0.0)
+    }
+
+    /// Applies a function given its value, first and second derivative at the real part
+    fn chain(&self, f: f64, df: f64, ddf: f64) -> Self {
+        Self {
+            real_part: f,
+            eps1_part: df * self.eps1_part,
+            eps2_part: df * self.eps2_part,
+            eps1eps2_part: df * self.eps1eps2_part + ddf * self.eps1_part * self.eps2_part,
+        }
+    }
+}
+
+impl AbsDiffEq for HyperDualScalar {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> Self::Epsilon {
+        1e-6
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.real_part.abs_diff_eq(&other.real_part, epsilon)
+    }
+}
+
+impl RelativeEq for HyperDualScalar {
+    fn default_max_relative() -> Self::Epsilon {
+        1e-6
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.real_part
+            .relative_eq(&other.real_part, epsilon, max_relative)
+    }
+}
+
+impl IsCoreScalar for HyperDualScalar {
+    fn number_category() -> NumberCategory {
+        NumberCategory::Real
+    }
+}
+
+impl IsSingleScalar for HyperDualScalar {
+    type SingleVector<const ROWS: usize> = HyperDualVector<ROWS>;
+    type SingleMatrix<const ROWS: usize, const COLS: usize> = HyperDualMatrix<ROWS, COLS>;
+
+    fn single_real_scalar(&self) -> f64 {
+        self.real_part
+    }
+
+    fn single_scalar(&self) -> Self {
+        *self
+    }
+
+    fn i64_floor(&self) -> i64 {
+        self.real_part.floor() as i64
+    }
+}
+
+impl One for HyperDualScalar {
+    fn one() -> Self {
+        <HyperDualScalar>::from_f64(1.0)
+    }
+}
+
+impl Zero for HyperDualScalar {
+    fn zero() -> Self {
+        <HyperDualScalar>::from_f64(0.0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.real_part == 0.0
+    }
+}
+
+impl PartialOrd for HyperDualScalar {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.real_part.partial_cmp(&other.real_part)
+    }
+}
+
+impl From<f64> for HyperDualScalar {
+    fn from(value: f64) -> Self {
+        Self::from_real_scalar(value)
+    }
+}
+
+impl Neg for HyperDualScalar {
+    type Output = HyperDualScalar;
+
+    fn neg(self) -> Self {
+        Self::new(
+            -self.real_part,
+            -self.eps1_part,
+            -self.eps2_part,
+            -self.eps1eps2_part,
+        )
+    }
+}
+
+impl IsScalar<1> for HyperDualScalar {
+    type Scalar = HyperDualScalar;
+    type RealScalar = f64;
+    type SingleScalar = HyperDualScalar;
+    type DualScalar = DualScalar;
+    type Float = f64;
+
+    type RealMatrix<const ROWS: usize, const COLS: usize> = MatF64<ROWS, COLS>;
+    type RealVector<const ROWS: usize> = VecF64<ROWS>;
+
+    type Vector<const ROWS: usize> = HyperDualVector<ROWS>;
+    type Matrix<const ROWS: usize, const COLS: usize> = HyperDualMatrix<ROWS, COLS>;
+
+    type DualVector<const ROWS: usize> = DualVector<ROWS>;
+    type DualMatrix<const ROWS: usize, const COLS: usize> = DualMatrix<ROWS, COLS>;
+
+    type Mask = bool;
+
+    fn from_real_scalar(val: f64) -> Self {
+        Self::new(val, 0.0, 0.0, 0.0)
+    }
+
+    fn from_real_array(arr: [f64; 1]) -> Self {
+        Self::from_f64(arr[0])
+    }
+
+    fn to_real_array(&self) -> [f64; 1] {
+        [self.real_part]
+    }
+
+    fn cos(self) -> Self {
+        let (s, c) = num_traits::Float::sin_cos(self.real_part);
+        self.chain(c, -s, -c)
+    }
+
+    fn sin(self) -> Self {
+        let (s, c) = num_traits::Float::sin_cos(self.real_part);
+        self.chain(s, c, -s)
+    }
+
+    fn abs(self) -> Self {
+        self.chain(self.real_part.abs(), self.real_part.signum(), 0.0)
+    }
+
+    fn atan2(self, rhs: Self) -> Self {
+        // g(y, x) = atan2(y, x), with y = self and x = rhs
+        let y = self.real_part;
+        let x = rhs.real_part;
+        let inv_sq_nrm = 1.0 / (x * x + y * y);
+        let inv_sq_nrm2 = inv_sq_nrm * inv_sq_nrm;
+
+        let g_y = x * inv_sq_nrm;
+        let g_x = -y * inv_sq_nrm;
+        let g_yy = -2.0 * x * y * inv_sq_nrm2;
+        let g_xx = -g_yy;
+        let g_xy = (y * y - x * x) * inv_sq_nrm2;
+
+        Self {
+            real_part: y.atan2(x),
+            eps1_part: g_y * self.eps1_part + g_x * rhs.eps1_part,
+            eps2_part: g_y * self.eps2_part + g_x * rhs.eps2_part,
+            eps1eps2_part: g_y * self.eps1eps2_part
+                + g_x * rhs.eps1eps2_part
+                + g_yy * self.eps1_part * self.eps2_part
+                + g_xy * (self.eps1_part * rhs.eps2_part + self.eps2_part * rhs.eps1_part)
+                + g_xx * rhs.eps1_part * rhs.eps2_part,
+        }
+    }
+
+    fn real_part(&self) -> f64 {
+        self.real_part
+    }
+
+    fn sqrt(self) -> Self {
+        let sqrt = self.real_part.sqrt();
+        let d = 0.5 / sqrt;
+        self.chain(sqrt, d, -0.5 * d / self.real_part)
+    }
+
+    fn exp(self) -> Self {
+        let exp = self.real_part.exp();
+        self.chain(exp, exp, exp)
+    }
+
+    fn ln(self) -> Self {
+        let inv = 1.0 / self.real_part;
+        self.chain(self.real_part.ln(), inv, -inv * inv)
+    }
+
+    fn to_vec(self) -> HyperDualVector<1> {
+        HyperDualVector::<1>::from_array([self])
+    }
+
+    fn tan(self) -> Self {
+        let tan = self.real_part.tan();
+        let sec_squared = 1.0 + tan * tan;
+        self.chain(tan, sec_squared, 2.0 * tan * sec_squared)
+    }
+
+    fn acos(self) -> Self {
+        let one_minus_sq = 1.0 - self.real_part * self.real_part;
+        let d = 1.0 / one_minus_sq.sqrt();
+        self.chain(
+            self.real_part.acos(),
+            -d,
+            -self.real_part * d / one_minus_sq,
+        )
+    }
+
+    fn asin(self) -> Self {
+        let one_minus_sq = 1.0 - self.real_part * self.real_part;
+        let d = 1.0 / one_minus_sq.sqrt();
+        self.chain(self.real_part.asin(), d, self.real_part * d / one_minus_sq)
+    }
+
+    fn atan(self) -> Self {
+        let d = 1.0 / (1.0 + self.real_part * self.real_part);
+        self.chain(self.real_part.atan(), d, -2.0 * self.real_part * d * d)
+    }
+
+    fn fract(self) -> Self {
+        self.chain(self.real_part.fract(), 1.0, 0.0)
+    }
+
+    fn floor(&self) -> f64 {
+        self.real_part.floor()
+    }
+
+    fn from_f64(val: f64) -> Self {
+        Self::from_real_scalar(val)
+    }
+
+    fn scalar_examples() -> Vec<Self> {
+        [1.0, 2.0, 3.0].iter().map(|&v| Self::from_f64(v)).collect()
+    }
+
+    fn extract_single(&self, _i: usize) -> Self::SingleScalar {
+        *self
+    }
+
+    fn signum(&self) -> Self {
+        Self::from_f64(self.real_part.signum())
+    }
+
+    fn less_equal(&self, rhs: &Self) -> Self::Mask {
+        self.real_part.less_equal(&rhs.real_part)
+    }
+
+    fn greater_equal(&self, rhs: &Self) -> Self::Mask {
+        self.real_part.greater_equal(&rhs.real_part)
+    }
+
+    /// Returns the real part as a dual number - the infinitesimal parts are dropped.
+    fn to_dual(self) -> Self::DualScalar {
+        DualScalar::from_f64(self.real_part)
+    }
+
+    fn select(self, mask: &Self::Mask, other: Self) -> Self {
+        if *mask {
+            self
+        } else {
+            other
+        }
+    }
+}
+
+impl AddAssign<HyperDualScalar> for HyperDualScalar {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign<HyperDualScalar> for HyperDualScalar {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Add<HyperDualScalar> for HyperDualScalar {
+    type Output = HyperDualScalar;
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.real_part + rhs.real_part,
+            self.eps1_part + rhs.eps1_part,
+            self.eps2_part + rhs.eps2_part,
+            self.eps1eps2_part + rhs.eps1eps2_part,
+        )
+    }
+}
+
+impl Sub<HyperDualScalar> for HyperDualScalar {
+    type Output = HyperDualScalar;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.real_part - rhs.real_part,
+            self.eps1_part - rhs.eps1_part,
+            self.eps2_part - rhs.eps2_part,
+            self.eps1eps2_part - rhs.eps1eps2_part,
+        )
+    }
+}
+
+impl Mul<HyperDualScalar> for HyperDualScalar {
+    type Output = HyperDualScalar;
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.real_part * rhs.real_part,
+            self.real_part * rhs.eps1_part + self.eps1_part * rhs.real_part,
+            self.real_part * rhs.eps2_part + self.eps2_part * rhs.real_part,
+            self.real_part * rhs.eps1eps2_part
+                + self.eps1_part * rhs.eps2_part
+                + self.eps2_part * rhs.eps1_part
+                + self.eps1eps2_part * rhs.real_part,
+        )
+    }
+}
+
+impl Div<HyperDualScalar> for HyperDualScalar {
+    type Output = HyperDualScalar;
+    fn div(self, rhs: Self) -> Self::Output {
+        let inv = 1.0 / rhs.real_part;
+        self * rhs.chain(inv, -inv * inv, 2.0 * inv * inv * inv)
+    }
+}
+
+#[test]
+fn hyperdual_scalar_tests() {
+    use crate::calculus::maps::curves::ScalarValuedCurve;
+
+    fn second_diff_quotient<TFn: Fn(f64) -> f64>(f: TFn, a: f64, h: f64) -> f64 {
+        (f(a + h) - 2.0 * f(a) + f(a - h)) / (h * h)
+    }
+
+    macro_rules! check_unary {
+        ($a:expr, |$x:ident: $s:ident| $body:expr) => {{
+            let f = |$x: f64| -> f64 {
+                #[allow(dead_code)]
+                type $s = f64;
+                $body
+            };
+            let hyper_f = |$x: HyperDualScalar| -> HyperDualScalar {
+                #[allow(dead_code)]
+                type $s = HyperDualScalar;
+                $body
+            };
+            let hyper = hyper_f(HyperDualScalar::new_with_eps($a));
+
+            approx::assert_abs_diff_eq!(hyper.real_part, f($a), epsilon = 1e-12);
+            let first = ScalarValuedCurve::sym_diff_quotient(f, $a, 1e-6);
+            approx::assert_abs_diff_eq!(hyper.eps1_part, first, epsilon = 1e-5);
+            approx::assert_abs_diff_eq!(hyper.eps2_part, first, epsilon = 1e-5);
+            let second = second_diff_quotient(f, $a, 1e-4);
+            approx::assert_relative_eq!(
+                hyper.eps1eps2_part,
+                second,
+                epsilon = 1e-4,
+                max_relative = 1e-5
+            );
+        }};
+    }
+
+    for i in 1..10 {
+        let a = 0.09 * (i as f64);
+
+        check_unary!(a, |x: S| x * x);
+        check_unary!(a, |x: S| x * x * x);
+        check_unary!(a, |x: S| x / S::from_f64(0.7));
+        check_unary!(a, |x: S| S::from_f64(0.7) / x);
+        check_unary!(a, |x: S| S::from_f64(0.7) - x * x);
+        check_unary!(a, |x: S| -x + S::from_f64(0.7));
+        check_unary!(a, |x: S| IsScalar::sin(x));
+        check_unary!(a, |x: S| IsScalar::cos(x));
+        check_unary!(a, |x: S| IsScalar::tan(x));
+        check_unary!(a, |x: S| IsScalar::exp(x));
+        check_unary!(a, |x: S| IsScalar::ln(x));
+        check_unary!(a, |x: S| IsScalar::sqrt(x));
+        check_unary!(a, |x: S| IsScalar::acos(x));
+        check_unary!(a, |x: S| IsScalar::asin(x));
+        check_unary!(a, |x: S| IsScalar::atan(x));
+        check_unary!(a, |x: S| IsScalar::abs(x - S::from_f64(0.7)));
+        check_unary!(a, |x: S| IsScalar::atan2(x, S::from_f64(0.7)));
+        check_unary!(a, |x: S| IsScalar::atan2(S::from_f64(0.7), x));
+        check_unary!(a, |x: S| IsScalar::atan2(x * x, x));
+    }
+
+    HyperDualScalar::test_suite();
+}
@@ -0,0 +1,300 @@
+use super::dual_vector::DualVector;
+use super::hyperdual_matrix::HyperDualMatrix;
+use super::hyperdual_scalar::HyperDualScalar;
+use crate::linalg::VecF64;
+use crate::prelude::*;
+use approx::AbsDiffEq;
+use approx::RelativeEq;
+use core::ops::Add;
+use core::ops::Neg;
+use core::ops::Sub;
+
+/// Hyper-dual vector
+///
+/// Vector of hyper-dual numbers, stored as four real vectors - one for each part.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HyperDualVector<const ROWS: usize> {
+    /// real part
+    pub real_part: VecF64<ROWS>,
+    /// first infinitesimal part - represents derivative in ϵ₁ direction
+    pub eps1_part: VecF64<ROWS>,
+    /// second infinitesimal part - represents derivative in ϵ₂ direction
+    pub eps2_part: VecF64<ROWS>,
+    /// mixed infinitesimal part - represents second derivative in ϵ₁ and ϵ₂ direction
+    pub eps1eps2_part: VecF64<ROWS>,
+}
+
+impl<const ROWS: usize> HyperDualVector<ROWS> {
+    /// Create a new hyper-dual vector for second order auto-differentiation
+    ///
+    /// The ϵ₁ part is seeded in direction i and the ϵ₂ part in direction j. Hence, the ϵ₁ϵ₂ part
+    /// of a scalar-valued function f(self) is the second derivative ∂²f/∂xᵢ∂xⱼ.
+    ///
+    /// Typically this is not called directly, but through the hessian call:
+    ///
+    ///  - ScalarValuedMapFromVector::fw_hessian(...);
+    pub fn new_with_eps(val: VecF64<ROWS>, i: usize, j: usize) -> Self {
+        let mut eps1_part = VecF64::<ROWS>::zeros();
+        eps1_part[i] = 1.0;
+        let mut eps2_part = VecF64::<ROWS>::zeros();
+        eps2_part[j] = 1.0;
+
+        Self {
+            real_part: val,
+            eps1_part,
+            eps2_part,
+            eps1eps2_part: VecF64::<ROWS>::zeros(),
+        }
+    }
+}
+
+impl<const ROWS: usize> Neg for HyperDualVector<ROWS> {
+    type Output = HyperDualVector<ROWS>;
+
+    fn neg(self) -> Self::Output {
+        HyperDualVector {
+            real_part: -self.real_part,
+            eps1_part: -self.eps1_part,
+            eps2_part: -self.eps2_part,
+            eps1eps2_part: -self.eps1eps2_part,
+        }
+    }
+}
+
+impl<const ROWS: usize> Sub for HyperDualVector<ROWS> {
+    type Output = HyperDualVector<ROWS>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        HyperDualVector {
+            real_part: self.real_part - rhs.real_part,
+            eps1_part: self.eps1_part - rhs.eps1_part,
+            eps2_part: self.eps2_part - rhs.eps2_part,
+            eps1eps2_part: self.eps1eps2_part - rhs.eps1eps2_part,
+        }
+    }
+}
+
+impl<const ROWS: usize> Add for HyperDualVector<ROWS> {
+    type Output = HyperDualVector<ROWS>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        HyperDualVector {
+            real_part: self.real_part + rhs.real_part,
+            eps1_part: self.eps1_part + rhs.eps1_part,
+            eps2_part: self.eps2_part + rhs.eps2_part,
+            eps1eps2_part: self.eps1eps2_part + rhs.eps1eps2_part,
+        }
+    }
+}
+
+impl<const ROWS: usize> AbsDiffEq for HyperDualVector<ROWS> {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> Self::Epsilon {
+        f64::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.real_part.abs_diff_eq(&other.real_part, epsilon)
+    }
+}
+
+impl<const ROWS: usize> RelativeEq for HyperDualVector<ROWS> {
+    fn default_max_relative() -> Self::Epsilon {
+        f64::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.real_part
+            .relative_eq(&other.real_part, epsilon, max_relative)
+    }
+}
+
+impl<const ROWS: usize> IsSingleVector<HyperDualScalar, ROWS> for HyperDualVector<ROWS> {
+    fn set_real_scalar(&mut self, idx: usize, v: f64) {
+        self.real_part[idx] = v;
+    }
+}
+
+impl<const ROWS: usize> IsVector<HyperDualScalar, ROWS, 1> for HyperDualVector<ROWS> {
+    fn from_f64(val: f64) -> Self {
+        Self::from_real_vector(VecF64::<ROWS>::from_element(val))
+    }
+
+    fn norm(&self) -> HyperDualScalar {
+        self.squared_norm().sqrt()
+    }
+
+    fn squared_norm(&self) -> HyperDualScalar {
+        self.dot(*self)
+    }
+
+    fn get_elem(&self, idx: usize) -> HyperDualScalar {
+        HyperDualScalar {
+            real_part: self.real_part[idx],
+            eps1_part: self.eps1_part[idx],
+            eps2_part: self.eps2_part[idx],
+            eps1eps2_part: self.eps1eps2_part[idx],
+        }
+    }
+
+    fn from_array(vals: [HyperDualScalar; ROWS]) -> Self {
+        let mut out = Self::zeros();
+        for (i, v) in vals.into_iter().enumerate() {
+            out.set_elem(i, v);
+        }
+        out
+    }
+
+    fn from_real_array(vals: [f64; ROWS]) -> Self {
+        Self::from_real_vector(VecF64::from_real_array(vals))
+    }
+
+    fn from_real_vector(val: VecF64<ROWS>) -> Self {
+        Self {
+            real_part: val,
+            eps1_part: VecF64::zeros(),
+            eps2_part: VecF64::zeros(),
+            eps1eps2_part: VecF64::zeros(),
+        }
+    }
+
+    fn real_vector(&self) -> &VecF64<ROWS> {
+        &self.real_part
+    }
+
+    fn to_mat(self) -> HyperDualMatrix<ROWS, 1> {
+        HyperDualMatrix {
+            real_part: self.real_part,
+            eps1_part: self.eps1_part,
+            eps2_part: self.eps2_part,
+            eps1eps2_part: self.eps1eps2_part,
+        }
+    }
+
+    fn block_vec2<const R0: usize, const R1: usize>(
+        top_row: HyperDualVector<R0>,
+        bot_row: HyperDualVector<R1>,
+    ) -> Self {
+        assert_eq!(R0 + R1, ROWS);
+
+        Self {
+            real_part: VecF64::block_vec2(top_row.real_part, bot_row.real_part),
+            eps1_part: VecF64::block_vec2(top_row.eps1_part, bot_row.eps1_part),
+            eps2_part: VecF64::block_vec2(top_row.eps2_part, bot_row.eps2_part),
+            eps1eps2_part: VecF64::block_vec2(top_row.eps1eps2_part, bot_row.eps1eps2_part),
+        }
+    }
+
+    fn scaled(&self, s: HyperDualScalar) -> Self {
+        HyperDualVector {
+            real_part: self.real_part * s.real_part,
+            eps1_part: self.eps1_part * s.real_part + self.real_part * s.eps1_part,
+            eps2_part: self.eps2_part * s.real_part + self.real_part * s.eps2_part,
+            eps1eps2_part: self.eps1eps2_part * s.real_part
+                + self.eps1_part * s.eps2_part
+                + self.eps2_part * s.eps1_part
+                + self.real_part * s.eps1eps2_part,
+        }
+    }
+
+    fn dot(self, rhs: Self) -> HyperDualScalar {
+        HyperDualScalar {
+            real_part: VecF64::dot(&self.real_part, &rhs.real_part),
+            eps1_part: VecF64::dot(&self.eps1_part, &rhs.real_part)
+                + VecF64::dot(&self.real_part, &rhs.eps1_part),
+            eps2_part: VecF64::dot(&self.eps2_part, &rhs.real_part)
+                + VecF64::dot(&self.real_part, &rhs.eps2_part),
+            eps1eps2_part: VecF64::dot(&self.eps1eps2_part, &rhs.real_part)
+                + VecF64::dot(&self.eps1_part, &rhs.eps2_part)
+                + VecF64::dot(&self.eps2_part, &rhs.eps1_part)
+                + VecF64::dot(&self.real_part, &rhs.eps1eps2_part),
+        }
+    }
+
+    fn normalized(&self) -> Self {
+        self.scaled(HyperDualScalar::from_f64(1.0) / self.norm())
+    }
+
+    fn from_f64_array(vals: [f64; ROWS]) -> Self {
+        Self::from_real_vector(VecF64::from_f64_array(vals))
+    }
+
+    fn from_scalar_array(vals: [HyperDualScalar; ROWS]) -> Self {
+        Self::from_array(vals)
+    }
+
+    fn set_elem(&mut self, idx: usize, v: HyperDualScalar) {
+        self.real_part[idx] = v.real_part;
+        self.eps1_part[idx] = v.eps1_part;
+        self.eps2_part[idx] = v.eps2_part;
+        self.eps1eps2_part[idx] = v.eps1eps2_part;
+    }
+
+    /// Returns the real part as a dual vector - the infinitesimal parts are dropped.
+    fn to_dual(self) -> DualVector<ROWS> {
+        DualVector::from_real_vector(self.real_part)
+    }
+
+    fn outer<const R2: usize>(self, rhs: HyperDualVector<R2>) -> HyperDualMatrix<ROWS, R2> {
+        HyperDualMatrix {
+            real_part: self.real_part * rhs.real_part.transpose(),
+            eps1_part: self.eps1_part * rhs.real_part.transpose()
+                + self.real_part * rhs.eps1_part.transpose(),
+            eps2_part: self.eps2_part * rhs.real_part.transpose()
+                + self.real_part * rhs.eps2_part.transpose(),
+            eps1eps2_part: self.eps1eps2_part * rhs.real_part.transpose()
+                + self.eps1_part * rhs.eps2_part.transpose()
+                + self.eps2_part * rhs.eps1_part.transpose()
+                + self.real_part * rhs.eps1eps2_part.transpose(),
+        }
+    }
+
+    fn select(self, mask: &bool, other: Self) -> Self {
+        if *mask {
+            self
+        } else {
+            other
+        }
+    }
+
+    fn get_fixed_subvec<const R: usize>(&self, start_r: usize) -> HyperDualVector<R> {
+        HyperDualVector {
+            real_part: self.real_part.fixed_rows::<R>(start_r).into(),
+            eps1_part: self.eps1_part.fixed_rows::<R>(start_r).into(),
+            eps2_part: self.eps2_part.fixed_rows::<R>(start_r).into(),
+            eps1eps2_part: self.eps1eps2_part.fixed_rows::<R>(start_r).into(),
+        }
+    }
+}
+
+#[test]
+fn hyperdual_vector_tests() {
+    use crate::calculus::dual::DualScalar;
+    use crate::calculus::maps::scalar_valued_maps::ScalarValuedMapFromVector;
+    use crate::calculus::maps::vector_valued_maps::VectorValuedMapFromVector;
+    use crate::linalg::vector::cross;
+
+    // f(x) = |x × a|² + |x|·(x ⋅ b), and its gradient using first order dual numbers
+    fn f<S: IsScalar<1>>(x: S::Vector<3>) -> S {
+        let a = S::Vector::<3>::from_f64_array([0.3, -0.2, 0.9]);
+        let b = S::Vector::<3>::from_f64_array([1.0, 0.5, -0.4]);
+        let x_cross_a = cross::<S, 1>(x.clone(), a);
+        let sub: S::Vector<2> = x.get_fixed_subvec(1);
+        x_cross_a.squared_norm() + x.norm() * x.clone().dot(b) + sub.normalized().get_elem(0)
+    }
+    fn grad(x: VecF64<3>) -> VecF64<3> {
+        ScalarValuedMapFromVector::<DualScalar, 1>::fw_autodiff(f::<DualScalar>, x)
+    }
+
+    let x = VecF64::<3>::new(0.6, 0.2, -1.1);
+    let hessian = ScalarValuedMapFromVector::<HyperDualScalar, 1>::fw_hessian(f, x);
+    let finite_diff = VectorValuedMapFromVector::<f64, 1>::static_sym_diff_quotient(grad, x, 1e-6);
+    approx::assert_abs_diff_eq!(hessian, finite_diff, epsilon = 1e-5);
+    approx::assert_abs_diff_eq!(hessian, hessian.transpose(), epsilon = 1e-10);
+}
@@ -0,0 +1,370 @@
+use super::jet_scalar::tangent_shape;
+use super::jet_scalar::JetScalar;
+use super::jet_vector::JetVector;
+use crate::linalg::MatF64;
+use crate::linalg::VecF64;
+use crate::prelude::*;
+use crate::tensor::mut_tensor::MutTensorDDRC;
+use approx::AbsDiffEq;
+use approx::RelativeEq;
+use core::ops::Add;
+use core::ops::Mul;
+use core::ops::Neg;
+use core::ops::Sub;
+
+/// Jet matrix
+///
+/// The infinitesimal part is an array of N matrices, where the k-th matrix is the derivative with
+/// respect to the k-th tangent direction. See [JetScalar] for the tangent layout TCOLS.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct JetMatrix<const ROWS: usize, const COLS: usize, const N: usize, const TCOLS: usize = 1> {
+    /// real part
+    pub real_part: MatF64<ROWS, COLS>,
+    /// infinitesimal part - represents derivative
+    pub dij_part: [MatF64<ROWS, COLS>; N],
+}
+
+impl<const ROWS: usize, const COLS: usize, const N: usize, const TCOLS: usize> IsDual
+    for JetMatrix<ROWS, COLS, N, TCOLS>
+{
+}
+
+impl<const ROWS: usize, const COLS: usize, const N: usize, const TCOLS: usize>
+    IsDualMatrix<JetScalar<N, TCOLS>, ROWS, COLS, 1> for JetMatrix<ROWS, COLS, N, TCOLS>
+{
+    /// Requires the tangent dimension N to be equal to ROWS * COLS, and TCOLS to be equal to COLS,
+    /// checked at compile time.
+    ///
+    /// The k-th tangent direction corresponds to the element (k / COLS, k % COLS).
+    fn new_with_dij(val: MatF64<ROWS, COLS>) -> Self {
+        const {
+            assert!(
+                N == ROWS * COLS && TCOLS == COLS,
+                "the tangent dimension must match the input dimension"
+            )
+        };
+        let mut dij_part = [MatF64::<ROWS, COLS>::zeros(); N];
+        for i in 0..ROWS {
+            for j in 0..COLS {
+                dij_part[i * COLS + j][(i, j)] = 1.0;
+            }
+        }
+
+        Self {
+            real_part: val,
+            dij_part,
+        }
+    }
+
+    /// Get the derivative - as a tensor of shape (N / TCOLS x TCOLS)
+    fn dij_val(self) -> Option<MutTensorDDRC<f64, ROWS, COLS>> {
+        let mut dij_val = MutTensorDDRC::<f64, ROWS, COLS>::from_shape(tangent_shape::<N, TCOLS>());
+        for k in 0..N {
+            *dij_val.mut_view().get_mut([k / TCOLS, k % TCOLS]) = self.dij_part[k];
+        }
+        Some(dij_val)
+    }
+}
+
+impl<const ROWS: usize, const COLS: usize, const N: usize, const TCOLS: usize>
+    IsSingleMatrix<JetScalar<N, TCOLS>, ROWS, COLS> for JetMatrix<ROWS, COLS, N, TCOLS>
+{
+}
+
+impl<const ROWS: usize, const COLS: usize, const N: usize, const TCOLS: usize> AbsDiffEq
+    for JetMatrix<ROWS, COLS, N, TCOLS>
+{
+    type Epsilon = f64;
+
+    fn default_epsilon() -> Self::Epsilon {
+        f64::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.real_part.abs_diff_eq(&other.real_part, epsilon)
+    }
+}
+
+impl<const ROWS: usize, const COLS: usize, const N: usize, const TCOLS: usize> RelativeEq
+    for JetMatrix<ROWS, COLS, N, TCOLS>
+{
+    fn default_max_relative() -> Self::Epsilon {
+        f64::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.real_part
+            .relative_eq(&other.real_part, epsilon, max_relative)
+    }
+}
+
+impl<const ROWS: usize, const COLS: usize, const N: usize, const TCOLS: usize>
+    IsMatrix<JetScalar<N, TCOLS>, ROWS, COLS, 1> for JetMatrix<ROWS, COLS, N, TCOLS>
+{
+    fn from_scalar(val: JetScalar<N, TCOLS>) -> Self {
+        JetMatrix {
+            real_part: MatF64::from_element(val.real_part),
+            dij_part: core::array::from_fn(|k| MatF64::from_element(val.dij_part[k])),
+        }
+    }
+
+    fn from_real_matrix(val: MatF64<ROWS, COLS>) -> Self {
+        Self {
+            real_part: val,
+            dij_part: [MatF64::<ROWS, COLS>::zeros(); N],
+        }
+    }
+
+    fn from_array2(vals: [[JetScalar<N, TCOLS>; COLS]; ROWS]) -> Self {
+        let mut out = Self::zeros();
+        for r in 0..ROWS {
+            for c in 0..COLS {
+                out.set_elem([r, c], vals[r][c]);
+            }
+        }
+        out
+    }
+
+    fn from_real_scalar_array2(vals: [[f64; COLS]; ROWS]) -> Self {
+        Self::from_real_matrix(MatF64::from_real_scalar_array2(vals))
+    }
+
+    fn from_f64_array2(vals: [[f64; COLS]; ROWS]) -> Self {
+        Self::from_real_matrix(MatF64::from_f64_array2(vals))
+    }
+
+    fn from_f64(val: f64) -> Self {
+        Self::from_real_matrix(MatF64::from_element(val))
+    }
+
+    fn identity() -> Self {
+        Self::from_real_matrix(MatF64::identity())
+    }
+
+    fn real_matrix(&self) -> &MatF64<ROWS, COLS> {
+        &self.real_part
+    }
+
+    fn get_elem(&self, idx: [usize; 2]) -> JetScalar<N, TCOLS> {
+        JetScalar {
+            real_part: self.real_part[(idx[0], idx[1])],
+            dij_part: VecF64::<N>::from_fn(|k, _| self.dij_part[k][(idx[0], idx[1])]),
+        }
+    }
+
+    fn set_elem(&mut self, idx: [usize; 2], val: JetScalar<N, TCOLS>) {
+        self.real_part[(idx[0], idx[1])] = val.real_part;
+        for k in 0..N {
+            self.dij_part[k][(idx[0], idx[1])] = val.dij_part[k];
+        }
+    }
+
+    fn mat_mul<const C2: usize>(
+        &self,
+        other: JetMatrix<COLS, C2, N, TCOLS>,
+    ) -> JetMatrix<ROWS, C2, N, TCOLS> {
+        JetMatrix {
+            real_part: self.real_part * other.real_part,
+            dij_part: core::array::from_fn(|k| {
+                self.dij_part[k] * other.real_part + self.real_part * other.dij_part[k]
+            }),
+        }
+    }
+
+    fn block_mat2x1<const R0: usize, const R1: usize>(
+        top_row: JetMatrix<R0, COLS, N, TCOLS>,
+        bot_row: JetMatrix<R1, COLS, N, TCOLS>,
+    ) -> Self {
+        JetMatrix {
+            real_part: MatF64::block_mat2x1(top_row.real_part, bot_row.real_part),
+            dij_part: core::array::from_fn(|k| {
+                MatF64::block_mat2x1(top_row.dij_part[k], bot_row.dij_part[k])
+            }),
+        }
+    }
+
+    fn block_mat1x2<const C0: usize, const C1: usize>(
+        left_col: JetMatrix<ROWS, C0, N, TCOLS>,
+        righ_col: JetMatrix<ROWS, C1, N, TCOLS>,
+    ) -> Self {
+        JetMatrix {
+            real_part: MatF64::block_mat1x2(left_col.real_part, righ_col.real_part),
+            dij_part: core::array::from_fn(|k| {
+                MatF64::block_mat1x2(left_col.dij_part[k], righ_col.dij_part[k])
+            }),
+        }
+    }
+
+    fn block_mat2x2<const R0: usize, const R1: usize, const C0: usize, const C1: usize>(
+        top_row: (JetMatrix<R0, C0, N, TCOLS>, JetMatrix<R0, C1, N, TCOLS>),
+        bot_row: (JetMatrix<R1, C0, N, TCOLS>, JetMatrix<R1, C1, N, TCOLS>),
+    ) -> Self {
+        assert_eq!(ROWS, R0 + R1);
+        assert_eq!(COLS, C0 + C1);
+        Self::block_mat2x1(
+            JetMatrix::<R0, COLS, N, TCOLS>::block_mat1x2(top_row.0, top_row.1),
+            JetMatrix::<R1, COLS, N, TCOLS>::block_mat1x2(bot_row.0, bot_row.1),
+        )
+    }
+
+    fn get_fixed_submat<const R: usize, const C: usize>(
+        &self,
+        start_r: usize,
+        start_c: usize,
+    ) -> JetMatrix<R, C, N, TCOLS> {
+        JetMatrix {
+            real_part: self.real_part.fixed_view::<R, C>(start_r, start_c).into(),
+            dij_part: core::array::from_fn(|k| {
+                self.dij_part[k].fixed_view::<R, C>(start_r, start_c).into()
+            }),
+        }
+    }
+
+    fn get_col_vec(&self, c: usize) -> JetVector<ROWS, N, TCOLS> {
+        JetVector {
+            real_part: self.real_part.column(c).into(),
+            dij_part: MatF64::<ROWS, N>::from_fn(|r, k| self.dij_part[k][(r, c)]),
+        }
+    }
+
+    fn get_row_vec(&self, r: usize) -> JetVector<COLS, N, TCOLS> {
+        JetVector {
+            real_part: self.real_part.row(r).transpose(),
+            dij_part: MatF64::<COLS, N>::from_fn(|c, k| self.dij_part[k][(r, c)]),
+        }
+    }
+
+    fn scaled(&self, s: JetScalar<N, TCOLS>) -> Self {
+        JetMatrix {
+            real_part: self.real_part * s.real_part,
+            dij_part: core::array::from_fn(|k| {
+                self.dij_part[k] * s.real_part + self.real_part * s.dij_part[k]
+            }),
+        }
+    }
+
+    fn set_col_vec(&mut self, c: usize, v: JetVector<ROWS, N, TCOLS>) {
+        self.real_part.set_column(c, &v.real_part);
+        for k in 0..N {
+            self.dij_part[k].set_column(c, &v.dij_part.column(k));
+        }
+    }
+
+    fn to_dual(self) -> JetMatrix<ROWS, COLS, N, TCOLS> {
+        self
+    }
+
+    fn select(self, mask: &bool, other: Self) -> Self {
+        if *mask {
+            self
+        } else {
+            other
+        }
+    }
+}
+
+impl<const ROWS: usize, const COLS: usize, const N: usize, const TCOLS: usize> Add
+    for JetMatrix<ROWS, COLS, N, TCOLS>
+{
+    type Output = JetMatrix<ROWS, COLS, N, TCOLS>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        JetMatrix {
+            real_part: self.real_part + rhs.real_part,
+            dij_part: core::array::from_fn(|k| self.dij_part[k] + rhs.dij_part[k]),
+        }
+    }
+}
+
+impl<const ROWS: usize, const COLS: usize, const N: usize, const TCOLS: usize> Sub
+    for JetMatrix<ROWS, COLS, N, TCOLS>
+{
+    type Output = JetMatrix<ROWS, COLS, N, TCOLS>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        JetMatrix {
+            real_part: self.real_part - rhs.real_part,
+            dij_part: core::array::from_fn(|k| self.dij_part[k] - rhs.dij_part[k]),
+        }
+    }
+}
+
+impl<const ROWS: usize, const COLS: usize, const N: usize, const TCOLS: usize> Neg
+    for JetMatrix<ROWS, COLS, N, TCOLS>
+{
+    type Output = JetMatrix<ROWS, COLS, N, TCOLS>;
+
+    fn neg(self) -> Self::Output {
+        JetMatrix {
+            real_part: -self.real_part,
+            dij_part: self.dij_part.map(|m| -m),
+        }
+    }
+}
+
+impl<const ROWS: usize, const COLS: usize, const N: usize, const TCOLS: usize>
+    Mul<JetVector<COLS, N, TCOLS>> for JetMatrix<ROWS, COLS, N, TCOLS>
+{
+    type Output = JetVector<ROWS, N, TCOLS>;
+
+    fn mul(self, rhs: JetVector<COLS, N, TCOLS>) -> Self::Output {
+        let mut dij_part = self.real_part * rhs.dij_part;
+        for k in 0..N {
+            dij_part.set_column(k, &(dij_part.column(k) + self.dij_part[k] * rhs.real_part));
+        }
+        JetVector {
+            real_part: self.real_part * rhs.real_part,
+            dij_part,
+        }
+    }
+}
+
+#[test]
+fn jet_matrix_tests() {
+    use crate::calculus::maps::matrix_valued_maps::MatrixValuedMapFromMatrix;
+    use crate::calculus::maps::scalar_valued_maps::ScalarValuedMapFromMatrix;
+
+    fn f<S: IsScalar<1>>(x: S::Matrix<2, 3>) -> S::Matrix<3, 2> {
+        let a = S::Matrix::<3, 2>::from_f64_array2([[1.0, 0.5], [-0.3, 2.0], [0.7, 0.1]]);
+        let x_t =
+            S::Matrix::<3, 2>::block_mat1x2(x.get_row_vec(0).to_mat(), x.get_row_vec(1).to_mat());
+        let prod: S::Matrix<3, 3> = x_t.mat_mul(x.clone());
+        let sub: S::Matrix<2, 2> = prod.get_fixed_submat(1, 0);
+        let mut out = S::Matrix::<3, 2>::block_mat2x1(
+            sub.scaled(x.get_elem([0, 1]).sin()),
+            S::Matrix::<1, 2>::from_scalar(x.get_elem([1, 2])),
+        ) - a.clone();
+        out.set_col_vec(1, a.get_col_vec(0) + x_t * x.get_col_vec(2));
+        out
+    }
+
+    fn g<S: IsScalar<1>>(x: S::Matrix<2, 3>) -> S {
+        let v = f::<S>(x).get_col_vec(1);
+        v.clone().dot(v)
+    }
+
+    let x = MatF64::<2, 3>::new(0.6, 0.2, -1.1, 0.4, 1.3, -0.7);
+    let finite_diff: MutTensorDDRC<f64, 3, 2> =
+        MatrixValuedMapFromMatrix::<f64, 1>::sym_diff_quotient(f::<f64>, x, 1e-6);
+    let jet_jac: MutTensorDDRC<f64, 3, 2> =
+        MatrixValuedMapFromMatrix::<JetScalar<6, 3>, 1>::fw_autodiff(f::<JetScalar<6, 3>>, x);
+    for r in 0..2 {
+        for c in 0..3 {
+            approx::assert_abs_diff_eq!(
+                finite_diff.get([r, c]),
+                jet_jac.get([r, c]),
+                epsilon = 0.0001
+            );
+        }
+    }
+
+    let finite_diff = ScalarValuedMapFromMatrix::<f64, 1>::sym_diff_quotient(g::<f64>, x, 1e-6);
+    let jet_grad =
+        ScalarValuedMapFromMatrix::<JetScalar<6, 3>, 1>::fw_autodiff(g::<JetScalar<6, 3>>, x);
+    approx::assert_abs_diff_eq!(finite_diff, jet_grad, epsilon = 0.0001);
+}
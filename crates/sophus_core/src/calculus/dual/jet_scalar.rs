@@ -0,0 +1,421 @@
+use super::jet_matrix::JetMatrix;
+use super::jet_vector::JetVector;
+use alloc::vec::Vec;
+
+use crate::linalg::scalar::NumberCategory;
+use crate::linalg::MatF64;
+use crate::linalg::VecF64;
+use crate::prelude::*;
+use crate::tensor::mut_tensor::MutTensorDD;
+
+use approx::AbsDiffEq;
+use approx::RelativeEq;
+use num_traits::One;
+use num_traits::Zero;
+
+use core::ops::Add;
+use core::ops::AddAssign;
+use core::ops::Div;
+use core::ops::Mul;
+use core::ops::Neg;
+use core::ops::Sub;
+use core::ops::SubAssign;
+
+/// Jet - a dual number with a fixed-size infinitesimal part
+///
+/// In contrast to [crate::calculus::dual::DualScalar], the derivative with respect to the N
+/// tangent directions is stored in a static vector, hence a jet lives entirely on the stack and
+/// arithmetic does not allocate. The tangent dimension N must match the number of inputs of the
+/// auto-differentiated map, e.g. VectorValuedMapFromVector::<JetScalar<6>, 1>::static_fw_autodiff
+/// for a map from ℝ⁶.
+///
+/// The tangent directions are laid out as a (N / TCOLS x TCOLS) matrix in row-major order, which
+/// is the shape of the derivative returned by [IsDualScalar::dij_val]. TCOLS is one for curves and
+/// maps from vectors, and equal to the number of input columns for maps from matrices, e.g.
+/// ScalarValuedMapFromMatrix::<JetScalar<6, 3>, 1>::fw_autodiff for a map from 2x3 matrices. A
+/// mismatch between the jet and the input dimensions is a compile-time error.
+#[derive(Clone, Copy, Debug)]
+pub struct JetScalar<const N: usize, const TCOLS: usize = 1> {
+    /// real part
+    pub real_part: f64,
+
+    /// infinitesimal part - represents derivative
+    pub dij_part: VecF64<N>,
+}
+
+impl<const N: usize, const TCOLS: usize> JetScalar<N, TCOLS> {
+    /// Applies a function given its value and derivative at the real part
+    fn chain(&self, f: f64, df: f64) -> Self {
+        Self {
+            real_part: f,
+            dij_part: self.dij_part * df,
+        }
+    }
+}
+
+impl<const N: usize, const TCOLS: usize> IsDual for JetScalar<N, TCOLS> {}
+
+/// Shape (N / TCOLS x TCOLS) of the tangent directions of a jet
+pub(crate) fn tangent_shape<const N: usize, const TCOLS: usize>() -> [usize; 2] {
+    const {
+        assert!(
+            TCOLS > 0 && N.is_multiple_of(TCOLS),
+            "the tangent dimension N must be a multiple of TCOLS"
+        )
+    };
+    [N / TCOLS, TCOLS]
+}
+
+impl<const N: usize, const TCOLS: usize> AbsDiffEq for JetScalar<N, TCOLS> {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> Self::Epsilon {
+        1e-6
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.real_part.abs_diff_eq(&other.real_part, epsilon)
+    }
+}
+
+impl<const N: usize, const TCOLS: usize> RelativeEq for JetScalar<N, TCOLS> {
+    fn default_max_relative() -> Self::Epsilon {
+        1e-6
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.real_part
+            .relative_eq(&other.real_part, epsilon, max_relative)
+    }
+}
+
+impl<const N: usize, const TCOLS: usize> IsCoreScalar for JetScalar<N, TCOLS> {
+    fn number_category() -> NumberCategory {
+        NumberCategory::Real
+    }
+}
+
+impl<const N: usize, const TCOLS: usize> IsSingleScalar for JetScalar<N, TCOLS> {
+    type SingleVector<const ROWS: usize> = JetVector<ROWS, N, TCOLS>;
+    type SingleMatrix<const ROWS: usize, const COLS: usize> = JetMatrix<ROWS, COLS, N, TCOLS>;
+
+    fn single_real_scalar(&self) -> f64 {
+        self.real_part
+    }
+
+    fn single_scalar(&self) -> Self {
+        *self
+    }
+
+    fn i64_floor(&self) -> i64 {
+        self.real_part.floor() as i64
+    }
+}
+
+impl<const N: usize, const TCOLS: usize> One for JetScalar<N, TCOLS> {
+    fn one() -> Self {
+        <JetScalar<N, TCOLS>>::from_f64(1.0)
+    }
+}
+
+impl<const N: usize, const TCOLS: usize> Zero for JetScalar<N, TCOLS> {
+    fn zero() -> Self {
+        <JetScalar<N, TCOLS>>::from_f64(0.0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.real_part == 0.0
+    }
+}
+
+impl<const N: usize, const TCOLS: usize> IsDualScalar<1> for JetScalar<N, TCOLS> {
+    /// Create a new jet for auto-differentiation with respect to self
+    ///
+    /// Requires the tangent dimension N to be one, checked at compile time.
+    fn new_with_dij(val: f64) -> Self {
+        const {
+            assert!(
+                N == 1 && TCOLS == 1,
+                "a jet curve argument requires the tangent dimension to be 1"
+            )
+        };
+        Self {
+            real_part: val,
+            dij_part: VecF64::<N>::from_element(1.0),
+        }
+    }
+
+    fn vector_with_dij<const ROWS: usize>(val: VecF64<ROWS>) -> JetVector<ROWS, N, TCOLS> {
+        JetVector::<ROWS, N, TCOLS>::new_with_dij(val)
+    }
+
+    fn matrix_with_dij<const ROWS: usize, const COLS: usize>(
+        val: MatF64<ROWS, COLS>,
+    ) -> JetMatrix<ROWS, COLS, N, TCOLS> {
+        JetMatrix::<ROWS, COLS, N, TCOLS>::new_with_dij(val)
+    }
+
+    /// Get the derivative - as a tensor of shape (N / TCOLS x TCOLS)
+    fn dij_val(self) -> Option<MutTensorDD<f64>> {
+        let mut dij_val = MutTensorDD::<f64>::from_shape(tangent_shape::<N, TCOLS>());
+        for k in 0..N {
+            *dij_val.mut_view().get_mut([k / TCOLS, k % TCOLS]) = self.dij_part[k];
+        }
+        Some(dij_val)
+    }
+}
+
+impl<const N: usize, const TCOLS: usize> Neg for JetScalar<N, TCOLS> {
+    type Output = JetScalar<N, TCOLS>;
+
+    fn neg(self) -> Self {
+        Self {
+            real_part: -self.real_part,
+            dij_part: -self.dij_part,
+        }
+    }
+}
+
+impl<const N: usize, const TCOLS: usize> PartialEq for JetScalar<N, TCOLS> {
+    fn eq(&self, other: &Self) -> bool {
+        self.real_part == other.real_part
+    }
+}
+
+impl<const N: usize, const TCOLS: usize> PartialOrd for JetScalar<N, TCOLS> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.real_part.partial_cmp(&other.real_part)
+    }
+}
+
+impl<const N: usize, const TCOLS: usize> From<f64> for JetScalar<N, TCOLS> {
+    fn from(value: f64) -> Self {
+        Self::from_real_scalar(value)
+    }
+}
+
+impl<const N: usize, const TCOLS: usize> IsScalar<1> for JetScalar<N, TCOLS> {
+    type Scalar = JetScalar<N, TCOLS>;
+    type RealScalar = f64;
+    type SingleScalar = JetScalar<N, TCOLS>;
+    type DualScalar = Self;
+    type Float = f64;
+
+    type RealMatrix<const ROWS: usize, const COLS: usize> = MatF64<ROWS, COLS>;
+    type RealVector<const ROWS: usize> = VecF64<ROWS>;
+
+    type Vector<const ROWS: usize> = JetVector<ROWS, N, TCOLS>;
+    type Matrix<const ROWS: usize, const COLS: usize> = JetMatrix<ROWS, COLS, N, TCOLS>;
+
+    type DualVector<const ROWS: usize> = JetVector<ROWS, N, TCOLS>;
+    type DualMatrix<const ROWS: usize, const COLS: usize> = JetMatrix<ROWS, COLS, N, TCOLS>;
+
+    type Mask = bool;
+
+    fn from_real_scalar(val: f64) -> Self {
+        Self {
+            real_part: val,
+            dij_part: VecF64::<N>::zeros(),
+        }
+    }
+
+    fn from_real_array(arr: [f64; 1]) -> Self {
+        Self::from_f64(arr[0])
+    }
+
+    fn to_real_array(&self) -> [f64; 1] {
+        [self.real_part]
+    }
+
+    fn cos(self) -> Self {
+        self.chain(self.real_part.cos(), -self.real_part.sin())
+    }
+
+    fn sin(self) -> Self {
+        self.chain(self.real_part.sin(), self.real_part.cos())
+    }
+
+    fn abs(self) -> Self {
+        self.chain(self.real_part.abs(), self.real_part.signum())
+    }
+
+    fn atan2(self, rhs: Self) -> Self {
+        let inv_sq_nrm: f64 =
+            1.0 / (self.real_part * self.real_part + rhs.real_part * rhs.real_part);
+        Self {
+            real_part: self.real_part.atan2(rhs.real_part),
+            dij_part: (self.dij_part * rhs.real_part - rhs.dij_part * self.real_part) * inv_sq_nrm,
+        }
+    }
+
+    fn real_part(&self) -> f64 {
+        self.real_part
+    }
+
+    fn sqrt(self) -> Self {
+        let sqrt = self.real_part.sqrt();
+        self.chain(sqrt, 1.0 / (2.0 * sqrt))
+    }
+
+    fn exp(self) -> Self {
+        let exp = self.real_part.exp();
+        self.chain(exp, exp)
+    }
+
+    fn ln(self) -> Self {
+        self.chain(self.real_part.ln(), 1.0 / self.real_part)
+    }
+
+    fn to_vec(self) -> JetVector<1, N, TCOLS> {
+        JetVector::<1, N, TCOLS>::from_array([self])
+    }
+
+    fn tan(self) -> Self {
+        let c = self.real_part.cos();
+        self.chain(self.real_part.tan(), 1.0 / (c * c))
+    }
+
+    fn acos(self) -> Self {
+        let dval = -1.0 / (1.0 - self.real_part * self.real_part).sqrt();
+        self.chain(self.real_part.acos(), dval)
+    }
+
+    fn asin(self) -> Self {
+        let dval = 1.0 / (1.0 - self.real_part * self.real_part).sqrt();
+        self.chain(self.real_part.asin(), dval)
+    }
+
+    fn atan(self) -> Self {
+        let dval = 1.0 / (1.0 + self.real_part * self.real_part);
+        self.chain(self.real_part.atan(), dval)
+    }
+
+    fn fract(self) -> Self {
+        self.chain(self.real_part.fract(), 1.0)
+    }
+
+    fn floor(&self) -> f64 {
+        self.real_part.floor()
+    }
+
+    fn from_f64(val: f64) -> Self {
+        Self::from_real_scalar(val)
+    }
+
+    fn scalar_examples() -> Vec<Self> {
+        [1.0, 2.0, 3.0].iter().map(|&v| Self::from_f64(v)).collect()
+    }
+
+    fn extract_single(&self, _i: usize) -> Self::SingleScalar {
+        *self
+    }
+
+    fn signum(&self) -> Self {
+        Self::from_f64(self.real_part.signum())
+    }
+
+    fn less_equal(&self, rhs: &Self) -> Self::Mask {
+        self.real_part.less_equal(&rhs.real_part)
+    }
+
+    fn to_dual(self) -> Self::DualScalar {
+        self
+    }
+
+    fn select(self, mask: &Self::Mask, other: Self) -> Self {
+        if *mask {
+            self
+        } else {
+            other
+        }
+    }
+
+    fn greater_equal(&self, rhs: &Self) -> Self::Mask {
+        self.real_part.greater_equal(&rhs.real_part)
+    }
+}
+
+impl<const N: usize, const TCOLS: usize> AddAssign<JetScalar<N, TCOLS>> for JetScalar<N, TCOLS> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.real_part += rhs.real_part;
+        self.dij_part += rhs.dij_part;
+    }
+}
+
+impl<const N: usize, const TCOLS: usize> SubAssign<JetScalar<N, TCOLS>> for JetScalar<N, TCOLS> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.real_part -= rhs.real_part;
+        self.dij_part -= rhs.dij_part;
+    }
+}
+
+impl<const N: usize, const TCOLS: usize> Add<JetScalar<N, TCOLS>> for JetScalar<N, TCOLS> {
+    type Output = JetScalar<N, TCOLS>;
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            real_part: self.real_part + rhs.real_part,
+            dij_part: self.dij_part + rhs.dij_part,
+        }
+    }
+}
+
+impl<const N: usize, const TCOLS: usize> Sub<JetScalar<N, TCOLS>> for JetScalar<N, TCOLS> {
+    type Output = JetScalar<N, TCOLS>;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            real_part: self.real_part - rhs.real_part,
+            dij_part: self.dij_part - rhs.dij_part,
+        }
+    }
+}
+
+impl<const N: usize, const TCOLS: usize> Mul<JetScalar<N, TCOLS>> for JetScalar<N, TCOLS> {
+    type Output = JetScalar<N, TCOLS>;
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self {
+            real_part: self.real_part * rhs.real_part,
+            dij_part: self.dij_part * rhs.real_part + rhs.dij_part * self.real_part,
+        }
+    }
+}
+
+impl<const N: usize, const TCOLS: usize> Div<JetScalar<N, TCOLS>> for JetScalar<N, TCOLS> {
+    type Output = JetScalar<N, TCOLS>;
+    fn div(self, rhs: Self) -> Self::Output {
+        let rhs_inv = 1.0 / rhs.real_part;
+        Self {
+            real_part: self.real_part * rhs_inv,
+            dij_part: (self.dij_part - rhs.dij_part * (self.real_part * rhs_inv)) * rhs_inv,
+        }
+    }
+}
+
+#[test]
+fn jet_scalar_tests() {
+    use crate::calculus::dual::DualScalar;
+    use crate::calculus::maps::curves::ScalarValuedCurve;
+
+    JetScalar::<1>::test_suite();
+
+    fn f<S: IsScalar<1>>(x: S) -> S {
+        let b = S::from_f64(0.7);
+        let y = x.clone() * x.clone() / (b.clone() + x.clone().exp());
+        y.clone().sin() + x.clone().atan2(b.clone()) - (x.clone() + b).sqrt().ln() * x.cos()
+            + y.clone().atan()
+            - y.tan().abs()
+    }
+
+    for i in 1..10 {
+        let a = 0.1 * (i as f64);
+        let finite_diff = ScalarValuedCurve::sym_diff_quotient(f::<f64>, a, 1e-6);
+        let dual_grad = ScalarValuedCurve::<DualScalar, 1>::fw_autodiff(f::<DualScalar>, a);
+        let jet_grad = ScalarValuedCurve::<JetScalar<1>, 1>::fw_autodiff(f::<JetScalar<1>>, a);
+        approx::assert_abs_diff_eq!(finite_diff, jet_grad, epsilon = 0.0001);
+        approx::assert_abs_diff_eq!(dual_grad, jet_grad, epsilon = 1e-12);
+    }
+}
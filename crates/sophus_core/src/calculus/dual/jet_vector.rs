@@ -0,0 +1,276 @@
+use super::jet_matrix::JetMatrix;
+use super::jet_scalar::tangent_shape;
+use super::jet_scalar::JetScalar;
+use crate::linalg::MatF64;
+use crate::linalg::VecF64;
+use crate::prelude::*;
+use crate::tensor::mut_tensor::MutTensorDDR;
+use approx::AbsDiffEq;
+use approx::RelativeEq;
+use core::ops::Add;
+use core::ops::Neg;
+use core::ops::Sub;
+
+/// Jet vector
+///
+/// The infinitesimal part is a (ROWS x N) matrix, where column k is the derivative with respect to
+/// the k-th tangent direction. See [JetScalar] for the tangent layout TCOLS.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct JetVector<const ROWS: usize, const N: usize, const TCOLS: usize = 1> {
+    /// real part
+    pub real_part: VecF64<ROWS>,
+    /// infinitesimal part - represents derivative
+    pub dij_part: MatF64<ROWS, N>,
+}
+
+impl<const ROWS: usize, const N: usize, const TCOLS: usize> IsDual for JetVector<ROWS, N, TCOLS> {}
+
+impl<const ROWS: usize, const N: usize, const TCOLS: usize>
+    IsDualVector<JetScalar<N, TCOLS>, ROWS, 1> for JetVector<ROWS, N, TCOLS>
+{
+    /// Requires the tangent dimension N to be equal to ROWS, checked at compile time.
+    fn new_with_dij(val: VecF64<ROWS>) -> Self {
+        const {
+            assert!(
+                N == ROWS && TCOLS == 1,
+                "the tangent dimension must match the input dimension"
+            )
+        };
+        Self {
+            real_part: val,
+            dij_part: MatF64::<ROWS, N>::identity(),
+        }
+    }
+
+    /// Get the derivative - as a tensor of shape (N / TCOLS x TCOLS)
+    fn dij_val(self) -> Option<MutTensorDDR<f64, ROWS>> {
+        let mut dij_val = MutTensorDDR::<f64, ROWS>::from_shape(tangent_shape::<N, TCOLS>());
+        for k in 0..N {
+            *dij_val.mut_view().get_mut([k / TCOLS, k % TCOLS]) = self.dij_part.column(k).into();
+        }
+        Some(dij_val)
+    }
+}
+
+impl<const ROWS: usize, const N: usize, const TCOLS: usize>
+    IsSingleVector<JetScalar<N, TCOLS>, ROWS> for JetVector<ROWS, N, TCOLS>
+{
+    fn set_real_scalar(&mut self, idx: usize, v: f64) {
+        self.real_part[idx] = v;
+    }
+}
+
+impl<const ROWS: usize, const N: usize, const TCOLS: usize> Neg for JetVector<ROWS, N, TCOLS> {
+    type Output = JetVector<ROWS, N, TCOLS>;
+
+    fn neg(self) -> Self::Output {
+        JetVector {
+            real_part: -self.real_part,
+            dij_part: -self.dij_part,
+        }
+    }
+}
+
+impl<const ROWS: usize, const N: usize, const TCOLS: usize> Sub for JetVector<ROWS, N, TCOLS> {
+    type Output = JetVector<ROWS, N, TCOLS>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        JetVector {
+            real_part: self.real_part - rhs.real_part,
+            dij_part: self.dij_part - rhs.dij_part,
+        }
+    }
+}
+
+impl<const ROWS: usize, const N: usize, const TCOLS: usize> Add for JetVector<ROWS, N, TCOLS> {
+    type Output = JetVector<ROWS, N, TCOLS>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        JetVector {
+            real_part: self.real_part + rhs.real_part,
+            dij_part: self.dij_part + rhs.dij_part,
+        }
+    }
+}
+
+impl<const ROWS: usize, const N: usize, const TCOLS: usize> AbsDiffEq
+    for JetVector<ROWS, N, TCOLS>
+{
+    type Epsilon = f64;
+
+    fn default_epsilon() -> Self::Epsilon {
+        f64::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.real_part.abs_diff_eq(&other.real_part, epsilon)
+    }
+}
+
+impl<const ROWS: usize, const N: usize, const TCOLS: usize> RelativeEq
+    for JetVector<ROWS, N, TCOLS>
+{
+    fn default_max_relative() -> Self::Epsilon {
+        f64::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.real_part
+            .relative_eq(&other.real_part, epsilon, max_relative)
+    }
+}
+
+impl<const ROWS: usize, const N: usize, const TCOLS: usize> IsVector<JetScalar<N, TCOLS>, ROWS, 1>
+    for JetVector<ROWS, N, TCOLS>
+{
+    fn from_f64(val: f64) -> Self {
+        Self::from_real_vector(VecF64::<ROWS>::from_element(val))
+    }
+
+    fn norm(&self) -> JetScalar<N, TCOLS> {
+        self.squared_norm().sqrt()
+    }
+
+    fn squared_norm(&self) -> JetScalar<N, TCOLS> {
+        self.dot(*self)
+    }
+
+    fn get_elem(&self, idx: usize) -> JetScalar<N, TCOLS> {
+        JetScalar {
+            real_part: self.real_part[idx],
+            dij_part: self.dij_part.row(idx).transpose(),
+        }
+    }
+
+    fn from_array(vals: [JetScalar<N, TCOLS>; ROWS]) -> Self {
+        let mut out = Self::zeros();
+        for (i, v) in vals.into_iter().enumerate() {
+            out.set_elem(i, v);
+        }
+        out
+    }
+
+    fn from_real_array(vals: [f64; ROWS]) -> Self {
+        Self::from_real_vector(VecF64::from_real_array(vals))
+    }
+
+    fn from_real_vector(val: VecF64<ROWS>) -> Self {
+        Self {
+            real_part: val,
+            dij_part: MatF64::<ROWS, N>::zeros(),
+        }
+    }
+
+    fn real_vector(&self) -> &VecF64<ROWS> {
+        &self.real_part
+    }
+
+    fn to_mat(self) -> JetMatrix<ROWS, 1, N, TCOLS> {
+        JetMatrix {
+            real_part: self.real_part,
+            dij_part: core::array::from_fn(|k| self.dij_part.column(k).into()),
+        }
+    }
+
+    fn block_vec2<const R0: usize, const R1: usize>(
+        top_row: JetVector<R0, N, TCOLS>,
+        bot_row: JetVector<R1, N, TCOLS>,
+    ) -> Self {
+        assert_eq!(R0 + R1, ROWS);
+
+        Self {
+            real_part: VecF64::block_vec2(top_row.real_part, bot_row.real_part),
+            dij_part: MatF64::block_mat2x1(top_row.dij_part, bot_row.dij_part),
+        }
+    }
+
+    fn scaled(&self, s: JetScalar<N, TCOLS>) -> Self {
+        JetVector {
+            real_part: self.real_part * s.real_part,
+            dij_part: self.dij_part * s.real_part + self.real_part * s.dij_part.transpose(),
+        }
+    }
+
+    fn dot(self, rhs: Self) -> JetScalar<N, TCOLS> {
+        JetScalar {
+            real_part: VecF64::dot(&self.real_part, &rhs.real_part),
+            dij_part: self.dij_part.transpose() * rhs.real_part
+                + rhs.dij_part.transpose() * self.real_part,
+        }
+    }
+
+    fn normalized(&self) -> Self {
+        self.scaled(JetScalar::<N, TCOLS>::from_f64(1.0) / self.norm())
+    }
+
+    fn from_f64_array(vals: [f64; ROWS]) -> Self {
+        Self::from_real_vector(VecF64::from_f64_array(vals))
+    }
+
+    fn from_scalar_array(vals: [JetScalar<N, TCOLS>; ROWS]) -> Self {
+        Self::from_array(vals)
+    }
+
+    fn set_elem(&mut self, idx: usize, v: JetScalar<N, TCOLS>) {
+        self.real_part[idx] = v.real_part;
+        self.dij_part.set_row(idx, &v.dij_part.transpose());
+    }
+
+    fn to_dual(self) -> JetVector<ROWS, N, TCOLS> {
+        self
+    }
+
+    fn outer<const R2: usize>(self, rhs: JetVector<R2, N, TCOLS>) -> JetMatrix<ROWS, R2, N, TCOLS> {
+        JetMatrix {
+            real_part: self.real_part * rhs.real_part.transpose(),
+            dij_part: core::array::from_fn(|k| {
+                self.dij_part.column(k) * rhs.real_part.transpose()
+                    + self.real_part * rhs.dij_part.column(k).transpose()
+            }),
+        }
+    }
+
+    fn select(self, mask: &bool, other: Self) -> Self {
+        if *mask {
+            self
+        } else {
+            other
+        }
+    }
+
+    fn get_fixed_subvec<const R: usize>(&self, start_r: usize) -> JetVector<R, N, TCOLS> {
+        JetVector {
+            real_part: self.real_part.fixed_rows::<R>(start_r).into(),
+            dij_part: self.dij_part.fixed_rows::<R>(start_r).into(),
+        }
+    }
+}
+
+#[test]
+fn jet_vector_tests() {
+    use crate::calculus::maps::vector_valued_maps::VectorValuedMapFromVector;
+    use crate::linalg::vector::cross;
+
+    fn f<S: IsScalar<1>>(x: S::Vector<4>) -> S::Vector<3> {
+        let a = S::Vector::<3>::from_f64_array([0.3, -0.2, 0.9]);
+        let head: S::Vector<3> = x.get_fixed_subvec(0);
+        let tail: S::Vector<2> = x.get_fixed_subvec(2);
+        let c = cross::<S, 1>(head.clone(), a.clone()).normalized();
+        let m = head.clone().outer(tail.clone().normalized());
+        let v = S::Vector::<3>::block_vec2(tail.clone(), head.clone().norm().to_vec());
+        c.scaled(head.dot(a)) + m * tail - v
+    }
+
+    let x = VecF64::<4>::new(0.6, 0.2, -1.1, 0.4);
+    let finite_diff: MatF64<3, 4> =
+        VectorValuedMapFromVector::<f64, 1>::static_sym_diff_quotient(f::<f64>, x, 1e-6);
+    let jet_jac =
+        VectorValuedMapFromVector::<JetScalar<4>, 1>::static_fw_autodiff(f::<JetScalar<4>>, x);
+
+    approx::assert_abs_diff_eq!(finite_diff, jet_jac, epsilon = 0.0001);
+}
@@ -8,7 +8,7 @@ use nalgebra::SVector;
 ///
 ///  f: ℝ -> ℝ
 pub struct ScalarValuedCurve<S: IsScalar<BATCH>, const BATCH: usize> {
-    phantom: std::marker::PhantomData<S>,
+    phantom: core::marker::PhantomData<S>,
 }
 
 impl<S: IsScalar<BATCH>, const BATCH: usize> ScalarValuedCurve<S, BATCH> {
@@ -44,7 +44,7 @@ impl<D: IsDualScalar<BATCH>, const BATCH: usize> ScalarValuedCurve<D, BATCH> {
 ///
 ///   f: ℝ -> ℝʳ
 pub struct VectorValuedCurve<S: IsScalar<BATCH>, const BATCH: usize> {
-    phantom: std::marker::PhantomData<S>,
+    phantom: core::marker::PhantomData<S>,
 }
 
 impl<S: IsScalar<BATCH>, const BATCH: usize> VectorValuedCurve<S, BATCH> {
@@ -79,7 +79,7 @@ impl<D: IsDualScalar<BATCH>, const BATCH: usize> VectorValuedCurve<D, BATCH> {
 /// This is a function which takes a scalar and returns a matrix:
 ///   f: ℝ -> ℝʳ x ℝᶜ
 pub struct MatrixValuedCurve<S: IsScalar<BATCH>, const BATCH: usize> {
-    phantom: std::marker::PhantomData<S>,
+    phantom: core::marker::PhantomData<S>,
 }
 
 impl<S: IsScalar<BATCH>, const BATCH: usize> MatrixValuedCurve<S, BATCH> {
@@ -118,9 +118,7 @@ fn curve_test() {
     use crate::calculus::dual::DualScalar;
     use crate::linalg::scalar::IsScalar;
 
-    #[cfg(feature = "simd")]
     use crate::calculus::dual::DualBatchScalar;
-    #[cfg(feature = "simd")]
     use crate::linalg::BatchScalarF64;
 
     trait CurveTest {
@@ -199,18 +197,12 @@ fn curve_test() {
     }
 
     def_curve_test_template!(1, f64, DualScalar);
-    #[cfg(feature = "simd")]
     def_curve_test_template!(2, BatchScalarF64<2>, DualBatchScalar<2>);
-    #[cfg(feature = "simd")]
     def_curve_test_template!(4, BatchScalarF64<4>, DualBatchScalar<4>);
-    #[cfg(feature = "simd")]
     def_curve_test_template!(8, BatchScalarF64<8>, DualBatchScalar<8>);
 
     DualScalar::run_curve_test();
-    #[cfg(feature = "simd")]
     DualBatchScalar::<2>::run_curve_test();
-    #[cfg(feature = "simd")]
     DualBatchScalar::<4>::run_curve_test();
-    #[cfg(feature = "simd")]
     DualBatchScalar::<8>::run_curve_test();
 }
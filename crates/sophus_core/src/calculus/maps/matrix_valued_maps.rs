@@ -2,8 +2,8 @@ use crate::linalg::SMat;
 use crate::prelude::*;
 use crate::tensor::mut_tensor::MutTensorDDRC;
 use crate::tensor::mut_tensor::MutTensorDRC;
+use core::marker::PhantomData;
 use nalgebra::SMatrix;
-use std::marker::PhantomData;
 
 /// Matrix-valued map on a vector space.
 ///
@@ -12,7 +12,7 @@ use std::marker::PhantomData;
 ///  f: ℝᵐ -> ℝʳ x ℝᶜ
 ///
 pub struct MatrixValuedMapFromVector<S: IsScalar<BATCH>, const BATCH: usize> {
-    phantom: std::marker::PhantomData<S>,
+    phantom: core::marker::PhantomData<S>,
 }
 
 impl<S: IsRealScalar<BATCH, RealScalar = S>, const BATCH: usize>
@@ -82,7 +82,7 @@ impl<D: IsDualScalar<BATCH, DualScalar = D>, const BATCH: usize>
 ///  f: ℝᵐ x ℝⁿ -> ℝʳ x ℝᶜ
 ///
 pub struct MatrixValuedMapFromMatrix<S: IsScalar<BATCH>, const BATCH: usize> {
-    phantom: std::marker::PhantomData<S>,
+    phantom: core::marker::PhantomData<S>,
 }
 
 impl<S: IsRealScalar<BATCH, RealScalar = S>, const BATCH: usize>
@@ -149,7 +149,9 @@ impl<D: IsDualScalar<BATCH, DualScalar = D>, const BATCH: usize>
     where
         TFn: Fn(D::DualMatrix<INROWS, INCOLS>) -> D::DualMatrix<OUTROWS, OUTCOLS>,
     {
-        matrix_valued(D::matrix_with_dij(a)).dij_val().unwrap()
+        let jacobian = matrix_valued(D::matrix_with_dij(a)).dij_val().unwrap();
+        assert_eq!(jacobian.dims(), [INROWS, INCOLS]);
+        jacobian
     }
 }
 
@@ -161,9 +163,7 @@ fn matrix_valued_map_from_vector_tests() {
     use crate::linalg::vector::IsVector;
     use crate::tensor::tensor_view::IsTensorLike;
 
-    #[cfg(feature = "simd")]
     use crate::calculus::dual::DualBatchScalar;
-    #[cfg(feature = "simd")]
     use crate::linalg::BatchScalarF64;
 
     #[cfg(test)]
@@ -286,14 +286,10 @@ fn matrix_valued_map_from_vector_tests() {
     }
 
     def_test_template!(f64, DualScalar, 1);
-    #[cfg(feature = "simd")]
     def_test_template!(BatchScalarF64<2>, DualBatchScalar<2>, 2);
-    #[cfg(feature = "simd")]
     def_test_template!(BatchScalarF64<4>, DualBatchScalar<4>, 4);
 
     f64::run();
-    #[cfg(feature = "simd")]
     BatchScalarF64::<2>::run();
-    #[cfg(feature = "simd")]
     BatchScalarF64::<4>::run();
 }
@@ -1,3 +1,15 @@
+use crate::calculus::dual::HyperDualScalar;
+use crate::calculus::dual::HyperDualVector;
+#[cfg(feature = "std")]
+use crate::calculus::tape::Tape;
+#[cfg(feature = "std")]
+use crate::calculus::tape::TapeMatrix;
+#[cfg(feature = "std")]
+use crate::calculus::tape::TapeScalar;
+#[cfg(feature = "std")]
+use crate::calculus::tape::TapeVector;
+use crate::linalg::MatF64;
+use crate::linalg::VecF64;
 use crate::prelude::*;
 use crate::tensor::mut_tensor::MutTensorDD;
 
@@ -10,7 +22,7 @@ use crate::tensor::mut_tensor::MutTensorDD;
 /// These functions are also called a scalar fields (on vector spaces).
 ///
 pub struct ScalarValuedMapFromVector<S: IsScalar<BATCH>, const BATCH: usize> {
-    phantom: std::marker::PhantomData<S>,
+    phantom: core::marker::PhantomData<S>,
 }
 
 impl<S: IsRealScalar<BATCH>, const BATCH: usize> ScalarValuedMapFromVector<S, BATCH> {
@@ -67,13 +79,60 @@ impl<D: IsDualScalar<BATCH>, const BATCH: usize> ScalarValuedMapFromVector<D, BA
     }
 }
 
+impl ScalarValuedMapFromVector<HyperDualScalar, 1> {
+    /// Second order auto differentiation of the scalar-valued map.
+    ///
+    /// Returns the Hessian, a symmetric matrix of shape (Rᵢ x Rᵢ). Each upper triangular entry
+    /// ∂²f/∂xᵢ∂xⱼ is obtained from a single evaluation with hyper-dual numbers seeded in
+    /// directions i and j.
+    pub fn fw_hessian<TFn, const INROWS: usize>(
+        scalar_valued: TFn,
+        a: VecF64<INROWS>,
+    ) -> MatF64<INROWS, INROWS>
+    where
+        TFn: Fn(HyperDualVector<INROWS>) -> HyperDualScalar,
+    {
+        let mut out = MatF64::<INROWS, INROWS>::zeros();
+
+        for i in 0..INROWS {
+            for j in i..INROWS {
+                let h = scalar_valued(HyperDualVector::new_with_eps(a, i, j)).eps1eps2_part;
+                out[(i, j)] = h;
+                out[(j, i)] = h;
+            }
+        }
+        out
+    }
+}
+
+#[cfg(feature = "std")]
+impl ScalarValuedMapFromVector<TapeScalar, 1> {
+    /// Reverse-mode auto differentiation of the scalar-valued map.
+    ///
+    /// The map is evaluated once while recording on the tape, followed by a single backward pass.
+    /// Hence, in contrast to fw_autodiff, the cost does not grow with the number of inputs.
+    pub fn gradient<TFn, const INROWS: usize>(
+        scalar_valued: TFn,
+        a: VecF64<INROWS>,
+    ) -> VecF64<INROWS>
+    where
+        TFn: Fn(TapeVector<INROWS>) -> TapeScalar,
+    {
+        let len = Tape::len();
+        let x = TapeVector::new_variables(a);
+        let grad = Tape::gradient(&scalar_valued(x), &x.to_array());
+        Tape::truncate(len);
+        VecF64::from_vec(grad)
+    }
+}
+
 /// Scalar-valued map on a product space (= space of matrices).
 ///
 /// This is a function which takes a matrix and returns a scalar:
 ///
 ///   f: ℝᵐ x ℝⁿ -> ℝ
 pub struct ScalarValuedMapFromMatrix<S: IsScalar<BATCH>, const BATCH: usize> {
-    phantom: std::marker::PhantomData<S>,
+    phantom: core::marker::PhantomData<S>,
 }
 
 impl<S: IsRealScalar<BATCH>, const BATCH: usize> ScalarValuedMapFromMatrix<S, BATCH> {
@@ -130,12 +189,28 @@ impl<D: IsDualScalar<BATCH>, const BATCH: usize> ScalarValuedMapFromMatrix<D, BA
     }
 }
 
+#[cfg(feature = "std")]
+impl ScalarValuedMapFromMatrix<TapeScalar, 1> {
+    /// Reverse-mode auto differentiation of the scalar-valued map.
+    pub fn gradient<TFn, const INROWS: usize, const INCOLS: usize>(
+        scalar_valued: TFn,
+        a: MatF64<INROWS, INCOLS>,
+    ) -> MatF64<INROWS, INCOLS>
+    where
+        TFn: Fn(TapeMatrix<INROWS, INCOLS>) -> TapeScalar,
+    {
+        let len = Tape::len();
+        let x = TapeMatrix::new_variables(a);
+        let grad = Tape::gradient(&scalar_valued(x), &x.to_array2().concat());
+        Tape::truncate(len);
+        MatF64::from_row_slice(&grad)
+    }
+}
+
 #[test]
 fn scalar_valued_map_tests() {
     use crate::calculus::dual::dual_scalar::DualScalar;
-    #[cfg(feature = "simd")]
     use crate::calculus::dual::DualBatchScalar;
-    #[cfg(feature = "simd")]
     use crate::linalg::BatchScalarF64;
 
     #[cfg(test)]
@@ -204,30 +279,57 @@ fn scalar_valued_map_tests() {
     }
 
     def_scalar_valued_map_test_template!(1, f64, DualScalar);
-    #[cfg(feature = "simd")]
     def_scalar_valued_map_test_template!(2, BatchScalarF64<2>, DualBatchScalar<2>);
-    #[cfg(feature = "simd")]
     def_scalar_valued_map_test_template!(4, BatchScalarF64<4>, DualBatchScalar<4>);
-    #[cfg(feature = "simd")]
     def_scalar_valued_map_test_template!(8, BatchScalarF64<8>, DualBatchScalar<8>);
-    #[cfg(feature = "simd")]
     def_scalar_valued_map_test_template!(16, BatchScalarF64<16>, DualBatchScalar<16>);
-    #[cfg(feature = "simd")]
     def_scalar_valued_map_test_template!(32, BatchScalarF64<32>, DualBatchScalar<32>);
-    #[cfg(feature = "simd")]
     def_scalar_valued_map_test_template!(64, BatchScalarF64<64>, DualBatchScalar<64>);
 
     f64::run();
-    #[cfg(feature = "simd")]
     BatchScalarF64::<2>::run();
-    #[cfg(feature = "simd")]
     BatchScalarF64::<4>::run();
-    #[cfg(feature = "simd")]
     BatchScalarF64::<8>::run();
-    #[cfg(feature = "simd")]
     BatchScalarF64::<16>::run();
-    #[cfg(feature = "simd")]
     BatchScalarF64::<32>::run();
-    #[cfg(feature = "simd")]
     BatchScalarF64::<64>::run();
+
+    // Hessian vs. symmetric difference quotient of the gradient
+    {
+        use crate::calculus::maps::vector_valued_maps::VectorValuedMapFromVector;
+
+        fn f<S: IsScalar<1>>(x: S::Vector<2>) -> S {
+            let x0 = x.get_elem(0);
+            let x1 = x.get_elem(1);
+            x0.clone().sin() * x1.clone().exp() + x1.clone().atan2(x0.clone()) + x0 / x1 + x.norm()
+        }
+        fn grad(x: VecF64<2>) -> VecF64<2> {
+            ScalarValuedMapFromVector::<DualScalar, 1>::fw_autodiff(f::<DualScalar>, x)
+        }
+
+        for a in [
+            VecF64::<2>::new(0.1, 0.4),
+            VecF64::<2>::new(-0.7, 1.3),
+            VecF64::<2>::new(2.0, -0.5),
+        ] {
+            let hessian = ScalarValuedMapFromVector::<HyperDualScalar, 1>::fw_hessian(f, a);
+            let finite_diff =
+                VectorValuedMapFromVector::<f64, 1>::static_sym_diff_quotient(grad, a, 1e-6);
+            approx::assert_abs_diff_eq!(hessian, finite_diff, epsilon = 0.0001);
+        }
+    }
+
+    // reverse-mode gradient vs. forward-mode gradient
+    {
+        fn f<S: IsScalar<1>>(x: S::Vector<8>) -> S {
+            let head: S::Vector<4> = x.get_fixed_subvec(0);
+            let tail: S::Vector<4> = x.get_fixed_subvec(4);
+            head.clone().dot(tail.clone()).sin() + head.squared_norm() * tail.norm().exp()
+        }
+
+        let a = VecF64::<8>::from_fn(|i, _| 0.1 * (i as f64) - 0.3);
+        let tape_grad = ScalarValuedMapFromVector::<TapeScalar, 1>::gradient(f::<TapeScalar>, a);
+        let dual_grad = ScalarValuedMapFromVector::<DualScalar, 1>::fw_autodiff(f::<DualScalar>, a);
+        approx::assert_abs_diff_eq!(tape_grad, dual_grad, epsilon = 1e-10);
+    }
 }
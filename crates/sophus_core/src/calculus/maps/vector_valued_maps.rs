@@ -2,7 +2,7 @@ use crate::linalg::SVec;
 use crate::prelude::*;
 use crate::tensor::mut_tensor::MutTensorDDR;
 use crate::tensor::mut_tensor::MutTensorDR;
-use std::marker::PhantomData;
+use core::marker::PhantomData;
 
 /// Vector-valued map on a vector space.
 ///
@@ -13,7 +13,7 @@ use std::marker::PhantomData;
 /// These functions are also called vector fields (on vector space).
 ///
 pub struct VectorValuedMapFromVector<S: IsScalar<BATCH>, const BATCH: usize> {
-    phantom: std::marker::PhantomData<S>,
+    phantom: core::marker::PhantomData<S>,
 }
 
 impl<S: IsRealScalar<BATCH, RealScalar = S>, const BATCH: usize>
@@ -134,7 +134,7 @@ impl<D: IsDualScalar<BATCH, DualScalar = D>, const BATCH: usize>
 /// This type of function is also called a vector field (on product spaces).
 ///
 pub struct VectorValuedMapFromMatrix<S: IsScalar<BATCH>, const BATCH: usize> {
-    phantom: std::marker::PhantomData<S>,
+    phantom: core::marker::PhantomData<S>,
 }
 
 impl<S: IsRealScalar<BATCH>, const BATCH: usize> VectorValuedMapFromMatrix<S, BATCH> {
@@ -186,7 +186,9 @@ impl<D: IsDualScalar<BATCH, DualScalar = D>, const BATCH: usize>
     where
         TFn: Fn(D::DualMatrix<INROWS, INCOLS>) -> D::DualVector<OUTROWS>,
     {
-        vector_valued(D::matrix_with_dij(a)).dij_val().unwrap()
+        let jacobian = vector_valued(D::matrix_with_dij(a)).dij_val().unwrap();
+        assert_eq!(jacobian.dims(), [INROWS, INCOLS]);
+        jacobian
     }
 }
 
@@ -199,9 +201,7 @@ fn vector_valued_map_from_vector_tests() {
     use crate::linalg::vector::IsVector;
     use crate::tensor::tensor_view::IsTensorLike;
 
-    #[cfg(feature = "simd")]
     use crate::calculus::dual::DualBatchScalar;
-    #[cfg(feature = "simd")]
     use crate::linalg::BatchScalarF64;
 
     #[cfg(test)]
@@ -309,14 +309,10 @@ fn vector_valued_map_from_vector_tests() {
     }
 
     def_test_template!(f64, DualScalar, 1);
-    #[cfg(feature = "simd")]
     def_test_template!(BatchScalarF64<2>, DualBatchScalar<2>, 2);
-    #[cfg(feature = "simd")]
     def_test_template!(BatchScalarF64<4>, DualBatchScalar<4>, 4);
 
     f64::run();
-    #[cfg(feature = "simd")]
     BatchScalarF64::<2>::run();
-    #[cfg(feature = "simd")]
     BatchScalarF64::<4>::run();
 }
@@ -4,6 +4,7 @@ use crate::IsPoint;
 
 /// Floating-point interval
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Interval {
     /// min and max of the interval
     pub min_max: Option<(f64, f64)>,
@@ -11,6 +12,7 @@ pub struct Interval {
 
 /// Integer interval
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IInterval {
     /// min and max of the interval
     pub min_max: Option<(i64, i64)>,
@@ -18,6 +20,7 @@ pub struct IInterval {
 
 /// Region - n-dimensional interval
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Region<const D: usize> {
     /// min and max of the region
     pub min_max: Option<(SVector<f64, D>, SVector<f64, D>)>,
@@ -25,6 +28,7 @@ pub struct Region<const D: usize> {
 
 /// Integer Region - n-dimensional interval
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IRegion<const D: usize> {
     /// min and max of the region
     pub min_max: Option<(SVector<i64, D>, SVector<i64, D>)>,
@@ -466,4 +470,28 @@ mod tests {
         assert!(!two_f64.is_proper());
         assert!(!two_f64.is_unbounded());
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn region_serde() {
+        let region = Region::<2>::from_min_max(
+            SVector::<f64, 2>::new(-1.0, 0.5),
+            SVector::<f64, 2>::new(2.0, 3.0),
+        );
+        let json = serde_json::to_string(&region).unwrap();
+        assert_eq!(json, r#"{"min_max":[[-1.0,0.5],[2.0,3.0]]}"#);
+        let region2: Region<2> = serde_json::from_str(&json).unwrap();
+        assert_eq!(region2.min(), region.min());
+        assert_eq!(region2.max(), region.max());
+
+        let empty: Region<2> =
+            serde_json::from_str(&serde_json::to_string(&Region::<2>::empty()).unwrap()).unwrap();
+        assert!(empty.is_empty());
+
+        let interval = IInterval::from_min_max(-3, 7);
+        let json = serde_json::to_string(&interval).unwrap();
+        assert_eq!(json, r#"{"min_max":[-3,7]}"#);
+        let interval2: IInterval = serde_json::from_str(&json).unwrap();
+        assert_eq!(interval2.min_max, interval.min_max);
+    }
 }
@@ -1,113 +1,12 @@
-/// Cubic B-Spline details
+/// B-Spline basis functions, and cubic B-Spline segments
 pub mod spline_segment;
 
-use crate::calculus::spline::spline_segment::CubicBSplineSegment;
-use crate::calculus::spline::spline_segment::SegmentCase;
-use crate::prelude::*;
-
-/// Cubic B-Spline implementation
-pub struct CubicBSplineImpl<S: IsSingleScalar, const DIMS: usize> {
-    /// Control points
-    pub control_points: Vec<S::SingleVector<DIMS>>,
-    /// delta between control points
-    pub delta_t: S,
-}
-
-impl<S: IsSingleScalar, const DIMS: usize> CubicBSplineImpl<S, DIMS> {
-    /// indices involved
-    pub fn idx_involved(&self, segment_idx: usize) -> Vec<usize> {
-        let num = self.num_segments();
-        assert!(segment_idx < num);
-
-        let idx_prev = if segment_idx == 0 { 0 } else { segment_idx - 1 };
-        let idx_0 = segment_idx;
-        let idx_1 = segment_idx + 1;
-        let idx_2 = (segment_idx + 2).min(self.control_points.len() - 1);
-        vec![idx_prev, idx_0, idx_1, idx_2]
-    }
-
-    /// Interpolate
-    pub fn interpolate(&self, segment_idx: usize, u: S) -> S::SingleVector<DIMS> {
-        let num = self.num_segments();
-        assert!(segment_idx < num);
-
-        let case = if segment_idx == 0 {
-            SegmentCase::First
-        } else if segment_idx == num - 1 {
-            SegmentCase::Last
-        } else {
-            SegmentCase::Normal
-        };
-
-        let idx_prev = if segment_idx == 0 { 0 } else { segment_idx - 1 };
-        let idx_0 = segment_idx;
-        let idx_1 = segment_idx + 1;
-        let idx_2 = (segment_idx + 2).min(self.control_points.len() - 1);
-        CubicBSplineSegment::<S, DIMS> {
-            case,
-            control_points: [
-                self.control_points[idx_prev].clone(),
-                self.control_points[idx_0].clone(),
-                self.control_points[idx_1].clone(),
-                self.control_points[idx_2].clone(),
-            ],
-        }
-        .interpolate(u)
-    }
+/// Least-squares fitting of B-splines to timestamped samples
+pub mod spline_fit;
 
-    fn num_segments(&self) -> usize {
-        assert!(!self.control_points.is_empty());
-        self.control_points.len() - 1
-    }
-
-    /// derivative of the interpolation
-    pub fn dxi_interpolate(
-        &self,
-        segment_idx: usize,
-        u: S,
-        control_point_idx: usize,
-    ) -> S::SingleMatrix<DIMS, DIMS> {
-        let num = self.num_segments();
-        assert!(segment_idx < num);
-
-        let case = if segment_idx == 0 {
-            SegmentCase::First
-        } else if segment_idx == num - 1 {
-            SegmentCase::Last
-        } else {
-            SegmentCase::Normal
-        };
-
-        let idx_prev = if segment_idx == 0 { 0 } else { segment_idx - 1 };
-        let idx_0 = segment_idx;
-        let idx_1 = segment_idx + 1;
-        let idx_2 = (segment_idx + 2).min(self.control_points.len() - 1);
-        let spline_segment = CubicBSplineSegment::<S, DIMS> {
-            case,
-            control_points: [
-                self.control_points[idx_prev].clone(),
-                self.control_points[idx_0].clone(),
-                self.control_points[idx_1].clone(),
-                self.control_points[idx_2].clone(),
-            ],
-        };
-
-        let mut dxi: S::SingleMatrix<DIMS, DIMS> = S::SingleMatrix::<DIMS, DIMS>::zeros();
-        if idx_prev == control_point_idx {
-            dxi = dxi + spline_segment.dxi_interpolate(u.clone(), 0);
-        }
-        if idx_0 == control_point_idx {
-            dxi = dxi + spline_segment.dxi_interpolate(u.clone(), 1);
-        }
-        if idx_1 == control_point_idx {
-            dxi = dxi + spline_segment.dxi_interpolate(u.clone(), 2);
-        }
-        if idx_2 == control_point_idx {
-            dxi = dxi + spline_segment.dxi_interpolate(u, 3);
-        }
-        dxi
-    }
-}
+use crate::calculus::spline::spline_segment::BSplineBasisFunction;
+use crate::prelude::*;
+use alloc::vec::Vec;
 
 /// Index and u
 #[derive(Clone, Debug, Copy)]
@@ -118,102 +17,215 @@ pub struct IndexAndU<S: IsSingleScalar> {
     pub u: S,
 }
 
-/// Cubic B-Spline
-pub struct CubicBSpline<S: IsSingleScalar, const DIMS: usize> {
-    /// Cubic B-Spline implementation
-    pub spline_impl: CubicBSplineImpl<S, DIMS>,
-    /// start time t0
-    pub t0: S,
+/// B-Spline of a given degree
+///
+/// The spline is defined by its control points p₀ ... p_N and a non-decreasing knot vector:
+///
+///   x(t) = ∑ⱼ Nⱼ,ₚ(t) · q(j)
+///
+/// with Nⱼ,ₚ being the basis functions of degree p, see [BSplineBasisFunction]. Here, q(j) is the
+/// j-th control point, possibly padded by repeating the first and last control point - see
+/// [BSpline::new].
+#[derive(Clone, Debug)]
+pub struct BSpline<S: IsSingleScalar, const DEGREE: usize, const DIMS: usize> {
+    /// Control points
+    pub control_points: Vec<S::SingleVector<DIMS>>,
+    /// Knot vector
+    pub knots: Vec<S>,
+    /// number of repetitions of the first control point
+    padding: usize,
 }
 
-/// Cubic B-Spline parameters
+/// Cubic B-Spline
+pub type CubicBSpline<S, const DIMS: usize> = BSpline<S, 3, DIMS>;
+
+/// Cubic B-Spline implementation
+#[deprecated(note = "the cubic B-Spline is a BSpline of degree 3 now, use CubicBSpline instead")]
+pub type CubicBSplineImpl<S, const DIMS: usize> = CubicBSpline<S, DIMS>;
+
+/// B-Spline parameters for uniform knots
 #[derive(Clone, Debug, Copy)]
-pub struct CubicBSplineParams<S: IsSingleScalar + 'static> {
+pub struct BSplineParams<S: IsSingleScalar + 'static> {
     /// delta between control points
     pub delta_t: S,
     /// start time t0
     pub t0: S,
 }
 
-impl<S: IsSingleScalar + 'static, const DIMS: usize> CubicBSpline<S, DIMS> {
-    /// create a new cubic B-Spline
-    pub fn new(control_points: Vec<S::SingleVector<DIMS>>, params: CubicBSplineParams<S>) -> Self {
+/// Cubic B-Spline parameters
+pub type CubicBSplineParams<S> = BSplineParams<S>;
+
+impl<S: IsSingleScalar + 'static, const DEGREE: usize, const DIMS: usize> BSpline<S, DEGREE, DIMS> {
+    /// create a new B-Spline with uniform knots
+    ///
+    /// The i-th control point is associated with time t0 + i·delta_t, and the spline is defined on
+    /// [t0, t0 + N·delta_t] for N+1 control points. To this end, the first and last control point
+    /// are repeated, such that there are DEGREE-1 additional basis functions. For the cubic
+    /// B-Spline, the first and last control point are duplicated.
+    pub fn new(control_points: Vec<S::SingleVector<DIMS>>, params: BSplineParams<S>) -> Self {
+        let times = (0..control_points.len())
+            .map(|i| params.t0.clone() + S::from_f64(i as f64) * params.delta_t.clone())
+            .collect();
+        Self::new_non_uniform(control_points, times)
+    }
+
+    /// create a new B-Spline with non-uniform knots
+    ///
+    /// Same as [BSpline::new], but the i-th control point is associated with times[i], e.g. the
+    /// timestamp of a sensor measurement. The times must be strictly increasing. Beyond the first
+    /// and last time, the knots are extrapolated using the first and last time delta respectively.
+    pub fn new_non_uniform(control_points: Vec<S::SingleVector<DIMS>>, times: Vec<S>) -> Self {
+        assert!(DEGREE >= 1);
+        assert!(control_points.len() >= 2);
+        assert_eq!(control_points.len(), times.len());
+        for w in times.windows(2) {
+            assert!(w[0].single_real_scalar() < w[1].single_real_scalar());
+        }
+
+        let n = times.len() - 1;
+        let first_delta = times[1].clone() - times[0].clone();
+        let last_delta = times[n].clone() - times[n - 1].clone();
+
+        // knot j is associated with time index j - DEGREE
+        let knots = (0..n + 1 + 2 * DEGREE)
+            .map(|j| {
+                if j < DEGREE {
+                    times[0].clone() - S::from_f64((DEGREE - j) as f64) * first_delta.clone()
+                } else if j - DEGREE > n {
+                    times[n].clone() + S::from_f64((j - DEGREE - n) as f64) * last_delta.clone()
+                } else {
+                    times[j - DEGREE].clone()
+                }
+            })
+            .collect();
+
         Self {
-            spline_impl: CubicBSplineImpl {
-                control_points,
-                delta_t: params.delta_t,
-            },
-            t0: params.t0,
+            control_points,
+            knots,
+            padding: (DEGREE - 1) / 2,
+        }
+    }
+
+    /// create a new B-Spline from a knot vector
+    ///
+    /// The knot vector must be non-decreasing and of length N + DEGREE + 1 for N control points.
+    /// The spline is defined on [knots[DEGREE], knots[N]]. No control points are repeated; hence,
+    /// e.g. a clamped spline is created by repeating the first and last knot DEGREE+1 times.
+    pub fn from_knots(control_points: Vec<S::SingleVector<DIMS>>, knots: Vec<S>) -> Self {
+        assert!(DEGREE >= 1);
+        assert!(control_points.len() > DEGREE);
+        assert_eq!(knots.len(), control_points.len() + DEGREE + 1);
+        for w in knots.windows(2) {
+            assert!(w[0].single_real_scalar() <= w[1].single_real_scalar());
+        }
+        assert!(
+            knots[DEGREE].single_real_scalar() < knots[control_points.len()].single_real_scalar()
+        );
+
+        Self {
+            control_points,
+            knots,
+            padding: 0,
         }
     }
 
     /// interpolate
     pub fn interpolate(&self, t: S) -> S::SingleVector<DIMS> {
-        let index_and_u = self.index_and_u(t);
-        self.spline_impl
-            .interpolate(index_and_u.segment_idx, index_and_u.u)
+        self.derivative(t, 0)
     }
 
-    /// derivative of the interpolation
+    /// derivative of the given order with respect to t
+    ///
+    /// Derivatives of order greater than DEGREE are zero.
+    pub fn derivative(&self, t: S, order: usize) -> S::SingleVector<DIMS> {
+        let span = self.span(&t);
+        let ders = BSplineBasisFunction::<S, DEGREE>::derivatives(&self.knots, span, t, order);
+
+        let mut x = S::SingleVector::<DIMS>::zeros();
+        for (j, n_j) in ders[order].iter().enumerate() {
+            x = x + self.control_point(span - DEGREE + j).scaled(n_j.clone());
+        }
+        x
+    }
+
+    /// derivative of the interpolation with respect to a control point
     pub fn dxi_interpolate(&self, t: S, control_point_idx: usize) -> S::SingleMatrix<DIMS, DIMS> {
-        let index_and_u = self.index_and_u(t);
-        self.spline_impl
-            .dxi_interpolate(index_and_u.segment_idx, index_and_u.u, control_point_idx)
+        let span = self.span(&t);
+        let ders = BSplineBasisFunction::<S, DEGREE>::derivatives(&self.knots, span, t, 0);
+
+        let mut n_sum = S::from_f64(0.0);
+        for (j, n_j) in ders[0].iter().enumerate() {
+            if self.control_point_idx(span - DEGREE + j) == control_point_idx {
+                n_sum += n_j.clone();
+            }
+        }
+        S::SingleMatrix::<DIMS, DIMS>::identity().scaled(n_sum)
     }
 
-    /// indices involved
+    /// indices of the control points involved at time t
     pub fn idx_involved(&self, t: S) -> Vec<usize> {
-        let index_and_u = self.index_and_u(t);
-        self.spline_impl.idx_involved(index_and_u.segment_idx)
+        let span = self.span(&t);
+        let mut idx: Vec<usize> = (span - DEGREE..=span)
+            .map(|j| self.control_point_idx(j))
+            .collect();
+        idx.dedup();
+        idx
     }
 
-    /// index and u
-    pub fn index_and_u(&self, t: S) -> IndexAndU<S> {
-        assert!(t.greater_equal(&self.t0).all());
-        assert!(t.less_equal(&self.t_max()).all());
+    /// number of segments - i.e. knot spans in [t0, t_max], including empty ones
+    pub fn num_segments(&self) -> usize {
+        self.num_basis() - DEGREE
+    }
 
-        let normalized_t: S = self.normalized_t(t.clone());
+    /// start time t0
+    pub fn t0(&self) -> S {
+        self.knots[DEGREE].clone()
+    }
 
-        let mut idx_and_u = IndexAndU::<S> {
-            segment_idx: normalized_t.i64_floor() as usize,
-            u: normalized_t.clone().fract(),
-        };
-        println!("{:?}", idx_and_u);
+    /// t_max
+    pub fn t_max(&self) -> S {
+        self.knots[self.num_basis()].clone()
+    }
 
-        let eps = 0.00001;
+    /// segment index and u in [0, 1] within the segment at time t
+    #[deprecated(note = "segments are knot spans now, use BSpline::knots instead")]
+    pub fn index_and_u(&self, t: S) -> IndexAndU<S> {
+        self.segment_idx_and_u(t)
+    }
 
-        if idx_and_u.u.single_real_scalar() > eps {
-            println!("case A");
-            return idx_and_u;
-        }
+    /// t normalized to [0, N] for N segments - i.e. segment index plus u
+    #[deprecated(note = "segments are knot spans now, use BSpline::knots instead")]
+    pub fn normalized_t(&self, t: S) -> S {
+        let idx_and_u = self.segment_idx_and_u(t);
+        S::from_f64(idx_and_u.segment_idx as f64) + idx_and_u.u
+    }
 
-        // i < N/2
-        if idx_and_u.segment_idx < self.num_segments() / 2 {
-            println!("case B");
-            return idx_and_u;
+    fn segment_idx_and_u(&self, t: S) -> IndexAndU<S> {
+        let span = self.span(&t);
+        let u = (t - self.knots[span].clone())
+            / (self.knots[span + 1].clone() - self.knots[span].clone());
+        IndexAndU {
+            segment_idx: span - DEGREE,
+            u,
         }
+    }
 
-        println!("case C");
-
-        idx_and_u.segment_idx -= 1;
-        idx_and_u.u += S::from_f64(1.0);
-
-        idx_and_u
+    fn num_basis(&self) -> usize {
+        self.knots.len() - DEGREE - 1
     }
 
-    /// normalized between [0, N]
-    pub fn normalized_t(&self, t: S) -> S {
-        (t - self.t0.clone()) / self.spline_impl.delta_t.clone()
+    fn span(&self, t: &S) -> usize {
+        BSplineBasisFunction::<S, DEGREE>::span(&self.knots, self.num_basis(), t)
     }
 
-    /// number of segments
-    pub fn num_segments(&self) -> usize {
-        self.spline_impl.num_segments()
+    fn control_point_idx(&self, basis_idx: usize) -> usize {
+        basis_idx
+            .saturating_sub(self.padding)
+            .min(self.control_points.len() - 1)
     }
 
-    /// t_max
-    pub fn t_max(&self) -> S {
-        self.t0.clone() + S::from_f64(self.num_segments() as f64) * self.spline_impl.delta_t.clone()
+    fn control_point(&self, basis_idx: usize) -> S::SingleVector<DIMS> {
+        self.control_points[self.control_point_idx(basis_idx)].clone()
     }
 }
 
@@ -263,4 +275,165 @@ mod test {
             println!("{:?}", spline.idx_involved(1.01));
         }
     }
+
+    #[test]
+    fn test_cubic_b_spline_segments() {
+        use super::super::spline::spline_segment::CubicBSplineSegment;
+        use super::super::spline::spline_segment::SegmentCase;
+        use super::super::spline::CubicBSpline;
+        use super::super::spline::CubicBSplineParams;
+        use crate::points::example_points;
+
+        // The cubic B-Spline agrees with the cumulative segment representation.
+        let points = example_points::<f64, 3, 1>();
+        let n = points.len() - 1;
+        let (t0, delta_t) = (0.5, 2.0);
+        let spline = CubicBSpline::new(points.clone(), CubicBSplineParams { delta_t, t0 });
+        approx::assert_abs_diff_eq!(spline.t0(), t0);
+        approx::assert_abs_diff_eq!(spline.t_max(), t0 + n as f64 * delta_t);
+        assert_eq!(spline.num_segments(), n);
+
+        for segment_idx in 0..n {
+            let case = if segment_idx == 0 {
+                SegmentCase::First
+            } else if segment_idx == n - 1 {
+                SegmentCase::Last
+            } else {
+                SegmentCase::Normal
+            };
+            let idx = [
+                segment_idx.max(1) - 1,
+                segment_idx,
+                segment_idx + 1,
+                (segment_idx + 2).min(n),
+            ];
+            let segment = CubicBSplineSegment::<f64, 3> {
+                case,
+                control_points: idx.map(|i| points[i]),
+            };
+
+            for k in 0..10 {
+                let u = 0.1 * k as f64;
+                let t = t0 + (segment_idx as f64 + u) * delta_t;
+                #[allow(deprecated)]
+                {
+                    let idx_and_u = spline.index_and_u(t);
+                    assert_eq!(idx_and_u.segment_idx, segment_idx);
+                    approx::assert_abs_diff_eq!(idx_and_u.u, u, epsilon = 1e-9);
+                    approx::assert_abs_diff_eq!(
+                        spline.normalized_t(t),
+                        (t - t0) / delta_t,
+                        epsilon = 1e-9
+                    );
+                }
+                approx::assert_abs_diff_eq!(
+                    spline.interpolate(t),
+                    segment.interpolate(u),
+                    epsilon = 1e-9
+                );
+                for i in 0..=n {
+                    let mut expected = nalgebra::Matrix3::<f64>::zeros();
+                    for (quadruple_idx, control_point_idx) in idx.iter().enumerate() {
+                        if *control_point_idx == i {
+                            expected += segment.dxi_interpolate(u, quadruple_idx);
+                        }
+                    }
+                    approx::assert_abs_diff_eq!(
+                        spline.dxi_interpolate(t, i),
+                        expected,
+                        epsilon = 1e-9
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_non_uniform_b_spline() {
+        use super::super::spline::BSpline;
+        use crate::calculus::maps::curves::VectorValuedCurve;
+        use crate::calculus::maps::vector_valued_maps::VectorValuedMapFromVector;
+        use crate::linalg::VecF64;
+        use crate::points::example_points;
+
+        fn run<const DEGREE: usize>() {
+            let points = example_points::<f64, 3, 1>();
+            // timestamps with gaps
+            let times: Vec<f64> = [0.0, 0.3, 0.5, 1.4, 1.6, 1.7, 2.9, 3.0]
+                .into_iter()
+                .take(points.len())
+                .collect();
+            let spline = BSpline::<f64, DEGREE, 3>::new_non_uniform(points.clone(), times.clone());
+            approx::assert_abs_diff_eq!(spline.t0(), times[0]);
+            approx::assert_abs_diff_eq!(spline.t_max(), *times.last().unwrap());
+
+            let constant =
+                BSpline::<f64, DEGREE, 3>::new_non_uniform(vec![points[1]; points.len()], times);
+
+            let mut t = spline.t0();
+            while t <= spline.t_max() {
+                // partition of unity
+                approx::assert_abs_diff_eq!(constant.interpolate(t), points[1], epsilon = 1e-9);
+
+                // derivatives up to the degree
+                for order in 1..=DEGREE + 1 {
+                    let dt = spline.derivative(t, order);
+                    if order > DEGREE {
+                        approx::assert_abs_diff_eq!(dt, VecF64::<3>::zeros());
+                        continue;
+                    }
+                    // the (DEGREE-1)-th derivative is not differentiable at the knots
+                    let h = 1e-6;
+                    if spline.knots.iter().any(|k| (t - k).abs() < 1e-4) {
+                        continue;
+                    }
+                    let num_dt = VectorValuedCurve::sym_diff_quotient(
+                        |t| spline.derivative(t, order - 1),
+                        t,
+                        h,
+                    );
+                    approx::assert_relative_eq!(dt, num_dt, epsilon = 1e-3, max_relative = 1e-4);
+                }
+
+                // Jacobians with respect to the control points
+                let involved = spline.idx_involved(t);
+                for i in 0..points.len() {
+                    let dxi = spline.dxi_interpolate(t, i);
+                    let num_dxi = VectorValuedMapFromVector::static_sym_diff_quotient(
+                        |p: VecF64<3>| {
+                            let mut perturbed = spline.clone();
+                            perturbed.control_points[i] = p;
+                            perturbed.interpolate(t)
+                        },
+                        points[i],
+                        1e-6,
+                    );
+                    approx::assert_abs_diff_eq!(dxi, num_dxi, epsilon = 1e-6);
+                    if !involved.contains(&i) {
+                        approx::assert_abs_diff_eq!(dxi, nalgebra::Matrix3::<f64>::zeros());
+                    }
+                }
+                t += 0.05;
+            }
+        }
+
+        run::<1>();
+        run::<2>();
+        run::<4>();
+        run::<5>();
+
+        // Linear B-Spline interpolates the control points.
+        let points = example_points::<f64, 2, 1>();
+        let times: Vec<f64> = (0..points.len()).map(|i| (i * i) as f64).collect();
+        let linear = BSpline::<f64, 1, 2>::new_non_uniform(points.clone(), times.clone());
+        for (p, t) in points.iter().zip(times) {
+            approx::assert_abs_diff_eq!(linear.interpolate(t), *p, epsilon = 1e-9);
+        }
+
+        // Clamped cubic B-Spline from a knot vector interpolates the first and last control point.
+        let knots = vec![0.0, 0.0, 0.0, 0.0, 0.5, 2.0, 2.5, 3.0, 3.0, 3.0, 3.0];
+        let clamped = BSpline::<f64, 3, 2>::from_knots(points[0..7].to_vec(), knots);
+        approx::assert_abs_diff_eq!(clamped.interpolate(0.0), points[0], epsilon = 1e-9);
+        approx::assert_abs_diff_eq!(clamped.interpolate(3.0), points[6], epsilon = 1e-9);
+    }
 }
@@ -0,0 +1,476 @@
+use crate::calculus::spline::BSpline;
+use crate::calculus::spline::BSplineParams;
+use crate::linalg::MatF64;
+use crate::linalg::VecF64;
+use alloc::vec;
+use alloc::vec::Vec;
+use nalgebra::DVector;
+use num_traits::Float;
+
+/// Error returned by the spline fitting functions
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SplineFitError {
+    /// fewer samples than required
+    NotEnoughSamples {
+        /// number of given samples
+        num_samples: usize,
+        /// minimal number of samples
+        min_samples: usize,
+    },
+    /// knot spacing is not positive, smoothness weight is negative, or a timestamp is not finite
+    InvalidParams,
+    /// the samples do not determine all control points - e.g. due to a gap in the timestamps
+    /// larger than the knot spacing. Consider a positive smoothness weight.
+    Underdetermined,
+}
+
+impl core::fmt::Display for SplineFitError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SplineFitError::NotEnoughSamples {
+                num_samples,
+                min_samples,
+            } => write!(
+                f,
+                "not enough samples: got {}, need at least {}",
+                num_samples, min_samples
+            ),
+            SplineFitError::InvalidParams => {
+                write!(
+                    f,
+                    "knot spacing must be positive, and smoothness weight non-negative"
+                )
+            }
+            SplineFitError::Underdetermined => {
+                write!(f, "samples do not determine all control points")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SplineFitError {}
+
+/// Spline fitting parameters
+#[derive(Clone, Debug, Copy)]
+pub struct SplineFitParams {
+    /// time between consecutive control points
+    pub delta_t: f64,
+    /// weight of the smoothness regularizer - zero to disable
+    ///
+    /// The regularizer penalizes the second differences of consecutive control points, divided
+    /// by delta_t², i.e. the acceleration of the control polygon.
+    pub smoothness_weight: f64,
+}
+
+/// Residual statistics of a spline fit
+///
+/// The residual of a sample is the distance between the sample and the fitted spline at the
+/// sample time. The smoothness regularizer is not included.
+#[derive(Clone, Debug, Copy, PartialEq)]
+pub struct SplineFitStats {
+    /// number of samples
+    pub num_samples: usize,
+    /// root mean square of the residual norms
+    pub rmse: f64,
+    /// mean of the residual norms
+    pub mean: f64,
+    /// largest residual norm
+    pub max: f64,
+}
+
+impl SplineFitStats {
+    /// Statistics from the residual norms
+    pub fn from_residual_norms(norms: &[f64]) -> Self {
+        let n = norms.len().max(1) as f64;
+        Self {
+            num_samples: norms.len(),
+            rmse: Float::sqrt(norms.iter().map(|r| r * r).sum::<f64>() / n),
+            mean: norms.iter().sum::<f64>() / n,
+            max: norms.iter().cloned().fold(0.0, f64::max),
+        }
+    }
+}
+
+/// Uniform knots covering the sample times
+///
+/// Returns the B-spline parameters and the number of control points, such that
+/// [t0, t0 + (N-1)·delta_t] contains all sample times.
+pub fn knots_covering_samples(
+    times: impl Iterator<Item = f64> + Clone,
+    params: &SplineFitParams,
+) -> Result<(BSplineParams<f64>, usize), SplineFitError> {
+    if !(params.delta_t > 0.0 && params.smoothness_weight >= 0.0)
+        || times.clone().any(|t| !t.is_finite())
+    {
+        return Err(SplineFitError::InvalidParams);
+    }
+    let t0 = times.clone().fold(f64::INFINITY, f64::min);
+    let t1 = times.fold(f64::NEG_INFINITY, f64::max);
+
+    // relative tolerance, such that t1 = t0 + k·delta_t does not add a segment
+    let num_segments = Float::ceil(((t1 - t0) / params.delta_t) * (1.0 - 1e-12)).max(1.0) as usize;
+    Ok((
+        BSplineParams {
+            delta_t: params.delta_t,
+            t0,
+        },
+        num_segments + 1,
+    ))
+}
+
+/// Block-banded normal equations H·x = b of a least squares problem in N block variables of size
+/// DIMS
+///
+/// Each residual may only depend on blocks i and j with |i - j| ≤ block_bandwidth. Hence, only the
+/// lower band of H is stored, and the system is solved with a banded Cholesky decomposition in
+/// O(N · DIMS³ · block_bandwidth²) time and O(N · DIMS² · block_bandwidth) memory.
+///
+/// Used for the linear fit here, and for each Gauss-Newton step of the Lie group spline fit.
+pub struct NormalEquations<const DIMS: usize> {
+    // number of scalar variables, N · DIMS
+    num_scalars: usize,
+    // number of sub-diagonals of H
+    bandwidth: usize,
+    // H(i, j) for j ≤ i ≤ j + bandwidth, stored at i · (bandwidth + 1) + (i - j)
+    lower_band: Vec<f64>,
+    gradient: DVector<f64>,
+}
+
+impl<const DIMS: usize> NormalEquations<DIMS> {
+    /// Zero normal equations for the given number of blocks, and the largest block index
+    /// difference of two blocks which appear in the same residual
+    pub fn new(num_blocks: usize, block_bandwidth: usize) -> Self {
+        let num_scalars = num_blocks * DIMS;
+        let bandwidth = (block_bandwidth + 1) * DIMS - 1;
+        Self {
+            num_scalars,
+            bandwidth,
+            lower_band: vec![0.0; num_scalars * (bandwidth + 1)],
+            gradient: DVector::zeros(num_scalars),
+        }
+    }
+
+    fn band_idx(&self, row: usize, col: usize) -> usize {
+        debug_assert!(col <= row && row - col <= self.bandwidth);
+        row * (self.bandwidth + 1) + (row - col)
+    }
+
+    /// Adds the residual r(x) ≈ residual + ∑ⱼ Jⱼ·xⱼ, with the Jacobians given per block index
+    ///
+    /// Panics if two block indices differ by more than the block bandwidth.
+    pub fn add<const R: usize>(
+        &mut self,
+        residual: &VecF64<R>,
+        jacobians: &[(usize, MatF64<R, DIMS>)],
+    ) {
+        for (i, j_i) in jacobians {
+            let g = j_i.transpose() * residual;
+            let mut b = self.gradient.fixed_rows_mut::<DIMS>(i * DIMS);
+            b -= g;
+            for (j, j_j) in jacobians {
+                if j > i {
+                    continue;
+                }
+                assert!(
+                    (i - j + 1) * DIMS - 1 <= self.bandwidth,
+                    "blocks {} and {} are outside of the band of the normal equations",
+                    j,
+                    i
+                );
+                let h = j_i.transpose() * j_j;
+                for r in 0..DIMS {
+                    for c in 0..DIMS {
+                        let (row, col) = (i * DIMS + r, j * DIMS + c);
+                        // the upper triangle of the diagonal blocks is implied by symmetry
+                        if col <= row {
+                            let idx = self.band_idx(row, col);
+                            self.lower_band[idx] += h[(r, c)];
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Adds the smoothness regularizer for the i-th second difference, i.e. for blocks i-1, i, i+1
+    pub fn add_smoothness(
+        &mut self,
+        i: usize,
+        second_difference: &VecF64<DIMS>,
+        jacobians: [MatF64<DIMS, DIMS>; 3],
+        weight: f64,
+    ) {
+        let sqrt_weight = Float::sqrt(weight);
+        self.add(
+            &(second_difference * sqrt_weight),
+            &[
+                (i - 1, jacobians[0] * sqrt_weight),
+                (i, jacobians[1] * sqrt_weight),
+                (i + 1, jacobians[2] * sqrt_weight),
+            ],
+        );
+    }
+
+    /// Solves for the update x, such that the sum of squared residuals is minimized
+    pub fn solve(mut self) -> Result<DVector<f64>, SplineFitError> {
+        let n = self.num_scalars;
+        let w = self.bandwidth;
+
+        // in-place Cholesky decomposition H = L·Lᵀ, with L having the same lower band as H
+        for j in 0..n {
+            let first = j.saturating_sub(w);
+            let mut diagonal = self.lower_band[self.band_idx(j, j)];
+            for k in first..j {
+                let l_jk = self.lower_band[self.band_idx(j, k)];
+                diagonal -= l_jk * l_jk;
+            }
+            if diagonal.is_nan() || diagonal <= 0.0 {
+                return Err(SplineFitError::Underdetermined);
+            }
+            let l_jj = Float::sqrt(diagonal);
+            let jj = self.band_idx(j, j);
+            self.lower_band[jj] = l_jj;
+
+            for i in j + 1..n.min(j + w + 1) {
+                let mut l_ij = self.lower_band[self.band_idx(i, j)];
+                for k in i.saturating_sub(w)..j {
+                    l_ij -=
+                        self.lower_band[self.band_idx(i, k)] * self.lower_band[self.band_idx(j, k)];
+                }
+                let ij = self.band_idx(i, j);
+                self.lower_band[ij] = l_ij / l_jj;
+            }
+        }
+
+        // L·y = b, then Lᵀ·x = y
+        let mut x = self.gradient.clone();
+        for i in 0..n {
+            for k in i.saturating_sub(w)..i {
+                x[i] -= self.lower_band[self.band_idx(i, k)] * x[k];
+            }
+            x[i] /= self.lower_band[self.band_idx(i, i)];
+        }
+        for i in (0..n).rev() {
+            for k in i + 1..n.min(i + w + 1) {
+                x[i] -= self.lower_band[self.band_idx(k, i)] * x[k];
+            }
+            x[i] /= self.lower_band[self.band_idx(i, i)];
+        }
+        Ok(x)
+    }
+}
+
+impl<const DEGREE: usize, const DIMS: usize> BSpline<f64, DEGREE, DIMS> {
+    /// Least squares fit of a B-spline with uniform knots to timestamped samples
+    ///
+    /// The knots are spaced by params.delta_t and cover all sample times, see [BSpline::new]. The
+    /// control points minimize
+    ///
+    ///   ∑ₖ |x(tₖ) - yₖ|²  +  λ · ∑ᵢ |(pᵢ₋₁ - 2pᵢ + pᵢ₊₁) / delta_t²|²
+    ///
+    /// with λ = params.smoothness_weight. Since x(t) is linear in the control points, the
+    /// problem is solved in closed form, using dxi_interpolate for the Jacobians.
+    pub fn fit(
+        samples: &[(f64, VecF64<DIMS>)],
+        params: SplineFitParams,
+    ) -> Result<(Self, SplineFitStats), SplineFitError> {
+        if samples.len() < 2 {
+            return Err(SplineFitError::NotEnoughSamples {
+                num_samples: samples.len(),
+                min_samples: 2,
+            });
+        }
+        let (spline_params, num_control_points) =
+            knots_covering_samples(samples.iter().map(|s| s.0), &params)?;
+        let mut spline = Self::new(
+            vec![VecF64::<DIMS>::zeros(); num_control_points],
+            spline_params,
+        );
+
+        // residuals at zero, i.e. -yₖ, and 0 for the regularizer
+        //
+        // A sample depends on DEGREE+1 consecutive control points, the regularizer on three.
+        let mut normal_equations = NormalEquations::<DIMS>::new(num_control_points, DEGREE.max(2));
+        for (t, y) in samples {
+            let jacobians: Vec<(usize, MatF64<DIMS, DIMS>)> = spline
+                .idx_involved(*t)
+                .into_iter()
+                .map(|i| (i, spline.dxi_interpolate(*t, i)))
+                .collect();
+            normal_equations.add(&-y, &jacobians);
+        }
+        if params.smoothness_weight > 0.0 {
+            let identity = MatF64::<DIMS, DIMS>::identity();
+            for i in 1..num_control_points - 1 {
+                normal_equations.add_smoothness(
+                    i,
+                    &VecF64::<DIMS>::zeros(),
+                    [identity, identity * -2.0, identity],
+                    params.smoothness_weight / Float::powi(params.delta_t, 4),
+                );
+            }
+        }
+
+        let x = normal_equations.solve()?;
+        for (i, p) in spline.control_points.iter_mut().enumerate() {
+            *p = x.fixed_rows::<DIMS>(i * DIMS).into_owned();
+        }
+
+        let residual_norms: Vec<f64> = samples
+            .iter()
+            .map(|(t, y)| (spline.interpolate(*t) - y).norm())
+            .collect();
+        Ok((spline, SplineFitStats::from_residual_norms(&residual_norms)))
+    }
+}
+
+#[test]
+fn spline_fit_tests() {
+    use crate::calculus::spline::CubicBSpline;
+
+    // exact recovery of a cubic B-spline from its samples
+    let control_points: Vec<VecF64<2>> = (0..8)
+        .map(|i| VecF64::<2>::new((i as f64).sin(), 0.5 * (i as f64)))
+        .collect();
+    let params = BSplineParams {
+        delta_t: 0.5,
+        t0: 1.0,
+    };
+    let spline = CubicBSpline::new(control_points.clone(), params);
+    let samples: Vec<(f64, VecF64<2>)> = (0..=70)
+        .map(|k| {
+            let t = 1.0 + 0.05 * (k as f64);
+            (t, spline.interpolate(t))
+        })
+        .collect();
+    let (fitted, stats) = CubicBSpline::<f64, 2>::fit(
+        &samples,
+        SplineFitParams {
+            delta_t: 0.5,
+            smoothness_weight: 0.0,
+        },
+    )
+    .unwrap();
+    assert_eq!(stats.num_samples, samples.len());
+    assert_eq!(fitted.control_points.len(), control_points.len());
+    approx::assert_abs_diff_eq!(stats.max, 0.0, epsilon = 1e-9);
+    for (a, b) in fitted.control_points.iter().zip(control_points.iter()) {
+        approx::assert_abs_diff_eq!(a, b, epsilon = 1e-8);
+    }
+
+    // noisy samples of a circle, with irregular timestamps and a gap
+    let samples: Vec<(f64, VecF64<2>)> = (0..200)
+        .filter(|k| !(80..100).contains(k))
+        .map(|k| {
+            let t = 0.05 * (k as f64) + 0.01 * ((7 * k % 5) as f64);
+            let noise = 0.01 * VecF64::<2>::new((13.0 * t).sin(), (17.0 * t).cos());
+            (t, VecF64::<2>::new(t.cos(), t.sin()) + noise)
+        })
+        .collect();
+    let mut prev_rmse = 0.0;
+    for smoothness_weight in [0.0, 1e-4, 0.01] {
+        let (fitted, stats) = CubicBSpline::<f64, 2>::fit(
+            &samples,
+            SplineFitParams {
+                delta_t: 0.5,
+                smoothness_weight,
+            },
+        )
+        .unwrap();
+        assert!(stats.rmse <= stats.max);
+        assert!(stats.mean <= stats.rmse + 1e-12);
+        assert!(stats.rmse >= prev_rmse);
+        prev_rmse = stats.rmse;
+        // the spline is smooth, and follows the circle within the noise level
+        for (t, _) in &samples {
+            let expected = VecF64::<2>::new(t.cos(), t.sin());
+            approx::assert_abs_diff_eq!(fitted.interpolate(*t), expected, epsilon = 0.05);
+        }
+    }
+    // a large weight flattens the control polygon, at the expense of the residuals
+    let (flat, stats) = CubicBSpline::<f64, 2>::fit(
+        &samples,
+        SplineFitParams {
+            delta_t: 0.5,
+            smoothness_weight: 1e6,
+        },
+    )
+    .unwrap();
+    assert!(stats.rmse > 10.0 * prev_rmse);
+    for p in flat.control_points.windows(3) {
+        approx::assert_abs_diff_eq!(
+            p[0] - 2.0 * p[1] + p[2],
+            VecF64::<2>::zeros(),
+            epsilon = 1e-3
+        );
+    }
+
+    // a gap larger than the knot spacing, without regularizer
+    let sparse = [
+        (0.0, VecF64::<1>::new(0.0)),
+        (0.1, VecF64::<1>::new(1.0)),
+        (5.0, VecF64::<1>::new(2.0)),
+    ];
+    let fit_params = |smoothness_weight| SplineFitParams {
+        delta_t: 0.2,
+        smoothness_weight,
+    };
+    assert_eq!(
+        CubicBSpline::<f64, 1>::fit(&sparse, fit_params(0.0)).err(),
+        Some(SplineFitError::Underdetermined)
+    );
+    assert!(CubicBSpline::<f64, 1>::fit(&sparse, fit_params(0.1)).is_ok());
+    assert_eq!(
+        CubicBSpline::<f64, 1>::fit(&sparse[0..1], fit_params(0.1)).err(),
+        Some(SplineFitError::NotEnoughSamples {
+            num_samples: 1,
+            min_samples: 2
+        })
+    );
+    assert_eq!(
+        CubicBSpline::<f64, 1>::fit(&sparse, fit_params(-1.0)).err(),
+        Some(SplineFitError::InvalidParams)
+    );
+}
+
+#[test]
+fn spline_fit_long_log_tests() {
+    use crate::calculus::spline::CubicBSpline;
+
+    // An hour-long log at 40 Hz, with 36k segments. Dense normal equations would take about
+    // 90 GB here.
+    let control_points: Vec<VecF64<3>> = (0..36_001)
+        .map(|i| {
+            let t = 0.1 * i as f64;
+            VecF64::<3>::new(100.0 * (0.01 * t).sin(), 100.0 * (0.013 * t).cos(), 0.5 * t)
+        })
+        .collect();
+    let spline = CubicBSpline::new(
+        control_points.clone(),
+        BSplineParams {
+            delta_t: 0.1,
+            t0: 0.0,
+        },
+    );
+    let samples: Vec<(f64, VecF64<3>)> = (0..=144_000)
+        .map(|k| {
+            let t = 0.025 * k as f64;
+            (t, spline.interpolate(t))
+        })
+        .collect();
+    let (fitted, stats) = CubicBSpline::<f64, 3>::fit(
+        &samples,
+        SplineFitParams {
+            delta_t: 0.1,
+            smoothness_weight: 0.0,
+        },
+    )
+    .unwrap();
+    assert_eq!(stats.num_samples, samples.len());
+    assert_eq!(fitted.control_points.len(), control_points.len());
+    approx::assert_abs_diff_eq!(stats.max, 0.0, epsilon = 1e-8);
+    for (a, b) in fitted.control_points.iter().zip(control_points.iter()) {
+        approx::assert_abs_diff_eq!(a, b, epsilon = 1e-6);
+    }
+}
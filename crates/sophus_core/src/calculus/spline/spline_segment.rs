@@ -1,12 +1,116 @@
 use crate::prelude::*;
-use std::marker::PhantomData;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
 
-/// cubic basis function
-pub struct CubicBasisFunction<S> {
+/// B-spline basis function of a given degree
+///
+/// For a (possibly non-uniform) knot vector u₀ ≤ u₁ ≤ ... , the basis functions Nⱼ,ₚ(t) of degree
+/// p are defined by the Cox-de Boor recursion. In each knot span [uₖ, uₖ₊₁), only the p+1 basis
+/// functions Nₖ₋ₚ,ₚ ... Nₖ,ₚ are non-zero.
+pub struct BSplineBasisFunction<S, const DEGREE: usize> {
     phantom: PhantomData<S>,
 }
 
-impl<S: IsSingleScalar> CubicBasisFunction<S> {
+/// Cubic basis function
+pub type CubicBasisFunction<S> = BSplineBasisFunction<S, 3>;
+
+impl<S: IsSingleScalar, const DEGREE: usize> BSplineBasisFunction<S, DEGREE> {
+    /// Index k of the knot span [uₖ, uₖ₊₁) which contains t
+    ///
+    /// The spline is defined on [u_DEGREE, u_num_basis], and t = u_num_basis is assigned to the last
+    /// non-empty span. Since the knots are sorted, the span is found by binary search.
+    pub fn span(knots: &[S], num_basis: usize, t: &S) -> usize {
+        assert!(num_basis > DEGREE);
+        assert_eq!(knots.len(), num_basis + DEGREE + 1);
+
+        let t = t.single_real_scalar();
+        let t_max = knots[num_basis].single_real_scalar();
+        assert!(t >= knots[DEGREE].single_real_scalar() && t <= t_max);
+
+        // The last knot uₖ ≤ t, with k < num_basis, starts a non-empty span - unless uₖ = t_max,
+        // then the last knot below t_max does.
+        let num_knots_up_to_t = knots[DEGREE..num_basis].partition_point(|u| {
+            let u = u.single_real_scalar();
+            u <= t && u < t_max
+        });
+        DEGREE + num_knots_up_to_t.max(1) - 1
+    }
+
+    /// Non-zero basis functions at t, and their derivatives up to the given order
+    ///
+    /// Returns ders, with ders[i][j] being the i-th derivative of Nₖ₋ₚ₊ⱼ,ₚ at t, for the span k.
+    /// Derivatives of order greater than the degree are zero.
+    pub fn derivatives(knots: &[S], span: usize, t: S, order: usize) -> Vec<Vec<S>> {
+        let p = DEGREE;
+        let zero = || S::from_f64(0.0);
+
+        // ndu: basis functions (upper triangle) and knot differences (lower triangle)
+        let mut ndu = vec![vec![zero(); p + 1]; p + 1];
+        let mut left = vec![zero(); p + 1];
+        let mut right = vec![zero(); p + 1];
+        ndu[0][0] = S::from_f64(1.0);
+        for j in 1..=p {
+            left[j] = t.clone() - knots[span + 1 - j].clone();
+            right[j] = knots[span + j].clone() - t.clone();
+            let mut saved = zero();
+            for r in 0..j {
+                ndu[j][r] = right[r + 1].clone() + left[j - r].clone();
+                let temp = ndu[r][j - 1].clone() / ndu[j][r].clone();
+                ndu[r][j] = saved + right[r + 1].clone() * temp.clone();
+                saved = left[j - r].clone() * temp;
+            }
+            ndu[j][j] = saved;
+        }
+
+        let mut ders = vec![vec![zero(); p + 1]; order + 1];
+        for j in 0..=p {
+            ders[0][j] = ndu[j][p].clone();
+        }
+
+        // derivatives, following "The NURBS Book" (Piegl & Tiller), algorithm A2.3
+        let n = order.min(p);
+        for r in 0..=p {
+            let mut a = [vec![zero(); p + 1], vec![zero(); p + 1]];
+            let (mut s1, mut s2) = (0, 1);
+            a[0][0] = S::from_f64(1.0);
+            for k in 1..=n {
+                let mut d = zero();
+                let pk = p - k;
+                if r >= k {
+                    let rk = r - k;
+                    a[s2][0] = a[s1][0].clone() / ndu[pk + 1][rk].clone();
+                    d = a[s2][0].clone() * ndu[rk][pk].clone();
+                }
+                let j1 = if r + 1 >= k { 1 } else { k - r };
+                let j2 = if r <= pk + 1 { k - 1 } else { p - r };
+                for j in j1..=j2 {
+                    // r + j >= k, since j >= j1
+                    let rkj = r + j - k;
+                    a[s2][j] = (a[s1][j].clone() - a[s1][j - 1].clone()) / ndu[pk + 1][rkj].clone();
+                    d += a[s2][j].clone() * ndu[rkj][pk].clone();
+                }
+                if r <= pk {
+                    a[s2][k] = -a[s1][k - 1].clone() / ndu[pk + 1][r].clone();
+                    d += a[s2][k].clone() * ndu[r][pk].clone();
+                }
+                ders[k][r] = d;
+                core::mem::swap(&mut s1, &mut s2);
+            }
+        }
+
+        let mut factor = p as f64;
+        for k in 1..=n {
+            for j in 0..=p {
+                ders[k][j] = ders[k][j].clone() * S::from_f64(factor);
+            }
+            factor *= (p - k) as f64;
+        }
+        ders
+    }
+}
+
+impl<S: IsSingleScalar> BSplineBasisFunction<S, 3> {
     /// C matrix
     pub fn c() -> S::SingleMatrix<3, 4> {
         S::SingleMatrix::<3, 4>::from_f64_array2([
@@ -251,6 +355,37 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_span() {
+        use crate::calculus::spline::spline_segment::CubicBasisFunction;
+
+        // knot spans [uₖ, uₖ₊₁), including empty ones and repeated end knots
+        let knots = [0.0, 0.0, 0.0, 0.0, 0.5, 0.5, 2.0, 2.5, 3.0, 3.0, 3.0, 3.0];
+        let num_basis = knots.len() - 4;
+        let linear_search = |t: f64| {
+            (3..num_basis)
+                .rev()
+                .find(|&k| knots[k] < knots[k + 1] && knots[k] <= t)
+                .unwrap()
+        };
+        for t in [0.0, 0.2, 0.5, 0.7, 2.0, 2.2, 2.5, 2.9, 3.0] {
+            let span = CubicBasisFunction::<f64>::span(&knots, num_basis, &t);
+            assert_eq!(span, linear_search(t), "t = {}", t);
+            assert!(knots[span] <= t && t <= knots[span + 1]);
+        }
+        assert_eq!(CubicBasisFunction::<f64>::span(&knots, num_basis, &0.5), 5);
+        assert_eq!(CubicBasisFunction::<f64>::span(&knots, num_basis, &3.0), 7);
+
+        // a long uniform knot vector
+        let knots: Vec<f64> = (0..100_000).map(|k| k as f64 * 0.1).collect();
+        let num_basis = knots.len() - 4;
+        let t = knots[54_321] + 0.05;
+        assert_eq!(
+            CubicBasisFunction::<f64>::span(&knots, num_basis, &t),
+            54_321
+        );
+    }
+
     #[test]
     fn test_spline_segment() {
         use crate::calculus::dual::dual_scalar::DualScalar;
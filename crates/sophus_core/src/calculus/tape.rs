@@ -0,0 +1,13 @@
+/// Tape matrix.
+pub mod tape_matrix;
+pub use crate::calculus::tape::tape_matrix::TapeMatrix;
+
+/// Tape scalar, and the reverse-mode tape.
+pub mod tape_scalar;
+pub use crate::calculus::tape::tape_scalar::Tape;
+pub use crate::calculus::tape::tape_scalar::TapeIndex;
+pub use crate::calculus::tape::tape_scalar::TapeScalar;
+
+/// Tape vector.
+pub mod tape_vector;
+pub use crate::calculus::tape::tape_vector::TapeVector;
@@ -0,0 +1,278 @@
+use super::tape_scalar::TapeIndex;
+use super::tape_scalar::TapeScalar;
+use super::tape_scalar::CONSTANT;
+use super::tape_vector::TapeVector;
+use crate::calculus::dual::DualMatrix;
+use crate::linalg::MatF64;
+use crate::prelude::*;
+use approx::AbsDiffEq;
+use approx::RelativeEq;
+use core::ops::Add;
+use core::ops::Mul;
+use core::ops::Neg;
+use core::ops::Sub;
+
+/// Tape matrix
+///
+/// Matrix of tape scalars, stored as a real matrix and the tape index of each element.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TapeMatrix<const ROWS: usize, const COLS: usize> {
+    /// real part
+    pub real_part: MatF64<ROWS, COLS>,
+    /// tape index of each element, in row-major order - or CONSTANT
+    pub index: [[TapeIndex; COLS]; ROWS],
+}
+
+impl<const ROWS: usize, const COLS: usize> TapeMatrix<ROWS, COLS> {
+    /// Create a new matrix of tape variables - i.e. inputs of reverse-mode auto-differentiation
+    ///
+    /// The variables are recorded in row-major order.
+    pub fn new_variables(val: MatF64<ROWS, COLS>) -> Self {
+        let mut out = Self::from_real_matrix(val);
+        for r in 0..ROWS {
+            for c in 0..COLS {
+                out.set_elem([r, c], TapeScalar::new_variable(val[(r, c)]));
+            }
+        }
+        out
+    }
+
+    /// Returns the elements as an array of tape scalars, in row-major order
+    pub fn to_array2(&self) -> [[TapeScalar; COLS]; ROWS] {
+        core::array::from_fn(|r| core::array::from_fn(|c| self.get_elem([r, c])))
+    }
+
+    pub(crate) fn from_fn<F: FnMut(usize, usize) -> TapeScalar>(mut f: F) -> Self {
+        let mut out = Self::zeros();
+        for r in 0..ROWS {
+            for c in 0..COLS {
+                out.set_elem([r, c], f(r, c));
+            }
+        }
+        out
+    }
+}
+
+impl<const ROWS: usize, const COLS: usize> IsSingleMatrix<TapeScalar, ROWS, COLS>
+    for TapeMatrix<ROWS, COLS>
+{
+}
+
+impl<const ROWS: usize, const COLS: usize> AbsDiffEq for TapeMatrix<ROWS, COLS> {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> Self::Epsilon {
+        f64::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.real_part.abs_diff_eq(&other.real_part, epsilon)
+    }
+}
+
+impl<const ROWS: usize, const COLS: usize> RelativeEq for TapeMatrix<ROWS, COLS> {
+    fn default_max_relative() -> Self::Epsilon {
+        f64::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.real_part
+            .relative_eq(&other.real_part, epsilon, max_relative)
+    }
+}
+
+impl<const ROWS: usize, const COLS: usize> IsMatrix<TapeScalar, ROWS, COLS, 1>
+    for TapeMatrix<ROWS, COLS>
+{
+    fn from_scalar(val: TapeScalar) -> Self {
+        Self::from_fn(|_, _| val)
+    }
+
+    fn from_real_matrix(val: MatF64<ROWS, COLS>) -> Self {
+        Self {
+            real_part: val,
+            index: [[CONSTANT; COLS]; ROWS],
+        }
+    }
+
+    fn from_array2(vals: [[TapeScalar; COLS]; ROWS]) -> Self {
+        Self::from_fn(|r, c| vals[r][c])
+    }
+
+    fn from_real_scalar_array2(vals: [[f64; COLS]; ROWS]) -> Self {
+        Self::from_real_matrix(MatF64::from_real_scalar_array2(vals))
+    }
+
+    fn from_f64_array2(vals: [[f64; COLS]; ROWS]) -> Self {
+        Self::from_real_matrix(MatF64::from_f64_array2(vals))
+    }
+
+    fn from_f64(val: f64) -> Self {
+        Self::from_real_matrix(MatF64::from_element(val))
+    }
+
+    fn identity() -> Self {
+        Self::from_real_matrix(MatF64::identity())
+    }
+
+    fn real_matrix(&self) -> &MatF64<ROWS, COLS> {
+        &self.real_part
+    }
+
+    fn get_elem(&self, idx: [usize; 2]) -> TapeScalar {
+        TapeScalar {
+            real_part: self.real_part[(idx[0], idx[1])],
+            index: self.index[idx[0]][idx[1]],
+        }
+    }
+
+    fn set_elem(&mut self, idx: [usize; 2], val: TapeScalar) {
+        self.real_part[(idx[0], idx[1])] = val.real_part;
+        self.index[idx[0]][idx[1]] = val.index;
+    }
+
+    fn mat_mul<const C2: usize>(&self, other: TapeMatrix<COLS, C2>) -> TapeMatrix<ROWS, C2> {
+        TapeMatrix::from_fn(|r, c| self.get_row_vec(r).dot(other.get_col_vec(c)))
+    }
+
+    fn block_mat2x1<const R0: usize, const R1: usize>(
+        top_row: TapeMatrix<R0, COLS>,
+        bot_row: TapeMatrix<R1, COLS>,
+    ) -> Self {
+        assert_eq!(ROWS, R0 + R1);
+        Self::from_fn(|r, c| {
+            if r < R0 {
+                top_row.get_elem([r, c])
+            } else {
+                bot_row.get_elem([r - R0, c])
+            }
+        })
+    }
+
+    fn block_mat1x2<const C0: usize, const C1: usize>(
+        left_col: TapeMatrix<ROWS, C0>,
+        righ_col: TapeMatrix<ROWS, C1>,
+    ) -> Self {
+        assert_eq!(COLS, C0 + C1);
+        Self::from_fn(|r, c| {
+            if c < C0 {
+                left_col.get_elem([r, c])
+            } else {
+                righ_col.get_elem([r, c - C0])
+            }
+        })
+    }
+
+    fn block_mat2x2<const R0: usize, const R1: usize, const C0: usize, const C1: usize>(
+        top_row: (TapeMatrix<R0, C0>, TapeMatrix<R0, C1>),
+        bot_row: (TapeMatrix<R1, C0>, TapeMatrix<R1, C1>),
+    ) -> Self {
+        assert_eq!(ROWS, R0 + R1);
+        assert_eq!(COLS, C0 + C1);
+        Self::block_mat2x1(
+            TapeMatrix::<R0, COLS>::block_mat1x2(top_row.0, top_row.1),
+            TapeMatrix::<R1, COLS>::block_mat1x2(bot_row.0, bot_row.1),
+        )
+    }
+
+    fn get_fixed_submat<const R: usize, const C: usize>(
+        &self,
+        start_r: usize,
+        start_c: usize,
+    ) -> TapeMatrix<R, C> {
+        TapeMatrix::from_fn(|r, c| self.get_elem([start_r + r, start_c + c]))
+    }
+
+    fn get_col_vec(&self, c: usize) -> TapeVector<ROWS> {
+        TapeVector::from_fn(|r| self.get_elem([r, c]))
+    }
+
+    fn get_row_vec(&self, r: usize) -> TapeVector<COLS> {
+        TapeVector::from_fn(|c| self.get_elem([r, c]))
+    }
+
+    fn scaled(&self, s: TapeScalar) -> Self {
+        Self::from_fn(|r, c| self.get_elem([r, c]) * s)
+    }
+
+    fn set_col_vec(&mut self, c: usize, v: TapeVector<ROWS>) {
+        for r in 0..ROWS {
+            self.set_elem([r, c], v.get_elem(r));
+        }
+    }
+
+    /// Returns the real part as a dual matrix - the tape indices are dropped.
+    fn to_dual(self) -> DualMatrix<ROWS, COLS> {
+        DualMatrix::from_real_matrix(self.real_part)
+    }
+
+    fn select(self, mask: &bool, other: Self) -> Self {
+        if *mask {
+            self
+        } else {
+            other
+        }
+    }
+}
+
+impl<const ROWS: usize, const COLS: usize> Add for TapeMatrix<ROWS, COLS> {
+    type Output = TapeMatrix<ROWS, COLS>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::from_fn(|r, c| self.get_elem([r, c]) + rhs.get_elem([r, c]))
+    }
+}
+
+impl<const ROWS: usize, const COLS: usize> Sub for TapeMatrix<ROWS, COLS> {
+    type Output = TapeMatrix<ROWS, COLS>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::from_fn(|r, c| self.get_elem([r, c]) - rhs.get_elem([r, c]))
+    }
+}
+
+impl<const ROWS: usize, const COLS: usize> Neg for TapeMatrix<ROWS, COLS> {
+    type Output = TapeMatrix<ROWS, COLS>;
+
+    fn neg(self) -> Self::Output {
+        Self::from_fn(|r, c| -self.get_elem([r, c]))
+    }
+}
+
+impl<const ROWS: usize, const COLS: usize> Mul<TapeVector<COLS>> for TapeMatrix<ROWS, COLS> {
+    type Output = TapeVector<ROWS>;
+
+    fn mul(self, rhs: TapeVector<COLS>) -> Self::Output {
+        TapeVector::from_fn(|r| self.get_row_vec(r).dot(rhs))
+    }
+}
+
+#[test]
+fn tape_matrix_tests() {
+    use crate::calculus::maps::scalar_valued_maps::ScalarValuedMapFromMatrix;
+
+    fn f<S: IsScalar<1>>(x: S::Matrix<2, 3>) -> S {
+        let a = S::Matrix::<3, 2>::from_f64_array2([[1.0, 0.5], [-0.3, 2.0], [0.7, 0.1]]);
+        let x_t =
+            S::Matrix::<3, 2>::block_mat1x2(x.get_row_vec(0).to_mat(), x.get_row_vec(1).to_mat());
+        let prod: S::Matrix<3, 3> = x_t.mat_mul(x.clone());
+        let sub: S::Matrix<2, 2> = prod.get_fixed_submat(1, 0);
+        let mut out = S::Matrix::<3, 2>::block_mat2x1(
+            sub.scaled(x.get_elem([0, 1]).sin()),
+            S::Matrix::<1, 2>::from_scalar(x.get_elem([1, 2])),
+        ) - a.clone();
+        out.set_col_vec(1, a.get_col_vec(0) + x_t * x.get_col_vec(2));
+        let v = out.get_col_vec(1) - out.get_col_vec(0);
+        v.clone().dot(v)
+    }
+
+    let x = MatF64::<2, 3>::new(0.6, 0.2, -1.1, 0.4, 1.3, -0.7);
+    let finite_diff = ScalarValuedMapFromMatrix::<f64, 1>::sym_diff_quotient(f::<f64>, x, 1e-6);
+    let tape_grad = ScalarValuedMapFromMatrix::<TapeScalar, 1>::gradient(f::<TapeScalar>, x);
+    approx::assert_abs_diff_eq!(finite_diff, tape_grad, epsilon = 0.0001);
+}
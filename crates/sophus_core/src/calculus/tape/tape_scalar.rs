@@ -0,0 +1,590 @@
+use super::tape_matrix::TapeMatrix;
+use super::tape_vector::TapeVector;
+
+use crate::calculus::dual::DualMatrix;
+use crate::calculus::dual::DualScalar;
+use crate::calculus::dual::DualVector;
+use crate::linalg::scalar::NumberCategory;
+use crate::linalg::MatF64;
+use crate::linalg::VecF64;
+use crate::prelude::*;
+
+use approx::AbsDiffEq;
+use approx::RelativeEq;
+use num_traits::One;
+use num_traits::Zero;
+
+use core::cell::RefCell;
+use core::ops::Add;
+use core::ops::AddAssign;
+use core::ops::Div;
+use core::ops::Mul;
+use core::ops::Neg;
+use core::ops::Sub;
+use core::ops::SubAssign;
+
+/// Index of a tape scalar: its position on the tape and the tape generation it was recorded in
+///
+/// The generation of the tape is incremented each time nodes are discarded, e.g. at the end of a
+/// gradient call. Hence, a tape scalar which outlives its node is detected, even if its position
+/// has been reused by a newer node.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TapeIndex {
+    /// position on the tape - or usize::MAX for constants
+    pub position: usize,
+    /// generation of the tape when the node was recorded
+    pub generation: u64,
+}
+
+/// Tape index of constants - i.e. of values which do not depend on any tape variable
+pub(crate) const CONSTANT: TapeIndex = TapeIndex {
+    position: usize::MAX,
+    generation: 0,
+};
+
+/// Node of the tape: the positions of the (up to two) parents and the corresponding partial
+/// derivatives. Unused parent slots are set to CONSTANT.
+#[derive(Clone, Copy, Debug)]
+struct TapeNode {
+    parents: [usize; 2],
+    partials: [f64; 2],
+    generation: u64,
+}
+
+struct TapeState {
+    nodes: Vec<TapeNode>,
+    generation: u64,
+}
+
+impl TapeState {
+    /// Panics if the node of the given index was discarded from the tape
+    fn check(&self, index: TapeIndex) {
+        if index.position == CONSTANT.position {
+            return;
+        }
+        let is_valid = self
+            .nodes
+            .get(index.position)
+            .is_some_and(|node| node.generation == index.generation);
+        assert!(
+            is_valid,
+            "TapeScalar of tape generation {} used with tape generation {}: its tape node was \
+             discarded by Tape::truncate (e.g. at the end of a gradient call) and tape scalars \
+             must not be used afterwards",
+            index.generation, self.generation
+        );
+    }
+}
+
+thread_local! {
+    static TAPE: RefCell<TapeState> = const {
+        RefCell::new(TapeState {
+            nodes: Vec::new(),
+            generation: 0,
+        })
+    };
+}
+
+/// Reverse-mode tape of the current thread
+///
+/// Each operation on a [TapeScalar] which depends on a tape variable appends a node to the tape.
+/// The gradient is then obtained by a single backward pass over the tape, hence its cost is
+/// independent of the number of inputs.
+///
+/// Typically, the tape is not used directly, but through the gradient call:
+///
+///  - ScalarValuedMapFromVector::gradient(...);
+pub struct Tape;
+
+impl Tape {
+    /// Number of nodes recorded on the tape of the current thread
+    pub fn len() -> usize {
+        TAPE.with(|tape| tape.borrow().nodes.len())
+    }
+
+    /// Generation of the tape of the current thread
+    ///
+    /// It is incremented each time nodes are discarded from the tape.
+    pub fn generation() -> u64 {
+        TAPE.with(|tape| tape.borrow().generation)
+    }
+
+    /// Discards all but the first `len` nodes of the tape
+    ///
+    /// Tape scalars which refer to discarded nodes must not be used afterwards - doing so panics.
+    pub fn truncate(len: usize) {
+        TAPE.with(|tape| {
+            let mut tape = tape.borrow_mut();
+            if len < tape.nodes.len() {
+                tape.nodes.truncate(len);
+                tape.generation += 1;
+            }
+        });
+    }
+
+    /// Derivatives of `output` with respect to the given tape variables, by back-propagation
+    ///
+    /// Panics if `output` or any of `wrt` refers to a node which was discarded from the tape.
+    pub fn gradient(output: &TapeScalar, wrt: &[TapeScalar]) -> Vec<f64> {
+        TAPE.with(|tape| {
+            let tape = tape.borrow();
+            tape.check(output.index);
+            for v in wrt {
+                tape.check(v.index);
+            }
+        });
+        if output.is_constant() {
+            return vec![0.0; wrt.len()];
+        }
+        let end = output.index.position;
+        let start = wrt
+            .iter()
+            .filter(|v| !v.is_constant())
+            .map(|v| v.index.position)
+            .min()
+            .unwrap_or(end)
+            .min(end);
+
+        let mut adjoints = vec![0.0; end + 1 - start];
+        adjoints[end - start] = 1.0;
+        TAPE.with(|tape| {
+            let tape = tape.borrow();
+            for i in (start..=end).rev() {
+                let adjoint = adjoints[i - start];
+                if adjoint == 0.0 {
+                    continue;
+                }
+                let node = tape.nodes[i];
+                for (&p, &partial) in node.parents.iter().zip(node.partials.iter()) {
+                    if p != CONSTANT.position && p >= start {
+                        adjoints[p - start] += partial * adjoint;
+                    }
+                }
+            }
+        });
+
+        wrt.iter()
+            .map(|v| {
+                if v.is_constant() || v.index.position > end {
+                    0.0
+                } else {
+                    adjoints[v.index.position - start]
+                }
+            })
+            .collect()
+    }
+
+    /// Records a node, after checking that its parents are still on the tape
+    fn push(parents: [TapeIndex; 2], partials: [f64; 2]) -> TapeIndex {
+        TAPE.with(|tape| {
+            let mut tape = tape.borrow_mut();
+            for parent in parents {
+                tape.check(parent);
+            }
+            let generation = tape.generation;
+            tape.nodes.push(TapeNode {
+                parents: parents.map(|p| p.position),
+                partials,
+                generation,
+            });
+            TapeIndex {
+                position: tape.nodes.len() - 1,
+                generation,
+            }
+        })
+    }
+}
+
+/// Tape scalar - a real number and its index on the reverse-mode tape
+///
+/// In contrast to the dual numbers in [crate::calculus::dual], no derivative is propagated
+/// forward. Instead, each operation is recorded on the thread-local [Tape]. Scalars which do not
+/// depend on a tape variable, e.g. those created by from_f64, are constants and are not recorded.
+///
+/// Tape scalars are only valid as long as their node is on the tape. Using a tape scalar after its
+/// node was discarded, e.g. after the gradient call which created it returned, panics.
+#[derive(Clone, Copy, Debug)]
+pub struct TapeScalar {
+    /// real part
+    pub real_part: f64,
+    /// index on the tape - or CONSTANT
+    pub index: TapeIndex,
+}
+
+impl TapeScalar {
+    /// Create a new tape variable - i.e. an input of reverse-mode auto-differentiation
+    pub fn new_variable(val: f64) -> Self {
+        Self {
+            real_part: val,
+            index: Tape::push([CONSTANT; 2], [0.0; 2]),
+        }
+    }
+
+    /// Returns true if the scalar does not depend on any tape variable
+    pub fn is_constant(&self) -> bool {
+        self.index.position == CONSTANT.position
+    }
+
+    /// Records a unary operation given its value and derivative at the real part
+    fn chain(&self, f: f64, df: f64) -> Self {
+        Self::record(f, [self.index, CONSTANT], [df, 0.0])
+    }
+
+    /// Records a binary operation given its value and partial derivatives at the real parts
+    fn chain2(&self, rhs: &Self, f: f64, df_lhs: f64, df_rhs: f64) -> Self {
+        Self::record(f, [self.index, rhs.index], [df_lhs, df_rhs])
+    }
+
+    fn record(f: f64, parents: [TapeIndex; 2], partials: [f64; 2]) -> Self {
+        if parents.iter().all(|p| p.position == CONSTANT.position) {
+            return Self::from_f64(f);
+        }
+        Self {
+            real_part: f,
+            index: Tape::push(parents, partials),
+        }
+    }
+}
+
+impl AbsDiffEq for TapeScalar {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> Self::Epsilon {
+        1e-6
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.real_part.abs_diff_eq(&other.real_part, epsilon)
+    }
+}
+
+impl RelativeEq for TapeScalar {
+    fn default_max_relative() -> Self::Epsilon {
+        1e-6
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.real_part
+            .relative_eq(&other.real_part, epsilon, max_relative)
+    }
+}
+
+impl IsCoreScalar for TapeScalar {
+    fn number_category() -> NumberCategory {
+        NumberCategory::Real
+    }
+}
+
+impl IsSingleScalar for TapeScalar {
+    type SingleVector<const ROWS: usize> = TapeVector<ROWS>;
+    type SingleMatrix<const ROWS: usize, const COLS: usize> = TapeMatrix<ROWS, COLS>;
+
+    fn single_real_scalar(&self) -> f64 {
+        self.real_part
+    }
+
+    fn single_scalar(&self) -> Self {
+        *self
+    }
+
+    fn i64_floor(&self) -> i64 {
+        self.real_part.floor() as i64
+    }
+}
+
+impl One for TapeScalar {
+    fn one() -> Self {
+        <TapeScalar>::from_f64(1.0)
+    }
+}
+
+impl Zero for TapeScalar {
+    fn zero() -> Self {
+        <TapeScalar>::from_f64(0.0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.real_part == 0.0
+    }
+}
+
+impl Neg for TapeScalar {
+    type Output = TapeScalar;
+
+    fn neg(self) -> Self {
+        self.chain(-self.real_part, -1.0)
+    }
+}
+
+impl PartialEq for TapeScalar {
+    fn eq(&self, other: &Self) -> bool {
+        self.real_part == other.real_part
+    }
+}
+
+impl PartialOrd for TapeScalar {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.real_part.partial_cmp(&other.real_part)
+    }
+}
+
+impl From<f64> for TapeScalar {
+    fn from(value: f64) -> Self {
+        Self::from_real_scalar(value)
+    }
+}
+
+impl IsScalar<1> for TapeScalar {
+    type Scalar = TapeScalar;
+    type RealScalar = f64;
+    type SingleScalar = TapeScalar;
+    type DualScalar = DualScalar;
+    type Float = f64;
+
+    type RealMatrix<const ROWS: usize, const COLS: usize> = MatF64<ROWS, COLS>;
+    type RealVector<const ROWS: usize> = VecF64<ROWS>;
+
+    type Vector<const ROWS: usize> = TapeVector<ROWS>;
+    type Matrix<const ROWS: usize, const COLS: usize> = TapeMatrix<ROWS, COLS>;
+
+    type DualVector<const ROWS: usize> = DualVector<ROWS>;
+    type DualMatrix<const ROWS: usize, const COLS: usize> = DualMatrix<ROWS, COLS>;
+
+    type Mask = bool;
+
+    fn from_real_scalar(val: f64) -> Self {
+        Self {
+            real_part: val,
+            index: CONSTANT,
+        }
+    }
+
+    fn from_real_array(arr: [f64; 1]) -> Self {
+        Self::from_f64(arr[0])
+    }
+
+    fn to_real_array(&self) -> [f64; 1] {
+        [self.real_part]
+    }
+
+    fn cos(self) -> Self {
+        self.chain(self.real_part.cos(), -self.real_part.sin())
+    }
+
+    fn sin(self) -> Self {
+        self.chain(self.real_part.sin(), self.real_part.cos())
+    }
+
+    fn abs(self) -> Self {
+        self.chain(self.real_part.abs(), self.real_part.signum())
+    }
+
+    fn atan2(self, rhs: Self) -> Self {
+        let inv_sq_nrm: f64 =
+            1.0 / (self.real_part * self.real_part + rhs.real_part * rhs.real_part);
+        self.chain2(
+            &rhs,
+            self.real_part.atan2(rhs.real_part),
+            rhs.real_part * inv_sq_nrm,
+            -self.real_part * inv_sq_nrm,
+        )
+    }
+
+    fn real_part(&self) -> f64 {
+        self.real_part
+    }
+
+    fn sqrt(self) -> Self {
+        let sqrt = self.real_part.sqrt();
+        self.chain(sqrt, 1.0 / (2.0 * sqrt))
+    }
+
+    fn exp(self) -> Self {
+        let exp = self.real_part.exp();
+        self.chain(exp, exp)
+    }
+
+    fn ln(self) -> Self {
+        self.chain(self.real_part.ln(), 1.0 / self.real_part)
+    }
+
+    fn to_vec(self) -> TapeVector<1> {
+        TapeVector::<1>::from_array([self])
+    }
+
+    fn tan(self) -> Self {
+        let c = self.real_part.cos();
+        self.chain(self.real_part.tan(), 1.0 / (c * c))
+    }
+
+    fn acos(self) -> Self {
+        let dval = -1.0 / (1.0 - self.real_part * self.real_part).sqrt();
+        self.chain(self.real_part.acos(), dval)
+    }
+
+    fn asin(self) -> Self {
+        let dval = 1.0 / (1.0 - self.real_part * self.real_part).sqrt();
+        self.chain(self.real_part.asin(), dval)
+    }
+
+    fn atan(self) -> Self {
+        let dval = 1.0 / (1.0 + self.real_part * self.real_part);
+        self.chain(self.real_part.atan(), dval)
+    }
+
+    fn fract(self) -> Self {
+        self.chain(self.real_part.fract(), 1.0)
+    }
+
+    fn floor(&self) -> f64 {
+        self.real_part.floor()
+    }
+
+    fn from_f64(val: f64) -> Self {
+        Self::from_real_scalar(val)
+    }
+
+    fn scalar_examples() -> Vec<Self> {
+        [1.0, 2.0, 3.0].iter().map(|&v| Self::from_f64(v)).collect()
+    }
+
+    fn extract_single(&self, _i: usize) -> Self::SingleScalar {
+        *self
+    }
+
+    fn signum(&self) -> Self {
+        Self::from_f64(self.real_part.signum())
+    }
+
+    fn less_equal(&self, rhs: &Self) -> Self::Mask {
+        self.real_part.less_equal(&rhs.real_part)
+    }
+
+    /// Returns the real part as a dual number - the tape index is dropped.
+    fn to_dual(self) -> Self::DualScalar {
+        DualScalar::from_f64(self.real_part)
+    }
+
+    fn select(self, mask: &Self::Mask, other: Self) -> Self {
+        if *mask {
+            self
+        } else {
+            other
+        }
+    }
+
+    fn greater_equal(&self, rhs: &Self) -> Self::Mask {
+        self.real_part.greater_equal(&rhs.real_part)
+    }
+}
+
+impl AddAssign<TapeScalar> for TapeScalar {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign<TapeScalar> for TapeScalar {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Add<TapeScalar> for TapeScalar {
+    type Output = TapeScalar;
+    fn add(self, rhs: Self) -> Self::Output {
+        self.chain2(&rhs, self.real_part + rhs.real_part, 1.0, 1.0)
+    }
+}
+
+impl Sub<TapeScalar> for TapeScalar {
+    type Output = TapeScalar;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.chain2(&rhs, self.real_part - rhs.real_part, 1.0, -1.0)
+    }
+}
+
+impl Mul<TapeScalar> for TapeScalar {
+    type Output = TapeScalar;
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.chain2(
+            &rhs,
+            self.real_part * rhs.real_part,
+            rhs.real_part,
+            self.real_part,
+        )
+    }
+}
+
+impl Div<TapeScalar> for TapeScalar {
+    type Output = TapeScalar;
+    fn div(self, rhs: Self) -> Self::Output {
+        let rhs_inv = 1.0 / rhs.real_part;
+        let f = self.real_part * rhs_inv;
+        self.chain2(&rhs, f, rhs_inv, -f * rhs_inv)
+    }
+}
+
+#[test]
+fn tape_scalar_tests() {
+    use crate::calculus::maps::curves::ScalarValuedCurve;
+
+    TapeScalar::test_suite();
+
+    fn f<S: IsScalar<1>>(x: S) -> S {
+        let b = S::from_f64(0.7);
+        let y = x.clone() * x.clone() / (b.clone() + x.clone().exp());
+        y.clone().sin() + x.clone().atan2(b.clone()) - (x.clone() + b).sqrt().ln() * x.cos()
+            + y.clone().atan()
+            - y.tan().abs()
+    }
+
+    for i in 1..10 {
+        let a = 0.1 * (i as f64);
+        let dual_grad = ScalarValuedCurve::<DualScalar, 1>::fw_autodiff(f::<DualScalar>, a);
+
+        let len = Tape::len();
+        let x = TapeScalar::new_variable(a);
+        let y = f(x);
+        approx::assert_abs_diff_eq!(y.real_part, f::<f64>(a), epsilon = 1e-12);
+        let tape_grad = Tape::gradient(&y, &[x]);
+        approx::assert_abs_diff_eq!(dual_grad, tape_grad[0], epsilon = 1e-12);
+
+        // constants are not recorded, and do not depend on the variable
+        let c = TapeScalar::from_f64(a).sin() * TapeScalar::from_f64(2.0);
+        assert!(c.is_constant());
+        assert_eq!(Tape::gradient(&c, &[x]), vec![0.0]);
+
+        Tape::truncate(len);
+        assert_eq!(Tape::len(), len);
+    }
+}
+
+#[test]
+fn tape_scalar_generation_tests() {
+    let len = Tape::len();
+    let x = TapeScalar::new_variable(0.5);
+    let y = x * x;
+    assert_eq!(Tape::gradient(&y, &[x]), vec![1.0]);
+    Tape::truncate(len);
+
+    // the position of x is reused by a node of the next generation
+    let generation = Tape::generation();
+    let z = TapeScalar::new_variable(2.0);
+    assert_eq!(z.index.position, x.index.position);
+    assert_eq!(z.index.generation, generation);
+    assert_ne!(z.index.generation, x.index.generation);
+    assert_eq!(Tape::gradient(&(z * z), &[z]), vec![4.0]);
+
+    // using x with the newer tape panics
+    let result = std::panic::catch_unwind(|| x * z);
+    assert!(result.is_err());
+    let result = std::panic::catch_unwind(|| Tape::gradient(&z, &[x]));
+    assert!(result.is_err());
+    Tape::truncate(len);
+}
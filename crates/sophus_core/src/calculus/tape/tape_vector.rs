@@ -0,0 +1,242 @@
+use super::tape_matrix::TapeMatrix;
+use super::tape_scalar::TapeIndex;
+use super::tape_scalar::TapeScalar;
+use super::tape_scalar::CONSTANT;
+use crate::calculus::dual::DualVector;
+use crate::linalg::VecF64;
+use crate::prelude::*;
+use approx::AbsDiffEq;
+use approx::RelativeEq;
+use core::ops::Add;
+use core::ops::Neg;
+use core::ops::Sub;
+
+/// Tape vector
+///
+/// Vector of tape scalars, stored as a real vector and the tape index of each element.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TapeVector<const ROWS: usize> {
+    /// real part
+    pub real_part: VecF64<ROWS>,
+    /// tape index of each element - or CONSTANT
+    pub index: [TapeIndex; ROWS],
+}
+
+impl<const ROWS: usize> TapeVector<ROWS> {
+    /// Create a new vector of tape variables - i.e. inputs of reverse-mode auto-differentiation
+    ///
+    /// Typically this is not called directly, but through the gradient call:
+    ///
+    ///  - ScalarValuedMapFromVector::gradient(...);
+    pub fn new_variables(val: VecF64<ROWS>) -> Self {
+        Self::from_fn(|i| TapeScalar::new_variable(val[i]))
+    }
+
+    /// Returns the elements as an array of tape scalars
+    pub fn to_array(&self) -> [TapeScalar; ROWS] {
+        core::array::from_fn(|i| self.get_elem(i))
+    }
+
+    pub(crate) fn from_fn<F: FnMut(usize) -> TapeScalar>(f: F) -> Self {
+        Self::from_array(core::array::from_fn(f))
+    }
+}
+
+impl<const ROWS: usize> Neg for TapeVector<ROWS> {
+    type Output = TapeVector<ROWS>;
+
+    fn neg(self) -> Self::Output {
+        Self::from_fn(|i| -self.get_elem(i))
+    }
+}
+
+impl<const ROWS: usize> Sub for TapeVector<ROWS> {
+    type Output = TapeVector<ROWS>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::from_fn(|i| self.get_elem(i) - rhs.get_elem(i))
+    }
+}
+
+impl<const ROWS: usize> Add for TapeVector<ROWS> {
+    type Output = TapeVector<ROWS>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::from_fn(|i| self.get_elem(i) + rhs.get_elem(i))
+    }
+}
+
+impl<const ROWS: usize> AbsDiffEq for TapeVector<ROWS> {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> Self::Epsilon {
+        f64::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.real_part.abs_diff_eq(&other.real_part, epsilon)
+    }
+}
+
+impl<const ROWS: usize> RelativeEq for TapeVector<ROWS> {
+    fn default_max_relative() -> Self::Epsilon {
+        f64::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.real_part
+            .relative_eq(&other.real_part, epsilon, max_relative)
+    }
+}
+
+impl<const ROWS: usize> IsSingleVector<TapeScalar, ROWS> for TapeVector<ROWS> {
+    fn set_real_scalar(&mut self, idx: usize, v: f64) {
+        self.real_part[idx] = v;
+        self.index[idx] = CONSTANT;
+    }
+}
+
+impl<const ROWS: usize> IsVector<TapeScalar, ROWS, 1> for TapeVector<ROWS> {
+    fn from_f64(val: f64) -> Self {
+        Self::from_real_vector(VecF64::<ROWS>::from_element(val))
+    }
+
+    fn norm(&self) -> TapeScalar {
+        self.squared_norm().sqrt()
+    }
+
+    fn squared_norm(&self) -> TapeScalar {
+        self.dot(*self)
+    }
+
+    fn get_elem(&self, idx: usize) -> TapeScalar {
+        TapeScalar {
+            real_part: self.real_part[idx],
+            index: self.index[idx],
+        }
+    }
+
+    fn from_array(vals: [TapeScalar; ROWS]) -> Self {
+        Self {
+            real_part: VecF64::<ROWS>::from_fn(|i, _| vals[i].real_part),
+            index: vals.map(|v| v.index),
+        }
+    }
+
+    fn from_real_array(vals: [f64; ROWS]) -> Self {
+        Self::from_real_vector(VecF64::from_real_array(vals))
+    }
+
+    fn from_real_vector(val: VecF64<ROWS>) -> Self {
+        Self {
+            real_part: val,
+            index: [CONSTANT; ROWS],
+        }
+    }
+
+    fn real_vector(&self) -> &VecF64<ROWS> {
+        &self.real_part
+    }
+
+    fn to_mat(self) -> TapeMatrix<ROWS, 1> {
+        TapeMatrix::from_fn(|r, _| self.get_elem(r))
+    }
+
+    fn block_vec2<const R0: usize, const R1: usize>(
+        top_row: TapeVector<R0>,
+        bot_row: TapeVector<R1>,
+    ) -> Self {
+        assert_eq!(R0 + R1, ROWS);
+
+        Self::from_fn(|i| {
+            if i < R0 {
+                top_row.get_elem(i)
+            } else {
+                bot_row.get_elem(i - R0)
+            }
+        })
+    }
+
+    fn scaled(&self, s: TapeScalar) -> Self {
+        Self::from_fn(|i| self.get_elem(i) * s)
+    }
+
+    fn dot(self, rhs: Self) -> TapeScalar {
+        let mut sum = TapeScalar::from_f64(0.0);
+        for i in 0..ROWS {
+            sum += self.get_elem(i) * rhs.get_elem(i);
+        }
+        sum
+    }
+
+    fn normalized(&self) -> Self {
+        self.scaled(TapeScalar::from_f64(1.0) / self.norm())
+    }
+
+    fn from_f64_array(vals: [f64; ROWS]) -> Self {
+        Self::from_real_vector(VecF64::from_f64_array(vals))
+    }
+
+    fn from_scalar_array(vals: [TapeScalar; ROWS]) -> Self {
+        Self::from_array(vals)
+    }
+
+    fn set_elem(&mut self, idx: usize, v: TapeScalar) {
+        self.real_part[idx] = v.real_part;
+        self.index[idx] = v.index;
+    }
+
+    /// Returns the real part as a dual vector - the tape indices are dropped.
+    fn to_dual(self) -> DualVector<ROWS> {
+        DualVector::from_real_vector(self.real_part)
+    }
+
+    fn outer<const R2: usize>(self, rhs: TapeVector<R2>) -> TapeMatrix<ROWS, R2> {
+        TapeMatrix::from_fn(|r, c| self.get_elem(r) * rhs.get_elem(c))
+    }
+
+    fn select(self, mask: &bool, other: Self) -> Self {
+        if *mask {
+            self
+        } else {
+            other
+        }
+    }
+
+    fn get_fixed_subvec<const R: usize>(&self, start_r: usize) -> TapeVector<R> {
+        TapeVector::from_fn(|i| self.get_elem(start_r + i))
+    }
+}
+
+#[test]
+fn tape_vector_tests() {
+    use super::tape_scalar::Tape;
+    use crate::calculus::dual::DualScalar;
+    use crate::calculus::maps::scalar_valued_maps::ScalarValuedMapFromVector;
+    use crate::linalg::vector::cross;
+
+    fn f<S: IsScalar<1>>(x: S::Vector<4>) -> S {
+        let a = S::Vector::<3>::from_f64_array([0.3, -0.2, 0.9]);
+        let head: S::Vector<3> = x.get_fixed_subvec(0);
+        let tail: S::Vector<2> = x.get_fixed_subvec(2);
+        let c = cross::<S, 1>(head.clone(), a.clone()).normalized();
+        let v = S::Vector::<3>::block_vec2(tail.clone(), head.clone().norm().to_vec());
+        (c.scaled(head.dot(a)) - v).squared_norm() + tail.get_elem(1) / x.get_elem(3).exp()
+    }
+
+    let x = VecF64::<4>::new(0.6, 0.2, -1.1, 0.4);
+    let dual_grad = ScalarValuedMapFromVector::<DualScalar, 1>::fw_autodiff(f::<DualScalar>, x);
+
+    let len = Tape::len();
+    let tape_x = TapeVector::<4>::new_variables(x);
+    let y = f::<TapeScalar>(tape_x);
+    let tape_grad = VecF64::<4>::from_vec(Tape::gradient(&y, &tape_x.to_array()));
+    Tape::truncate(len);
+
+    approx::assert_abs_diff_eq!(dual_grad, tape_grad, epsilon = 1e-12);
+}
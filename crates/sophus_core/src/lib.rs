@@ -1,3 +1,4 @@
+#![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(feature = "simd", feature(portable_simd))]
 #![deny(missing_docs)]
 #![allow(clippy::needless_range_loop)]
@@ -5,22 +6,38 @@
 //!  - linear algebra types
 //!      * such as [linalg::VecF64], and [linalg::MatF64]
 //!      * batch types such as [linalg::BatchScalarF64], [linalg::BatchVecF64],
-//!        [linalg::BatchMatF64] - require the `simd` feature
+//!        [linalg::BatchMatF64] - array-based on stable, `std::simd` with the `simd` feature
 //!  - tensors
 //!      * design: dynamic tensor (ndarray) of static tensors (nalgebra)
 //!  - differentiation tools
 //!      * dual numbers: [calculus::dual::DualScalar], [calculus::dual::DualVector],
 //!        [calculus::dual::DualMatrix]
+//!      * hyper-dual numbers for second derivatives: [calculus::dual::HyperDualScalar],
+//!        [calculus::dual::HyperDualVector], [calculus::dual::HyperDualMatrix]
+//!      * stack-allocated jets with a static tangent dimension: [calculus::dual::JetScalar],
+//!        [calculus::dual::JetVector], [calculus::dual::JetMatrix]
+//!      * reverse-mode tape for gradients of scalar-valued maps: [calculus::tape::TapeScalar],
+//!        [calculus::tape::TapeVector], [calculus::tape::TapeMatrix]
 //!      * [calculus::maps::curves] f: ℝ -> ℝ,   f: ℝ -> ℝʳ,   f: ℝ -> ℝʳ x ℝᶜ
 //!      * [calculus::maps::scalar_valued_maps]: f: ℝᵐ -> ℝ,   f: ℝᵐ x ℝⁿ -> ℝ
 //!      * [calculus::maps::vector_valued_maps]: f: ℝᵐ -> ℝᵖ,   f: ℝᵐ x ℝⁿ -> ℝᵖ
 //!      * [calculus::maps::matrix_valued_maps]: f: ℝᵐ -> ℝʳ x ℝᶜ,   f: ℝᵐ x ℝⁿ -> ℝʳ x ℝᶜ
 //!  - splines
+//!      * [calculus::spline::BSpline] of arbitrary degree, with uniform or non-uniform knots
 //!      * [calculus::spline::CubicBSpline]
+//!      * least-squares fitting to timestamped samples, see [calculus::spline::BSpline::fit]
 //!  - intervals, regions
 //!      * closed interval: [calculus::region::Interval]
 //!      * closed region: [calculus::region::Interval]
 //!  - manifolds: [manifold::traits]
+//!
+//! Without the default `std` feature, the crate is `no_std` and uses libm for the elementary
+//! functions. Scalars, vectors, matrices and their traits are always available; dual numbers,
+//! tensors, maps and splines need a heap and hence the `alloc` feature. The tape is thread-local
+//! and needs `std`.
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 /// calculus - differentiation, splines, and more
 pub mod calculus;
@@ -41,17 +58,26 @@ pub mod points;
 pub use crate::points::*;
 
 /// tensors
+#[cfg(feature = "alloc")]
 pub mod tensor;
+#[cfg(feature = "alloc")]
 pub use crate::tensor::arc_tensor::*;
+#[cfg(feature = "alloc")]
 pub use crate::tensor::mut_tensor::*;
+#[cfg(feature = "alloc")]
 pub use crate::tensor::mut_tensor_view::*;
+#[cfg(feature = "alloc")]
 pub use crate::tensor::tensor_view::*;
 
 /// sophus_core prelude
 pub mod prelude {
+    #[cfg(feature = "alloc")]
     pub use crate::calculus::dual::dual_matrix::IsDualMatrix;
+    #[cfg(feature = "alloc")]
     pub use crate::calculus::dual::dual_scalar::IsDual;
+    #[cfg(feature = "alloc")]
     pub use crate::calculus::dual::dual_scalar::IsDualScalar;
+    #[cfg(feature = "alloc")]
     pub use crate::calculus::dual::dual_vector::IsDualVector;
     pub use crate::calculus::region::IsRegion;
     pub use crate::linalg::bool_mask::IsBoolMask;
@@ -59,6 +85,7 @@ pub mod prelude {
     pub use crate::linalg::matrix::IsRealMatrix;
     pub use crate::linalg::matrix::IsSingleMatrix;
     pub use crate::linalg::scalar::IsCoreScalar;
+    pub use crate::linalg::scalar::IsFloat;
     pub use crate::linalg::scalar::IsRealScalar;
     pub use crate::linalg::scalar::IsScalar;
     pub use crate::linalg::scalar::IsSingleScalar;
@@ -67,8 +94,12 @@ pub mod prelude {
     pub use crate::linalg::vector::IsVector;
     pub use crate::manifold::traits::IsManifold;
     pub use crate::params::HasParams;
+    #[cfg(feature = "alloc")]
     pub use crate::tensor::element::IsStaticTensor;
+    #[cfg(feature = "alloc")]
     pub use crate::tensor::mut_tensor_view::IsMutTensorLike;
+    #[cfg(feature = "alloc")]
     pub use crate::tensor::tensor_view::IsTensorLike;
+    #[cfg(feature = "alloc")]
     pub use crate::tensor::tensor_view::IsTensorView;
 }
@@ -1,44 +1,41 @@
-#[cfg(feature = "simd")]
+/// Array-based stand-in for the subset of `std::simd` used by the batch types
+///
+/// Names and signatures follow `std::simd`, such that the batch types compile unchanged against
+/// either backend. Lanes are plain arrays, and all operations are lane-wise loops.
+#[cfg(not(feature = "simd"))]
+pub mod array_simd;
+/// Backend of the batch types: `std::simd` and sleef with the `simd` feature, and
+/// [array_simd] on stable otherwise
+pub mod batch_backend;
 /// Boolean mask - generalization of bool to SIMD
 pub mod batch_mask;
 /// Bool and boolean mask traits
 pub mod bool_mask;
 
-#[cfg(feature = "simd")]
-/// Batch matrix types - require the `simd` feature
+/// Batch matrix types
 pub mod batch_matrix;
 /// Matrix types
 pub mod matrix;
 
-#[cfg(feature = "simd")]
-/// Batch scalar types - require the `simd` feature
+/// Batch scalar types
 pub mod batch_scalar;
 /// Scalar types
 pub mod scalar;
 
-#[cfg(feature = "simd")]
-/// Batch vector types - require the `simd` feature
+/// Batch vector types
 pub mod batch_vector;
 /// Vector types
 pub mod vector;
 
-#[cfg(feature = "simd")]
-use std::ops::Add;
-
-#[cfg(feature = "simd")]
-use std::simd::cmp::SimdPartialEq;
-#[cfg(feature = "simd")]
-use std::simd::num::SimdFloat;
-#[cfg(feature = "simd")]
-use std::simd::LaneCount;
-#[cfg(feature = "simd")]
-use std::simd::Mask;
-#[cfg(feature = "simd")]
-use std::simd::Simd;
-#[cfg(feature = "simd")]
-use std::simd::SimdElement;
-#[cfg(feature = "simd")]
-use std::simd::SupportedLaneCount;
+use core::ops::Add;
+
+use crate::linalg::batch_backend::cmp::SimdPartialEq;
+use crate::linalg::batch_backend::num::SimdFloat;
+use crate::linalg::batch_backend::LaneCount;
+use crate::linalg::batch_backend::Mask;
+use crate::linalg::batch_backend::Simd;
+use crate::linalg::batch_backend::SimdElement;
+use crate::linalg::batch_backend::SupportedLaneCount;
 
 /// Static vector
 pub type SVec<ScalarLike, const ROWS: usize> = nalgebra::SVector<ScalarLike, ROWS>;
@@ -50,12 +47,11 @@ pub type SMat<ScalarLike, const ROWS: usize, const COLS: usize> =
 pub type VecF32<const ROWS: usize> = nalgebra::SVector<f32, ROWS>;
 /// f64 vector
 pub type VecF64<const ROWS: usize> = nalgebra::SMatrix<f64, ROWS, 1>;
-/// f64 matrix
+/// f32 matrix
 pub type MatF32<const ROWS: usize, const COLS: usize> = nalgebra::SMatrix<f32, ROWS, COLS>;
 /// f64 matrix
 pub type MatF64<const ROWS: usize, const COLS: usize> = nalgebra::SMatrix<f64, ROWS, COLS>;
 
-#[cfg(feature = "simd")]
 /// Batch of scalar
 #[derive(Clone, Debug, PartialEq, Copy)]
 pub struct BatchScalar<ScalarLike: SimdElement, const BATCH_SIZE: usize>(
@@ -64,28 +60,22 @@ pub struct BatchScalar<ScalarLike: SimdElement, const BATCH_SIZE: usize>(
 where
     LaneCount<BATCH_SIZE>: SupportedLaneCount;
 
-#[cfg(feature = "simd")]
 /// Batch of vectors
 pub type BatchVec<ScalarLike, const ROWS: usize, const BATCH_SIZE: usize> =
     nalgebra::SVector<BatchScalar<ScalarLike, BATCH_SIZE>, ROWS>;
 
-#[cfg(feature = "simd")]
 /// Batch of matrices
 pub type BatchMat<ScalarLike, const ROWS: usize, const COLS: usize, const BATCH_SIZE: usize> =
     nalgebra::SMatrix<BatchScalar<ScalarLike, BATCH_SIZE>, ROWS, COLS>;
 
-#[cfg(feature = "simd")]
 /// batch of f64 scalars
 pub type BatchScalarF64<const BATCH: usize> = BatchScalar<f64, BATCH>;
 /// batch of f64 vectors
-#[cfg(feature = "simd")]
 pub type BatchVecF64<const ROWS: usize, const BATCH: usize> = BatchVec<f64, ROWS, BATCH>;
 /// batch of f64 matrices
-#[cfg(feature = "simd")]
 pub type BatchMatF64<const ROWS: usize, const COLS: usize, const BATCH: usize> =
     BatchMat<f64, ROWS, COLS, BATCH>;
 
-#[cfg(feature = "simd")]
 impl<S: SimdElement + num_traits::Zero, const BATCH_SIZE: usize> Add for BatchScalar<S, BATCH_SIZE>
 where
     LaneCount<BATCH_SIZE>: SupportedLaneCount,
@@ -98,7 +88,6 @@ where
     }
 }
 
-#[cfg(feature = "simd")]
 impl<S: SimdElement + num_traits::Zero, const BATCH_SIZE: usize> num_traits::Zero
     for BatchScalar<S, BATCH_SIZE>
 where
@@ -126,7 +115,6 @@ fn test_core() {
 }
 
 #[test]
-#[cfg(feature = "simd")]
 fn test_simd_core() {
     use crate::linalg::scalar::IsScalar;
     use approx::assert_abs_diff_eq;
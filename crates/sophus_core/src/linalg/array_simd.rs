@@ -0,0 +1,509 @@
+use core::fmt::Debug;
+use core::ops::Add;
+use core::ops::AddAssign;
+use core::ops::BitAnd;
+use core::ops::BitOr;
+use core::ops::Div;
+use core::ops::DivAssign;
+use core::ops::Index;
+use core::ops::IndexMut;
+use core::ops::Mul;
+use core::ops::MulAssign;
+use core::ops::Neg;
+use core::ops::Not;
+use core::ops::Sub;
+use core::ops::SubAssign;
+use num_traits::Float;
+
+/// Number of lanes - stand-in for `std::simd::LaneCount`
+pub struct LaneCount<const N: usize>;
+
+/// Supported number of lanes - powers of two up to 64, as for `std::simd`
+pub trait SupportedLaneCount {}
+
+impl SupportedLaneCount for LaneCount<1> {}
+impl SupportedLaneCount for LaneCount<2> {}
+impl SupportedLaneCount for LaneCount<4> {}
+impl SupportedLaneCount for LaneCount<8> {}
+impl SupportedLaneCount for LaneCount<16> {}
+impl SupportedLaneCount for LaneCount<32> {}
+impl SupportedLaneCount for LaneCount<64> {}
+
+/// Lane element of a mask - stand-in for `std::simd::MaskElement`
+pub trait MaskElement: Copy + Debug + PartialEq + 'static {}
+
+impl MaskElement for i32 {}
+impl MaskElement for i64 {}
+
+/// Lane element of a batch - stand-in for `std::simd::SimdElement`
+pub trait SimdElement: Copy + Debug + PartialEq + PartialOrd + 'static {
+    /// mask element of the same width
+    type Mask: MaskElement;
+}
+
+impl SimdElement for f32 {
+    type Mask = i32;
+}
+
+impl SimdElement for f64 {
+    type Mask = i64;
+}
+
+/// Batch of N lanes - stand-in for `std::simd::Simd`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Simd<T: SimdElement, const N: usize>([T; N])
+where
+    LaneCount<N>: SupportedLaneCount;
+
+impl<T: SimdElement, const N: usize> Simd<T, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    /// all lanes set to the given value
+    pub fn splat(value: T) -> Self {
+        Self([value; N])
+    }
+
+    /// from array of lanes
+    pub const fn from_array(array: [T; N]) -> Self {
+        Self(array)
+    }
+
+    /// to array of lanes
+    pub const fn to_array(self) -> [T; N] {
+        self.0
+    }
+
+    /// array of lanes, by reference
+    pub const fn as_array(&self) -> &[T; N] {
+        &self.0
+    }
+
+    fn map<F: FnMut(T) -> T>(self, f: F) -> Self {
+        Self(self.0.map(f))
+    }
+
+    fn zip_map<F: FnMut(T, T) -> T>(self, rhs: Self, mut f: F) -> Self {
+        Self(core::array::from_fn(|i| f(self.0[i], rhs.0[i])))
+    }
+
+    fn compare<F: FnMut(&T, &T) -> bool>(self, rhs: Self, mut f: F) -> Mask<T::Mask, N> {
+        Mask::from_array(core::array::from_fn(|i| f(&self.0[i], &rhs.0[i])))
+    }
+}
+
+impl<T: SimdElement, const N: usize> Index<usize> for Simd<T, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        &self.0[index]
+    }
+}
+
+impl<T: SimdElement, const N: usize> IndexMut<usize> for Simd<T, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        &mut self.0[index]
+    }
+}
+
+macro_rules! def_lanewise_binary_op {
+    ($op_trait:ident, $op_fn:ident, $assign_trait:ident, $assign_fn:ident, $op:tt) => {
+        impl<T: SimdElement + $op_trait<Output = T>, const N: usize> $op_trait for Simd<T, N>
+        where
+            LaneCount<N>: SupportedLaneCount,
+        {
+            type Output = Self;
+
+            fn $op_fn(self, rhs: Self) -> Self {
+                self.zip_map(rhs, |a, b| a $op b)
+            }
+        }
+
+        impl<T: SimdElement + $op_trait<Output = T>, const N: usize> $assign_trait for Simd<T, N>
+        where
+            LaneCount<N>: SupportedLaneCount,
+        {
+            fn $assign_fn(&mut self, rhs: Self) {
+                *self = *self $op rhs;
+            }
+        }
+    };
+}
+
+def_lanewise_binary_op!(Add, add, AddAssign, add_assign, +);
+def_lanewise_binary_op!(Sub, sub, SubAssign, sub_assign, -);
+def_lanewise_binary_op!(Mul, mul, MulAssign, mul_assign, *);
+def_lanewise_binary_op!(Div, div, DivAssign, div_assign, /);
+
+impl<T: SimdElement + Neg<Output = T>, const N: usize> Neg for Simd<T, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.map(|a| -a)
+    }
+}
+
+/// Boolean mask of N lanes - stand-in for `std::simd::Mask`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Mask<T: MaskElement, const N: usize>([bool; N], core::marker::PhantomData<T>)
+where
+    LaneCount<N>: SupportedLaneCount;
+
+impl<T: MaskElement, const N: usize> Mask<T, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    /// all lanes set to the given value
+    pub fn splat(value: bool) -> Self {
+        Self::from_array([value; N])
+    }
+
+    /// from array of lanes
+    pub fn from_array(array: [bool; N]) -> Self {
+        Self(array, core::marker::PhantomData)
+    }
+
+    /// to array of lanes
+    pub fn to_array(self) -> [bool; N] {
+        self.0
+    }
+
+    /// true if all lanes are true
+    pub fn all(self) -> bool {
+        self.0.iter().all(|b| *b)
+    }
+
+    /// true if any lane is true
+    pub fn any(self) -> bool {
+        self.0.iter().any(|b| *b)
+    }
+
+    /// lane value
+    pub fn test(&self, index: usize) -> bool {
+        self.0[index]
+    }
+
+    /// sets lane value
+    pub fn set(&mut self, index: usize, value: bool) {
+        self.0[index] = value;
+    }
+
+    /// choose lanes from true_values where the mask is true, and from false_values otherwise
+    pub fn select<U: SimdElement<Mask = T>>(
+        self,
+        true_values: Simd<U, N>,
+        false_values: Simd<U, N>,
+    ) -> Simd<U, N> {
+        Simd(core::array::from_fn(|i| {
+            if self.0[i] {
+                true_values.0[i]
+            } else {
+                false_values.0[i]
+            }
+        }))
+    }
+}
+
+impl<T: MaskElement, const N: usize> BitAnd for Mask<T, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        Self::from_array(core::array::from_fn(|i| self.0[i] & rhs.0[i]))
+    }
+}
+
+impl<T: MaskElement, const N: usize> BitOr for Mask<T, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self::from_array(core::array::from_fn(|i| self.0[i] | rhs.0[i]))
+    }
+}
+
+impl<T: MaskElement, const N: usize> Not for Mask<T, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    type Output = Self;
+
+    fn not(self) -> Self {
+        Self::from_array(self.0.map(|b| !b))
+    }
+}
+
+/// Lane-wise comparisons - stand-in for `std::simd::cmp`
+pub mod cmp {
+    use super::LaneCount;
+    use super::Mask;
+    use super::Simd;
+    use super::SimdElement;
+    use super::SupportedLaneCount;
+
+    /// Lane-wise equality
+    pub trait SimdPartialEq {
+        /// mask type of the comparison
+        type Mask;
+
+        /// lane-wise ==
+        fn simd_eq(self, other: Self) -> Self::Mask;
+
+        /// lane-wise !=
+        fn simd_ne(self, other: Self) -> Self::Mask;
+    }
+
+    /// Lane-wise ordering
+    pub trait SimdPartialOrd: SimdPartialEq {
+        /// lane-wise <
+        fn simd_lt(self, other: Self) -> Self::Mask;
+
+        /// lane-wise <=
+        fn simd_le(self, other: Self) -> Self::Mask;
+
+        /// lane-wise >
+        fn simd_gt(self, other: Self) -> Self::Mask;
+
+        /// lane-wise >=
+        fn simd_ge(self, other: Self) -> Self::Mask;
+    }
+
+    impl<T: SimdElement, const N: usize> SimdPartialEq for Simd<T, N>
+    where
+        LaneCount<N>: SupportedLaneCount,
+    {
+        type Mask = Mask<T::Mask, N>;
+
+        fn simd_eq(self, other: Self) -> Self::Mask {
+            self.compare(other, T::eq)
+        }
+
+        fn simd_ne(self, other: Self) -> Self::Mask {
+            self.compare(other, T::ne)
+        }
+    }
+
+    impl<T: SimdElement, const N: usize> SimdPartialOrd for Simd<T, N>
+    where
+        LaneCount<N>: SupportedLaneCount,
+    {
+        fn simd_lt(self, other: Self) -> Self::Mask {
+            self.compare(other, T::lt)
+        }
+
+        fn simd_le(self, other: Self) -> Self::Mask {
+            self.compare(other, T::le)
+        }
+
+        fn simd_gt(self, other: Self) -> Self::Mask {
+            self.compare(other, T::gt)
+        }
+
+        fn simd_ge(self, other: Self) -> Self::Mask {
+            self.compare(other, T::ge)
+        }
+    }
+}
+
+/// Lane-wise float operations - stand-in for `std::simd::num`
+pub mod num {
+    /// Lane-wise float operations which do not need the standard library
+    pub trait SimdFloat: Copy {
+        /// lane-wise absolute value
+        fn abs(self) -> Self;
+
+        /// lane-wise sign: 1.0, -1.0 or NaN
+        fn signum(self) -> Self;
+
+        /// lane-wise minimum
+        fn simd_min(self, other: Self) -> Self;
+
+        /// lane-wise maximum
+        fn simd_max(self, other: Self) -> Self;
+    }
+}
+
+/// Lane-wise float operations which need the standard library - stand-in for `std::simd::StdFloat`
+pub trait StdFloat: Copy {
+    /// lane-wise square root
+    fn sqrt(self) -> Self;
+
+    /// lane-wise floor
+    fn floor(self) -> Self;
+
+    /// lane-wise ceil
+    fn ceil(self) -> Self;
+
+    /// lane-wise fractional part, i.e. x - trunc(x)
+    fn fract(self) -> Self;
+}
+
+/// Lane-wise transcendental functions - stand-in for `sleef::Sleef`
+pub trait SimdMath: Copy {
+    /// lane-wise sine
+    fn sin(self) -> Self;
+
+    /// lane-wise cosine
+    fn cos(self) -> Self;
+
+    /// lane-wise tangent
+    fn tan(self) -> Self;
+
+    /// lane-wise arcsine
+    fn asin(self) -> Self;
+
+    /// lane-wise arccosine
+    fn acos(self) -> Self;
+
+    /// lane-wise arctangent
+    fn atan(self) -> Self;
+
+    /// lane-wise four-quadrant arctangent of self / x
+    fn atan2(self, x: Self) -> Self;
+
+    /// lane-wise exponential
+    fn exp(self) -> Self;
+
+    /// lane-wise natural logarithm
+    fn ln(self) -> Self;
+}
+
+macro_rules! def_float_ops {
+    ($scalar:ty) => {
+        impl<const N: usize> num::SimdFloat for Simd<$scalar, N>
+        where
+            LaneCount<N>: SupportedLaneCount,
+        {
+            fn abs(self) -> Self {
+                self.map(<$scalar as Float>::abs)
+            }
+
+            fn signum(self) -> Self {
+                self.map(<$scalar as Float>::signum)
+            }
+
+            fn simd_min(self, other: Self) -> Self {
+                self.zip_map(other, <$scalar as Float>::min)
+            }
+
+            fn simd_max(self, other: Self) -> Self {
+                self.zip_map(other, <$scalar as Float>::max)
+            }
+        }
+
+        impl<const N: usize> StdFloat for Simd<$scalar, N>
+        where
+            LaneCount<N>: SupportedLaneCount,
+        {
+            fn sqrt(self) -> Self {
+                self.map(<$scalar as Float>::sqrt)
+            }
+
+            fn floor(self) -> Self {
+                self.map(<$scalar as Float>::floor)
+            }
+
+            fn ceil(self) -> Self {
+                self.map(<$scalar as Float>::ceil)
+            }
+
+            fn fract(self) -> Self {
+                self.map(<$scalar as Float>::fract)
+            }
+        }
+
+        impl<const N: usize> SimdMath for Simd<$scalar, N>
+        where
+            LaneCount<N>: SupportedLaneCount,
+        {
+            fn sin(self) -> Self {
+                self.map(<$scalar as Float>::sin)
+            }
+
+            fn cos(self) -> Self {
+                self.map(<$scalar as Float>::cos)
+            }
+
+            fn tan(self) -> Self {
+                self.map(<$scalar as Float>::tan)
+            }
+
+            fn asin(self) -> Self {
+                self.map(<$scalar as Float>::asin)
+            }
+
+            fn acos(self) -> Self {
+                self.map(<$scalar as Float>::acos)
+            }
+
+            fn atan(self) -> Self {
+                self.map(<$scalar as Float>::atan)
+            }
+
+            fn atan2(self, x: Self) -> Self {
+                self.zip_map(x, <$scalar as Float>::atan2)
+            }
+
+            fn exp(self) -> Self {
+                self.map(<$scalar as Float>::exp)
+            }
+
+            fn ln(self) -> Self {
+                self.map(<$scalar as Float>::ln)
+            }
+        }
+    };
+}
+
+def_float_ops!(f32);
+def_float_ops!(f64);
+
+#[test]
+fn array_simd_tests() {
+    use cmp::SimdPartialEq;
+    use cmp::SimdPartialOrd;
+    use num::SimdFloat;
+
+    let a = Simd::<f64, 4>::from_array([1.0, -2.0, 3.5, 0.25]);
+    let b = Simd::<f64, 4>::splat(0.5);
+
+    assert_eq!((a + b).to_array(), [1.5, -1.5, 4.0, 0.75]);
+    assert_eq!((a - b).to_array(), [0.5, -2.5, 3.0, -0.25]);
+    assert_eq!((a * b).to_array(), [0.5, -1.0, 1.75, 0.125]);
+    assert_eq!((a / b).to_array(), [2.0, -4.0, 7.0, 0.5]);
+    assert_eq!((-a).to_array(), [-1.0, 2.0, -3.5, -0.25]);
+    assert_eq!(SimdFloat::abs(a).to_array(), [1.0, 2.0, 3.5, 0.25]);
+    assert_eq!(a.signum().to_array(), [1.0, -1.0, 1.0, 1.0]);
+    assert_eq!(StdFloat::floor(a).to_array(), [1.0, -2.0, 3.0, 0.0]);
+    assert_eq!(StdFloat::fract(a).to_array(), [0.0, 0.0, 0.5, 0.25]);
+    assert_eq!(SimdMath::atan2(a, b)[1], (-2.0f64).atan2(0.5));
+
+    let mut c = a;
+    c += b;
+    c *= b;
+    c -= b;
+    c[3] = 7.0;
+    assert_eq!(c.to_array(), [0.25, -1.25, 1.5, 7.0]);
+
+    let le = a.simd_le(b);
+    assert_eq!(le.to_array(), [false, true, false, true]);
+    assert_eq!(a.simd_ge(b), !le);
+    assert!(!le.all() && le.any());
+    assert!((le | !le).all());
+    assert!(!(le & !le).any());
+    assert_eq!(a.simd_eq(a), Mask::splat(true));
+    assert_eq!(le.select(a, b).to_array(), [0.5, -2.0, 0.5, 0.25]);
+}
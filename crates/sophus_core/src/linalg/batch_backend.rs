@@ -0,0 +1,7 @@
+#[cfg(feature = "simd")]
+pub use sleef::Sleef as SimdMath;
+#[cfg(feature = "simd")]
+pub use std::simd::*;
+
+#[cfg(not(feature = "simd"))]
+pub use crate::linalg::array_simd::*;
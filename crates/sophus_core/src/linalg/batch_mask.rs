@@ -1,8 +1,8 @@
+use crate::linalg::batch_backend::LaneCount;
+use crate::linalg::batch_backend::Mask;
+use crate::linalg::batch_backend::MaskElement;
+use crate::linalg::batch_backend::SupportedLaneCount;
 use crate::prelude::IsBoolMask;
-use std::simd::LaneCount;
-use std::simd::Mask;
-use std::simd::MaskElement;
-use std::simd::SupportedLaneCount;
 
 impl<const BATCH: usize, T> IsBoolMask for Mask<T, BATCH>
 where
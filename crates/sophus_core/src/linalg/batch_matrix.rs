@@ -1,3 +1,4 @@
+#[cfg(feature = "alloc")]
 use crate::calculus::dual::DualBatchMatrix;
 
 use crate::linalg::BatchMatF64;
@@ -6,9 +7,9 @@ use crate::linalg::BatchVecF64;
 
 use crate::prelude::*;
 
-use std::simd::LaneCount;
-use std::simd::Mask;
-use std::simd::SupportedLaneCount;
+use crate::linalg::batch_backend::LaneCount;
+use crate::linalg::batch_backend::Mask;
+use crate::linalg::batch_backend::SupportedLaneCount;
 
 impl<const ROWS: usize, const COLS: usize, const BATCH: usize>
     IsMatrix<BatchScalarF64<BATCH>, ROWS, COLS, BATCH> for BatchMatF64<ROWS, COLS, BATCH>
@@ -127,6 +128,7 @@ where
         Self::from_element(BatchScalarF64::<BATCH>::from_f64(val))
     }
 
+    #[cfg(feature = "alloc")]
     fn to_dual(self) -> <BatchScalarF64<BATCH> as IsScalar<BATCH>>::DualMatrix<ROWS, COLS> {
         DualBatchMatrix::from_real_matrix(self)
     }
@@ -1,6 +1,18 @@
+#[cfg(feature = "alloc")]
 use crate::calculus::dual::DualBatchMatrix;
+#[cfg(feature = "alloc")]
 use crate::calculus::dual::DualBatchScalar;
+#[cfg(feature = "alloc")]
 use crate::calculus::dual::DualBatchVector;
+use crate::linalg::batch_backend::cmp::SimdPartialOrd;
+use crate::linalg::batch_backend::num::SimdFloat;
+use crate::linalg::batch_backend::LaneCount;
+use crate::linalg::batch_backend::Mask;
+use crate::linalg::batch_backend::Simd;
+use crate::linalg::batch_backend::SimdElement;
+use crate::linalg::batch_backend::SimdMath;
+use crate::linalg::batch_backend::StdFloat;
+use crate::linalg::batch_backend::SupportedLaneCount;
 use crate::linalg::scalar::IsBatchScalar;
 use crate::linalg::scalar::NumberCategory;
 use crate::linalg::BatchMatF64;
@@ -9,24 +21,20 @@ use crate::linalg::BatchScalarF64;
 use crate::linalg::BatchVecF64;
 use crate::prelude::IsCoreScalar;
 use crate::prelude::*;
+#[cfg(feature = "alloc")]
+use alloc::vec;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 use approx::AbsDiffEq;
 use approx::RelativeEq;
-use std::fmt::Debug;
-use std::ops::AddAssign;
-use std::ops::Div;
-use std::ops::Mul;
-use std::ops::MulAssign;
-use std::ops::Neg;
-use std::ops::Sub;
-use std::ops::SubAssign;
-use std::simd::cmp::SimdPartialOrd;
-use std::simd::num::SimdFloat;
-use std::simd::LaneCount;
-use std::simd::Mask;
-use std::simd::Simd;
-use std::simd::SimdElement;
-use std::simd::StdFloat;
-use std::simd::SupportedLaneCount;
+use core::fmt::Debug;
+use core::ops::AddAssign;
+use core::ops::Div;
+use core::ops::Mul;
+use core::ops::MulAssign;
+use core::ops::Neg;
+use core::ops::Sub;
+use core::ops::SubAssign;
 
 impl<S: SimdElement + IsCoreScalar, const BATCH_SIZE: usize> IsCoreScalar
     for BatchScalar<S, BATCH_SIZE>
@@ -34,7 +42,7 @@ where
     LaneCount<BATCH_SIZE>: SupportedLaneCount,
     Simd<S, BATCH_SIZE>: SimdFloat,
     BatchScalar<S, BATCH_SIZE>:
-        Clone + Debug + nalgebra::Scalar + num_traits::Zero + std::ops::AddAssign,
+        Clone + Debug + nalgebra::Scalar + num_traits::Zero + core::ops::AddAssign,
 {
     fn number_category() -> NumberCategory {
         NumberCategory::Real
@@ -180,7 +188,9 @@ where
     type Scalar = BatchScalarF64<BATCH>;
     type RealScalar = Self;
     type SingleScalar = f64;
+    #[cfg(feature = "alloc")]
     type DualScalar = DualBatchScalar<BATCH>;
+    type Float = f64;
 
     type RealVector<const ROWS: usize> = BatchVecF64<ROWS, BATCH>;
 
@@ -198,6 +208,7 @@ where
         self.0.simd_ge(rhs.0)
     }
 
+    #[cfg(feature = "alloc")]
     fn scalar_examples() -> Vec<BatchScalarF64<BATCH>> {
         vec![
             BatchScalarF64::<BATCH>::from_f64(1.0),
@@ -242,37 +253,37 @@ where
 
     fn cos(self) -> Self {
         BatchScalarF64 {
-            0: sleef::Sleef::cos(self.0),
+            0: SimdMath::cos(self.0),
         }
     }
 
     fn sin(self) -> Self {
         BatchScalarF64 {
-            0: sleef::Sleef::sin(self.0),
+            0: SimdMath::sin(self.0),
         }
     }
 
     fn tan(self) -> Self {
         BatchScalarF64 {
-            0: sleef::Sleef::tan(self.0),
+            0: SimdMath::tan(self.0),
         }
     }
 
     fn acos(self) -> Self {
         BatchScalarF64 {
-            0: sleef::Sleef::acos(self.0),
+            0: SimdMath::acos(self.0),
         }
     }
 
     fn asin(self) -> Self {
         BatchScalarF64 {
-            0: sleef::Sleef::asin(self.0),
+            0: SimdMath::asin(self.0),
         }
     }
 
     fn atan(self) -> Self {
         BatchScalarF64 {
-            0: sleef::Sleef::atan(self.0),
+            0: SimdMath::atan(self.0),
         }
     }
 
@@ -280,9 +291,21 @@ where
         BatchScalarF64 { 0: self.0.sqrt() }
     }
 
+    fn exp(self) -> Self {
+        BatchScalarF64 {
+            0: SimdMath::exp(self.0),
+        }
+    }
+
+    fn ln(self) -> Self {
+        BatchScalarF64 {
+            0: SimdMath::ln(self.0),
+        }
+    }
+
     fn atan2(self, x: Self) -> Self {
         BatchScalarF64 {
-            0: sleef::Sleef::atan2(self.0, x.0),
+            0: SimdMath::atan2(self.0, x.0),
         }
     }
 
@@ -302,10 +325,13 @@ where
         BatchScalarF64 { 0: self.0.signum() }
     }
 
+    #[cfg(feature = "alloc")]
     type DualVector<const ROWS: usize> = DualBatchVector<ROWS, BATCH>;
 
+    #[cfg(feature = "alloc")]
     type DualMatrix<const ROWS: usize, const COLS: usize> = DualBatchMatrix<ROWS, COLS, BATCH>;
 
+    #[cfg(feature = "alloc")]
     fn to_dual(self) -> Self::DualScalar {
         DualBatchScalar::from_real_scalar(self)
     }
@@ -1,11 +1,12 @@
+#[cfg(feature = "alloc")]
 use crate::calculus::dual::DualBatchVector;
+use crate::linalg::batch_backend::LaneCount;
+use crate::linalg::batch_backend::Mask;
+use crate::linalg::batch_backend::SupportedLaneCount;
 use crate::linalg::BatchMatF64;
 use crate::linalg::BatchScalarF64;
 use crate::linalg::BatchVecF64;
 use crate::prelude::*;
-use std::simd::LaneCount;
-use std::simd::Mask;
-use std::simd::SupportedLaneCount;
 
 impl<const ROWS: usize, const BATCH: usize> IsVector<BatchScalarF64<BATCH>, ROWS, BATCH>
     for BatchVecF64<ROWS, BATCH>
@@ -94,6 +95,7 @@ where
         Self::from_element(BatchScalarF64::<BATCH>::from_f64(val))
     }
 
+    #[cfg(feature = "alloc")]
     fn to_dual(self) -> <BatchScalarF64<BATCH> as IsScalar<BATCH>>::DualVector<ROWS> {
         DualBatchVector::from_real_vector(self)
     }
@@ -1,19 +1,22 @@
+#[cfg(feature = "alloc")]
 use crate::calculus::dual::DualMatrix;
+use crate::linalg::MatF32;
 use crate::linalg::MatF64;
+use crate::linalg::VecF32;
 use crate::linalg::VecF64;
 use crate::prelude::*;
 use approx::AbsDiffEq;
 use approx::RelativeEq;
-use std::fmt::Debug;
-use std::ops::Add;
-use std::ops::Index;
-use std::ops::IndexMut;
-use std::ops::Mul;
-use std::ops::Neg;
-use std::ops::Sub;
+use core::fmt::Debug;
+use core::ops::Add;
+use core::ops::Index;
+use core::ops::IndexMut;
+use core::ops::Mul;
+use core::ops::Neg;
+use core::ops::Sub;
 
 /// Matrix trait
-///  - either a real (f64) or a dual number matrix
+///  - either a real (f32 or f64) or a dual number matrix
 ///  - either a single matrix or a batch matrix
 pub trait IsMatrix<
     S: IsScalar<BATCH_SIZE>,
@@ -29,8 +32,8 @@ pub trait IsMatrix<
     + Add<Output = Self>
     + Sub<Output = Self>
     + Neg
-    + AbsDiffEq<Epsilon = f64>
-    + RelativeEq<Epsilon = f64>
+    + AbsDiffEq<Epsilon = S::Float>
+    + RelativeEq<Epsilon = S::Float>
 {
     /// creates matrix from a left and right block columns
     fn block_mat1x2<const C0: usize, const C1: usize>(
@@ -123,6 +126,7 @@ pub trait IsMatrix<
     ///
     /// If self is a real matrix, this will return a dual matrix with the infinitesimal part set to
     /// zero: (self, 0ϵ)
+    #[cfg(feature = "alloc")]
     fn to_dual(self) -> S::DualMatrix<ROWS, COLS>;
 
     /// zeros
@@ -151,148 +155,158 @@ pub trait IsSingleMatrix<S: IsSingleScalar, const ROWS: usize, const COLS: usize
 {
 }
 
-impl<const ROWS: usize, const COLS: usize> IsRealMatrix<f64, ROWS, COLS, 1> for MatF64<ROWS, COLS> {}
+macro_rules! def_real_matrix {
+    ($scalar:ty, $vec:ident, $mat:ident) => {
+        impl<const ROWS: usize, const COLS: usize> IsRealMatrix<$scalar, ROWS, COLS, 1>
+            for $mat<ROWS, COLS>
+        {
+        }
 
-impl<const ROWS: usize, const COLS: usize> IsSingleMatrix<f64, ROWS, COLS> for MatF64<ROWS, COLS> {}
+        impl<const ROWS: usize, const COLS: usize> IsSingleMatrix<$scalar, ROWS, COLS>
+            for $mat<ROWS, COLS>
+        {
+        }
 
-impl<const ROWS: usize, const COLS: usize> IsMatrix<f64, ROWS, COLS, 1> for MatF64<ROWS, COLS> {
-    fn from_real_matrix(val: MatF64<ROWS, COLS>) -> Self {
-        val
-    }
+        impl<const ROWS: usize, const COLS: usize> IsMatrix<$scalar, ROWS, COLS, 1>
+            for $mat<ROWS, COLS>
+        {
+            fn from_real_matrix(val: $mat<ROWS, COLS>) -> Self {
+                val
+            }
 
-    fn from_scalar(val: f64) -> Self {
-        Self::from_f64(val)
-    }
+            fn from_scalar(val: $scalar) -> Self {
+                $mat::<ROWS, COLS>::from_element(val)
+            }
 
-    fn from_array2(vals: [[f64; COLS]; ROWS]) -> MatF64<ROWS, COLS> {
-        let mut m = MatF64::<ROWS, COLS>::zeros();
+            fn from_array2(vals: [[$scalar; COLS]; ROWS]) -> $mat<ROWS, COLS> {
+                let mut m = $mat::<ROWS, COLS>::zeros();
 
-        for c in 0..COLS {
-            for r in 0..ROWS {
-                m[(r, c)] = vals[r][c];
+                for c in 0..COLS {
+                    for r in 0..ROWS {
+                        m[(r, c)] = vals[r][c];
+                    }
+                }
+                m
             }
-        }
-        m
-    }
 
-    fn from_real_scalar_array2(vals: [[f64; COLS]; ROWS]) -> Self {
-        let mut m = MatF64::<ROWS, COLS>::zeros();
-        for c in 0..COLS {
-            for r in 0..ROWS {
-                m[(r, c)] = vals[r][c];
+            fn from_real_scalar_array2(vals: [[$scalar; COLS]; ROWS]) -> Self {
+                let mut m = $mat::<ROWS, COLS>::zeros();
+                for c in 0..COLS {
+                    for r in 0..ROWS {
+                        m[(r, c)] = vals[r][c];
+                    }
+                }
+                m
             }
-        }
-        m
-    }
 
-    fn get_elem(&self, idx: [usize; 2]) -> f64 {
-        self[(idx[0], idx[1])]
-    }
+            fn get_elem(&self, idx: [usize; 2]) -> $scalar {
+                self[(idx[0], idx[1])]
+            }
 
-    fn identity() -> Self {
-        Self::identity()
-    }
+            fn identity() -> Self {
+                Self::identity()
+            }
 
-    fn real_matrix(&self) -> &Self {
-        self
-    }
+            fn real_matrix(&self) -> &Self {
+                self
+            }
 
-    fn mat_mul<const C2: usize>(&self, other: MatF64<COLS, C2>) -> MatF64<ROWS, C2> {
-        self * other
-    }
+            fn mat_mul<const C2: usize>(&self, other: $mat<COLS, C2>) -> $mat<ROWS, C2> {
+                self * other
+            }
 
-    fn block_mat2x1<const R0: usize, const R1: usize>(
-        top_row: MatF64<R0, COLS>,
-        bot_row: MatF64<R1, COLS>,
-    ) -> Self {
-        assert_eq!(ROWS, R0 + R1);
-        let mut m = Self::zeros();
-
-        m.fixed_view_mut::<R0, COLS>(0, 0).copy_from(&top_row);
-        m.fixed_view_mut::<R1, COLS>(R0, 0).copy_from(&bot_row);
-        m
-    }
+            fn block_mat2x1<const R0: usize, const R1: usize>(
+                top_row: $mat<R0, COLS>,
+                bot_row: $mat<R1, COLS>,
+            ) -> Self {
+                assert_eq!(ROWS, R0 + R1);
+                let mut m = Self::zeros();
 
-    fn block_mat2x2<const R0: usize, const R1: usize, const C0: usize, const C1: usize>(
-        top_row: (MatF64<R0, C0>, MatF64<R0, C1>),
-        bot_row: (MatF64<R1, C0>, MatF64<R1, C1>),
-    ) -> Self {
-        assert_eq!(ROWS, R0 + R1);
-        assert_eq!(COLS, C0 + C1);
-        let mut m = Self::zeros();
-
-        m.fixed_view_mut::<R0, C0>(0, 0).copy_from(&top_row.0);
-        m.fixed_view_mut::<R0, C1>(0, C0).copy_from(&top_row.1);
-
-        m.fixed_view_mut::<R1, C0>(R0, 0).copy_from(&bot_row.0);
-        m.fixed_view_mut::<R1, C1>(R0, C0).copy_from(&bot_row.1);
-        m
-    }
+                m.fixed_view_mut::<R0, COLS>(0, 0).copy_from(&top_row);
+                m.fixed_view_mut::<R1, COLS>(R0, 0).copy_from(&bot_row);
+                m
+            }
 
-    fn block_mat1x2<const C0: usize, const C1: usize>(
-        left_col: MatF64<ROWS, C0>,
-        righ_col: MatF64<ROWS, C1>,
-    ) -> Self {
-        assert_eq!(COLS, C0 + C1);
-        let mut m = Self::zeros();
+            fn block_mat2x2<const R0: usize, const R1: usize, const C0: usize, const C1: usize>(
+                top_row: ($mat<R0, C0>, $mat<R0, C1>),
+                bot_row: ($mat<R1, C0>, $mat<R1, C1>),
+            ) -> Self {
+                assert_eq!(ROWS, R0 + R1);
+                assert_eq!(COLS, C0 + C1);
+                let mut m = Self::zeros();
 
-        m.fixed_view_mut::<ROWS, C0>(0, 0).copy_from(&left_col);
-        m.fixed_view_mut::<ROWS, C1>(0, C0).copy_from(&righ_col);
+                m.fixed_view_mut::<R0, C0>(0, 0).copy_from(&top_row.0);
+                m.fixed_view_mut::<R0, C1>(0, C0).copy_from(&top_row.1);
 
-        m
-    }
+                m.fixed_view_mut::<R1, C0>(R0, 0).copy_from(&bot_row.0);
+                m.fixed_view_mut::<R1, C1>(R0, C0).copy_from(&bot_row.1);
+                m
+            }
 
-    fn get_fixed_submat<const R: usize, const C: usize>(
-        &self,
-        start_r: usize,
-        start_c: usize,
-    ) -> MatF64<R, C> {
-        self.fixed_view::<R, C>(start_r, start_c).into()
-    }
+            fn block_mat1x2<const C0: usize, const C1: usize>(
+                left_col: $mat<ROWS, C0>,
+                righ_col: $mat<ROWS, C1>,
+            ) -> Self {
+                assert_eq!(COLS, C0 + C1);
+                let mut m = Self::zeros();
 
-    fn get_col_vec(&self, c: usize) -> VecF64<ROWS> {
-        self.fixed_view::<ROWS, 1>(0, c).into()
-    }
+                m.fixed_view_mut::<ROWS, C0>(0, 0).copy_from(&left_col);
+                m.fixed_view_mut::<ROWS, C1>(0, C0).copy_from(&righ_col);
 
-    fn get_row_vec(&self, r: usize) -> VecF64<COLS> {
-        self.fixed_view::<1, COLS>(r, 0).transpose()
-    }
+                m
+            }
 
-    fn scaled(&self, v: f64) -> Self {
-        self * v
-    }
+            fn get_fixed_submat<const R: usize, const C: usize>(
+                &self,
+                start_r: usize,
+                start_c: usize,
+            ) -> $mat<R, C> {
+                self.fixed_view::<R, C>(start_r, start_c).into()
+            }
 
-    fn from_f64_array2(vals: [[f64; COLS]; ROWS]) -> Self {
-        let mut m = MatF64::<ROWS, COLS>::zeros();
-        for c in 0..COLS {
-            for r in 0..ROWS {
-                m[(r, c)] = vals[r][c];
+            fn get_col_vec(&self, c: usize) -> $vec<ROWS> {
+                self.fixed_view::<ROWS, 1>(0, c).into()
             }
-        }
-        m
-    }
 
-    fn from_f64(val: f64) -> Self {
-        MatF64::<ROWS, COLS>::from_element(val)
-    }
+            fn get_row_vec(&self, r: usize) -> $vec<COLS> {
+                self.fixed_view::<1, COLS>(r, 0).transpose()
+            }
 
-    fn set_col_vec(&mut self, c: usize, v: VecF64<ROWS>) {
-        self.fixed_columns_mut::<1>(c).copy_from(&v);
-    }
+            fn scaled(&self, v: $scalar) -> Self {
+                self * v
+            }
 
-    fn to_dual(self) -> <f64 as IsScalar<1>>::DualMatrix<ROWS, COLS> {
-        DualMatrix::from_real_matrix(self)
-    }
+            fn from_f64_array2(vals: [[f64; COLS]; ROWS]) -> Self {
+                $mat::<ROWS, COLS>::from_fn(|r, c| vals[r][c] as $scalar)
+            }
 
-    fn select(self, mask: &bool, other: Self) -> Self {
-        if *mask {
-            self
-        } else {
-            other
-        }
-    }
+            fn from_f64(val: f64) -> Self {
+                $mat::<ROWS, COLS>::from_element(val as $scalar)
+            }
 
-    fn set_elem(&mut self, idx: [usize; 2], val: f64) {
-        self[(idx[0], idx[1])] = val;
-    }
+            fn set_col_vec(&mut self, c: usize, v: $vec<ROWS>) {
+                self.fixed_columns_mut::<1>(c).copy_from(&v);
+            }
+
+            #[cfg(feature = "alloc")]
+            fn to_dual(self) -> <$scalar as IsScalar<1>>::DualMatrix<ROWS, COLS> {
+                DualMatrix::from_real_matrix(self.cast())
+            }
+
+            fn select(self, mask: &bool, other: Self) -> Self {
+                if *mask {
+                    self
+                } else {
+                    other
+                }
+            }
+
+            fn set_elem(&mut self, idx: [usize; 2], val: $scalar) {
+                self[(idx[0], idx[1])] = val;
+            }
+        }
+    };
 }
+
+def_real_matrix!(f32, VecF32, MatF32);
+def_real_matrix!(f64, VecF64, MatF64);
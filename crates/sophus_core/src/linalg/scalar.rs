@@ -1,21 +1,28 @@
+#[cfg(feature = "alloc")]
 use crate::calculus::dual::DualMatrix;
+#[cfg(feature = "alloc")]
 use crate::calculus::dual::DualScalar;
+#[cfg(feature = "alloc")]
 use crate::calculus::dual::DualVector;
+use crate::linalg::MatF32;
 use crate::linalg::MatF64;
+use crate::linalg::VecF32;
 use crate::linalg::VecF64;
 use crate::prelude::*;
-use approx::assert_abs_diff_eq;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 use approx::AbsDiffEq;
 use approx::RelativeEq;
+use core::fmt::Debug;
+use core::ops::Add;
+use core::ops::AddAssign;
+use core::ops::Div;
+use core::ops::Mul;
+use core::ops::Neg;
+use core::ops::Sub;
+use core::ops::SubAssign;
 use nalgebra::SimdValue;
-use std::fmt::Debug;
-use std::ops::Add;
-use std::ops::AddAssign;
-use std::ops::Div;
-use std::ops::Mul;
-use std::ops::Neg;
-use std::ops::Sub;
-use std::ops::SubAssign;
+use num_traits::Float;
 
 /// Number category
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -30,7 +37,7 @@ pub enum NumberCategory {
 
 /// Trait for scalar and batch scalar linalg
 pub trait IsCoreScalar:
-    Clone + Debug + nalgebra::Scalar + num_traits::Zero + std::ops::AddAssign
+    Clone + Debug + nalgebra::Scalar + num_traits::Zero + core::ops::AddAssign
 {
     /// Get the number category
     fn number_category() -> NumberCategory;
@@ -57,15 +64,36 @@ def_is_tensor_scalar_single!(i64, NumberCategory::Signed);
 def_is_tensor_scalar_single!(f32, NumberCategory::Real);
 def_is_tensor_scalar_single!(f64, NumberCategory::Real);
 
+/// Floating point type of the real lanes of a scalar - either f32 or f64
+pub trait IsFloat: Copy + Debug + PartialOrd + 'static {
+    /// Tolerance for a test which holds up to `eps` in double precision
+    ///
+    /// For f64, this is `eps`. For f32, the tolerance is at least 1e-3, which leaves room for
+    /// rounding errors accumulated over a handful of transcendental functions.
+    fn test_tolerance(eps: f64) -> Self;
+}
+
+impl IsFloat for f64 {
+    fn test_tolerance(eps: f64) -> f64 {
+        eps
+    }
+}
+
+impl IsFloat for f32 {
+    fn test_tolerance(eps: f64) -> f32 {
+        eps.max(1e-3) as f32
+    }
+}
+
 /// Scalar trait
 ///
-///  - either a real (f64) or a dual number
+///  - either a real (f32 or f64) or a dual number
 ///  - either a single scalar or a batch scalar
 pub trait IsScalar<const BATCH_SIZE: usize>:
     PartialEq
     + Debug
     + Clone
-    + std::ops::Div<Output = Self>
+    + core::ops::Div<Output = Self>
     + Add<Output = Self>
     + Mul<Output = Self>
     + Sub<Output = Self>
@@ -73,10 +101,15 @@ pub trait IsScalar<const BATCH_SIZE: usize>:
     + SubAssign
     + Sized
     + Neg<Output = Self>
-    + AbsDiffEq<Epsilon = f64>
-    + RelativeEq<Epsilon = f64>
+    + AbsDiffEq<Epsilon = Self::Float>
+    + RelativeEq<Epsilon = Self::Float>
     + IsCoreScalar
 {
+    /// Floating point type of the real lanes, f32 for single precision scalars and f64 otherwise
+    ///
+    /// This is the epsilon type of the approximate comparisons.
+    type Float: IsFloat;
+
     /// Scalar type
     type Scalar: IsScalar<BATCH_SIZE>;
 
@@ -84,9 +117,10 @@ pub trait IsScalar<const BATCH_SIZE: usize>:
     type SingleScalar: IsSingleScalar;
 
     /// Real scalar type
-    type RealScalar: IsRealScalar<BATCH_SIZE>;
+    type RealScalar: IsRealScalar<BATCH_SIZE, Float = Self::Float>;
 
     /// Dual scalar type
+    #[cfg(feature = "alloc")]
     type DualScalar: IsDualScalar<BATCH_SIZE>;
 
     /// Mask type
@@ -107,12 +141,14 @@ pub trait IsScalar<const BATCH_SIZE: usize>:
     type RealVector<const ROWS: usize>: IsRealVector<Self::RealScalar, ROWS, BATCH_SIZE>;
 
     /// Dual vector type
+    #[cfg(feature = "alloc")]
     type DualVector<const ROWS: usize>: IsDualVector<Self::DualScalar, ROWS, BATCH_SIZE>;
 
     /// Matrix type
     type Matrix<const ROWS: usize, const COLS: usize>: IsMatrix<Self, ROWS, COLS, BATCH_SIZE>;
 
     /// Dual matrix type
+    #[cfg(feature = "alloc")]
     type DualMatrix<const ROWS: usize, const COLS: usize>: IsDualMatrix<
         Self::DualScalar,
         ROWS,
@@ -138,6 +174,9 @@ pub trait IsScalar<const BATCH_SIZE: usize>:
     /// cosine
     fn cos(self) -> Self;
 
+    /// exponential function
+    fn exp(self) -> Self;
+
     /// Returns value of single lane
     fn extract_single(&self, i: usize) -> Self::SingleScalar;
 
@@ -170,6 +209,9 @@ pub trait IsScalar<const BATCH_SIZE: usize>:
     /// Less or equal comparison
     fn less_equal(&self, rhs: &Self) -> Self::Mask;
 
+    /// natural logarithm
+    fn ln(self) -> Self;
+
     /// ones
     fn ones() -> Self {
         Self::from_f64(1.0)
@@ -179,6 +221,7 @@ pub trait IsScalar<const BATCH_SIZE: usize>:
     fn real_part(&self) -> Self::RealScalar;
 
     /// return examples of scalar values
+    #[cfg(feature = "alloc")]
     fn scalar_examples() -> Vec<Self>;
 
     /// Return the self if the mask is true, otherwise the other value
@@ -202,6 +245,7 @@ pub trait IsScalar<const BATCH_SIZE: usize>:
     /// Returns dual number representation
     ///
     /// If self is a real number, the infinitesimal part is zero: (self, 0ϵ)
+    #[cfg(feature = "alloc")]
     fn to_dual(self) -> Self::DualScalar;
 
     /// Return as a real array
@@ -221,6 +265,7 @@ pub trait IsScalar<const BATCH_SIZE: usize>:
     }
 
     /// test suite
+    #[cfg(feature = "alloc")]
     fn test_suite() {
         let examples = Self::scalar_examples();
         for a in &examples {
@@ -230,7 +275,12 @@ pub trait IsScalar<const BATCH_SIZE: usize>:
             let one = Self::ones();
 
             for i in 0..BATCH_SIZE {
-                assert_abs_diff_eq!(val.extract_single(i), one.extract_single(i));
+                approx::assert_abs_diff_eq!(
+                    val.extract_single(i),
+                    one.extract_single(i),
+                    epsilon =
+                        <Self::SingleScalar as IsScalar<1>>::Float::test_tolerance(f64::EPSILON)
+                );
             }
         }
     }
@@ -260,154 +310,173 @@ pub trait IsSingleScalar: IsScalar<1> + PartialEq + Div<Output = Self> {
 /// Batch scalar
 pub trait IsBatchScalar<const BATCH_SIZE: usize>: IsScalar<BATCH_SIZE> {}
 
-impl IsRealScalar<1> for f64 {}
-
-impl IsScalar<1> for f64 {
-    type Scalar = f64;
-    type RealScalar = f64;
-    type SingleScalar = f64;
-    type DualScalar = DualScalar;
-    type Vector<const ROWS: usize> = VecF64<ROWS>;
-    type Matrix<const ROWS: usize, const COLS: usize> = MatF64<ROWS, COLS>;
-    type RealVector<const ROWS: usize> = VecF64<ROWS>;
-    type RealMatrix<const ROWS: usize, const COLS: usize> = MatF64<ROWS, COLS>;
+macro_rules! def_real_single_scalar {
+    ($scalar:ty, $vec:ident, $mat:ident) => {
+        impl IsRealScalar<1> for $scalar {}
+
+        impl IsScalar<1> for $scalar {
+            type Scalar = $scalar;
+            type RealScalar = $scalar;
+            type SingleScalar = $scalar;
+            #[cfg(feature = "alloc")]
+            type DualScalar = DualScalar;
+            type Float = $scalar;
+            type Vector<const ROWS: usize> = $vec<ROWS>;
+            type Matrix<const ROWS: usize, const COLS: usize> = $mat<ROWS, COLS>;
+            type RealVector<const ROWS: usize> = $vec<ROWS>;
+            type RealMatrix<const ROWS: usize, const COLS: usize> = $mat<ROWS, COLS>;
+
+            type Mask = bool;
+
+            fn less_equal(&self, rhs: &Self) -> Self::Mask {
+                self <= rhs
+            }
 
-    type Mask = bool;
+            fn greater_equal(&self, rhs: &Self) -> Self::Mask {
+                self >= rhs
+            }
 
-    fn less_equal(&self, rhs: &Self) -> Self::Mask {
-        self <= rhs
-    }
+            #[cfg(feature = "alloc")]
+            fn scalar_examples() -> Vec<$scalar> {
+                alloc::vec![1.0, 2.0, 3.0]
+            }
 
-    fn greater_equal(&self, rhs: &Self) -> Self::Mask {
-        self >= rhs
-    }
+            fn abs(self) -> $scalar {
+                <$scalar as Float>::abs(self)
+            }
 
-    fn scalar_examples() -> Vec<f64> {
-        vec![1.0, 2.0, 3.0]
-    }
+            fn cos(self) -> $scalar {
+                <$scalar as Float>::cos(self)
+            }
 
-    fn abs(self) -> f64 {
-        f64::abs(self)
-    }
+            fn sin(self) -> $scalar {
+                <$scalar as Float>::sin(self)
+            }
 
-    fn cos(self) -> f64 {
-        f64::cos(self)
-    }
+            fn sqrt(self) -> $scalar {
+                <$scalar as Float>::sqrt(self)
+            }
 
-    fn sin(self) -> f64 {
-        f64::sin(self)
-    }
+            fn exp(self) -> $scalar {
+                <$scalar as Float>::exp(self)
+            }
 
-    fn sqrt(self) -> f64 {
-        f64::sqrt(self)
-    }
+            fn ln(self) -> $scalar {
+                <$scalar as Float>::ln(self)
+            }
 
-    fn from_f64(val: f64) -> f64 {
-        val
-    }
+            fn from_f64(val: f64) -> $scalar {
+                val as $scalar
+            }
 
-    fn from_real_scalar(val: f64) -> f64 {
-        val
-    }
+            fn from_real_scalar(val: $scalar) -> $scalar {
+                val
+            }
 
-    fn atan2(self, x: Self) -> Self {
-        self.atan2(x)
-    }
+            fn atan2(self, x: Self) -> Self {
+                <$scalar as Float>::atan2(self, x)
+            }
 
-    fn from_real_array(arr: [Self::RealScalar; 1]) -> Self {
-        arr[0]
-    }
+            fn from_real_array(arr: [f64; 1]) -> Self {
+                arr[0] as $scalar
+            }
 
-    fn to_real_array(&self) -> [Self::RealScalar; 1] {
-        [*self]
-    }
+            fn to_real_array(&self) -> [f64; 1] {
+                [*self as f64]
+            }
 
-    fn real_part(&self) -> f64 {
-        *self
-    }
+            fn real_part(&self) -> $scalar {
+                *self
+            }
 
-    fn to_vec(self) -> VecF64<1> {
-        VecF64::<1>::new(self)
-    }
+            fn to_vec(self) -> $vec<1> {
+                $vec::<1>::new(self)
+            }
 
-    fn tan(self) -> Self {
-        self.tan()
-    }
+            fn tan(self) -> Self {
+                <$scalar as Float>::tan(self)
+            }
 
-    fn acos(self) -> Self {
-        self.acos()
-    }
+            fn acos(self) -> Self {
+                <$scalar as Float>::acos(self)
+            }
 
-    fn asin(self) -> Self {
-        self.asin()
-    }
+            fn asin(self) -> Self {
+                <$scalar as Float>::asin(self)
+            }
 
-    fn atan(self) -> Self {
-        self.atan()
-    }
+            fn atan(self) -> Self {
+                <$scalar as Float>::atan(self)
+            }
 
-    fn fract(self) -> Self {
-        f64::fract(self)
-    }
+            fn fract(self) -> Self {
+                <$scalar as Float>::fract(self)
+            }
 
-    fn floor(&self) -> f64 {
-        f64::floor(*self)
-    }
+            fn floor(&self) -> $scalar {
+                <$scalar as Float>::floor(*self)
+            }
 
-    fn extract_single(&self, i: usize) -> f64 {
-        self.extract(i)
-    }
+            fn extract_single(&self, i: usize) -> $scalar {
+                self.extract(i)
+            }
 
-    fn signum(&self) -> Self {
-        f64::signum(*self)
-    }
+            fn signum(&self) -> Self {
+                <$scalar as Float>::signum(*self)
+            }
 
-    type DualVector<const ROWS: usize> = DualVector<ROWS>;
+            #[cfg(feature = "alloc")]
+            type DualVector<const ROWS: usize> = DualVector<ROWS>;
 
-    type DualMatrix<const ROWS: usize, const COLS: usize> = DualMatrix<ROWS, COLS>;
+            #[cfg(feature = "alloc")]
+            type DualMatrix<const ROWS: usize, const COLS: usize> = DualMatrix<ROWS, COLS>;
 
-    fn to_dual(self) -> Self::DualScalar {
-        DualScalar::from_f64(self)
-    }
+            #[cfg(feature = "alloc")]
+            fn to_dual(self) -> Self::DualScalar {
+                DualScalar::from_f64(self as f64)
+            }
 
-    fn select(self, mask: &Self::Mask, other: Self) -> Self {
-        if *mask {
-            self
-        } else {
-            other
+            fn select(self, mask: &Self::Mask, other: Self) -> Self {
+                if *mask {
+                    self
+                } else {
+                    other
+                }
+            }
         }
-    }
-}
 
-impl IsSingleScalar for f64 {
-    type SingleMatrix<const ROWS: usize, const COLS: usize> = MatF64<ROWS, COLS>;
+        impl IsSingleScalar for $scalar {
+            type SingleMatrix<const ROWS: usize, const COLS: usize> = $mat<ROWS, COLS>;
 
-    type SingleVector<const ROWS: usize> = VecF64<ROWS>;
+            type SingleVector<const ROWS: usize> = $vec<ROWS>;
 
-    fn single_real_scalar(&self) -> f64 {
-        *self
-    }
+            fn single_real_scalar(&self) -> f64 {
+                *self as f64
+            }
 
-    fn single_scalar(&self) -> Self {
-        *self
-    }
+            fn single_scalar(&self) -> Self {
+                *self
+            }
 
-    fn i64_floor(&self) -> i64 {
-        self.floor() as i64
-    }
+            fn i64_floor(&self) -> i64 {
+                <$scalar as Float>::floor(*self) as i64
+            }
+        }
+    };
 }
 
+// f32 scalars use the f64 dual numbers, so that derivatives are computed in double precision.
+def_real_single_scalar!(f32, VecF32, MatF32);
+def_real_single_scalar!(f64, VecF64, MatF64);
+
 #[test]
 fn scalar_prop_tests() {
-    #[cfg(feature = "simd")]
     use crate::linalg::BatchScalarF64;
 
+    f32::test_suite();
     f64::test_suite();
 
-    #[cfg(feature = "simd")]
     BatchScalarF64::<2>::test_suite();
-    #[cfg(feature = "simd")]
     BatchScalarF64::<4>::test_suite();
-    #[cfg(feature = "simd")]
     BatchScalarF64::<8>::test_suite();
 }
@@ -1,17 +1,20 @@
+#[cfg(feature = "alloc")]
 use crate::calculus::dual::DualVector;
+use crate::linalg::MatF32;
 use crate::linalg::MatF64;
+use crate::linalg::VecF32;
 use crate::linalg::VecF64;
 use crate::prelude::*;
 use approx::AbsDiffEq;
 use approx::RelativeEq;
-use std::fmt::Debug;
-use std::ops::Add;
-use std::ops::Index;
-use std::ops::IndexMut;
-use std::ops::Neg;
-use std::ops::Sub;
-
-/// Vector - either a real (f64) or a dual number vector
+use core::fmt::Debug;
+use core::ops::Add;
+use core::ops::Index;
+use core::ops::IndexMut;
+use core::ops::Neg;
+use core::ops::Sub;
+
+/// Vector - either a real (f32 or f64) or a dual number vector
 pub trait IsVector<S: IsScalar<BATCH_SIZE>, const ROWS: usize, const BATCH_SIZE: usize>:
     Clone
     + Neg<Output = Self>
@@ -19,8 +22,8 @@ pub trait IsVector<S: IsScalar<BATCH_SIZE>, const ROWS: usize, const BATCH_SIZE:
     + Sub<Output = Self>
     + Neg
     + Debug
-    + AbsDiffEq<Epsilon = f64>
-    + RelativeEq<Epsilon = f64>
+    + AbsDiffEq<Epsilon = S::Float>
+    + RelativeEq<Epsilon = S::Float>
 {
     /// creates vector from a block of two vectors
     fn block_vec2<const R0: usize, const R1: usize>(
@@ -85,6 +88,7 @@ pub trait IsVector<S: IsScalar<BATCH_SIZE>, const ROWS: usize, const BATCH_SIZE:
     ///
     /// If self is a real vector, this will return a dual vector with the infinitesimal part set to
     /// zero: (self, 0ϵ)
+    #[cfg(feature = "alloc")]
     fn to_dual(
         self,
     ) -> <<S as IsScalar<BATCH_SIZE>>::DualScalar as IsScalar<BATCH_SIZE>>::Vector<ROWS>;
@@ -125,108 +129,116 @@ pub trait IsSingleVector<S: IsSingleScalar, const ROWS: usize>: IsVector<S, ROWS
     fn set_real_scalar(&mut self, idx: usize, v: f64);
 }
 
-impl<const BATCH: usize> IsSingleVector<f64, BATCH> for VecF64<BATCH> {
-    fn set_real_scalar(&mut self, idx: usize, v: f64) {
-        self[idx] = v;
-    }
-}
-
-impl<const ROWS: usize> IsRealVector<f64, ROWS, 1> for VecF64<ROWS> {}
-
-impl<const ROWS: usize> IsVector<f64, ROWS, 1> for VecF64<ROWS> {
-    fn block_vec2<const R0: usize, const R1: usize>(
-        top_row: VecF64<R0>,
-        bot_row: VecF64<R1>,
-    ) -> Self {
-        assert_eq!(ROWS, R0 + R1);
-        let mut m = Self::zeros();
-
-        m.fixed_view_mut::<R0, 1>(0, 0).copy_from(&top_row);
-        m.fixed_view_mut::<R1, 1>(R0, 0).copy_from(&bot_row);
-        m
-    }
-
-    fn from_array(vals: [f64; ROWS]) -> VecF64<ROWS> {
-        VecF64::<ROWS>::from_row_slice(&vals[..])
-    }
-
-    fn from_real_array(vals: [f64; ROWS]) -> Self {
-        VecF64::<ROWS>::from_row_slice(&vals[..])
-    }
-
-    fn from_real_vector(val: VecF64<ROWS>) -> Self {
-        val
-    }
-
-    fn from_f64_array(vals: [f64; ROWS]) -> Self {
-        VecF64::<ROWS>::from_row_slice(&vals[..])
-    }
-
-    fn from_scalar_array(vals: [f64; ROWS]) -> Self {
-        VecF64::<ROWS>::from_row_slice(&vals[..])
-    }
-
-    fn get_elem(&self, idx: usize) -> f64 {
-        self[idx]
-    }
-
-    fn norm(&self) -> f64 {
-        self.norm()
-    }
-
-    fn real_vector(&self) -> &Self {
-        self
-    }
-
-    fn set_elem(&mut self, idx: usize, v: f64) {
-        self[idx] = v;
-    }
-
-    fn squared_norm(&self) -> f64 {
-        self.norm_squared()
-    }
-
-    fn to_mat(self) -> MatF64<ROWS, 1> {
-        self
-    }
-
-    fn scaled(&self, v: f64) -> Self {
-        self * v
-    }
-
-    fn dot(self, rhs: Self) -> f64 {
-        VecF64::dot(&self, &rhs)
-    }
-
-    fn normalized(&self) -> Self {
-        self.normalize()
-    }
-
-    fn from_f64(val: f64) -> Self {
-        VecF64::<ROWS>::from_element(val)
-    }
-
-    fn to_dual(self) -> <f64 as IsScalar<1>>::DualVector<ROWS> {
-        DualVector::from_real_vector(self)
-    }
-
-    fn outer<const R2: usize>(self, rhs: VecF64<R2>) -> MatF64<ROWS, R2> {
-        self * rhs.transpose()
-    }
-
-    fn select(self, mask: &bool, other: Self) -> Self {
-        if *mask {
-            self
-        } else {
-            other
+macro_rules! def_real_vector {
+    ($scalar:ty, $vec:ident, $mat:ident) => {
+        impl<const BATCH: usize> IsSingleVector<$scalar, BATCH> for $vec<BATCH> {
+            fn set_real_scalar(&mut self, idx: usize, v: f64) {
+                self[idx] = v as $scalar;
+            }
         }
-    }
 
-    fn get_fixed_subvec<const R: usize>(&self, start_r: usize) -> VecF64<R> {
-        self.fixed_rows::<R>(start_r).into()
-    }
+        impl<const ROWS: usize> IsRealVector<$scalar, ROWS, 1> for $vec<ROWS> {}
+
+        impl<const ROWS: usize> IsVector<$scalar, ROWS, 1> for $vec<ROWS> {
+            fn block_vec2<const R0: usize, const R1: usize>(
+                top_row: $vec<R0>,
+                bot_row: $vec<R1>,
+            ) -> Self {
+                assert_eq!(ROWS, R0 + R1);
+                let mut m = Self::zeros();
+
+                m.fixed_view_mut::<R0, 1>(0, 0).copy_from(&top_row);
+                m.fixed_view_mut::<R1, 1>(R0, 0).copy_from(&bot_row);
+                m
+            }
+
+            fn from_array(vals: [$scalar; ROWS]) -> $vec<ROWS> {
+                $vec::<ROWS>::from_row_slice(&vals[..])
+            }
+
+            fn from_real_array(vals: [$scalar; ROWS]) -> Self {
+                $vec::<ROWS>::from_row_slice(&vals[..])
+            }
+
+            fn from_real_vector(val: $vec<ROWS>) -> Self {
+                val
+            }
+
+            fn from_f64_array(vals: [f64; ROWS]) -> Self {
+                $vec::<ROWS>::from_fn(|r, _| vals[r] as $scalar)
+            }
+
+            fn from_scalar_array(vals: [$scalar; ROWS]) -> Self {
+                $vec::<ROWS>::from_row_slice(&vals[..])
+            }
+
+            fn get_elem(&self, idx: usize) -> $scalar {
+                self[idx]
+            }
+
+            fn norm(&self) -> $scalar {
+                self.norm()
+            }
+
+            fn real_vector(&self) -> &Self {
+                self
+            }
+
+            fn set_elem(&mut self, idx: usize, v: $scalar) {
+                self[idx] = v;
+            }
+
+            fn squared_norm(&self) -> $scalar {
+                self.norm_squared()
+            }
+
+            fn to_mat(self) -> $mat<ROWS, 1> {
+                self
+            }
+
+            fn scaled(&self, v: $scalar) -> Self {
+                self * v
+            }
+
+            fn dot(self, rhs: Self) -> $scalar {
+                $vec::dot(&self, &rhs)
+            }
+
+            fn normalized(&self) -> Self {
+                self.normalize()
+            }
+
+            fn from_f64(val: f64) -> Self {
+                $vec::<ROWS>::from_element(val as $scalar)
+            }
+
+            #[cfg(feature = "alloc")]
+            fn to_dual(self) -> <$scalar as IsScalar<1>>::DualVector<ROWS> {
+                DualVector::from_real_vector(self.cast())
+            }
+
+            fn outer<const R2: usize>(self, rhs: $vec<R2>) -> $mat<ROWS, R2> {
+                self * rhs.transpose()
+            }
+
+            fn select(self, mask: &bool, other: Self) -> Self {
+                if *mask {
+                    self
+                } else {
+                    other
+                }
+            }
+
+            fn get_fixed_subvec<const R: usize>(&self, start_r: usize) -> $vec<R> {
+                self.fixed_rows::<R>(start_r).into()
+            }
+        }
+    };
 }
 
+def_real_vector!(f32, VecF32, MatF32);
+def_real_vector!(f64, VecF64, MatF64);
+
 /// cross product
 pub fn cross<S: IsScalar<BATCH>, const BATCH: usize>(
     lhs: S::Vector<3>,
@@ -1,10 +1,13 @@
 use crate::linalg::VecF64;
 use crate::params::ParamsImpl;
 use crate::prelude::*;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 
 /// A tangent implementation.
 pub trait TangentImpl<S: IsScalar<BATCH_SIZE>, const DOF: usize, const BATCH_SIZE: usize> {
     /// Examples of tangent vectors.
+    #[cfg(feature = "alloc")]
     fn tangent_examples() -> Vec<S::Vector<DOF>>;
 }
 
@@ -30,7 +33,7 @@ pub trait IsManifold<
     const PARAMS: usize,
     const DOF: usize,
     const BATCH_SIZE: usize,
->: HasParams<S, PARAMS, BATCH_SIZE> + std::fmt::Debug + Clone
+>: HasParams<S, PARAMS, BATCH_SIZE> + core::fmt::Debug + Clone
 {
     /// manifold parameters
     fn params(&self) -> &S::Vector<PARAMS>;
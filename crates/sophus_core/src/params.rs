@@ -1,14 +1,21 @@
 use crate::linalg::VecF64;
+#[cfg(feature = "alloc")]
 use crate::points::example_points;
 use crate::prelude::*;
+#[cfg(feature = "alloc")]
+use alloc::vec;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 
 /// Parameter implementation.
 pub trait ParamsImpl<S: IsScalar<BATCH_SIZE>, const PARAMS: usize, const BATCH_SIZE: usize> {
     /// Is the parameter vector valid?
     fn are_params_valid(params: &S::Vector<PARAMS>) -> S::Mask;
     /// Examples of valid parameter vectors.
+    #[cfg(feature = "alloc")]
     fn params_examples() -> Vec<S::Vector<PARAMS>>;
     /// Examples of invalid parameter vectors.
+    #[cfg(feature = "alloc")]
     fn invalid_params_examples() -> Vec<S::Vector<PARAMS>>;
 }
 
@@ -29,10 +36,12 @@ impl<const N: usize> ParamsImpl<f64, N, 1> for VecF64<N> {
         true
     }
 
+    #[cfg(feature = "alloc")]
     fn params_examples() -> Vec<VecF64<N>> {
         example_points::<f64, N, 1>()
     }
 
+    #[cfg(feature = "alloc")]
     fn invalid_params_examples() -> Vec<VecF64<N>> {
         vec![]
     }
@@ -1,4 +1,9 @@
+#[cfg(feature = "alloc")]
 use crate::prelude::*;
+#[cfg(feature = "alloc")]
+use alloc::vec;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 use nalgebra::SVector;
 use num_traits::Bounded;
 
@@ -85,6 +90,7 @@ impl<const D: usize> IsPoint<D> for SVector<i64, D> {
 }
 
 /// Example points
+#[cfg(feature = "alloc")]
 pub fn example_points<S: IsScalar<BATCH>, const POINT: usize, const BATCH: usize>(
 ) -> Vec<S::Vector<POINT>> {
     let points4 = vec![
@@ -7,7 +7,7 @@ use crate::tensor::MutTensor;
 use crate::tensor::TensorView;
 use ndarray::Dimension;
 
-use std::marker::PhantomData;
+use core::marker::PhantomData;
 
 /// Arc tensor - a tensor with shared ownership
 ///
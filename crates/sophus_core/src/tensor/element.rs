@@ -2,7 +2,7 @@ use crate::linalg::scalar::NumberCategory;
 use crate::linalg::SMat;
 use crate::linalg::SVec;
 use crate::prelude::*;
-use std::fmt::Debug;
+use core::fmt::Debug;
 pub use typenum::generic_const_mappings::Const;
 
 /// Trait for static tensors
@@ -137,7 +137,7 @@ impl STensorFormat {
             num_rows: ROWS,
             num_cols: COLS,
             batch_size: BATCH_SIZE,
-            num_bytes_per_scalar: std::mem::size_of::<Scalar>(),
+            num_bytes_per_scalar: core::mem::size_of::<Scalar>(),
         }
     }
 
@@ -149,15 +149,11 @@ impl STensorFormat {
 
 #[test]
 fn test_elements() {
-    #[cfg(feature = "simd")]
     use crate::linalg::scalar::IsScalar;
     use crate::linalg::scalar::NumberCategory;
 
-    #[cfg(feature = "simd")]
     use crate::linalg::BatchScalar;
-    #[cfg(feature = "simd")]
     use crate::linalg::BatchScalarF64;
-    #[cfg(feature = "simd")]
     use crate::linalg::BatchVecF64;
 
     use crate::linalg::VecF32;
@@ -165,7 +161,6 @@ fn test_elements() {
     assert_eq!(f32::number_category(), NumberCategory::Real);
     assert_eq!(u32::number_category(), NumberCategory::Unsigned);
     assert_eq!(i32::number_category(), NumberCategory::Signed);
-    #[cfg(feature = "simd")]
     assert_eq!(
         BatchScalar::<f64, 4>::number_category(),
         NumberCategory::Real
@@ -186,7 +181,6 @@ fn test_elements() {
     assert_eq!(mat.scalar([1, 1]), &4.0);
     assert_abs_diff_eq!(mat, SMat::<f32, 2, 2>::new(1.0, 2.0, 3.0, 4.0));
 
-    #[cfg(feature = "simd")]
     {
         let batch_vec: BatchVecF64<2, 2> =
             BatchVecF64::from_element(BatchScalarF64::from_real_array([1.0, 2.0]));
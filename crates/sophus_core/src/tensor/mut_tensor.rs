@@ -4,10 +4,10 @@ use crate::prelude::*;
 use crate::tensor::ArcTensor;
 use crate::tensor::MutTensorView;
 use crate::tensor::TensorView;
+use core::fmt::Debug;
+use core::marker::PhantomData;
 use ndarray::Dim;
 use ndarray::Ix;
-use std::fmt::Debug;
-use std::marker::PhantomData;
 
 /// mutable tensor
 ///
@@ -438,7 +438,6 @@ mut_tensor_is_view!(5, 2, 3);
 
 #[test]
 fn mut_tensor_tests() {
-    #[cfg(feature = "simd")]
     use crate::linalg::BatchMatF64;
     {
         let _rank1_tensor = MutTensorD::<u8>::default();
@@ -515,7 +514,6 @@ fn mut_tensor_tests() {
     }
 
     //linalg
-    #[cfg(feature = "simd")]
     {
         let shape = [3];
 
@@ -1,8 +1,11 @@
 use crate::prelude::*;
+use crate::tensor::tensor_view::reshaped_dims;
+use crate::tensor::tensor_view::DimSlice;
+use crate::tensor::tensor_view::TensorReshapeError;
 use crate::tensor::MutTensor;
 use crate::tensor::TensorView;
 use concat_arrays::concat_arrays;
-use std::marker::PhantomData;
+use core::marker::PhantomData;
 
 /// Mutable tensor view
 ///
@@ -102,8 +105,8 @@ macro_rules! mut_view_is_view {
 
                 let ptr = elem_view_mut.as_ptr() as *mut Scalar;
                 use ndarray::ShapeBuilder;
-                assert_eq!(std::mem::size_of::<STensor>(),
-                    std::mem::size_of::<Scalar>() * ROWS * COLS
+                assert_eq!(core::mem::size_of::<STensor>(),
+                    core::mem::size_of::<Scalar>() * ROWS * COLS
                 );
 
                 let scalar_view_mut =
@@ -115,6 +118,40 @@ macro_rules! mut_view_is_view {
                 }
             }
 
+            /// Strided mutable sub-view, with one slice per dynamic dimension
+            ///
+            /// This is a zero-copy view, see [TensorView::slice].
+            pub fn slice_mut(
+                &mut self,
+                slices: [DimSlice; $drank],
+            ) -> MutTensorView<'_, $scalar_rank, $drank, $srank, Scalar, STensor, ROWS, COLS> {
+                let mut elem_view_mut = self.elem_view_mut.view_mut();
+                elem_view_mut.slice_each_axis_inplace(|axis| {
+                    slices[axis.axis.index()].to_ndarray_slice()
+                });
+                MutTensorView::<'_, $scalar_rank, $drank, $srank, Scalar, STensor, ROWS, COLS>::new(elem_view_mut)
+            }
+
+            /// Mutable view with permuted dynamic dimensions, see [TensorView::permuted_axes]
+            pub fn permuted_axes_mut(
+                &mut self,
+                axes: [usize; $drank],
+            ) -> MutTensorView<'_, $scalar_rank, $drank, $srank, Scalar, STensor, ROWS, COLS> {
+                MutTensorView::<'_, $scalar_rank, $drank, $srank, Scalar, STensor, ROWS, COLS>::new(
+                    self.elem_view_mut.view_mut().permuted_axes(axes),
+                )
+            }
+
+            /// Mutable view with the order of the dynamic dimensions reversed, see
+            /// [TensorView::transposed]
+            pub fn transposed_mut(
+                &mut self,
+            ) -> MutTensorView<'_, $scalar_rank, $drank, $srank, Scalar, STensor, ROWS, COLS> {
+                MutTensorView::<'_, $scalar_rank, $drank, $srank, Scalar, STensor, ROWS, COLS>::new(
+                    self.elem_view_mut.view_mut().reversed_axes(),
+                )
+            }
+
             /// get mutable reference to scalar at index idx
             pub fn mut_scalar(&'a mut self, idx: [usize; $scalar_rank]) -> &mut Scalar{
                 &mut self.scalar_view_mut[idx]
@@ -258,6 +295,48 @@ macro_rules! mut_view_is_view {
     };
 }
 
+impl<
+        'a,
+        const TOTAL_RANK: usize,
+        const DRANK: usize,
+        const SRANK: usize,
+        Scalar: IsCoreScalar + 'static,
+        STensor: IsStaticTensor<Scalar, SRANK, ROWS, COLS> + 'static,
+        const ROWS: usize,
+        const COLS: usize,
+    > MutTensorView<'a, TOTAL_RANK, DRANK, SRANK, Scalar, STensor, ROWS, COLS>
+where
+    ndarray::Dim<[ndarray::Ix; DRANK]>: ndarray::Dimension,
+    ndarray::Dim<[ndarray::Ix; TOTAL_RANK]>: ndarray::Dimension,
+{
+    /// Mutable view with new dynamic dimensions, see [TensorView::reshaped]
+    pub fn reshaped_mut<const NEW_TOTAL_RANK: usize, const NEW_DRANK: usize>(
+        &mut self,
+        shape: [usize; NEW_DRANK],
+    ) -> Result<
+        MutTensorView<'_, NEW_TOTAL_RANK, NEW_DRANK, SRANK, Scalar, STensor, ROWS, COLS>,
+        TensorReshapeError,
+    >
+    where
+        ndarray::Dim<[ndarray::Ix; NEW_DRANK]>: ndarray::Dimension,
+        ndarray::Dim<[ndarray::Ix; NEW_TOTAL_RANK]>: ndarray::Dimension,
+    {
+        let (elem_dims, scalar_shape) =
+            reshaped_dims::<NEW_TOTAL_RANK, NEW_DRANK, SRANK, Scalar, STensor, ROWS, COLS>(
+                self.elem_view_mut.shape(),
+                self.elem_view_mut.is_standard_layout(),
+                shape,
+            )?;
+        let mut elem_view_mut = self.elem_view_mut.view_mut().into_shape(elem_dims).unwrap();
+        let ptr = elem_view_mut.as_mut_ptr() as *mut Scalar;
+        let scalar_view_mut = unsafe { ndarray::ArrayViewMut::from_shape_ptr(scalar_shape, ptr) };
+        Ok(MutTensorView {
+            elem_view_mut,
+            scalar_view_mut,
+        })
+    }
+}
+
 mut_view_is_view!(1, 0, 1);
 mut_view_is_view!(2, 0, 2);
 mut_view_is_view!(2, 1, 1);
@@ -270,3 +349,48 @@ mut_view_is_view!(4, 2, 2);
 mut_view_is_view!(5, 0, 5);
 mut_view_is_view!(5, 1, 4);
 mut_view_is_view!(5, 2, 3);
+
+#[test]
+fn mut_tensor_view_slicing_tests() {
+    use crate::linalg::SVec;
+    use crate::tensor::mut_tensor::MutTensorDDR;
+
+    let mut tensor = MutTensorDDR::<f32, 2>::from_shape([4, 6]);
+    {
+        let mut view = tensor.mut_view();
+        let mut sliced = view.slice_mut([DimSlice::full().step_by(2), (1..3).into()]);
+        assert_eq!(sliced.dims(), [2, 2]);
+        sliced.elem_view_mut.fill(SVec::<f32, 2>::new(1.0, 2.0));
+    }
+    for d0 in 0..4 {
+        for d1 in 0..6 {
+            let expected = if d0 % 2 == 0 && (1..3).contains(&d1) {
+                SVec::<f32, 2>::new(1.0, 2.0)
+            } else {
+                SVec::<f32, 2>::zeros()
+            };
+            assert_eq!(tensor.get([d0, d1]), expected);
+        }
+    }
+
+    {
+        let mut view = tensor.mut_view();
+        let mut transposed = view.transposed_mut();
+        assert_eq!(transposed.dims(), [6, 4]);
+        transposed.scalar_view_mut[[5, 3, 1]] = 7.0;
+    }
+    assert_eq!(tensor.get([3, 5]), SVec::<f32, 2>::new(0.0, 7.0));
+
+    {
+        let mut view = tensor.mut_view();
+        let mut flat = view.reshaped_mut::<2, 1>([24]).unwrap();
+        flat.elem_view_mut[[7]] = SVec::<f32, 2>::new(3.0, 4.0);
+
+        let mut permuted = view.permuted_axes_mut([1, 0]);
+        assert_eq!(
+            permuted.reshaped_mut::<2, 1>([24]).unwrap_err(),
+            TensorReshapeError::NonContiguous
+        );
+    }
+    assert_eq!(tensor.get([1, 1]), SVec::<f32, 2>::new(3.0, 4.0));
+}
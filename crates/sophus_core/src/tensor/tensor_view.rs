@@ -3,7 +3,14 @@ use crate::linalg::SVec;
 use crate::prelude::*;
 use crate::tensor::MutTensor;
 use concat_arrays::concat_arrays;
-use std::marker::PhantomData;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::marker::PhantomData;
+use core::ops::Range;
+use core::ops::RangeFrom;
+use core::ops::RangeFull;
+use core::ops::RangeTo;
+use ndarray::Dimension;
 
 /// Tensor view
 ///
@@ -104,6 +111,198 @@ pub type TensorViewDDDR<'a, Scalar, const R: usize> = TensorViewXR<'a, 4, 3, 1,
 pub type TensorViewDDRC<'a, Scalar, const R: usize, const C: usize> =
     TensorViewXRC<'a, 4, 2, 2, Scalar, R, C>;
 
+/// Slice of a single dynamic dimension: every step-th index in start..end
+///
+/// Used to create strided sub-views, see [TensorView::slice]. Ranges convert into slices with
+/// step one, e.g. ``DimSlice::from(2..5)`` or ``DimSlice::from(..).step_by(2)``.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct DimSlice {
+    /// first index
+    pub start: usize,
+    /// end index (exclusive) - None for the end of the dimension
+    pub end: Option<usize>,
+    /// step size, must be positive
+    pub step: usize,
+}
+
+impl DimSlice {
+    /// the whole dimension
+    pub fn full() -> Self {
+        Self {
+            start: 0,
+            end: None,
+            step: 1,
+        }
+    }
+
+    /// a single index - the dimension is kept, with length one
+    pub fn index(idx: usize) -> Self {
+        Self {
+            start: idx,
+            end: Some(idx + 1),
+            step: 1,
+        }
+    }
+
+    /// returns the slice with the given step size
+    ///
+    /// Panics if step is zero.
+    pub fn step_by(self, step: usize) -> Self {
+        assert!(step > 0, "step size must be positive");
+        Self { step, ..self }
+    }
+
+    pub(crate) fn to_ndarray_slice(self) -> ndarray::Slice {
+        assert!(self.step > 0, "step size must be positive");
+        ndarray::Slice::new(
+            self.start as isize,
+            self.end.map(|end| end as isize),
+            self.step as isize,
+        )
+    }
+}
+
+impl From<Range<usize>> for DimSlice {
+    fn from(range: Range<usize>) -> Self {
+        Self {
+            start: range.start,
+            end: Some(range.end),
+            step: 1,
+        }
+    }
+}
+
+impl From<RangeFrom<usize>> for DimSlice {
+    fn from(range: RangeFrom<usize>) -> Self {
+        Self {
+            start: range.start,
+            end: None,
+            step: 1,
+        }
+    }
+}
+
+impl From<RangeTo<usize>> for DimSlice {
+    fn from(range: RangeTo<usize>) -> Self {
+        Self {
+            start: 0,
+            end: Some(range.end),
+            step: 1,
+        }
+    }
+}
+
+impl From<RangeFull> for DimSlice {
+    fn from(_: RangeFull) -> Self {
+        Self::full()
+    }
+}
+
+/// Error when reshaping a tensor view
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TensorReshapeError {
+    /// the new shape has a different number of elements
+    IncompatibleShape {
+        /// number of elements of the view
+        num_elements: usize,
+        /// number of elements of the requested shape
+        new_num_elements: usize,
+    },
+    /// the view is not contiguous in row-major order, e.g. a strided or transposed view
+    NonContiguous,
+}
+
+impl Display for TensorReshapeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            TensorReshapeError::IncompatibleShape {
+                num_elements,
+                new_num_elements,
+            } => write!(
+                f,
+                "cannot reshape {num_elements} elements into a shape with {new_num_elements} elements"
+            ),
+            TensorReshapeError::NonContiguous => {
+                write!(f, "cannot reshape a view which is not contiguous in row-major order")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TensorReshapeError {}
+
+/// Checks whether a view of the given dimensions can be reshaped to shape.
+///
+/// Returns the new element dimensions, and the shape and strides of the corresponding scalar view.
+#[allow(clippy::type_complexity)]
+pub(crate) fn reshaped_dims<
+    const NEW_TOTAL_RANK: usize,
+    const NEW_DRANK: usize,
+    const SRANK: usize,
+    Scalar: IsCoreScalar + 'static,
+    STensor: IsStaticTensor<Scalar, SRANK, ROWS, COLS> + 'static,
+    const ROWS: usize,
+    const COLS: usize,
+>(
+    dims: &[usize],
+    is_standard_layout: bool,
+    shape: [usize; NEW_DRANK],
+) -> Result<
+    (
+        ndarray::Dim<[ndarray::Ix; NEW_DRANK]>,
+        ndarray::StrideShape<ndarray::Dim<[ndarray::Ix; NEW_TOTAL_RANK]>>,
+    ),
+    TensorReshapeError,
+>
+where
+    ndarray::Dim<[ndarray::Ix; NEW_DRANK]>: Dimension,
+    ndarray::Dim<[ndarray::Ix; NEW_TOTAL_RANK]>: Dimension,
+{
+    const {
+        assert!(
+            NEW_TOTAL_RANK == NEW_DRANK + SRANK,
+            "NEW_TOTAL_RANK must be NEW_DRANK + SRANK"
+        )
+    };
+    let num_elements: usize = dims.iter().product();
+    let new_num_elements: usize = shape.iter().product();
+    if num_elements != new_num_elements {
+        return Err(TensorReshapeError::IncompatibleShape {
+            num_elements,
+            new_num_elements,
+        });
+    }
+    if !is_standard_layout {
+        return Err(TensorReshapeError::NonContiguous);
+    }
+
+    let mut elem_dims = ndarray::Dim::<[ndarray::Ix; NEW_DRANK]>::zeros(NEW_DRANK);
+    elem_dims.slice_mut().copy_from_slice(&shape);
+
+    let mut scalar_dims = ndarray::Dim::<[ndarray::Ix; NEW_TOTAL_RANK]>::zeros(NEW_TOTAL_RANK);
+    let mut scalar_strides = ndarray::Dim::<[ndarray::Ix; NEW_TOTAL_RANK]>::zeros(NEW_TOTAL_RANK);
+    // row-major element strides, in units of scalars
+    let mut stride = STensor::num_scalars();
+    for d in (0..NEW_DRANK).rev() {
+        scalar_dims[d] = shape[d];
+        scalar_strides[d] = stride;
+        stride *= shape[d];
+    }
+    for (s, (sdim, sstride)) in STensor::sdims()
+        .into_iter()
+        .zip(STensor::strides())
+        .enumerate()
+    {
+        scalar_dims[NEW_DRANK + s] = sdim;
+        scalar_strides[NEW_DRANK + s] = sstride;
+    }
+    Ok((
+        elem_dims,
+        ndarray::ShapeBuilder::strides(scalar_dims, scalar_strides),
+    ))
+}
+
 /// Is a tensor-like object
 pub trait IsTensorLike<
     'a,
@@ -193,8 +392,8 @@ macro_rules! tensor_view_is_view {
                 use ndarray::ShapeBuilder;
 
                 assert_eq!(
-                    std::mem::size_of::<STensor>(),
-                    std::mem::size_of::<Scalar>() * ROWS * COLS
+                    core::mem::size_of::<STensor>(),
+                    core::mem::size_of::<Scalar>() * ROWS * COLS
                 );
                 let scalar_view =
                     unsafe { ndarray::ArrayView::from_shape_ptr(shape.strides(strides), ptr) };
@@ -210,6 +409,32 @@ macro_rules! tensor_view_is_view {
                 let elem_view = ndarray::ArrayView::from_shape(shape, slice).unwrap();
                 Self::new(elem_view)
             }
+
+            /// Strided sub-view, with one slice per dynamic dimension
+            ///
+            /// This is a zero-copy view. Panics if a slice exceeds its dimension.
+            pub fn slice(&self, slices: [DimSlice; $drank]) -> Self {
+                let mut elem_view = self.elem_view;
+                elem_view
+                    .slice_each_axis_inplace(|axis| slices[axis.axis.index()].to_ndarray_slice());
+                Self::new(elem_view)
+            }
+
+            /// View with permuted dynamic dimensions
+            ///
+            /// The i-th dimension of the returned view is dimension axes[i] of self. This is a
+            /// zero-copy view; the static tensors are not affected. Panics if axes is not a
+            /// permutation of 0..DRANK.
+            pub fn permuted_axes(&self, axes: [usize; $drank]) -> Self {
+                Self::new(self.elem_view.permuted_axes(axes))
+            }
+
+            /// View with the order of the dynamic dimensions reversed, e.g. [D0 x D1] -> [D1 x D0]
+            ///
+            /// This is a zero-copy view; the static tensors are not affected.
+            pub fn transposed(&self) -> Self {
+                Self::new(self.elem_view.reversed_axes())
+            }
         }
 
         impl<
@@ -287,6 +512,53 @@ macro_rules! tensor_view_is_view {
     };
 }
 
+impl<
+        'a,
+        const TOTAL_RANK: usize,
+        const DRANK: usize,
+        const SRANK: usize,
+        Scalar: IsCoreScalar + 'static,
+        STensor: IsStaticTensor<Scalar, SRANK, ROWS, COLS> + 'static,
+        const ROWS: usize,
+        const COLS: usize,
+    > TensorView<'a, TOTAL_RANK, DRANK, SRANK, Scalar, STensor, ROWS, COLS>
+where
+    ndarray::Dim<[ndarray::Ix; DRANK]>: Dimension,
+    ndarray::Dim<[ndarray::Ix; TOTAL_RANK]>: Dimension,
+{
+    /// View with new dynamic dimensions, e.g. [D0 x D1] -> [D0 * D1]
+    ///
+    /// This is a zero-copy view, hence self must be contiguous in row-major order - such as the
+    /// view of an ArcTensor or MutTensor, but not a strided or transposed view. The static
+    /// tensors are not affected, i.e. NEW_TOTAL_RANK must be NEW_DRANK + SRANK - which is checked
+    /// at compile time.
+    pub fn reshaped<const NEW_TOTAL_RANK: usize, const NEW_DRANK: usize>(
+        &self,
+        shape: [usize; NEW_DRANK],
+    ) -> Result<
+        TensorView<'a, NEW_TOTAL_RANK, NEW_DRANK, SRANK, Scalar, STensor, ROWS, COLS>,
+        TensorReshapeError,
+    >
+    where
+        ndarray::Dim<[ndarray::Ix; NEW_DRANK]>: Dimension,
+        ndarray::Dim<[ndarray::Ix; NEW_TOTAL_RANK]>: Dimension,
+    {
+        let (elem_dims, scalar_shape) =
+            reshaped_dims::<NEW_TOTAL_RANK, NEW_DRANK, SRANK, Scalar, STensor, ROWS, COLS>(
+                self.elem_view.shape(),
+                self.elem_view.is_standard_layout(),
+                shape,
+            )?;
+        let elem_view = self.elem_view.into_shape(elem_dims).unwrap();
+        let ptr = elem_view.as_ptr() as *const Scalar;
+        let scalar_view = unsafe { ndarray::ArrayView::from_shape_ptr(scalar_shape, ptr) };
+        Ok(TensorView {
+            elem_view,
+            scalar_view,
+        })
+    }
+}
+
 tensor_view_is_view!(1, 0, 1);
 tensor_view_is_view!(2, 0, 2);
 tensor_view_is_view!(2, 1, 1);
@@ -399,3 +671,122 @@ fn tensor_view_tests() {
         }
     }
 }
+
+#[test]
+fn tensor_view_slicing_tests() {
+    use crate::tensor::arc_tensor::ArcTensorDDR;
+    use crate::tensor::arc_tensor::ArcTensorX;
+    use crate::tensor::mut_tensor::MutTensorDDR;
+    use crate::tensor::mut_tensor::MutTensorX;
+
+    let mut mut_tensor = MutTensorDDR::<f32, 2>::from_shape([4, 6]);
+    for d0 in 0..4 {
+        for d1 in 0..6 {
+            mut_tensor.mut_array[[d0, d1]] = SVec::<f32, 2>::new(d0 as f32, 10.0 * d1 as f32);
+        }
+    }
+    let tensor = ArcTensorDDR::from_mut_tensor(mut_tensor);
+    let view = tensor.view();
+
+    // slicing with ranges and steps
+    {
+        let sliced = view.slice([(1..4).into(), DimSlice::full().step_by(2)]);
+        assert_eq!(sliced.dims(), [3, 3]);
+        assert_eq!(sliced.scalar_dims(), [3, 3, 2]);
+        assert_eq!(
+            sliced.elem_view.as_ptr(),
+            &view.elem_view[[1, 0]] as *const SVec<f32, 2>
+        );
+        for d0 in 0..3 {
+            for d1 in 0..3 {
+                assert_eq!(sliced.get([d0, d1]), view.get([1 + d0, 2 * d1]));
+                for r in 0..2 {
+                    assert_eq!(
+                        sliced.scalar_view[[d0, d1, r]],
+                        view.scalar_get([1 + d0, 2 * d1, r])
+                    );
+                }
+            }
+        }
+
+        let row = view.slice([DimSlice::index(2), (..3).into()]);
+        assert_eq!(row.dims(), [1, 3]);
+        assert_eq!(row.get([0, 2]), view.get([2, 2]));
+
+        let tail = view.slice([(3..).into(), (..).into()]);
+        assert_eq!(tail.dims(), [1, 6]);
+        assert_eq!(tail.get([0, 5]), view.get([3, 5]));
+    }
+
+    // transposition and axis permutation
+    {
+        let transposed = view.transposed();
+        assert_eq!(transposed.dims(), [6, 4]);
+        for d0 in 0..4 {
+            for d1 in 0..6 {
+                assert_eq!(transposed.get([d1, d0]), view.get([d0, d1]));
+                for r in 0..2 {
+                    assert_eq!(
+                        transposed.scalar_view[[d1, d0, r]],
+                        view.scalar_get([d0, d1, r])
+                    );
+                }
+            }
+        }
+
+        let mut mut_rank3 = MutTensorX::<3, u8>::from_shape([2, 3, 4]);
+        for (i, v) in mut_rank3.mut_array.iter_mut().enumerate() {
+            *v = i as u8;
+        }
+        let rank3 = ArcTensorX::<3, u8>::from_mut_tensor(mut_rank3);
+        let rank3_view = rank3.view();
+        let permuted = rank3_view.permuted_axes([2, 0, 1]);
+        assert_eq!(permuted.dims(), [4, 2, 3]);
+        for d0 in 0..2 {
+            for d1 in 0..3 {
+                for d2 in 0..4 {
+                    assert_eq!(permuted.get([d2, d0, d1]), rank3_view.get([d0, d1, d2]));
+                }
+            }
+        }
+    }
+
+    // reshaping
+    {
+        let flat = view.reshaped::<2, 1>([24]).unwrap();
+        assert_eq!(flat.dims(), [24]);
+        assert_eq!(flat.scalar_dims(), [24, 2]);
+        for d0 in 0..4 {
+            for d1 in 0..6 {
+                assert_eq!(flat.get([6 * d0 + d1]), view.get([d0, d1]));
+                for r in 0..2 {
+                    assert_eq!(
+                        flat.scalar_view[[6 * d0 + d1, r]],
+                        view.scalar_get([d0, d1, r])
+                    );
+                }
+            }
+        }
+
+        let blocks = view.reshaped::<4, 3>([2, 3, 4]).unwrap();
+        assert_eq!(blocks.get([1, 2, 3]), view.get([3, 5]));
+
+        assert_eq!(
+            view.reshaped::<2, 1>([25]).unwrap_err(),
+            TensorReshapeError::IncompatibleShape {
+                num_elements: 24,
+                new_num_elements: 25
+            }
+        );
+        assert_eq!(
+            view.transposed().reshaped::<2, 1>([24]).unwrap_err(),
+            TensorReshapeError::NonContiguous
+        );
+        assert_eq!(
+            view.slice([(..).into(), (1..).into()])
+                .reshaped::<2, 1>([20])
+                .unwrap_err(),
+            TensorReshapeError::NonContiguous
+        );
+    }
+}
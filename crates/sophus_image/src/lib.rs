@@ -25,6 +25,7 @@ pub use crate::mut_image_view::MutImageView;
 
 /// Image size
 #[derive(Debug, Copy, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ImageSize {
     /// Width of the image - number of columns
     pub width: usize,
@@ -0,0 +1,794 @@
+use crate::traits::IsLieGroupImpl;
+use crate::traits::IsRealLieGroupImpl;
+use sophus_core::calculus::dual::DualScalar;
+use sophus_core::calculus::maps::VectorValuedMapFromVector;
+use sophus_core::linalg::MatF64;
+use sophus_core::linalg::VecF64;
+use sophus_core::points::example_points;
+use sophus_core::prelude::*;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+/// Tolerances and options of the conformance checks
+#[derive(Clone, Copy, Debug)]
+pub struct ConformanceParams {
+    /// tolerance for algebraic identities, relative to max(1, |rhs|)
+    pub tolerance: f64,
+    /// tolerance for analytic Jacobians compared to numeric ones, relative to max(1, |rhs|)
+    pub jacobian_tolerance: f64,
+    /// step size of the symmetric difference quotient
+    pub step_size: f64,
+    /// whether compact(a) is checked to be the upper rows of matrix(a)
+    ///
+    /// This holds for all groups of this crate, but the direct products, whose compact and matrix
+    /// representations are block-diagonal, see [crate::groups::direct_product].
+    pub compact_is_upper_rows: bool,
+}
+
+impl Default for ConformanceParams {
+    fn default() -> Self {
+        Self {
+            tolerance: 1e-6,
+            jacobian_tolerance: 1e-4,
+            step_size: 1e-5,
+            compact_is_upper_rows: true,
+        }
+    }
+}
+
+/// A violated identity of the conformance checks
+#[derive(Clone, Debug)]
+pub struct ConformanceFailure {
+    /// the identity which does not hold, e.g. "associativity: (a·b)·c = a·(b·c)"
+    pub identity: String,
+    /// the example inputs for which the identity does not hold
+    pub example: String,
+    /// description of the deviation
+    pub details: String,
+}
+
+impl Display for ConformanceFailure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} does not hold for {}: {}",
+            self.identity, self.example, self.details
+        )
+    }
+}
+
+struct Checker {
+    params: ConformanceParams,
+    failures: Vec<ConformanceFailure>,
+}
+
+impl Checker {
+    fn fail(&mut self, identity: &str, example: String, details: String) {
+        self.failures.push(ConformanceFailure {
+            identity: identity.to_owned(),
+            example,
+            details,
+        });
+    }
+
+    fn check_close<const ROWS: usize, const COLS: usize>(
+        &mut self,
+        identity: &str,
+        example: impl FnOnce() -> String,
+        lhs: &MatF64<ROWS, COLS>,
+        rhs: &MatF64<ROWS, COLS>,
+        tolerance: f64,
+    ) {
+        let deviation = (lhs - rhs).amax();
+        let threshold = tolerance * rhs.amax().max(1.0);
+        if deviation.is_nan() || deviation > threshold {
+            self.fail(
+                identity,
+                example(),
+                format!(
+                    "max deviation {:.3e} exceeds {:.3e}\n  lhs: {}\n  rhs: {}",
+                    deviation,
+                    threshold,
+                    fmt_matrix(lhs),
+                    fmt_matrix(rhs)
+                ),
+            );
+        }
+    }
+
+    fn check_identity<const ROWS: usize, const COLS: usize>(
+        &mut self,
+        identity: &str,
+        example: impl FnOnce() -> String,
+        lhs: &MatF64<ROWS, COLS>,
+        rhs: &MatF64<ROWS, COLS>,
+    ) {
+        let tolerance = self.params.tolerance;
+        self.check_close(identity, example, lhs, rhs, tolerance);
+    }
+
+    fn check_jacobian<const ROWS: usize, const COLS: usize>(
+        &mut self,
+        identity: &str,
+        example: impl FnOnce() -> String,
+        analytic: &MatF64<ROWS, COLS>,
+        numeric: &MatF64<ROWS, COLS>,
+    ) {
+        let tolerance = self.params.jacobian_tolerance;
+        self.check_close(identity, example, analytic, numeric, tolerance);
+    }
+
+    fn numeric_diff<const OUT: usize, const IN: usize>(
+        &self,
+        f: impl Fn(VecF64<IN>) -> VecF64<OUT>,
+        x: VecF64<IN>,
+    ) -> MatF64<OUT, IN> {
+        VectorValuedMapFromVector::<f64, 1>::static_sym_diff_quotient(f, x, self.params.step_size)
+    }
+}
+
+fn fmt_matrix<const ROWS: usize, const COLS: usize>(m: &MatF64<ROWS, COLS>) -> String {
+    let rows: Vec<Vec<f64>> = (0..ROWS)
+        .map(|r| (0..COLS).map(|c| m[(r, c)]).collect())
+        .collect();
+    if COLS == 1 {
+        format!("{:?}", m.as_slice())
+    } else {
+        format!("{:?}", rows)
+    }
+}
+
+fn fmt_vec<const N: usize>(name: &str, v: &VecF64<N>) -> String {
+    format!("{} = {:?}", name, v.as_slice())
+}
+
+/// Checks that a Lie group implementation is consistent
+///
+/// Using the params, tangent and point examples of `G`, the following is verified:
+///
+///  - the params examples are valid and the invalid params examples are not,
+///  - the group axioms: identity, inverse, associativity, and that the group multiplication, the
+///    group action and the matrix and compact representations are consistent,
+///  - the exp/log round trips and that exp agrees with the matrix exponential of hat,
+///  - hat/vee round trips,
+///  - the adjoint identities Adj(a)·x = vee(A·hat(x)·A⁻¹) and ad(x)·y = vee([hat(x), hat(y)]),
+///  - all analytic Jacobians against numeric differentiation, or against forward mode automatic
+///    differentiation for the Jacobians with respect to the params, which are evaluated off the
+///    manifold.
+///
+/// Returns all violated identities, each with the example inputs for which it does not hold.
+pub fn check_lie_group_conformance<
+    const DOF: usize,
+    const PARAMS: usize,
+    const POINT: usize,
+    const AMBIENT: usize,
+    G: IsRealLieGroupImpl<f64, DOF, PARAMS, POINT, AMBIENT, 1>,
+>(
+    params: ConformanceParams,
+) -> Vec<ConformanceFailure> {
+    let mut checker = Checker {
+        params,
+        failures: vec![],
+    };
+
+    let elements = G::params_examples();
+    let tangents = G::tangent_examples();
+    let points = example_points::<f64, POINT, 1>();
+
+    if elements.is_empty() {
+        checker.fail(
+            "params_examples() is non-empty",
+            "the group".to_owned(),
+            "no examples".to_owned(),
+        );
+    }
+    if tangents.is_empty() {
+        checker.fail(
+            "tangent_examples() is non-empty",
+            "the group".to_owned(),
+            "no examples".to_owned(),
+        );
+    }
+
+    params_tests::<DOF, PARAMS, POINT, AMBIENT, G>(&mut checker, &elements);
+    group_axiom_tests::<DOF, PARAMS, POINT, AMBIENT, G>(&mut checker, &elements, &points);
+    exp_log_tests::<DOF, PARAMS, POINT, AMBIENT, G>(&mut checker, &elements, &tangents);
+    hat_vee_tests::<DOF, PARAMS, POINT, AMBIENT, G>(&mut checker, &tangents);
+    adjoint_tests::<DOF, PARAMS, POINT, AMBIENT, G>(&mut checker, &elements, &tangents);
+    jacobian_tests::<DOF, PARAMS, POINT, AMBIENT, G>(&mut checker, &elements, &tangents, &points);
+
+    checker.failures
+}
+
+/// Asserts that a Lie group implementation is consistent, see [check_lie_group_conformance]
+///
+/// Panics with a list of all violated identities.
+pub fn assert_lie_group_conformance<
+    const DOF: usize,
+    const PARAMS: usize,
+    const POINT: usize,
+    const AMBIENT: usize,
+    G: IsRealLieGroupImpl<f64, DOF, PARAMS, POINT, AMBIENT, 1>,
+>(
+    params: ConformanceParams,
+) {
+    let failures = check_lie_group_conformance::<DOF, PARAMS, POINT, AMBIENT, G>(params);
+    if !failures.is_empty() {
+        let list: Vec<String> = failures.iter().map(|f| format!(" - {}", f)).collect();
+        panic!(
+            "Lie group conformance check failed with {} violation(s):\n{}",
+            failures.len(),
+            list.join("\n")
+        );
+    }
+}
+
+fn params_tests<
+    const DOF: usize,
+    const PARAMS: usize,
+    const POINT: usize,
+    const AMBIENT: usize,
+    G: IsRealLieGroupImpl<f64, DOF, PARAMS, POINT, AMBIENT, 1>,
+>(
+    checker: &mut Checker,
+    elements: &[VecF64<PARAMS>],
+) {
+    for a in elements {
+        if !G::are_params_valid(a) {
+            checker.fail(
+                "are_params_valid(a) for all params_examples()",
+                fmt_vec("a", a),
+                "rejected".to_owned(),
+            );
+        }
+    }
+    for a in G::invalid_params_examples() {
+        if G::are_params_valid(&a) {
+            checker.fail(
+                "!are_params_valid(a) for all invalid_params_examples()",
+                fmt_vec("a", &a),
+                "accepted".to_owned(),
+            );
+        }
+    }
+}
+
+// Returns true if each row of m is a unit row vector, and no two rows are equal.
+fn is_row_selection<const ROWS: usize, const COLS: usize>(m: &MatF64<ROWS, COLS>) -> bool {
+    let mut selected = [false; COLS];
+    for r in 0..ROWS {
+        let ones: Vec<usize> = (0..COLS).filter(|&c| m[(r, c)] == 1.0).collect();
+        let zeros = (0..COLS).filter(|&c| m[(r, c)] == 0.0).count();
+        if ones.len() != 1 || zeros != COLS - 1 || selected[ones[0]] {
+            return false;
+        }
+        selected[ones[0]] = true;
+    }
+    true
+}
+
+fn group_axiom_tests<
+    const DOF: usize,
+    const PARAMS: usize,
+    const POINT: usize,
+    const AMBIENT: usize,
+    G: IsRealLieGroupImpl<f64, DOF, PARAMS, POINT, AMBIENT, 1>,
+>(
+    checker: &mut Checker,
+    elements: &[VecF64<PARAMS>],
+    points: &[VecF64<POINT>],
+) {
+    let e = G::identity_params();
+    let compact_e = G::compact(&e);
+    if !checker.params.compact_is_upper_rows && !is_row_selection(&compact_e) {
+        // otherwise, e.g. compact(a) = 0 would pass the check below
+        checker.fail(
+            "compact(e) selects distinct rows of matrix(e)",
+            "the identity".to_owned(),
+            format!("compact(e): {}", fmt_matrix(&compact_e)),
+        );
+    }
+    for a in elements {
+        let mat_a = G::matrix(a);
+        checker.check_identity(
+            "left identity: e·a = a",
+            || fmt_vec("a", a),
+            &G::matrix(&G::group_mul(&e, a)),
+            &mat_a,
+        );
+        checker.check_identity(
+            "right identity: a·e = a",
+            || fmt_vec("a", a),
+            &G::matrix(&G::group_mul(a, &e)),
+            &mat_a,
+        );
+        checker.check_identity(
+            "right inverse: a·a⁻¹ = e",
+            || fmt_vec("a", a),
+            &G::matrix(&G::group_mul(a, &G::inverse(a))),
+            &G::matrix(&e),
+        );
+        checker.check_identity(
+            "left inverse: a⁻¹·a = e",
+            || fmt_vec("a", a),
+            &G::matrix(&G::group_mul(&G::inverse(a), a)),
+            &G::matrix(&e),
+        );
+        if checker.params.compact_is_upper_rows {
+            checker.check_identity(
+                "compact(a) = upper rows of matrix(a)",
+                || fmt_vec("a", a),
+                &G::compact(a),
+                &mat_a.fixed_view::<POINT, AMBIENT>(0, 0).into_owned(),
+            );
+        } else {
+            checker.check_identity(
+                "compact(a) = compact(e)·matrix(a)",
+                || fmt_vec("a", a),
+                &G::compact(a),
+                &(compact_e * mat_a),
+            );
+        }
+
+        for b in elements {
+            let ab = G::group_mul(a, b);
+            checker.check_identity(
+                "homomorphism: matrix(a·b) = matrix(a)·matrix(b)",
+                || format!("{}, {}", fmt_vec("a", a), fmt_vec("b", b)),
+                &G::matrix(&ab),
+                &(mat_a * G::matrix(b)),
+            );
+            checker.check_identity(
+                "compact(a·b) = compact(a)·matrix(b)",
+                || format!("{}, {}", fmt_vec("a", a), fmt_vec("b", b)),
+                &G::compact(&ab),
+                &(G::compact(a) * G::matrix(b)),
+            );
+            checker.check_identity(
+                "inverse of product: (a·b)⁻¹ = b⁻¹·a⁻¹",
+                || format!("{}, {}", fmt_vec("a", a), fmt_vec("b", b)),
+                &G::matrix(&G::inverse(&ab)),
+                &G::matrix(&G::group_mul(&G::inverse(b), &G::inverse(a))),
+            );
+            for p in points.iter().take(2) {
+                checker.check_identity(
+                    "compatible action: transform(a·b, p) = transform(a, transform(b, p))",
+                    || {
+                        format!(
+                            "{}, {}, {}",
+                            fmt_vec("a", a),
+                            fmt_vec("b", b),
+                            fmt_vec("p", p)
+                        )
+                    },
+                    &G::transform(&ab, p),
+                    &G::transform(a, &G::transform(b, p)),
+                );
+            }
+            for c in elements {
+                checker.check_identity(
+                    "associativity: (a·b)·c = a·(b·c)",
+                    || {
+                        format!(
+                            "{}, {}, {}",
+                            fmt_vec("a", a),
+                            fmt_vec("b", b),
+                            fmt_vec("c", c)
+                        )
+                    },
+                    &G::matrix(&G::group_mul(&ab, c)),
+                    &G::matrix(&G::group_mul(a, &G::group_mul(b, c))),
+                );
+            }
+        }
+    }
+}
+
+// matrix exponential using scaling and squaring
+fn matrix_exp<const N: usize>(mat: &MatF64<N, N>) -> MatF64<N, N> {
+    let mut num_squarings = 0;
+    let mut scaled = *mat;
+    while scaled.norm() > 0.5 {
+        scaled *= 0.5;
+        num_squarings += 1;
+    }
+    let mut result = MatF64::<N, N>::identity();
+    let mut term = MatF64::<N, N>::identity();
+    for k in 1..20 {
+        term = term * scaled / k as f64;
+        result += term;
+    }
+    for _ in 0..num_squarings {
+        result = result * result;
+    }
+    result
+}
+
+fn exp_log_tests<
+    const DOF: usize,
+    const PARAMS: usize,
+    const POINT: usize,
+    const AMBIENT: usize,
+    G: IsRealLieGroupImpl<f64, DOF, PARAMS, POINT, AMBIENT, 1>,
+>(
+    checker: &mut Checker,
+    elements: &[VecF64<PARAMS>],
+    tangents: &[VecF64<DOF>],
+) {
+    checker.check_identity(
+        "exp(0) = e",
+        || "x = 0".to_owned(),
+        &G::matrix(&G::exp(&VecF64::<DOF>::zeros())),
+        &G::matrix(&G::identity_params()),
+    );
+    for a in elements {
+        if G::has_shortest_path_ambiguity(a) {
+            continue;
+        }
+        checker.check_identity(
+            "exp(log(a)) = a",
+            || fmt_vec("a", a),
+            &G::matrix(&G::exp(&G::log(a))),
+            &G::matrix(a),
+        );
+        checker.check_identity(
+            "log(a⁻¹) = -log(a)",
+            || fmt_vec("a", a),
+            &G::log(&G::inverse(a)),
+            &-G::log(a),
+        );
+    }
+    for x in tangents {
+        let exp_x = G::exp(x);
+        if !G::has_shortest_path_ambiguity(&exp_x) {
+            checker.check_identity("log(exp(x)) = x", || fmt_vec("x", x), &G::log(&exp_x), x);
+        }
+        checker.check_identity(
+            "exp(-x) = exp(x)⁻¹",
+            || fmt_vec("x", x),
+            &G::matrix(&G::exp(&-x)),
+            &G::matrix(&G::inverse(&exp_x)),
+        );
+        checker.check_identity(
+            "matrix(exp(x)) = matrix exponential of hat(x)",
+            || fmt_vec("x", x),
+            &G::matrix(&exp_x),
+            &matrix_exp(&G::hat(x)),
+        );
+    }
+}
+
+fn hat_vee_tests<
+    const DOF: usize,
+    const PARAMS: usize,
+    const POINT: usize,
+    const AMBIENT: usize,
+    G: IsRealLieGroupImpl<f64, DOF, PARAMS, POINT, AMBIENT, 1>,
+>(
+    checker: &mut Checker,
+    tangents: &[VecF64<DOF>],
+) {
+    for x in tangents {
+        checker.check_identity(
+            "vee(hat(x)) = x",
+            || fmt_vec("x", x),
+            &G::vee(&G::hat(x)),
+            x,
+        );
+    }
+}
+
+fn adjoint_tests<
+    const DOF: usize,
+    const PARAMS: usize,
+    const POINT: usize,
+    const AMBIENT: usize,
+    G: IsRealLieGroupImpl<f64, DOF, PARAMS, POINT, AMBIENT, 1>,
+>(
+    checker: &mut Checker,
+    elements: &[VecF64<PARAMS>],
+    tangents: &[VecF64<DOF>],
+) {
+    for a in elements {
+        let mat_a = G::matrix(a);
+        let mat_a_inv = G::matrix(&G::inverse(a));
+        for x in tangents {
+            checker.check_identity(
+                "group adjoint: adj(a)·x = vee(matrix(a)·hat(x)·matrix(a⁻¹))",
+                || format!("{}, {}", fmt_vec("a", a), fmt_vec("x", x)),
+                &(G::adj(a) * x),
+                &G::vee(&(mat_a * G::hat(x) * mat_a_inv)),
+            );
+        }
+    }
+    for x in tangents {
+        for y in tangents {
+            checker.check_identity(
+                "algebra adjoint: ad(x)·y = vee(hat(x)·hat(y) - hat(y)·hat(x))",
+                || format!("{}, {}", fmt_vec("x", x), fmt_vec("y", y)),
+                &(G::ad(x) * y),
+                &G::vee(&(G::hat(x) * G::hat(y) - G::hat(y) * G::hat(x))),
+            );
+        }
+    }
+}
+
+fn jacobian_tests<
+    const DOF: usize,
+    const PARAMS: usize,
+    const POINT: usize,
+    const AMBIENT: usize,
+    G: IsRealLieGroupImpl<f64, DOF, PARAMS, POINT, AMBIENT, 1>,
+>(
+    checker: &mut Checker,
+    elements: &[VecF64<PARAMS>],
+    tangents: &[VecF64<DOF>],
+    points: &[VecF64<POINT>],
+) {
+    type DualVector<const N: usize> = <DualScalar as IsScalar<1>>::Vector<N>;
+    let dual = |v: &VecF64<PARAMS>| DualVector::<PARAMS>::from_real_vector(*v);
+    let autodiff = |f: &dyn Fn(DualVector<PARAMS>) -> DualVector<PARAMS>, x: &VecF64<PARAMS>| {
+        VectorValuedMapFromVector::<DualScalar, 1>::static_fw_autodiff(f, *x)
+    };
+    let zero = VecF64::<DOF>::zeros();
+
+    checker.check_jacobian(
+        "dx_exp_x_at_0() = ∂exp(x)/∂x at x=0",
+        || "x = 0".to_owned(),
+        &G::dx_exp_x_at_0(),
+        &checker.numeric_diff(|x| G::exp(&x), zero),
+    );
+    for p in points {
+        checker.check_jacobian(
+            "dx_exp_x_times_point_at_0(p) = ∂(exp(x)·p)/∂x at x=0",
+            || fmt_vec("p", p),
+            &G::dx_exp_x_times_point_at_0(*p),
+            &checker.numeric_diff(|x| G::transform(&G::exp(&x), p), zero),
+        );
+    }
+
+    for x in tangents {
+        checker.check_jacobian(
+            "dx_exp(x) = ∂exp(x)/∂x",
+            || fmt_vec("x", x),
+            &G::dx_exp(x),
+            &checker.numeric_diff(|x| G::exp(&x), *x),
+        );
+
+        let exp_x = G::exp(x);
+        let exp_x_inv = G::inverse(&exp_x);
+        let jl = G::jl(x);
+        let jr = G::jr(x);
+        checker.check_jacobian(
+            "left Jacobian: jl(x) = ∂log(exp(x+δ)·exp(x)⁻¹)/∂δ at δ=0",
+            || fmt_vec("x", x),
+            &jl,
+            &checker.numeric_diff(
+                |d| G::log(&G::group_mul(&G::exp(&(x + d)), &exp_x_inv)),
+                zero,
+            ),
+        );
+        checker.check_jacobian(
+            "right Jacobian: jr(x) = ∂log(exp(x)⁻¹·exp(x+δ))/∂δ at δ=0",
+            || fmt_vec("x", x),
+            &jr,
+            &checker.numeric_diff(
+                |d| G::log(&G::group_mul(&exp_x_inv, &G::exp(&(x + d)))),
+                zero,
+            ),
+        );
+        checker.check_identity(
+            "jl(x) = adj(exp(x))·jr(x)",
+            || fmt_vec("x", x),
+            &jl,
+            &(G::adj(&exp_x) * jr),
+        );
+        checker.check_identity(
+            "jl_inv(x)·jl(x) = I",
+            || fmt_vec("x", x),
+            &(G::jl_inv(x) * jl),
+            &MatF64::<DOF, DOF>::identity(),
+        );
+        checker.check_identity(
+            "jr_inv(x)·jr(x) = I",
+            || fmt_vec("x", x),
+            &(G::jr_inv(x) * jr),
+            &MatF64::<DOF, DOF>::identity(),
+        );
+    }
+
+    for a in elements {
+        if !G::has_shortest_path_ambiguity(a) {
+            let dual_log = |v: DualVector<PARAMS>| G::DualG::log(&v);
+            checker.check_jacobian(
+                "dx_log_x(a) = ∂log(a)/∂a",
+                || fmt_vec("a", a),
+                &G::dx_log_x(a),
+                &VectorValuedMapFromVector::<DualScalar, 1>::static_fw_autodiff(dual_log, *a),
+            );
+        }
+
+        for b in elements {
+            let dual_a = dual(a);
+            let dual_b = dual(b);
+            checker.check_jacobian(
+                "da_a_mul_b(a, b) = ∂(a·b)/∂a",
+                || format!("{}, {}", fmt_vec("a", a), fmt_vec("b", b)),
+                &G::da_a_mul_b(a, b),
+                &autodiff(&|v| G::DualG::group_mul(&v, &dual_b), a),
+            );
+            checker.check_jacobian(
+                "db_a_mul_b(a, b) = ∂(a·b)/∂b",
+                || format!("{}, {}", fmt_vec("a", a), fmt_vec("b", b)),
+                &G::db_a_mul_b(a, b),
+                &autodiff(&|v| G::DualG::group_mul(&dual_a, &v), b),
+            );
+        }
+    }
+}
+
+#[test]
+fn conformance_tests() {
+    use crate::groups::direct_product::Isometry3xIsometry3Impl;
+    use crate::groups::direct_product::Rotation3xTranslation3Impl;
+    use crate::groups::extended_isometry3::ExtendedIsometry3Impl;
+    use crate::groups::isometry2::Isometry2Impl;
+    use crate::groups::isometry3::Isometry3Impl;
+    use crate::groups::rotation2::Rotation2Impl;
+    use crate::groups::rotation3::Rotation3Impl;
+    use crate::groups::rxso2::RxSO2Impl;
+    use crate::groups::rxso3::RxSO3Impl;
+    use crate::groups::sim2::Sim2Impl;
+    use crate::groups::sim3::Sim3Impl;
+    use crate::groups::special_linear3::SpecialLinear3Impl;
+    use crate::groups::translation::Translation3Impl;
+    use sophus_core::manifold::traits::TangentImpl;
+    use sophus_core::params::ParamsImpl;
+
+    let params = ConformanceParams::default();
+    assert_lie_group_conformance::<1, 2, 2, 2, Rotation2Impl<f64, 1>>(params);
+    assert_lie_group_conformance::<3, 4, 3, 3, Rotation3Impl<f64, 1>>(params);
+    assert_lie_group_conformance::<2, 2, 2, 2, RxSO2Impl<f64, 1>>(params);
+    assert_lie_group_conformance::<4, 4, 3, 3, RxSO3Impl<f64, 1>>(params);
+    assert_lie_group_conformance::<3, 4, 2, 3, Isometry2Impl<f64, 1>>(params);
+    assert_lie_group_conformance::<6, 7, 3, 4, Isometry3Impl<f64, 1>>(params);
+    assert_lie_group_conformance::<4, 4, 2, 3, Sim2Impl<f64, 1>>(params);
+    assert_lie_group_conformance::<7, 7, 3, 4, Sim3Impl<f64, 1>>(params);
+    assert_lie_group_conformance::<9, 10, 3, 5, ExtendedIsometry3Impl<f64, 1>>(params);
+    assert_lie_group_conformance::<8, 9, 3, 3, SpecialLinear3Impl<f64, 1>>(params);
+    assert_lie_group_conformance::<3, 3, 3, 4, Translation3Impl<f64, 1>>(params);
+
+    let product_params = ConformanceParams {
+        compact_is_upper_rows: false,
+        ..params
+    };
+    assert_lie_group_conformance::<6, 7, 6, 7, Rotation3xTranslation3Impl<f64, 1>>(product_params);
+    assert_lie_group_conformance::<12, 14, 6, 8, Isometry3xIsometry3Impl<f64, 1>>(product_params);
+
+    // A user-defined group which wraps SO(2), but has a wrong left Jacobian.
+    #[derive(Debug, Clone)]
+    struct BrokenRotation2Impl;
+
+    type R2 = Rotation2Impl<f64, 1>;
+
+    impl ParamsImpl<f64, 2, 1> for BrokenRotation2Impl {
+        fn are_params_valid(params: &VecF64<2>) -> bool {
+            R2::are_params_valid(params)
+        }
+        fn params_examples() -> Vec<VecF64<2>> {
+            R2::params_examples()
+        }
+        fn invalid_params_examples() -> Vec<VecF64<2>> {
+            R2::invalid_params_examples()
+        }
+    }
+
+    impl TangentImpl<f64, 1, 1> for BrokenRotation2Impl {
+        fn tangent_examples() -> Vec<VecF64<1>> {
+            R2::tangent_examples()
+        }
+    }
+
+    impl IsLieGroupImpl<f64, 1, 2, 2, 2, 1> for BrokenRotation2Impl {
+        type GenG<S2: IsScalar<1>> = Rotation2Impl<S2, 1>;
+        type RealG = R2;
+        type DualG = Rotation2Impl<DualScalar, 1>;
+
+        const IS_ORIGIN_PRESERVING: bool = true;
+        const IS_AXIS_DIRECTION_PRESERVING: bool = false;
+        const IS_DIRECTION_VECTOR_PRESERVING: bool = false;
+        const IS_SHAPE_PRESERVING: bool = true;
+        const IS_DISTANCE_PRESERVING: bool = true;
+        const IS_PARALLEL_LINE_PRESERVING: bool = true;
+
+        fn identity_params() -> VecF64<2> {
+            R2::identity_params()
+        }
+        fn adj(params: &VecF64<2>) -> MatF64<1, 1> {
+            R2::adj(params)
+        }
+        fn ad(tangent: &VecF64<1>) -> MatF64<1, 1> {
+            R2::ad(tangent)
+        }
+        fn exp(omega: &VecF64<1>) -> VecF64<2> {
+            R2::exp(omega)
+        }
+        fn log(params: &VecF64<2>) -> VecF64<1> {
+            R2::log(params)
+        }
+        fn hat(omega: &VecF64<1>) -> MatF64<2, 2> {
+            R2::hat(omega)
+        }
+        fn vee(hat: &MatF64<2, 2>) -> VecF64<1> {
+            R2::vee(hat)
+        }
+        fn jl(_tangent: &VecF64<1>) -> MatF64<1, 1> {
+            MatF64::<1, 1>::new(2.0)
+        }
+        fn jl_inv(tangent: &VecF64<1>) -> MatF64<1, 1> {
+            R2::jl_inv(tangent)
+        }
+        fn group_mul(params1: &VecF64<2>, params2: &VecF64<2>) -> VecF64<2> {
+            R2::group_mul(params1, params2)
+        }
+        fn inverse(params: &VecF64<2>) -> VecF64<2> {
+            R2::inverse(params)
+        }
+        fn transform(params: &VecF64<2>, point: &VecF64<2>) -> VecF64<2> {
+            R2::transform(params, point)
+        }
+        fn to_ambient(point: &VecF64<2>) -> VecF64<2> {
+            R2::to_ambient(point)
+        }
+        fn compact(params: &VecF64<2>) -> MatF64<2, 2> {
+            R2::compact(params)
+        }
+        fn matrix(params: &VecF64<2>) -> MatF64<2, 2> {
+            R2::matrix(params)
+        }
+    }
+
+    impl IsRealLieGroupImpl<f64, 1, 2, 2, 2, 1> for BrokenRotation2Impl {
+        fn da_a_mul_b(a: &VecF64<2>, b: &VecF64<2>) -> MatF64<2, 2> {
+            R2::da_a_mul_b(a, b)
+        }
+        fn db_a_mul_b(a: &VecF64<2>, b: &VecF64<2>) -> MatF64<2, 2> {
+            R2::db_a_mul_b(a, b)
+        }
+        fn dx_exp(tangent: &VecF64<1>) -> MatF64<2, 1> {
+            R2::dx_exp(tangent)
+        }
+        fn dx_exp_x_at_0() -> MatF64<2, 1> {
+            R2::dx_exp_x_at_0()
+        }
+        fn dx_log_x(params: &VecF64<2>) -> MatF64<1, 2> {
+            R2::dx_log_x(params)
+        }
+        fn dx_exp_x_times_point_at_0(point: VecF64<2>) -> MatF64<2, 1> {
+            R2::dx_exp_x_times_point_at_0(point)
+        }
+        fn has_shortest_path_ambiguity(params: &VecF64<2>) -> bool {
+            R2::has_shortest_path_ambiguity(params)
+        }
+    }
+
+    let failures = check_lie_group_conformance::<1, 2, 2, 2, BrokenRotation2Impl>(params);
+    // jr is derived from jl, hence both are wrong for every tangent example
+    let identities: Vec<&str> = failures.iter().map(|f| f.identity.as_str()).collect();
+    assert_eq!(
+        identities[0..4],
+        vec![
+            "left Jacobian: jl(x) = ∂log(exp(x+δ)·exp(x)⁻¹)/∂δ at δ=0",
+            "right Jacobian: jr(x) = ∂log(exp(x)⁻¹·exp(x+δ))/∂δ at δ=0",
+            "jl_inv(x)·jl(x) = I",
+            "jr_inv(x)·jr(x) = I",
+        ]
+    );
+    assert_eq!(failures.len(), 4 * R2::tangent_examples().len());
+    assert!(failures[0].to_string().contains("does not hold for x = ["));
+
+    let result = std::panic::catch_unwind(|| {
+        assert_lie_group_conformance::<1, 2, 2, 2, BrokenRotation2Impl>(params)
+    });
+    assert!(result.is_err());
+}
@@ -1,17 +1,27 @@
 use crate::lie_group::LieGroup;
 use crate::prelude::*;
 use crate::traits::IsRealLieFactorGroupImpl;
+#[cfg(feature = "std")]
 use crate::Rotation2;
+#[cfg(feature = "std")]
 use crate::Rotation3;
+#[cfg(feature = "std")]
+use crate::RxSO2;
+#[cfg(feature = "std")]
+use crate::RxSO3;
+#[cfg(feature = "std")]
 use approx::assert_relative_eq;
+#[cfg(feature = "std")]
 use sophus_core::calculus::dual::DualScalar;
+#[cfg(feature = "std")]
 use sophus_core::calculus::maps::MatrixValuedMapFromVector;
+#[cfg(feature = "std")]
 use sophus_core::manifold::traits::TangentImpl;
 
-#[cfg(feature = "simd")]
+#[cfg(feature = "std")]
 use sophus_core::calculus::dual::DualBatchScalar;
 
-#[cfg(feature = "simd")]
+#[cfg(feature = "std")]
 use sophus_core::linalg::BatchScalarF64;
 
 impl<
@@ -53,6 +63,7 @@ impl<
 }
 
 /// A trait for Lie groups.
+#[cfg(feature = "std")]
 pub trait RealFactorLieGroupTest {
     /// Run all tests.
     fn run_real_factor_tests() {
@@ -70,6 +81,7 @@ pub trait RealFactorLieGroupTest {
 macro_rules! def_real_group_test_template {
     ($scalar:ty, $dual_scalar:ty, $group: ty, $dual_group: ty, $batch:literal
 ) => {
+        #[cfg(feature = "std")]
         impl RealFactorLieGroupTest for $group {
             fn mat_v_test() {
                 use crate::traits::IsLieGroup;
@@ -158,7 +170,6 @@ macro_rules! def_real_group_test_template {
 }
 
 def_real_group_test_template!(f64, DualScalar, Rotation2<f64, 1>, Rotation2<DualScalar, 1>,  1);
-#[cfg(feature = "simd")]
 def_real_group_test_template!(
     BatchScalarF64<8>,
     DualBatchScalar<8>,
@@ -168,7 +179,6 @@ def_real_group_test_template!(
 );
 
 def_real_group_test_template!(f64, DualScalar, Rotation3<f64, 1>, Rotation3<DualScalar, 1>,  1);
-#[cfg(feature = "simd")]
 def_real_group_test_template!(
     BatchScalarF64<8>,
     DualBatchScalar<8>,
@@ -176,3 +186,21 @@ def_real_group_test_template!(
     Rotation3<DualBatchScalar<8>, 8>,
     8
 );
+
+def_real_group_test_template!(f64, DualScalar, RxSO2<f64, 1>, RxSO2<DualScalar, 1>,  1);
+def_real_group_test_template!(
+    BatchScalarF64<8>,
+    DualBatchScalar<8>,
+    RxSO2<BatchScalarF64<8>, 8>,
+    RxSO2<DualBatchScalar<8>, 8>,
+    8
+);
+
+def_real_group_test_template!(f64, DualScalar, RxSO3<f64, 1>, RxSO3<DualScalar, 1>,  1);
+def_real_group_test_template!(
+    BatchScalarF64<8>,
+    DualBatchScalar<8>,
+    RxSO3<BatchScalarF64<8>, 8>,
+    RxSO3<DualBatchScalar<8>, 8>,
+    8
+);
@@ -1,10 +1,28 @@
+/// direct product of two Lie groups
+pub mod direct_product;
+/// 3d extended isometry
+pub mod extended_isometry3;
 /// 2d isometry
 pub mod isometry2;
 /// 3d  isometry
 pub mod isometry3;
+pub(crate) mod jacobian_details;
 /// 2d rotation
 pub mod rotation2;
 /// 3d rotation
 pub mod rotation3;
+/// 2d scaled rotation
+pub mod rxso2;
+/// 3d scaled rotation
+pub mod rxso3;
+/// 2d similarity
+pub mod sim2;
+/// 3d similarity
+pub mod sim3;
+pub(crate) mod sim_details;
+/// 3x3 special linear group
+pub mod special_linear3;
+/// translation group
+pub mod translation;
 /// semi-direct product
 pub mod translation_product_product;
@@ -0,0 +1,785 @@
+use super::isometry3::Isometry3Impl;
+use super::rotation3::Rotation3Impl;
+use super::translation::Translation3Impl;
+use crate::lie_group::LieGroup;
+use crate::prelude::*;
+use crate::traits::IsLieGroupImpl;
+use crate::traits::IsRealLieGroupImpl;
+#[cfg(feature = "alloc")]
+use alloc::format;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use sophus_core::manifold::traits::TangentImpl;
+use sophus_core::params::ParamsImpl;
+
+/// implementation of a direct product group G1 × G2
+///
+/// Both factors act independently: parameters, tangent vectors and points are stacked as
+/// [first; second], while the adjoint, the Jacobians and the matrix representation are block
+/// diagonal. The dimensions of the product must be the sums of the factor dimensions, i.e.
+/// DOF = DOF1 + DOF2, PARAMS = PARAMS1 + PARAMS2, POINT = POINT1 + POINT2 and
+/// AMBIENT = AMBIENT1 + AMBIENT2.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct DirectProductGroupImpl<
+    S: IsScalar<BATCH>,
+    const DOF: usize,
+    const PARAMS: usize,
+    const POINT: usize,
+    const AMBIENT: usize,
+    const DOF1: usize,
+    const PARAMS1: usize,
+    const POINT1: usize,
+    const AMBIENT1: usize,
+    const DOF2: usize,
+    const PARAMS2: usize,
+    const POINT2: usize,
+    const AMBIENT2: usize,
+    const BATCH: usize,
+    G1: IsLieGroupImpl<S, DOF1, PARAMS1, POINT1, AMBIENT1, BATCH>,
+    G2: IsLieGroupImpl<S, DOF2, PARAMS2, POINT2, AMBIENT2, BATCH>,
+> {
+    phantom: PhantomData<(S, G1, G2)>,
+}
+
+impl<
+        S: IsScalar<BATCH>,
+        const DOF: usize,
+        const PARAMS: usize,
+        const POINT: usize,
+        const AMBIENT: usize,
+        const DOF1: usize,
+        const PARAMS1: usize,
+        const POINT1: usize,
+        const AMBIENT1: usize,
+        const DOF2: usize,
+        const PARAMS2: usize,
+        const POINT2: usize,
+        const AMBIENT2: usize,
+        const BATCH: usize,
+        G1: IsLieGroupImpl<S, DOF1, PARAMS1, POINT1, AMBIENT1, BATCH>,
+        G2: IsLieGroupImpl<S, DOF2, PARAMS2, POINT2, AMBIENT2, BATCH>,
+    >
+    DirectProductGroupImpl<
+        S,
+        DOF,
+        PARAMS,
+        POINT,
+        AMBIENT,
+        DOF1,
+        PARAMS1,
+        POINT1,
+        AMBIENT1,
+        DOF2,
+        PARAMS2,
+        POINT2,
+        AMBIENT2,
+        BATCH,
+        G1,
+        G2,
+    >
+{
+    // evaluated at compile time, whenever the block helpers below are instantiated
+    const DIMENSIONS_ARE_CONSISTENT: () = assert!(
+        DOF == DOF1 + DOF2
+            && PARAMS == PARAMS1 + PARAMS2
+            && POINT == POINT1 + POINT2
+            && AMBIENT == AMBIENT1 + AMBIENT2,
+        "direct product dimensions must be the sums of the factor dimensions"
+    );
+
+    /// first factor part of the group parameters
+    pub fn first_params(params: &S::Vector<PARAMS>) -> S::Vector<PARAMS1> {
+        let () = Self::DIMENSIONS_ARE_CONSISTENT;
+        params.get_fixed_subvec::<PARAMS1>(0)
+    }
+
+    /// second factor part of the group parameters
+    pub fn second_params(params: &S::Vector<PARAMS>) -> S::Vector<PARAMS2> {
+        let () = Self::DIMENSIONS_ARE_CONSISTENT;
+        params.get_fixed_subvec::<PARAMS2>(PARAMS1)
+    }
+
+    /// create group parameters from first and second factor parameters
+    pub fn params_from(
+        first_params: &S::Vector<PARAMS1>,
+        second_params: &S::Vector<PARAMS2>,
+    ) -> S::Vector<PARAMS> {
+        let () = Self::DIMENSIONS_ARE_CONSISTENT;
+        S::Vector::block_vec2(first_params.clone(), second_params.clone())
+    }
+
+    /// first factor part of the tangent vector
+    pub fn first_tangent(tangent: &S::Vector<DOF>) -> S::Vector<DOF1> {
+        tangent.get_fixed_subvec::<DOF1>(0)
+    }
+
+    /// second factor part of the tangent vector
+    pub fn second_tangent(tangent: &S::Vector<DOF>) -> S::Vector<DOF2> {
+        tangent.get_fixed_subvec::<DOF2>(DOF1)
+    }
+
+    /// create tangent vector from first and second factor tangent
+    pub fn tangent_from(
+        first_tangent: &S::Vector<DOF1>,
+        second_tangent: &S::Vector<DOF2>,
+    ) -> S::Vector<DOF> {
+        let () = Self::DIMENSIONS_ARE_CONSISTENT;
+        S::Vector::block_vec2(first_tangent.clone(), second_tangent.clone())
+    }
+
+    /// first factor part of a point
+    fn first_point(point: &S::Vector<POINT>) -> S::Vector<POINT1> {
+        point.get_fixed_subvec::<POINT1>(0)
+    }
+
+    /// second factor part of a point
+    fn second_point(point: &S::Vector<POINT>) -> S::Vector<POINT2> {
+        point.get_fixed_subvec::<POINT2>(POINT1)
+    }
+
+    /// block diagonal matrix [[a, 0], [0, b]]
+    fn block_diag<
+        const R1: usize,
+        const R2: usize,
+        const C1: usize,
+        const C2: usize,
+        const ROWS: usize,
+        const COLS: usize,
+    >(
+        a: S::Matrix<R1, C1>,
+        b: S::Matrix<R2, C2>,
+    ) -> S::Matrix<ROWS, COLS> {
+        S::Matrix::block_mat2x2::<R1, R2, C1, C2>((a, S::Matrix::zeros()), (S::Matrix::zeros(), b))
+    }
+
+    /// pairs the i-th examples of both factors, cycling through the shorter list
+    #[cfg(feature = "alloc")]
+    fn zip_examples<T, const N1: usize, const N2: usize>(
+        first: Vec<S::Vector<N1>>,
+        second: Vec<S::Vector<N2>>,
+        from: impl Fn(&S::Vector<N1>, &S::Vector<N2>) -> T,
+    ) -> Vec<T> {
+        let num = first.len().max(second.len());
+        (0..num)
+            .map(|i| from(&first[i % first.len()], &second[i % second.len()]))
+            .collect()
+    }
+}
+
+impl<
+        S: IsScalar<BATCH>,
+        const DOF: usize,
+        const PARAMS: usize,
+        const POINT: usize,
+        const AMBIENT: usize,
+        const DOF1: usize,
+        const PARAMS1: usize,
+        const POINT1: usize,
+        const AMBIENT1: usize,
+        const DOF2: usize,
+        const PARAMS2: usize,
+        const POINT2: usize,
+        const AMBIENT2: usize,
+        const BATCH: usize,
+        G1: IsLieGroupImpl<S, DOF1, PARAMS1, POINT1, AMBIENT1, BATCH>,
+        G2: IsLieGroupImpl<S, DOF2, PARAMS2, POINT2, AMBIENT2, BATCH>,
+    > ParamsImpl<S, PARAMS, BATCH>
+    for DirectProductGroupImpl<
+        S,
+        DOF,
+        PARAMS,
+        POINT,
+        AMBIENT,
+        DOF1,
+        PARAMS1,
+        POINT1,
+        AMBIENT1,
+        DOF2,
+        PARAMS2,
+        POINT2,
+        AMBIENT2,
+        BATCH,
+        G1,
+        G2,
+    >
+{
+    fn are_params_valid(params: &S::Vector<PARAMS>) -> S::Mask {
+        let num_valid = S::ones().select(
+            &G1::are_params_valid(&Self::first_params(params)),
+            S::zeros(),
+        ) + S::ones().select(
+            &G2::are_params_valid(&Self::second_params(params)),
+            S::zeros(),
+        );
+        num_valid.greater_equal(&S::from_f64(1.5))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn params_examples() -> Vec<S::Vector<PARAMS>> {
+        Self::zip_examples(
+            G1::params_examples(),
+            G2::params_examples(),
+            Self::params_from,
+        )
+    }
+
+    #[cfg(feature = "alloc")]
+    fn invalid_params_examples() -> Vec<S::Vector<PARAMS>> {
+        let mut examples = vec![];
+        for first_params in G1::invalid_params_examples() {
+            examples.push(Self::params_from(&first_params, &G2::identity_params()));
+        }
+        for second_params in G2::invalid_params_examples() {
+            examples.push(Self::params_from(&G1::identity_params(), &second_params));
+        }
+        examples
+    }
+}
+
+impl<
+        S: IsScalar<BATCH>,
+        const DOF: usize,
+        const PARAMS: usize,
+        const POINT: usize,
+        const AMBIENT: usize,
+        const DOF1: usize,
+        const PARAMS1: usize,
+        const POINT1: usize,
+        const AMBIENT1: usize,
+        const DOF2: usize,
+        const PARAMS2: usize,
+        const POINT2: usize,
+        const AMBIENT2: usize,
+        const BATCH: usize,
+        G1: IsLieGroupImpl<S, DOF1, PARAMS1, POINT1, AMBIENT1, BATCH>,
+        G2: IsLieGroupImpl<S, DOF2, PARAMS2, POINT2, AMBIENT2, BATCH>,
+    > TangentImpl<S, DOF, BATCH>
+    for DirectProductGroupImpl<
+        S,
+        DOF,
+        PARAMS,
+        POINT,
+        AMBIENT,
+        DOF1,
+        PARAMS1,
+        POINT1,
+        AMBIENT1,
+        DOF2,
+        PARAMS2,
+        POINT2,
+        AMBIENT2,
+        BATCH,
+        G1,
+        G2,
+    >
+{
+    #[cfg(feature = "alloc")]
+    fn tangent_examples() -> Vec<S::Vector<DOF>> {
+        Self::zip_examples(
+            G1::tangent_examples(),
+            G2::tangent_examples(),
+            Self::tangent_from,
+        )
+    }
+}
+
+impl<
+        S: IsScalar<BATCH>,
+        const DOF: usize,
+        const PARAMS: usize,
+        const POINT: usize,
+        const AMBIENT: usize,
+        const DOF1: usize,
+        const PARAMS1: usize,
+        const POINT1: usize,
+        const AMBIENT1: usize,
+        const DOF2: usize,
+        const PARAMS2: usize,
+        const POINT2: usize,
+        const AMBIENT2: usize,
+        const BATCH: usize,
+        G1: IsLieGroupImpl<S, DOF1, PARAMS1, POINT1, AMBIENT1, BATCH>,
+        G2: IsLieGroupImpl<S, DOF2, PARAMS2, POINT2, AMBIENT2, BATCH>,
+    > IsLieGroupImpl<S, DOF, PARAMS, POINT, AMBIENT, BATCH>
+    for DirectProductGroupImpl<
+        S,
+        DOF,
+        PARAMS,
+        POINT,
+        AMBIENT,
+        DOF1,
+        PARAMS1,
+        POINT1,
+        AMBIENT1,
+        DOF2,
+        PARAMS2,
+        POINT2,
+        AMBIENT2,
+        BATCH,
+        G1,
+        G2,
+    >
+{
+    const IS_ORIGIN_PRESERVING: bool = G1::IS_ORIGIN_PRESERVING && G2::IS_ORIGIN_PRESERVING;
+    const IS_AXIS_DIRECTION_PRESERVING: bool =
+        G1::IS_AXIS_DIRECTION_PRESERVING && G2::IS_AXIS_DIRECTION_PRESERVING;
+    const IS_DIRECTION_VECTOR_PRESERVING: bool =
+        G1::IS_DIRECTION_VECTOR_PRESERVING && G2::IS_DIRECTION_VECTOR_PRESERVING;
+    const IS_SHAPE_PRESERVING: bool = G1::IS_SHAPE_PRESERVING && G2::IS_SHAPE_PRESERVING;
+    const IS_DISTANCE_PRESERVING: bool = G1::IS_DISTANCE_PRESERVING && G2::IS_DISTANCE_PRESERVING;
+    const IS_PARALLEL_LINE_PRESERVING: bool =
+        G1::IS_PARALLEL_LINE_PRESERVING && G2::IS_PARALLEL_LINE_PRESERVING;
+
+    #[cfg(feature = "alloc")]
+    fn params_constraint() -> String {
+        format!(
+            "first factor: {}; second factor: {}",
+            G1::params_constraint(),
+            G2::params_constraint()
+        )
+    }
+
+    fn identity_params() -> S::Vector<PARAMS> {
+        Self::params_from(&G1::identity_params(), &G2::identity_params())
+    }
+
+    //    Manifold / Lie Group concepts
+
+    fn adj(params: &S::Vector<PARAMS>) -> S::Matrix<DOF, DOF> {
+        Self::block_diag(
+            G1::adj(&Self::first_params(params)),
+            G2::adj(&Self::second_params(params)),
+        )
+    }
+
+    fn ad(tangent: &S::Vector<DOF>) -> S::Matrix<DOF, DOF> {
+        Self::block_diag(
+            G1::ad(&Self::first_tangent(tangent)),
+            G2::ad(&Self::second_tangent(tangent)),
+        )
+    }
+
+    fn exp(omega: &S::Vector<DOF>) -> S::Vector<PARAMS> {
+        Self::params_from(
+            &G1::exp(&Self::first_tangent(omega)),
+            &G2::exp(&Self::second_tangent(omega)),
+        )
+    }
+
+    fn log(params: &S::Vector<PARAMS>) -> S::Vector<DOF> {
+        Self::tangent_from(
+            &G1::log(&Self::first_params(params)),
+            &G2::log(&Self::second_params(params)),
+        )
+    }
+
+    fn hat(omega: &S::Vector<DOF>) -> S::Matrix<AMBIENT, AMBIENT> {
+        Self::block_diag(
+            G1::hat(&Self::first_tangent(omega)),
+            G2::hat(&Self::second_tangent(omega)),
+        )
+    }
+
+    fn vee(hat: &S::Matrix<AMBIENT, AMBIENT>) -> S::Vector<DOF> {
+        Self::tangent_from(
+            &G1::vee(&hat.get_fixed_submat::<AMBIENT1, AMBIENT1>(0, 0)),
+            &G2::vee(&hat.get_fixed_submat::<AMBIENT2, AMBIENT2>(AMBIENT1, AMBIENT1)),
+        )
+    }
+
+    fn jl(tangent: &S::Vector<DOF>) -> S::Matrix<DOF, DOF> {
+        Self::block_diag(
+            G1::jl(&Self::first_tangent(tangent)),
+            G2::jl(&Self::second_tangent(tangent)),
+        )
+    }
+
+    fn jl_inv(tangent: &S::Vector<DOF>) -> S::Matrix<DOF, DOF> {
+        Self::block_diag(
+            G1::jl_inv(&Self::first_tangent(tangent)),
+            G2::jl_inv(&Self::second_tangent(tangent)),
+        )
+    }
+
+    // group operations
+
+    fn group_mul(params1: &S::Vector<PARAMS>, params2: &S::Vector<PARAMS>) -> S::Vector<PARAMS> {
+        Self::params_from(
+            &G1::group_mul(&Self::first_params(params1), &Self::first_params(params2)),
+            &G2::group_mul(&Self::second_params(params1), &Self::second_params(params2)),
+        )
+    }
+
+    fn inverse(params: &S::Vector<PARAMS>) -> S::Vector<PARAMS> {
+        Self::params_from(
+            &G1::inverse(&Self::first_params(params)),
+            &G2::inverse(&Self::second_params(params)),
+        )
+    }
+
+    fn transform(params: &S::Vector<PARAMS>, point: &S::Vector<POINT>) -> S::Vector<POINT> {
+        S::Vector::block_vec2(
+            G1::transform(&Self::first_params(params), &Self::first_point(point)),
+            G2::transform(&Self::second_params(params), &Self::second_point(point)),
+        )
+    }
+
+    fn to_ambient(point: &S::Vector<POINT>) -> S::Vector<AMBIENT> {
+        S::Vector::block_vec2(
+            G1::to_ambient(&Self::first_point(point)),
+            G2::to_ambient(&Self::second_point(point)),
+        )
+    }
+
+    fn compact(params: &S::Vector<PARAMS>) -> S::Matrix<POINT, AMBIENT> {
+        Self::block_diag(
+            G1::compact(&Self::first_params(params)),
+            G2::compact(&Self::second_params(params)),
+        )
+    }
+
+    fn matrix(params: &S::Vector<PARAMS>) -> S::Matrix<AMBIENT, AMBIENT> {
+        Self::block_diag(
+            G1::matrix(&Self::first_params(params)),
+            G2::matrix(&Self::second_params(params)),
+        )
+    }
+
+    type GenG<S2: IsScalar<BATCH>> = DirectProductGroupImpl<
+        S2,
+        DOF,
+        PARAMS,
+        POINT,
+        AMBIENT,
+        DOF1,
+        PARAMS1,
+        POINT1,
+        AMBIENT1,
+        DOF2,
+        PARAMS2,
+        POINT2,
+        AMBIENT2,
+        BATCH,
+        G1::GenG<S2>,
+        G2::GenG<S2>,
+    >;
+
+    type RealG = DirectProductGroupImpl<
+        S::RealScalar,
+        DOF,
+        PARAMS,
+        POINT,
+        AMBIENT,
+        DOF1,
+        PARAMS1,
+        POINT1,
+        AMBIENT1,
+        DOF2,
+        PARAMS2,
+        POINT2,
+        AMBIENT2,
+        BATCH,
+        G1::RealG,
+        G2::RealG,
+    >;
+
+    #[cfg(feature = "alloc")]
+    type DualG = DirectProductGroupImpl<
+        S::DualScalar,
+        DOF,
+        PARAMS,
+        POINT,
+        AMBIENT,
+        DOF1,
+        PARAMS1,
+        POINT1,
+        AMBIENT1,
+        DOF2,
+        PARAMS2,
+        POINT2,
+        AMBIENT2,
+        BATCH,
+        G1::DualG,
+        G2::DualG,
+    >;
+}
+
+impl<
+        S: IsRealScalar<BATCH>,
+        const DOF: usize,
+        const PARAMS: usize,
+        const POINT: usize,
+        const AMBIENT: usize,
+        const DOF1: usize,
+        const PARAMS1: usize,
+        const POINT1: usize,
+        const AMBIENT1: usize,
+        const DOF2: usize,
+        const PARAMS2: usize,
+        const POINT2: usize,
+        const AMBIENT2: usize,
+        const BATCH: usize,
+        G1: IsRealLieGroupImpl<S, DOF1, PARAMS1, POINT1, AMBIENT1, BATCH>,
+        G2: IsRealLieGroupImpl<S, DOF2, PARAMS2, POINT2, AMBIENT2, BATCH>,
+    > IsRealLieGroupImpl<S, DOF, PARAMS, POINT, AMBIENT, BATCH>
+    for DirectProductGroupImpl<
+        S,
+        DOF,
+        PARAMS,
+        POINT,
+        AMBIENT,
+        DOF1,
+        PARAMS1,
+        POINT1,
+        AMBIENT1,
+        DOF2,
+        PARAMS2,
+        POINT2,
+        AMBIENT2,
+        BATCH,
+        G1,
+        G2,
+    >
+{
+    fn da_a_mul_b(a: &S::Vector<PARAMS>, b: &S::Vector<PARAMS>) -> S::Matrix<PARAMS, PARAMS> {
+        Self::block_diag(
+            G1::da_a_mul_b(&Self::first_params(a), &Self::first_params(b)),
+            G2::da_a_mul_b(&Self::second_params(a), &Self::second_params(b)),
+        )
+    }
+
+    fn db_a_mul_b(a: &S::Vector<PARAMS>, b: &S::Vector<PARAMS>) -> S::Matrix<PARAMS, PARAMS> {
+        Self::block_diag(
+            G1::db_a_mul_b(&Self::first_params(a), &Self::first_params(b)),
+            G2::db_a_mul_b(&Self::second_params(a), &Self::second_params(b)),
+        )
+    }
+
+    fn dx_exp(tangent: &S::Vector<DOF>) -> S::Matrix<PARAMS, DOF> {
+        Self::block_diag(
+            G1::dx_exp(&Self::first_tangent(tangent)),
+            G2::dx_exp(&Self::second_tangent(tangent)),
+        )
+    }
+
+    fn dx_exp_x_at_0() -> S::Matrix<PARAMS, DOF> {
+        Self::block_diag(G1::dx_exp_x_at_0(), G2::dx_exp_x_at_0())
+    }
+
+    fn dx_log_x(params: &S::Vector<PARAMS>) -> S::Matrix<DOF, PARAMS> {
+        Self::block_diag(
+            G1::dx_log_x(&Self::first_params(params)),
+            G2::dx_log_x(&Self::second_params(params)),
+        )
+    }
+
+    fn dx_exp_x_times_point_at_0(point: S::Vector<POINT>) -> S::Matrix<POINT, DOF> {
+        Self::block_diag(
+            G1::dx_exp_x_times_point_at_0(Self::first_point(&point)),
+            G2::dx_exp_x_times_point_at_0(Self::second_point(&point)),
+        )
+    }
+
+    fn has_shortest_path_ambiguity(params: &S::Vector<PARAMS>) -> S::Mask {
+        let num_ambiguous = S::ones().select(
+            &G1::has_shortest_path_ambiguity(&Self::first_params(params)),
+            S::zeros(),
+        ) + S::ones().select(
+            &G2::has_shortest_path_ambiguity(&Self::second_params(params)),
+            S::zeros(),
+        );
+        num_ambiguous.greater_equal(&S::from_f64(0.5))
+    }
+}
+
+impl<
+        S: IsScalar<BATCH>,
+        const DOF: usize,
+        const PARAMS: usize,
+        const POINT: usize,
+        const AMBIENT: usize,
+        const DOF1: usize,
+        const PARAMS1: usize,
+        const POINT1: usize,
+        const AMBIENT1: usize,
+        const DOF2: usize,
+        const PARAMS2: usize,
+        const POINT2: usize,
+        const AMBIENT2: usize,
+        const BATCH: usize,
+        G1: IsLieGroupImpl<S, DOF1, PARAMS1, POINT1, AMBIENT1, BATCH>,
+        G2: IsLieGroupImpl<S, DOF2, PARAMS2, POINT2, AMBIENT2, BATCH>,
+    >
+    LieGroup<
+        S,
+        DOF,
+        PARAMS,
+        POINT,
+        AMBIENT,
+        BATCH,
+        DirectProductGroupImpl<
+            S,
+            DOF,
+            PARAMS,
+            POINT,
+            AMBIENT,
+            DOF1,
+            PARAMS1,
+            POINT1,
+            AMBIENT1,
+            DOF2,
+            PARAMS2,
+            POINT2,
+            AMBIENT2,
+            BATCH,
+            G1,
+            G2,
+        >,
+    >
+{
+    /// create direct product element from its two factors
+    pub fn from_factors(
+        first: &LieGroup<S, DOF1, PARAMS1, POINT1, AMBIENT1, BATCH, G1>,
+        second: &LieGroup<S, DOF2, PARAMS2, POINT2, AMBIENT2, BATCH, G2>,
+    ) -> Self {
+        Self::from_params(&S::Vector::block_vec2(
+            first.params().clone(),
+            second.params().clone(),
+        ))
+    }
+
+    /// first factor
+    pub fn first(&self) -> LieGroup<S, DOF1, PARAMS1, POINT1, AMBIENT1, BATCH, G1> {
+        LieGroup::from_params(&self.params().get_fixed_subvec::<PARAMS1>(0))
+    }
+
+    /// second factor
+    pub fn second(&self) -> LieGroup<S, DOF2, PARAMS2, POINT2, AMBIENT2, BATCH, G2> {
+        LieGroup::from_params(&self.params().get_fixed_subvec::<PARAMS2>(PARAMS1))
+    }
+
+    /// set first factor
+    pub fn set_first(&mut self, first: &LieGroup<S, DOF1, PARAMS1, POINT1, AMBIENT1, BATCH, G1>) {
+        self.set_params(&S::Vector::block_vec2(
+            first.params().clone(),
+            self.second().params().clone(),
+        ))
+    }
+
+    /// set second factor
+    pub fn set_second(&mut self, second: &LieGroup<S, DOF2, PARAMS2, POINT2, AMBIENT2, BATCH, G2>) {
+        self.set_params(&S::Vector::block_vec2(
+            self.first().params().clone(),
+            second.params().clone(),
+        ))
+    }
+}
+
+/// SO(3) × R^3 group implementation struct
+pub type Rotation3xTranslation3Impl<S, const BATCH: usize> = DirectProductGroupImpl<
+    S,
+    6,
+    7,
+    6,
+    7,
+    3,
+    4,
+    3,
+    3,
+    3,
+    3,
+    3,
+    4,
+    BATCH,
+    Rotation3Impl<S, BATCH>,
+    Translation3Impl<S, BATCH>,
+>;
+/// SO(3) × R^3 group, e.g. a rotation and a velocity
+pub type Rotation3xTranslation3<S, const BATCH: usize> =
+    LieGroup<S, 6, 7, 6, 7, BATCH, Rotation3xTranslation3Impl<S, BATCH>>;
+
+/// SE(3) × SE(3) group implementation struct
+pub type Isometry3xIsometry3Impl<S, const BATCH: usize> = DirectProductGroupImpl<
+    S,
+    12,
+    14,
+    6,
+    8,
+    6,
+    7,
+    3,
+    4,
+    6,
+    7,
+    3,
+    4,
+    BATCH,
+    Isometry3Impl<S, BATCH>,
+    Isometry3Impl<S, BATCH>,
+>;
+/// SE(3) × SE(3) group, e.g. a pose together with a relative extrinsic calibration
+pub type Isometry3xIsometry3<S, const BATCH: usize> =
+    LieGroup<S, 12, 14, 6, 8, BATCH, Isometry3xIsometry3Impl<S, BATCH>>;
+
+#[test]
+fn direct_product_prop_tests() {
+    use crate::real_lie_group::RealLieGroupTest;
+    use crate::Isometry3;
+    use crate::Rotation3;
+    use approx::assert_relative_eq;
+    use sophus_core::calculus::dual::dual_scalar::DualScalar;
+    use sophus_core::linalg::VecF64;
+
+    use sophus_core::calculus::dual::dual_batch_scalar::DualBatchScalar;
+    use sophus_core::linalg::BatchScalarF64;
+
+    Rotation3xTranslation3::<f64, 1>::test_suite();
+    Rotation3xTranslation3::<f32, 1>::test_suite();
+    Rotation3xTranslation3::<BatchScalarF64<8>, 8>::test_suite();
+    Rotation3xTranslation3::<DualScalar, 1>::test_suite();
+    Rotation3xTranslation3::<DualBatchScalar<8>, 8>::test_suite();
+    Isometry3xIsometry3::<f64, 1>::test_suite();
+    Isometry3xIsometry3::<f32, 1>::test_suite();
+    Isometry3xIsometry3::<DualScalar, 1>::test_suite();
+
+    Rotation3xTranslation3::<f64, 1>::run_real_tests();
+    Rotation3xTranslation3::<BatchScalarF64<8>, 8>::run_real_tests();
+    Isometry3xIsometry3::<f64, 1>::run_real_tests();
+
+    // the factors compose independently
+    let a = Isometry3::<f64, 1>::exp(&VecF64::<6>::new(0.1, 0.2, 0.3, 0.4, -0.5, 0.6));
+    let b = Isometry3::<f64, 1>::exp(&VecF64::<6>::new(-1.0, 0.5, 0.0, 0.2, 0.1, -0.3));
+    let ab = Isometry3xIsometry3::from_factors(&a, &b);
+    assert_relative_eq!(ab.first().compact(), a.compact(), epsilon = 1e-12);
+    assert_relative_eq!(ab.second().compact(), b.compact(), epsilon = 1e-12);
+    let ab_ba = ab.group_mul(&Isometry3xIsometry3::from_factors(&b, &a));
+    assert_relative_eq!(
+        ab_ba.first().compact(),
+        a.group_mul(&b).compact(),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        ab_ba.second().compact(),
+        b.group_mul(&a).compact(),
+        epsilon = 1e-12
+    );
+
+    let mut rotation_velocity = Rotation3xTranslation3::<f64, 1>::identity();
+    let rotation = Rotation3::<f64, 1>::exp(&VecF64::<3>::new(0.3, -0.2, 0.1));
+    rotation_velocity.set_first(&rotation);
+    assert_relative_eq!(
+        rotation_velocity.first().compact(),
+        rotation.compact(),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        rotation_velocity.second().params(),
+        &VecF64::<3>::zeros(),
+        epsilon = 1e-12
+    );
+}
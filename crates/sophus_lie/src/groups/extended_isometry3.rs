@@ -0,0 +1,594 @@
+use crate::groups::jacobian_details::left_jacobian_upper_right_block;
+use crate::groups::rotation3::Rotation3Impl;
+use crate::lie_group::LieGroup;
+use crate::prelude::*;
+use crate::traits::IsLieFactorGroupImpl;
+use crate::traits::IsLieGroupImpl;
+use crate::traits::IsRealLieFactorGroupImpl;
+use crate::traits::IsRealLieGroupImpl;
+use crate::Rotation3;
+#[cfg(feature = "alloc")]
+use alloc::format;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use sophus_core::manifold::traits::TangentImpl;
+use sophus_core::params::ParamsImpl;
+#[cfg(feature = "alloc")]
+use sophus_core::points::example_points;
+
+/// 3d extended isometry implementation - SE_2(3)
+///
+/// The group of rotation, velocity and position, as used for inertial navigation states.
+/// The parameters are (position, velocity, unit quaternion) and the tangent vector is
+/// (position tangent, velocity tangent, rotation vector). The matrix representation is
+///
+///   [[R, p, v],
+///    [0, 1, 0],
+///    [0, 0, 1]].
+#[derive(Debug, Copy, Clone, Default)]
+pub struct ExtendedIsometry3Impl<S: IsScalar<BATCH>, const BATCH: usize> {
+    phantom: PhantomData<S>,
+}
+
+impl<S: IsScalar<BATCH>, const BATCH: usize> ExtendedIsometry3Impl<S, BATCH> {
+    /// position part of the group parameters
+    pub fn position(params: &S::Vector<10>) -> S::Vector<3> {
+        params.get_fixed_subvec::<3>(0)
+    }
+
+    /// velocity part of the group parameters
+    pub fn velocity(params: &S::Vector<10>) -> S::Vector<3> {
+        params.get_fixed_subvec::<3>(3)
+    }
+
+    /// rotation part of the group parameters
+    pub fn rotation_params(params: &S::Vector<10>) -> S::Vector<4> {
+        params.get_fixed_subvec::<4>(6)
+    }
+
+    /// create group parameters from position, velocity and rotation parameters
+    pub fn params_from(
+        position: &S::Vector<3>,
+        velocity: &S::Vector<3>,
+        rotation_params: &S::Vector<4>,
+    ) -> S::Vector<10> {
+        S::Vector::block_vec2(
+            position.clone(),
+            S::Vector::<7>::block_vec2(velocity.clone(), rotation_params.clone()),
+        )
+    }
+
+    fn position_tangent(tangent: &S::Vector<9>) -> S::Vector<3> {
+        tangent.get_fixed_subvec::<3>(0)
+    }
+
+    fn velocity_tangent(tangent: &S::Vector<9>) -> S::Vector<3> {
+        tangent.get_fixed_subvec::<3>(3)
+    }
+
+    fn rotation_tangent(tangent: &S::Vector<9>) -> S::Vector<3> {
+        tangent.get_fixed_subvec::<3>(6)
+    }
+
+    fn tangent_from(
+        position_tangent: &S::Vector<3>,
+        velocity_tangent: &S::Vector<3>,
+        rotation_tangent: &S::Vector<3>,
+    ) -> S::Vector<9> {
+        S::Vector::block_vec2(
+            position_tangent.clone(),
+            S::Vector::<6>::block_vec2(velocity_tangent.clone(), rotation_tangent.clone()),
+        )
+    }
+
+    /// upper right block Q of the left Jacobian [[J, Q], [0, J]] of the SE(3) sub-group
+    fn jl_translation_block(
+        translation_tangent: &S::Vector<3>,
+        rotation_tangent: &S::Vector<3>,
+    ) -> S::Matrix<3, 3> {
+        let mat_omega = Rotation3Impl::<S, BATCH>::hat(rotation_tangent);
+        left_jacobian_upper_right_block::<S, 3, 3, BATCH>(
+            &mat_omega,
+            &Rotation3Impl::<S, BATCH>::hat(translation_tangent),
+            &mat_omega,
+        )
+    }
+
+    /// 9x9 matrix with the 3x3 blocks given row by row
+    fn block_mat3x3(
+        row0: (S::Matrix<3, 3>, S::Matrix<3, 3>, S::Matrix<3, 3>),
+        row1: (S::Matrix<3, 3>, S::Matrix<3, 3>, S::Matrix<3, 3>),
+        row2: (S::Matrix<3, 3>, S::Matrix<3, 3>, S::Matrix<3, 3>),
+    ) -> S::Matrix<9, 9> {
+        S::Matrix::block_mat2x2::<3, 6, 3, 6>(
+            (row0.0, S::Matrix::block_mat1x2::<3, 3>(row0.1, row0.2)),
+            (
+                S::Matrix::block_mat2x1::<3, 3>(row1.0, row2.0),
+                S::Matrix::block_mat2x2::<3, 3, 3, 3>((row1.1, row1.2), (row2.1, row2.2)),
+            ),
+        )
+    }
+}
+
+impl<S: IsScalar<BATCH>, const BATCH: usize> ParamsImpl<S, 10, BATCH>
+    for ExtendedIsometry3Impl<S, BATCH>
+{
+    fn are_params_valid(params: &S::Vector<10>) -> S::Mask {
+        Rotation3Impl::<S, BATCH>::are_params_valid(&Self::rotation_params(params))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn params_examples() -> Vec<S::Vector<10>> {
+        let points = example_points::<S, 3, BATCH>();
+        let mut examples = vec![];
+        for rotation_params in Rotation3Impl::<S, BATCH>::params_examples() {
+            for i in 0..points.len() {
+                examples.push(Self::params_from(
+                    &points[i],
+                    &points[(i + 3) % points.len()],
+                    &rotation_params,
+                ));
+            }
+        }
+        examples
+    }
+
+    #[cfg(feature = "alloc")]
+    fn invalid_params_examples() -> Vec<S::Vector<10>> {
+        vec![Self::params_from(
+            &S::Vector::zeros(),
+            &S::Vector::zeros(),
+            &Rotation3Impl::<S, BATCH>::invalid_params_examples()[0],
+        )]
+    }
+}
+
+impl<S: IsScalar<BATCH>, const BATCH: usize> TangentImpl<S, 9, BATCH>
+    for ExtendedIsometry3Impl<S, BATCH>
+{
+    #[cfg(feature = "alloc")]
+    fn tangent_examples() -> Vec<S::Vector<9>> {
+        let points = example_points::<S, 3, BATCH>();
+        let mut examples = vec![];
+        for rotation_tangent in Rotation3Impl::<S, BATCH>::tangent_examples() {
+            for i in 0..points.len() {
+                examples.push(Self::tangent_from(
+                    &points[i],
+                    &points[(i + 5) % points.len()],
+                    &rotation_tangent,
+                ));
+            }
+        }
+        examples
+    }
+}
+
+impl<S: IsScalar<BATCH>, const BATCH: usize> IsLieGroupImpl<S, 9, 10, 3, 5, BATCH>
+    for ExtendedIsometry3Impl<S, BATCH>
+{
+    type GenG<S2: IsScalar<BATCH>> = ExtendedIsometry3Impl<S2, BATCH>;
+    type RealG = ExtendedIsometry3Impl<S::RealScalar, BATCH>;
+    #[cfg(feature = "alloc")]
+    type DualG = ExtendedIsometry3Impl<S::DualScalar, BATCH>;
+
+    const IS_ORIGIN_PRESERVING: bool = false;
+    const IS_AXIS_DIRECTION_PRESERVING: bool = false;
+    const IS_DIRECTION_VECTOR_PRESERVING: bool = false;
+    const IS_SHAPE_PRESERVING: bool = true;
+    const IS_DISTANCE_PRESERVING: bool = true;
+    const IS_PARALLEL_LINE_PRESERVING: bool = true;
+
+    #[cfg(feature = "alloc")]
+    fn params_constraint() -> String {
+        format!(
+            "rotation: {}",
+            Rotation3Impl::<S, BATCH>::params_constraint()
+        )
+    }
+
+    fn identity_params() -> S::Vector<10> {
+        Self::params_from(
+            &S::Vector::zeros(),
+            &S::Vector::zeros(),
+            &Rotation3Impl::<S, BATCH>::identity_params(),
+        )
+    }
+
+    fn adj(params: &S::Vector<10>) -> S::Matrix<9, 9> {
+        let mat_r = Rotation3Impl::<S, BATCH>::matrix(&Self::rotation_params(params));
+        let zero = S::Matrix::<3, 3>::zeros();
+
+        Self::block_mat3x3(
+            (
+                mat_r.clone(),
+                zero.clone(),
+                Rotation3Impl::<S, BATCH>::hat(&Self::position(params)).mat_mul(mat_r.clone()),
+            ),
+            (
+                zero.clone(),
+                mat_r.clone(),
+                Rotation3Impl::<S, BATCH>::hat(&Self::velocity(params)).mat_mul(mat_r.clone()),
+            ),
+            (zero.clone(), zero, mat_r),
+        )
+    }
+
+    fn ad(tangent: &S::Vector<9>) -> S::Matrix<9, 9> {
+        let mat_omega = Rotation3Impl::<S, BATCH>::hat(&Self::rotation_tangent(tangent));
+        let zero = S::Matrix::<3, 3>::zeros();
+
+        Self::block_mat3x3(
+            (
+                mat_omega.clone(),
+                zero.clone(),
+                Rotation3Impl::<S, BATCH>::hat(&Self::position_tangent(tangent)),
+            ),
+            (
+                zero.clone(),
+                mat_omega.clone(),
+                Rotation3Impl::<S, BATCH>::hat(&Self::velocity_tangent(tangent)),
+            ),
+            (zero.clone(), zero, mat_omega),
+        )
+    }
+
+    fn jl(tangent: &S::Vector<9>) -> S::Matrix<9, 9> {
+        let rotation_tangent = Self::rotation_tangent(tangent);
+        let mat_jl = Rotation3Impl::<S, BATCH>::jl(&rotation_tangent);
+        let zero = S::Matrix::<3, 3>::zeros();
+
+        Self::block_mat3x3(
+            (
+                mat_jl.clone(),
+                zero.clone(),
+                Self::jl_translation_block(&Self::position_tangent(tangent), &rotation_tangent),
+            ),
+            (
+                zero.clone(),
+                mat_jl.clone(),
+                Self::jl_translation_block(&Self::velocity_tangent(tangent), &rotation_tangent),
+            ),
+            (zero.clone(), zero, mat_jl),
+        )
+    }
+
+    fn jl_inv(tangent: &S::Vector<9>) -> S::Matrix<9, 9> {
+        let rotation_tangent = Self::rotation_tangent(tangent);
+        let mat_jl_inv = Rotation3Impl::<S, BATCH>::jl_inv(&rotation_tangent);
+        let zero = S::Matrix::<3, 3>::zeros();
+        let off_diagonal = |translation_tangent: &S::Vector<3>| {
+            -mat_jl_inv
+                .mat_mul(Self::jl_translation_block(
+                    translation_tangent,
+                    &rotation_tangent,
+                ))
+                .mat_mul(mat_jl_inv.clone())
+        };
+
+        Self::block_mat3x3(
+            (
+                mat_jl_inv.clone(),
+                zero.clone(),
+                off_diagonal(&Self::position_tangent(tangent)),
+            ),
+            (
+                zero.clone(),
+                mat_jl_inv.clone(),
+                off_diagonal(&Self::velocity_tangent(tangent)),
+            ),
+            (zero.clone(), zero, mat_jl_inv.clone()),
+        )
+    }
+
+    fn exp(omega: &S::Vector<9>) -> S::Vector<10> {
+        let rotation_tangent = Self::rotation_tangent(omega);
+        let mat_v = Rotation3Impl::<S, BATCH>::mat_v(&rotation_tangent);
+
+        Self::params_from(
+            &(mat_v.clone() * Self::position_tangent(omega)),
+            &(mat_v * Self::velocity_tangent(omega)),
+            &Rotation3Impl::<S, BATCH>::exp(&rotation_tangent),
+        )
+    }
+
+    fn log(params: &S::Vector<10>) -> S::Vector<9> {
+        let rotation_tangent = Rotation3Impl::<S, BATCH>::log(&Self::rotation_params(params));
+        let mat_v_inv = Rotation3Impl::<S, BATCH>::mat_v_inverse(&rotation_tangent);
+
+        Self::tangent_from(
+            &(mat_v_inv.clone() * Self::position(params)),
+            &(mat_v_inv * Self::velocity(params)),
+            &rotation_tangent,
+        )
+    }
+
+    fn hat(omega: &S::Vector<9>) -> S::Matrix<5, 5> {
+        S::Matrix::block_mat2x2::<3, 2, 3, 2>(
+            (
+                Rotation3Impl::<S, BATCH>::hat(&Self::rotation_tangent(omega)),
+                S::Matrix::block_mat1x2::<1, 1>(
+                    Self::position_tangent(omega).to_mat(),
+                    Self::velocity_tangent(omega).to_mat(),
+                ),
+            ),
+            (S::Matrix::zeros(), S::Matrix::zeros()),
+        )
+    }
+
+    fn vee(hat: &S::Matrix<5, 5>) -> S::Vector<9> {
+        Self::tangent_from(
+            &hat.get_fixed_submat::<3, 1>(0, 3).get_col_vec(0),
+            &hat.get_fixed_submat::<3, 1>(0, 4).get_col_vec(0),
+            &Rotation3Impl::<S, BATCH>::vee(&hat.get_fixed_submat::<3, 3>(0, 0)),
+        )
+    }
+
+    fn group_mul(params1: &S::Vector<10>, params2: &S::Vector<10>) -> S::Vector<10> {
+        let rotation_params1 = Self::rotation_params(params1);
+        let rotation_params2 = Self::rotation_params(params2);
+
+        Self::params_from(
+            &(Rotation3Impl::<S, BATCH>::transform(&rotation_params1, &Self::position(params2))
+                + Self::position(params1)),
+            &(Rotation3Impl::<S, BATCH>::transform(&rotation_params1, &Self::velocity(params2))
+                + Self::velocity(params1)),
+            &Rotation3Impl::<S, BATCH>::group_mul(&rotation_params1, &rotation_params2),
+        )
+    }
+
+    fn inverse(params: &S::Vector<10>) -> S::Vector<10> {
+        let rotation_params = Rotation3Impl::<S, BATCH>::inverse(&Self::rotation_params(params));
+
+        Self::params_from(
+            &-Rotation3Impl::<S, BATCH>::transform(&rotation_params, &Self::position(params)),
+            &-Rotation3Impl::<S, BATCH>::transform(&rotation_params, &Self::velocity(params)),
+            &rotation_params,
+        )
+    }
+
+    fn transform(params: &S::Vector<10>, point: &S::Vector<3>) -> S::Vector<3> {
+        Rotation3Impl::<S, BATCH>::transform(&Self::rotation_params(params), point)
+            + Self::position(params)
+    }
+
+    fn to_ambient(point: &S::Vector<3>) -> S::Vector<5> {
+        // homogeneous coordinates
+        S::Vector::block_vec2(point.clone(), S::Vector::<2>::from_f64_array([1.0, 0.0]))
+    }
+
+    fn compact(params: &S::Vector<10>) -> S::Matrix<3, 5> {
+        S::Matrix::block_mat1x2::<3, 2>(
+            Rotation3Impl::<S, BATCH>::matrix(&Self::rotation_params(params)),
+            S::Matrix::block_mat1x2::<1, 1>(
+                Self::position(params).to_mat(),
+                Self::velocity(params).to_mat(),
+            ),
+        )
+    }
+
+    fn matrix(params: &S::Vector<10>) -> S::Matrix<5, 5> {
+        S::Matrix::block_mat2x1::<3, 2>(
+            Self::compact(params),
+            S::Matrix::block_mat1x2::<3, 2>(
+                S::Matrix::<2, 3>::zeros(),
+                S::Matrix::<2, 2>::identity(),
+            ),
+        )
+    }
+}
+
+impl<S: IsRealScalar<BATCH>, const BATCH: usize> IsRealLieGroupImpl<S, 9, 10, 3, 5, BATCH>
+    for ExtendedIsometry3Impl<S, BATCH>
+{
+    fn dx_exp_x_at_0() -> S::Matrix<10, 9> {
+        S::Matrix::block_mat2x2::<6, 4, 6, 3>(
+            (S::Matrix::<6, 6>::identity(), S::Matrix::<6, 3>::zeros()),
+            (
+                S::Matrix::<4, 6>::zeros(),
+                Rotation3Impl::<S, BATCH>::dx_exp_x_at_0(),
+            ),
+        )
+    }
+
+    fn dx_exp_x_times_point_at_0(point: S::Vector<3>) -> S::Matrix<3, 9> {
+        S::Matrix::block_mat1x2::<3, 6>(
+            S::Matrix::<3, 3>::identity(),
+            S::Matrix::block_mat1x2::<3, 3>(
+                S::Matrix::<3, 3>::zeros(),
+                Rotation3Impl::<S, BATCH>::dx_exp_x_times_point_at_0(point),
+            ),
+        )
+    }
+
+    fn dx_exp(tangent: &S::Vector<9>) -> S::Matrix<10, 9> {
+        let rotation_tangent = Self::rotation_tangent(tangent);
+        let mat_v = Rotation3Impl::<S, BATCH>::mat_v(&rotation_tangent);
+        let dx_mat_v = Rotation3Impl::<S, BATCH>::dx_mat_v(&rotation_tangent);
+
+        let mut dx_mat_v_position = S::Matrix::<3, 3>::zeros();
+        let mut dx_mat_v_velocity = S::Matrix::<3, 3>::zeros();
+        for i in 0..3 {
+            dx_mat_v_position.set_col_vec(i, dx_mat_v[i].clone() * Self::position_tangent(tangent));
+            dx_mat_v_velocity.set_col_vec(i, dx_mat_v[i].clone() * Self::velocity_tangent(tangent));
+        }
+
+        S::Matrix::block_mat2x2::<6, 4, 6, 3>(
+            (
+                S::Matrix::block_mat2x2::<3, 3, 3, 3>(
+                    (mat_v.clone(), S::Matrix::zeros()),
+                    (S::Matrix::zeros(), mat_v),
+                ),
+                S::Matrix::block_mat2x1::<3, 3>(dx_mat_v_position, dx_mat_v_velocity),
+            ),
+            (
+                S::Matrix::<4, 6>::zeros(),
+                Rotation3Impl::<S, BATCH>::dx_exp(&rotation_tangent),
+            ),
+        )
+    }
+
+    fn dx_log_x(params: &S::Vector<10>) -> S::Matrix<9, 10> {
+        let rotation_params = Self::rotation_params(params);
+        let rotation_tangent = Rotation3Impl::<S, BATCH>::log(&rotation_params);
+
+        let dx_log_x = Rotation3Impl::<S, BATCH>::dx_log_x(&rotation_params);
+        let mat_v_inverse = Rotation3Impl::<S, BATCH>::mat_v_inverse(&rotation_tangent);
+        let dx_mat_v_inverse = Rotation3Impl::<S, BATCH>::dx_mat_v_inverse(&rotation_tangent);
+
+        let mut dx_mat_v_inv_position = S::Matrix::<3, 4>::zeros();
+        let mut dx_mat_v_inv_velocity = S::Matrix::<3, 4>::zeros();
+        for i in 0..3 {
+            let r: S::Vector<4> = dx_log_x.get_row_vec(i);
+            dx_mat_v_inv_position = dx_mat_v_inv_position
+                + (dx_mat_v_inverse[i].clone() * Self::position(params)).outer(r.clone());
+            dx_mat_v_inv_velocity = dx_mat_v_inv_velocity
+                + (dx_mat_v_inverse[i].clone() * Self::velocity(params)).outer(r);
+        }
+
+        S::Matrix::block_mat2x2::<6, 3, 6, 4>(
+            (
+                S::Matrix::block_mat2x2::<3, 3, 3, 3>(
+                    (mat_v_inverse.clone(), S::Matrix::zeros()),
+                    (S::Matrix::zeros(), mat_v_inverse),
+                ),
+                S::Matrix::block_mat2x1::<3, 3>(dx_mat_v_inv_position, dx_mat_v_inv_velocity),
+            ),
+            (S::Matrix::<3, 6>::zeros(), dx_log_x),
+        )
+    }
+
+    fn da_a_mul_b(a: &S::Vector<10>, b: &S::Vector<10>) -> S::Matrix<10, 10> {
+        let a_rotation_params = Self::rotation_params(a);
+        let b_rotation_params = Self::rotation_params(b);
+
+        S::Matrix::block_mat2x2::<6, 4, 6, 4>(
+            (
+                S::Matrix::<6, 6>::identity(),
+                S::Matrix::block_mat2x1::<3, 3>(
+                    Rotation3Impl::<S, BATCH>::dparams_matrix_times_point(
+                        &a_rotation_params,
+                        &Self::position(b),
+                    ),
+                    Rotation3Impl::<S, BATCH>::dparams_matrix_times_point(
+                        &a_rotation_params,
+                        &Self::velocity(b),
+                    ),
+                ),
+            ),
+            (
+                S::Matrix::<4, 6>::zeros(),
+                Rotation3Impl::<S, BATCH>::da_a_mul_b(&a_rotation_params, &b_rotation_params),
+            ),
+        )
+    }
+
+    fn db_a_mul_b(a: &S::Vector<10>, b: &S::Vector<10>) -> S::Matrix<10, 10> {
+        let a_rotation_params = Self::rotation_params(a);
+        let b_rotation_params = Self::rotation_params(b);
+        let mat_r = Rotation3Impl::<S, BATCH>::matrix(&a_rotation_params);
+
+        S::Matrix::block_mat2x2::<6, 4, 6, 4>(
+            (
+                S::Matrix::block_mat2x2::<3, 3, 3, 3>(
+                    (mat_r.clone(), S::Matrix::zeros()),
+                    (S::Matrix::zeros(), mat_r),
+                ),
+                S::Matrix::<6, 4>::zeros(),
+            ),
+            (
+                S::Matrix::<4, 6>::zeros(),
+                Rotation3Impl::<S, BATCH>::db_a_mul_b(&a_rotation_params, &b_rotation_params),
+            ),
+        )
+    }
+
+    fn has_shortest_path_ambiguity(params: &S::Vector<10>) -> S::Mask {
+        Rotation3Impl::<S, BATCH>::has_shortest_path_ambiguity(&Self::rotation_params(params))
+    }
+}
+
+/// 3d extended isometry group - SE_2(3)
+pub type ExtendedIsometry3<S, const BATCH: usize> =
+    LieGroup<S, 9, 10, 3, 5, BATCH, ExtendedIsometry3Impl<S, BATCH>>;
+
+impl<S: IsScalar<BATCH>, const BATCH: usize> ExtendedIsometry3<S, BATCH> {
+    /// create extended isometry from position, velocity and rotation
+    pub fn from_position_velocity_and_rotation(
+        position: &S::Vector<3>,
+        velocity: &S::Vector<3>,
+        rotation: &Rotation3<S, BATCH>,
+    ) -> Self {
+        Self::from_params(&ExtendedIsometry3Impl::<S, BATCH>::params_from(
+            position,
+            velocity,
+            rotation.params(),
+        ))
+    }
+
+    /// set position
+    pub fn set_position(&mut self, position: &S::Vector<3>) {
+        self.set_params(&ExtendedIsometry3Impl::<S, BATCH>::params_from(
+            position,
+            &self.velocity(),
+            self.rotation().params(),
+        ))
+    }
+
+    /// get position
+    pub fn position(&self) -> S::Vector<3> {
+        ExtendedIsometry3Impl::<S, BATCH>::position(self.params())
+    }
+
+    /// set velocity
+    pub fn set_velocity(&mut self, velocity: &S::Vector<3>) {
+        self.set_params(&ExtendedIsometry3Impl::<S, BATCH>::params_from(
+            &self.position(),
+            velocity,
+            self.rotation().params(),
+        ))
+    }
+
+    /// get velocity
+    pub fn velocity(&self) -> S::Vector<3> {
+        ExtendedIsometry3Impl::<S, BATCH>::velocity(self.params())
+    }
+
+    /// set rotation
+    pub fn set_rotation(&mut self, rotation: &Rotation3<S, BATCH>) {
+        self.set_params(&ExtendedIsometry3Impl::<S, BATCH>::params_from(
+            &self.position(),
+            &self.velocity(),
+            rotation.params(),
+        ))
+    }
+
+    /// get rotation
+    pub fn rotation(&self) -> Rotation3<S, BATCH> {
+        Rotation3::from_params(&ExtendedIsometry3Impl::<S, BATCH>::rotation_params(
+            self.params(),
+        ))
+    }
+}
+
+#[test]
+fn extended_isometry3_prop_tests() {
+    use crate::real_lie_group::RealLieGroupTest;
+    use sophus_core::calculus::dual::dual_scalar::DualScalar;
+
+    use sophus_core::calculus::dual::DualBatchScalar;
+    use sophus_core::linalg::BatchScalarF64;
+
+    ExtendedIsometry3::<f64, 1>::test_suite();
+    ExtendedIsometry3::<f32, 1>::test_suite();
+    ExtendedIsometry3::<BatchScalarF64<8>, 8>::test_suite();
+    ExtendedIsometry3::<DualScalar, 1>::test_suite();
+    ExtendedIsometry3::<DualBatchScalar<8>, 8>::test_suite();
+
+    ExtendedIsometry3::<f64, 1>::run_real_tests();
+    ExtendedIsometry3::<BatchScalarF64<8>, 8>::run_real_tests();
+}
@@ -1,8 +1,22 @@
 use crate::groups::rotation2::Rotation2Impl;
 use crate::groups::translation_product_product::TranslationProductGroupImpl;
 use crate::lie_group::LieGroup;
+#[cfg(feature = "alloc")]
+use crate::lie_group_error::check_homogeneous_last_row;
+#[cfg(feature = "alloc")]
+use crate::lie_group_error::normalize_unit_params;
+#[cfg(feature = "alloc")]
+use crate::lie_group_error::LieGroupError;
 use crate::prelude::*;
+#[cfg(feature = "alloc")]
+use crate::traits::IsLieGroupImpl;
 use crate::Rotation2;
+#[cfg(feature = "alloc")]
+use alloc::format;
+#[cfg(feature = "alloc")]
+use sophus_core::linalg::MatF64;
+#[cfg(feature = "alloc")]
+use sophus_core::linalg::VecF64;
 
 /// 2D isometry group implementation struct - SE(2)
 pub type Isometry2Impl<S, const BATCH: usize> =
@@ -31,24 +45,70 @@ impl<S: IsScalar<BATCH>, const BATCH: usize> Isometry2<S, BATCH> {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl Isometry2<f64, 1> {
+    /// create isometry from a 3x3 homogeneous transformation matrix
+    ///
+    /// Returns an error if the last row is not [0, …, 0, 1] or if the upper left block is not a
+    /// rotation matrix, within 1e-6.
+    pub fn try_from_matrix(mat: &MatF64<3, 3>) -> Result<Self, LieGroupError> {
+        check_homogeneous_last_row(mat)?;
+        let rotation =
+            Rotation2::<f64, 1>::try_from_matrix(&mat.fixed_view::<2, 2>(0, 0).into_owned())
+                .map_err(|err| match err {
+                    LieGroupError::InvalidMatrix { constraint, .. } => {
+                        LieGroupError::InvalidMatrix {
+                            matrix: format!("{:?}", mat),
+                            constraint: format!("rotation block: {}", constraint),
+                        }
+                    }
+                    err => err,
+                })?;
+        Ok(Self::from_translation_and_rotation(
+            &mat.fixed_view::<2, 1>(0, 2).into_owned(),
+            &rotation,
+        ))
+    }
+
+    /// create isometry from parameters [translation; rotation], normalizing the rotation first
+    ///
+    /// Returns an error if the norm of the rotation parameters deviates from one by more than
+    /// the tolerance.
+    pub fn try_from_params_normalized(
+        params: &VecF64<4>,
+        tolerance: f64,
+    ) -> Result<Self, LieGroupError> {
+        let rotation_params = params.fixed_rows::<2>(2).into_owned();
+        normalize_unit_params(&rotation_params, tolerance)
+            .map(|rotation_params| {
+                Self::from_translation_and_rotation(
+                    &params.fixed_rows::<2>(0).into_owned(),
+                    &Rotation2::from_params(&rotation_params),
+                )
+            })
+            .map_err(|deviation| LieGroupError::NormalizationFailed {
+                params: format!("{:?}", params),
+                constraint: Isometry2Impl::<f64, 1>::params_constraint(),
+                deviation,
+                tolerance,
+            })
+    }
+}
+
 #[test]
 fn isometry2_prop_tests() {
     use crate::real_lie_group::RealLieGroupTest;
     use sophus_core::calculus::dual::dual_scalar::DualScalar;
 
-    #[cfg(feature = "simd")]
     use sophus_core::calculus::dual::dual_batch_scalar::DualBatchScalar;
-    #[cfg(feature = "simd")]
     use sophus_core::linalg::BatchScalarF64;
 
     Isometry2::<f64, 1>::test_suite();
-    #[cfg(feature = "simd")]
+    Isometry2::<f32, 1>::test_suite();
     Isometry2::<BatchScalarF64<8>, 8>::test_suite();
     Isometry2::<DualScalar, 1>::test_suite();
-    #[cfg(feature = "simd")]
     Isometry2::<DualBatchScalar<8>, 8>::test_suite();
 
     Isometry2::<f64, 1>::run_real_tests();
-    #[cfg(feature = "simd")]
     Isometry2::<BatchScalarF64<8>, 8>::run_real_tests();
 }
@@ -1,8 +1,23 @@
 use super::rotation3::Rotation3Impl;
 use super::translation_product_product::TranslationProductGroupImpl;
 use crate::lie_group::LieGroup;
+#[cfg(feature = "alloc")]
+use crate::lie_group_error::check_homogeneous_last_row;
+#[cfg(feature = "alloc")]
+use crate::lie_group_error::normalize_unit_params;
+#[cfg(feature = "alloc")]
+use crate::lie_group_error::LieGroupError;
 use crate::prelude::*;
+#[cfg(feature = "alloc")]
+use crate::traits::IsLieGroupImpl;
 use crate::Rotation3;
+#[cfg(feature = "alloc")]
+use alloc::format;
+use sophus_core::linalg::vector::cross;
+#[cfg(feature = "alloc")]
+use sophus_core::linalg::MatF64;
+#[cfg(feature = "alloc")]
+use sophus_core::linalg::VecF64;
 
 /// 3D isometry group implementation struct - SE(3)
 pub type Isometry3Impl<S, const BATCH: usize> =
@@ -28,6 +43,73 @@ impl<S: IsScalar<BATCH>, const BATCH: usize> Isometry3<S, BATCH> {
     pub fn rotation(&self) -> Rotation3<S, BATCH> {
         self.factor()
     }
+
+    /// create camera pose looking from `eye` at `target`
+    ///
+    /// Returns the pose world_from_camera of a camera at `eye`, using the computer vision
+    /// convention: the z-axis points towards `target`, the x-axis to the right and the y-axis
+    /// down, such that `up` is projected onto the negative y-axis. `up` must not be parallel to
+    /// the viewing direction.
+    pub fn look_at(eye: &S::Vector<3>, target: &S::Vector<3>, up: &S::Vector<3>) -> Self {
+        let z = (target.clone() - eye.clone()).normalized();
+        let x = cross::<S, BATCH>(z.clone(), up.clone()).normalized();
+        let y = cross::<S, BATCH>(z.clone(), x.clone());
+        let cols = [x, y, z];
+        let mat = S::Matrix::<3, 3>::from_array2(core::array::from_fn(|r| {
+            core::array::from_fn(|c| cols[c].get_elem(r))
+        }));
+        Self::from_translation_and_rotation(eye, &Rotation3::from_rotation_matrix(&mat))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Isometry3<f64, 1> {
+    /// create isometry from a 4x4 homogeneous transformation matrix
+    ///
+    /// Returns an error if the last row is not [0, …, 0, 1] or if the upper left block is not a
+    /// rotation matrix, within 1e-6.
+    pub fn try_from_matrix(mat: &MatF64<4, 4>) -> Result<Self, LieGroupError> {
+        check_homogeneous_last_row(mat)?;
+        let rotation =
+            Rotation3::<f64, 1>::try_from_matrix(&mat.fixed_view::<3, 3>(0, 0).into_owned())
+                .map_err(|err| match err {
+                    LieGroupError::InvalidMatrix { constraint, .. } => {
+                        LieGroupError::InvalidMatrix {
+                            matrix: format!("{:?}", mat),
+                            constraint: format!("rotation block: {}", constraint),
+                        }
+                    }
+                    err => err,
+                })?;
+        Ok(Self::from_translation_and_rotation(
+            &mat.fixed_view::<3, 1>(0, 3).into_owned(),
+            &rotation,
+        ))
+    }
+
+    /// create isometry from parameters [translation; rotation], normalizing the rotation first
+    ///
+    /// Returns an error if the norm of the rotation parameters deviates from one by more than
+    /// the tolerance.
+    pub fn try_from_params_normalized(
+        params: &VecF64<7>,
+        tolerance: f64,
+    ) -> Result<Self, LieGroupError> {
+        let rotation_params = params.fixed_rows::<4>(3).into_owned();
+        normalize_unit_params(&rotation_params, tolerance)
+            .map(|rotation_params| {
+                Self::from_translation_and_rotation(
+                    &params.fixed_rows::<3>(0).into_owned(),
+                    &Rotation3::from_params(&rotation_params),
+                )
+            })
+            .map_err(|deviation| LieGroupError::NormalizationFailed {
+                params: format!("{:?}", params),
+                constraint: Isometry3Impl::<f64, 1>::params_constraint(),
+                deviation,
+                tolerance,
+            })
+    }
 }
 
 #[test]
@@ -35,19 +117,15 @@ fn isometry3_prop_tests() {
     use crate::real_lie_group::RealLieGroupTest;
     use sophus_core::calculus::dual::dual_scalar::DualScalar;
 
-    #[cfg(feature = "simd")]
     use sophus_core::calculus::dual::DualBatchScalar;
-    #[cfg(feature = "simd")]
     use sophus_core::linalg::BatchScalarF64;
 
     Isometry3::<f64, 1>::test_suite();
-    #[cfg(feature = "simd")]
+    Isometry3::<f32, 1>::test_suite();
     Isometry3::<BatchScalarF64<8>, 8>::test_suite();
     Isometry3::<DualScalar, 1>::test_suite();
-    #[cfg(feature = "simd")]
     Isometry3::<DualBatchScalar<8>, 8>::test_suite();
 
     Isometry3::<f64, 1>::run_real_tests();
-    #[cfg(feature = "simd")]
     Isometry3::<BatchScalarF64<8>, 8>::run_real_tests();
 }
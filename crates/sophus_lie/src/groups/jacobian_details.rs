@@ -0,0 +1,118 @@
+use crate::prelude::*;
+
+/// Highest power of ad(x) used to evaluate the power series of groups without closed form.
+pub(crate) const SERIES_ORDER: usize = 40;
+
+/// Highest order supported by [bernoulli_by_factorial], and hence the inverse series below.
+pub(crate) const MAX_SERIES_ORDER: usize = 64;
+
+/// Bₙ / n! for n = 0, …, MAX_SERIES_ORDER, with the Bernoulli numbers Bₙ and B₁ = -1/2
+///
+/// They are the coefficients of x / (exp(x) - 1) = Σₙ Bₙ / n! · xⁿ and follow from the
+/// recursion Σₖ₌₀ⁿ (Bₖ / k!) / (n-k+1)! = 0 for n > 0.
+pub(crate) fn bernoulli_by_factorial() -> [f64; MAX_SERIES_ORDER + 1] {
+    let mut inverse_factorials = [1.0; MAX_SERIES_ORDER + 2];
+    for n in 1..=MAX_SERIES_ORDER + 1 {
+        inverse_factorials[n] = inverse_factorials[n - 1] / n as f64;
+    }
+    let mut bernoulli_by_factorial = [0.0; MAX_SERIES_ORDER + 1];
+    bernoulli_by_factorial[0] = 1.0;
+    for n in 1..=MAX_SERIES_ORDER {
+        let mut sum = 0.0;
+        for k in 0..n {
+            sum += bernoulli_by_factorial[k] * inverse_factorials[n - k + 1];
+        }
+        bernoulli_by_factorial[n] = -sum;
+    }
+    bernoulli_by_factorial
+}
+
+/// Left Jacobian, given the algebra adjoint ad(x), using its power series up to ad(x)^order
+///
+///   jl(x) = Σₙ ad(x)ⁿ / (n+1)!
+///
+/// This is used for groups without a known closed form, and for [crate::LieGroup::dexp].
+pub(crate) fn left_jacobian_from_series<
+    S: IsScalar<BATCH>,
+    const DOF: usize,
+    const BATCH: usize,
+>(
+    ad: &S::Matrix<DOF, DOF>,
+    order: usize,
+) -> S::Matrix<DOF, DOF> {
+    let mut jl = S::Matrix::<DOF, DOF>::identity();
+    let mut term = S::Matrix::<DOF, DOF>::identity();
+    for n in 1..=order {
+        term = term
+            .mat_mul(ad.clone())
+            .scaled(S::from_f64(1.0 / (n + 1) as f64));
+        jl = jl + term.clone();
+    }
+    jl
+}
+
+/// Inverse of the left Jacobian, given the algebra adjoint ad(x), using its power series up to
+/// ad(x)^order
+///
+///   jl_inv(x) = Σₙ Bₙ / n! · ad(x)ⁿ
+///
+/// with Bₙ being the Bernoulli numbers. The series converges if the spectral radius of ad(x) is
+/// less than 2π.
+///
+/// Panics if order exceeds MAX_SERIES_ORDER.
+pub(crate) fn left_jacobian_inverse_from_series<
+    S: IsScalar<BATCH>,
+    const DOF: usize,
+    const BATCH: usize,
+>(
+    ad: &S::Matrix<DOF, DOF>,
+    order: usize,
+) -> S::Matrix<DOF, DOF> {
+    assert!(
+        order <= MAX_SERIES_ORDER,
+        "series order {} exceeds the maximum of {}",
+        order,
+        MAX_SERIES_ORDER
+    );
+    let bernoulli_by_factorial = bernoulli_by_factorial();
+
+    let mut jl_inv = S::Matrix::<DOF, DOF>::identity();
+    let mut ad_pow = S::Matrix::<DOF, DOF>::identity();
+    for coeff in bernoulli_by_factorial.iter().take(order + 1).skip(1) {
+        ad_pow = ad_pow.mat_mul(ad.clone());
+        jl_inv = jl_inv + ad_pow.scaled(S::from_f64(*coeff));
+    }
+    jl_inv
+}
+
+/// Upper right block of the left Jacobian of a semi-direct product
+///
+/// Given the algebra adjoint in block form
+///
+///   ad(x) = [[A, B],
+///            [0, C]],
+///
+/// the upper right block of ad(x)ⁿ is Pₙ = A·Pₙ₋₁ + B·Cⁿ⁻¹ with P₁ = B, and the corresponding
+/// block of the left Jacobian is Σₙ Pₙ / (n+1)!.
+pub(crate) fn left_jacobian_upper_right_block<
+    S: IsScalar<BATCH>,
+    const ROWS: usize,
+    const COLS: usize,
+    const BATCH: usize,
+>(
+    mat_a: &S::Matrix<ROWS, ROWS>,
+    mat_b: &S::Matrix<ROWS, COLS>,
+    mat_c: &S::Matrix<COLS, COLS>,
+) -> S::Matrix<ROWS, COLS> {
+    let mut mat_p = mat_b.clone();
+    let mut mat_c_pow = mat_c.clone();
+    let mut factorial = 2.0;
+    let mut block = mat_p.scaled(S::from_f64(1.0 / factorial));
+    for n in 2..=SERIES_ORDER {
+        mat_p = mat_a.mat_mul(mat_p) + mat_b.mat_mul(mat_c_pow.clone());
+        mat_c_pow = mat_c_pow.mat_mul(mat_c.clone());
+        factorial *= (n + 1) as f64;
+        block = block + mat_p.scaled(S::from_f64(1.0 / factorial));
+    }
+    block
+}
@@ -1,12 +1,32 @@
 use crate::lie_group::LieGroup;
+#[cfg(feature = "alloc")]
+use crate::lie_group_error::check_rotation_matrix;
+#[cfg(feature = "alloc")]
+use crate::lie_group_error::normalize_unit_params;
+#[cfg(feature = "alloc")]
+use crate::lie_group_error::LieGroupError;
 use crate::prelude::*;
 use crate::traits::IsLieFactorGroupImpl;
 use crate::traits::IsLieGroupImpl;
 use crate::traits::IsRealLieFactorGroupImpl;
 use crate::traits::IsRealLieGroupImpl;
+#[cfg(feature = "alloc")]
+use alloc::borrow::ToOwned;
+#[cfg(feature = "alloc")]
+use alloc::format;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+#[cfg(feature = "alloc")]
+use sophus_core::linalg::MatF64;
+#[cfg(feature = "alloc")]
+use sophus_core::linalg::VecF64;
 use sophus_core::manifold::traits::TangentImpl;
 use sophus_core::params::ParamsImpl;
-use std::marker::PhantomData;
 
 /// 2D rotation group implementation struct - SO(2)
 #[derive(Debug, Copy, Clone, Default)]
@@ -19,10 +39,11 @@ impl<S: IsScalar<BATCH_SIZE>, const BATCH_SIZE: usize> Rotation2Impl<S, BATCH_SI
 impl<S: IsScalar<BATCH_SIZE>, const BATCH_SIZE: usize> ParamsImpl<S, 2, BATCH_SIZE>
     for Rotation2Impl<S, BATCH_SIZE>
 {
+    #[cfg(feature = "alloc")]
     fn params_examples() -> Vec<S::Vector<2>> {
         let mut params = vec![];
         for i in 0..10 {
-            let angle = S::from_f64(i as f64 * std::f64::consts::PI / 5.0);
+            let angle = S::from_f64(i as f64 * core::f64::consts::PI / 5.0);
             params.push(
                 Rotation2::<S, BATCH_SIZE>::exp(&S::Vector::<1>::from_array([angle]))
                     .params()
@@ -32,6 +53,7 @@ impl<S: IsScalar<BATCH_SIZE>, const BATCH_SIZE: usize> ParamsImpl<S, 2, BATCH_SI
         params
     }
 
+    #[cfg(feature = "alloc")]
     fn invalid_params_examples() -> Vec<S::Vector<2>> {
         vec![
             S::Vector::<2>::from_array([S::from_f64(0.0), S::from_f64(0.0)]),
@@ -51,6 +73,7 @@ impl<S: IsScalar<BATCH_SIZE>, const BATCH_SIZE: usize> ParamsImpl<S, 2, BATCH_SI
 impl<S: IsScalar<BATCH_SIZE>, const BATCH_SIZE: usize> TangentImpl<S, 1, BATCH_SIZE>
     for Rotation2Impl<S, BATCH_SIZE>
 {
+    #[cfg(feature = "alloc")]
     fn tangent_examples() -> Vec<S::Vector<1>> {
         vec![
             S::Vector::<1>::from_array([S::from_f64(0.0)]),
@@ -67,6 +90,7 @@ impl<S: IsScalar<BATCH_SIZE>, const BATCH_SIZE: usize>
 {
     type GenG<S2: IsScalar<BATCH_SIZE>> = Rotation2Impl<S2, BATCH_SIZE>;
     type RealG = Rotation2Impl<S::RealScalar, BATCH_SIZE>;
+    #[cfg(feature = "alloc")]
     type DualG = Rotation2Impl<S::DualScalar, BATCH_SIZE>;
 
     const IS_ORIGIN_PRESERVING: bool = true;
@@ -76,6 +100,11 @@ impl<S: IsScalar<BATCH_SIZE>, const BATCH_SIZE: usize>
     const IS_DISTANCE_PRESERVING: bool = true;
     const IS_PARALLEL_LINE_PRESERVING: bool = true;
 
+    #[cfg(feature = "alloc")]
+    fn params_constraint() -> String {
+        "unit complex number, |z| = 1 (within 1e-6)".to_owned()
+    }
+
     fn identity_params() -> S::Vector<2> {
         S::Vector::<2>::from_array([S::ones(), S::zeros()])
     }
@@ -144,6 +173,14 @@ impl<S: IsScalar<BATCH_SIZE>, const BATCH_SIZE: usize>
     fn ad(_tangent: &S::Vector<1>) -> S::Matrix<1, 1> {
         S::Matrix::zeros()
     }
+
+    fn jl(_tangent: &S::Vector<1>) -> S::Matrix<1, 1> {
+        S::Matrix::<1, 1>::identity()
+    }
+
+    fn jl_inv(_tangent: &S::Vector<1>) -> S::Matrix<1, 1> {
+        S::Matrix::<1, 1>::identity()
+    }
 }
 
 impl<S: IsRealScalar<BATCH_SIZE>, const BATCH_SIZE: usize>
@@ -178,7 +215,7 @@ impl<S: IsRealScalar<BATCH_SIZE>, const BATCH_SIZE: usize>
     }
 
     fn has_shortest_path_ambiguity(params: &S::Vector<2>) -> S::Mask {
-        (Self::log(params).get_elem(0).abs() - S::from_f64(std::f64::consts::PI))
+        (Self::log(params).get_elem(0).abs() - S::from_f64(core::f64::consts::PI))
             .abs()
             .less_equal(&S::from_f64(1e-5))
     }
@@ -187,11 +224,42 @@ impl<S: IsRealScalar<BATCH_SIZE>, const BATCH_SIZE: usize>
 /// 2d rotation group - SO(2)
 pub type Rotation2<S, const B: usize> = LieGroup<S, 1, 2, 2, 2, B, Rotation2Impl<S, B>>;
 
+#[cfg(feature = "alloc")]
+impl Rotation2<f64, 1> {
+    /// create rotation from a 2x2 rotation matrix
+    ///
+    /// Returns an error if the matrix is not orthogonal with determinant one, within 1e-6.
+    pub fn try_from_matrix(mat: &MatF64<2, 2>) -> Result<Self, LieGroupError> {
+        check_rotation_matrix(mat, mat.determinant())?;
+        Ok(Self::from_params(
+            &VecF64::<2>::new(mat[(0, 0)], mat[(1, 0)]).normalize(),
+        ))
+    }
+
+    /// create rotation from a unit complex number, normalizing it first
+    ///
+    /// Returns an error if ||z| - 1| exceeds the tolerance.
+    pub fn try_from_params_normalized(
+        params: &VecF64<2>,
+        tolerance: f64,
+    ) -> Result<Self, LieGroupError> {
+        normalize_unit_params(params, tolerance)
+            .map(|params| Self::from_params(&params))
+            .map_err(|deviation| LieGroupError::NormalizationFailed {
+                params: format!("{:?}", params),
+                constraint: Rotation2Impl::<f64, 1>::params_constraint(),
+                deviation,
+                tolerance,
+            })
+    }
+}
+
 impl<S: IsScalar<BATCH_SIZE>, const BATCH_SIZE: usize> IsLieFactorGroupImpl<S, 1, 2, 2, BATCH_SIZE>
     for Rotation2Impl<S, BATCH_SIZE>
 {
     type GenFactorG<S2: IsScalar<BATCH_SIZE>> = Rotation2Impl<S2, BATCH_SIZE>;
     type RealFactorG = Rotation2Impl<S::RealScalar, BATCH_SIZE>;
+    #[cfg(feature = "alloc")]
     type DualFactorG = Rotation2Impl<S::DualScalar, BATCH_SIZE>;
 
     fn mat_v(v: &S::Vector<1>) -> S::Matrix<2, 2> {
@@ -300,25 +368,20 @@ fn rotation2_prop_tests() {
     use crate::real_lie_group::RealLieGroupTest;
     use sophus_core::calculus::dual::dual_scalar::DualScalar;
 
-    #[cfg(feature = "simd")]
     use sophus_core::calculus::dual::DualBatchScalar;
 
-    #[cfg(feature = "simd")]
     use sophus_core::linalg::BatchScalarF64;
 
     Rotation2::<f64, 1>::test_suite();
-    #[cfg(feature = "simd")]
+    Rotation2::<f32, 1>::test_suite();
     Rotation2::<BatchScalarF64<8>, 8>::test_suite();
 
     Rotation2::<DualScalar, 1>::test_suite();
-    #[cfg(feature = "simd")]
     Rotation2::<DualBatchScalar<8>, 8>::test_suite();
 
     Rotation2::<f64, 1>::run_real_tests();
-    #[cfg(feature = "simd")]
     Rotation2::<BatchScalarF64<8>, 8>::run_real_tests();
 
     Rotation2::<f64, 1>::run_real_factor_tests();
-    #[cfg(feature = "simd")]
     Rotation2::<BatchScalarF64<8>, 8>::run_real_factor_tests();
 }
@@ -1,12 +1,33 @@
 use crate::lie_group::LieGroup;
+#[cfg(feature = "alloc")]
+use crate::lie_group_error::check_rotation_matrix;
+#[cfg(feature = "alloc")]
+use crate::lie_group_error::normalize_unit_params;
+#[cfg(feature = "alloc")]
+use crate::lie_group_error::LieGroupError;
 use crate::prelude::*;
+use crate::traits::IsLieFactorGroupImpl;
 use crate::traits::IsLieGroupImpl;
 use crate::traits::IsRealLieFactorGroupImpl;
 use crate::traits::IsRealLieGroupImpl;
+#[cfg(feature = "alloc")]
+use alloc::borrow::ToOwned;
+#[cfg(feature = "alloc")]
+use alloc::format;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::marker::PhantomData;
 use sophus_core::linalg::vector::cross;
+#[cfg(feature = "alloc")]
+use sophus_core::linalg::MatF64;
+#[cfg(feature = "alloc")]
+use sophus_core::linalg::VecF64;
 use sophus_core::manifold::traits::TangentImpl;
 use sophus_core::params::ParamsImpl;
-use std::marker::PhantomData;
 
 /// 3d rotation implementation - SO(3)
 #[derive(Debug, Copy, Clone, Default)]
@@ -15,6 +36,7 @@ pub struct Rotation3Impl<S: IsScalar<BATCH>, const BATCH: usize> {
 }
 
 impl<S: IsScalar<BATCH>, const BATCH: usize> ParamsImpl<S, 4, BATCH> for Rotation3Impl<S, BATCH> {
+    #[cfg(feature = "alloc")]
     fn params_examples() -> Vec<S::Vector<4>> {
         vec![
             Rotation3::<S, BATCH>::exp(&S::Vector::<3>::from_f64_array([0.0, 0.0, 0.0]))
@@ -32,6 +54,7 @@ impl<S: IsScalar<BATCH>, const BATCH: usize> ParamsImpl<S, 4, BATCH> for Rotatio
         ]
     }
 
+    #[cfg(feature = "alloc")]
     fn invalid_params_examples() -> Vec<S::Vector<4>> {
         vec![
             S::Vector::<4>::from_f64_array([0.0, 0.0, 0.0, 0.0]),
@@ -49,6 +72,7 @@ impl<S: IsScalar<BATCH>, const BATCH: usize> ParamsImpl<S, 4, BATCH> for Rotatio
 }
 
 impl<S: IsScalar<BATCH>, const BATCH: usize> TangentImpl<S, 3, BATCH> for Rotation3Impl<S, BATCH> {
+    #[cfg(feature = "alloc")]
     fn tangent_examples() -> Vec<S::Vector<3>> {
         vec![
             S::Vector::<3>::from_f64_array([0.0, 0.0, 0.0]),
@@ -71,6 +95,11 @@ impl<S: IsScalar<BATCH>, const BATCH: usize> IsLieGroupImpl<S, 3, 4, 3, 3, BATCH
     const IS_DISTANCE_PRESERVING: bool = true;
     const IS_PARALLEL_LINE_PRESERVING: bool = true;
 
+    #[cfg(feature = "alloc")]
+    fn params_constraint() -> String {
+        "unit quaternion, |q| = 1 (within 1e-6)".to_owned()
+    }
+
     fn identity_params() -> S::Vector<4> {
         S::Vector::<4>::from_f64_array([1.0, 0.0, 0.0, 0.0])
     }
@@ -204,8 +233,18 @@ impl<S: IsScalar<BATCH>, const BATCH: usize> IsLieGroupImpl<S, 3, 4, 3, 3, BATCH
         Self::hat(omega)
     }
 
+    fn jl(omega: &S::Vector<3>) -> S::Matrix<3, 3> {
+        // For SO(3), the left Jacobian coincides with the V matrix of SE(3).
+        Self::mat_v(omega)
+    }
+
+    fn jl_inv(omega: &S::Vector<3>) -> S::Matrix<3, 3> {
+        Self::mat_v_inverse(omega)
+    }
+
     type GenG<S2: IsScalar<BATCH>> = Rotation3Impl<S2, BATCH>;
     type RealG = Rotation3Impl<S::RealScalar, BATCH>;
+    #[cfg(feature = "alloc")]
     type DualG = Rotation3Impl<S::DualScalar, BATCH>;
 
     fn group_mul(lhs_params: &S::Vector<4>, rhs_params: &S::Vector<4>) -> S::Vector<4> {
@@ -285,8 +324,6 @@ impl<S: IsRealScalar<BATCH>, const BATCH: usize> IsRealLieGroupImpl<S, 3, 4, 3,
 
         let dx0 = Self::dx_exp_x_at_0();
 
-        println!("dx0\n{:?}", dx0);
-
         let omega_0 = omega.get_elem(0);
         let omega_1 = omega.get_elem(1);
         let omega_2 = omega.get_elem(2);
@@ -349,7 +386,7 @@ impl<S: IsRealScalar<BATCH>, const BATCH: usize> IsRealLieGroupImpl<S, 3, 4, 3,
 
     fn has_shortest_path_ambiguity(params: &S::Vector<4>) -> S::Mask {
         let theta = Self::log(params).norm();
-        (theta - S::from_f64(std::f64::consts::PI))
+        (theta - S::from_f64(core::f64::consts::PI))
             .abs()
             .less_equal(&S::from_f64(1e-6))
     }
@@ -360,6 +397,7 @@ impl<S: IsScalar<BATCH>, const BATCH: usize> crate::traits::IsLieFactorGroupImpl
 {
     type GenFactorG<S2: IsScalar<BATCH>> = Rotation3Impl<S2, BATCH>;
     type RealFactorG = Rotation3Impl<S::RealScalar, BATCH>;
+    #[cfg(feature = "alloc")]
     type DualFactorG = Rotation3Impl<S::DualScalar, BATCH>;
 
     fn mat_v(omega: &S::Vector<3>) -> S::Matrix<3, 3> {
@@ -462,20 +500,12 @@ impl<S: IsRealScalar<BATCH>, const BATCH: usize> IsRealLieFactorGroupImpl<S, 3,
         ];
 
         let a = S::from_f64(0.5).select(&near_zero, a);
-        println!("a = {:?}", a);
-
-        println!("omega = {:?}", omega);
-        println!("b = {:?}", b);
-        println!("dt_b = {:?}", dt_b);
-
-        println!("dt_mat_omega_sq = {:?}", dt_mat_omega_sq);
 
         let set = |i| {
             let tmp0 = mat_omega.clone().scaled(dt_a * domega_theta.get_elem(i));
             let tmp1 = dt_mat_omega_sq[i].scaled(b);
             let tmp2 = mat_omega_sq.scaled(dt_b * domega_theta.get_elem(i));
 
-            println!("tmp2 = {:?}", tmp2);
             let mut l_i: S::Matrix<3, 3> =
                 S::Matrix::zeros().select(&near_zero, tmp0 + tmp1 + tmp2);
             let pos_idx = dt_mat_omega_pos_idx[i];
@@ -599,28 +629,599 @@ impl<S: IsRealScalar<BATCH>, const BATCH: usize> IsRealLieFactorGroupImpl<S, 3,
 /// 3d rotation group - SO(3)
 pub type Rotation3<S, const BATCH: usize> = LieGroup<S, 3, 4, 3, 3, BATCH, Rotation3Impl<S, BATCH>>;
 
+/// Rotation axis of an elementary rotation in an Euler angle sequence
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum EulerAxis {
+    /// x-axis
+    X,
+    /// y-axis
+    Y,
+    /// z-axis
+    Z,
+}
+
+impl EulerAxis {
+    fn index(self) -> usize {
+        match self {
+            EulerAxis::X => 0,
+            EulerAxis::Y => 1,
+            EulerAxis::Z => 2,
+        }
+    }
+}
+
+impl<S: IsScalar<BATCH>, const BATCH: usize> Rotation3<S, BATCH> {
+    /// rotation around the x-axis
+    pub fn rot_x(angle: S) -> Self {
+        Self::from_elementary_rotation(0, angle)
+    }
+
+    /// rotation around the y-axis
+    pub fn rot_y(angle: S) -> Self {
+        Self::from_elementary_rotation(1, angle)
+    }
+
+    /// rotation around the z-axis
+    pub fn rot_z(angle: S) -> Self {
+        Self::from_elementary_rotation(2, angle)
+    }
+
+    fn from_elementary_rotation(axis_idx: usize, angle: S) -> Self {
+        let half_angle = angle * S::from_f64(0.5);
+        let sin = half_angle.clone().sin();
+        Self::from_params(&S::Vector::from_array(core::array::from_fn(|i| {
+            if i == 0 {
+                half_angle.clone().cos()
+            } else if i == 1 + axis_idx {
+                sin.clone()
+            } else {
+                S::zeros()
+            }
+        })))
+    }
+
+    /// create rotation from axis and angle
+    ///
+    /// The axis does not need to be normalized, but must not be zero.
+    pub fn from_axis_angle(axis: &S::Vector<3>, angle: S) -> Self {
+        let half_angle = angle * S::from_f64(0.5);
+        Self::from_params(&S::Vector::block_vec2(
+            half_angle.clone().cos().to_vec(),
+            axis.normalized().scaled(half_angle.sin()),
+        ))
+    }
+
+    /// axis and angle of the rotation
+    ///
+    /// The angle is in [0, π]. For the identity, the axis is arbitrarily chosen to be the x-axis.
+    pub fn axis_angle(&self) -> (S::Vector<3>, S) {
+        let omega = self.log();
+        let angle = omega.norm();
+        let near_zero = angle.less_equal(&S::from_f64(1e-10));
+        let axis = S::Vector::<3>::from_f64_array([1.0, 0.0, 0.0])
+            .select(&near_zero, omega.scaled(S::from_f64(1.0) / angle.clone()));
+        (axis, angle)
+    }
+
+    /// create rotation from Euler angles
+    ///
+    /// The sequence is applied intrinsically, i.e. about the axes of the rotated frame:
+    ///
+    ///   R = R_{sequence[0]}(angles[0]) · R_{sequence[1]}(angles[1]) · R_{sequence[2]}(angles[2])
+    ///
+    /// The extrinsic sequence (about the fixed axes) with reversed order yields the same
+    /// rotation. All twelve sequences with distinct consecutive axes are supported, both
+    /// Tait-Bryan (e.g. ZYX) and proper Euler sequences (e.g. ZYZ).
+    pub fn from_euler_angles(angles: &S::Vector<3>, sequence: [EulerAxis; 3]) -> Self {
+        assert_valid_euler_sequence(sequence);
+        Self::from_elementary_rotation(sequence[0].index(), angles.get_elem(0))
+            .group_mul(&Self::from_elementary_rotation(
+                sequence[1].index(),
+                angles.get_elem(1),
+            ))
+            .group_mul(&Self::from_elementary_rotation(
+                sequence[2].index(),
+                angles.get_elem(2),
+            ))
+    }
+
+    /// Euler angles of the rotation, see [Self::from_euler_angles] for the convention
+    ///
+    /// The middle angle is in [-π/2, π/2] for Tait-Bryan and in [0, π] for proper Euler
+    /// sequences, the other two angles are in [-π, π]. At the singularity (gimbal lock), the
+    /// third angle is set to zero.
+    pub fn euler_angles(&self, sequence: [EulerAxis; 3]) -> S::Vector<3> {
+        assert_valid_euler_sequence(sequence);
+        const EPS: f64 = 1e-10;
+
+        let i = sequence[0].index();
+        let j = sequence[1].index();
+        let is_proper = sequence[0] == sequence[2];
+        let k = if is_proper {
+            3 - i - j
+        } else {
+            sequence[2].index()
+        };
+        // +1 for cyclic sequences (xyz, yzx, zxy), -1 otherwise
+        let s = S::from_f64(if (i + 1) % 3 == j { 1.0 } else { -1.0 });
+
+        let mat = self.matrix();
+        let m = |r: usize, c: usize| mat.get_elem([r, c]);
+
+        let (second, sin_second_abs) = if is_proper {
+            let sin_abs = (m(i, j) * m(i, j) + m(i, k) * m(i, k)).sqrt();
+            (sin_abs.clone().atan2(m(i, i)), sin_abs)
+        } else {
+            let cos_abs = (m(i, i) * m(i, i) + m(i, j) * m(i, j)).sqrt();
+            ((s.clone() * m(i, k)).atan2(cos_abs.clone()), cos_abs)
+        };
+
+        let (first, third) = if is_proper {
+            (
+                m(j, i).atan2(-s.clone() * m(k, i)),
+                m(i, j).atan2(s.clone() * m(i, k)),
+            )
+        } else {
+            (
+                (-s.clone() * m(j, k)).atan2(m(k, k)),
+                (-s.clone() * m(i, j)).atan2(m(i, i)),
+            )
+        };
+
+        let is_singular = sin_second_abs.less_equal(&S::from_f64(EPS));
+        let first = (s * m(k, j)).atan2(m(j, j)).select(&is_singular, first);
+        let third = S::zeros().select(&is_singular, third);
+
+        S::Vector::from_array([first, second, third])
+    }
+
+    /// create rotation from roll, pitch and yaw
+    ///
+    /// R = R_z(yaw) · R_y(pitch) · R_x(roll), i.e. the intrinsic ZYX sequence.
+    pub fn from_rpy(roll: S, pitch: S, yaw: S) -> Self {
+        Self::from_euler_angles(
+            &S::Vector::from_array([yaw, pitch, roll]),
+            [EulerAxis::Z, EulerAxis::Y, EulerAxis::X],
+        )
+    }
+
+    /// roll, pitch and yaw of the rotation, see [Self::from_rpy]
+    pub fn rpy(&self) -> S::Vector<3> {
+        let ypr = self.euler_angles([EulerAxis::Z, EulerAxis::Y, EulerAxis::X]);
+        S::Vector::from_array([ypr.get_elem(2), ypr.get_elem(1), ypr.get_elem(0)])
+    }
+
+    /// create rotation from a 3x3 matrix
+    ///
+    /// The input does not need to be exactly orthogonal: it is projected onto the closest
+    /// rotation matrix in the Frobenius norm sense, which is the orthogonal factor of its polar
+    /// decomposition (equivalently U·Vᵀ of its SVD). The input must have a positive
+    /// determinant.
+    pub fn from_rotation_matrix(mat: &S::Matrix<3, 3>) -> Self {
+        Self::from_orthonormal_matrix(&polar_orthogonal_factor::<S, BATCH>(mat))
+    }
+
+    /// create minimal rotation which maps the direction of `a` onto the direction of `b`
+    ///
+    /// Neither `a` nor `b` need to be normalized, but both must be non-zero. If `a` and `b` point
+    /// in opposite directions, the rotation by π about an axis orthogonal to `a` is returned.
+    pub fn from_two_vectors(a: &S::Vector<3>, b: &S::Vector<3>) -> Self {
+        const EPS: f64 = 1e-10;
+
+        let a = a.normalized();
+        let b = b.normalized();
+        let one_plus_cos = S::from_f64(1.0) + a.clone().dot(b.clone());
+
+        // q ∝ (1 + a·b, a×b) is the half-way quaternion
+        let regular = S::Vector::block_vec2(
+            one_plus_cos.clone().to_vec(),
+            cross::<S, BATCH>(a.clone(), b),
+        );
+
+        let axis_from_x = cross::<S, BATCH>(a.clone(), S::Vector::from_f64_array([1.0, 0.0, 0.0]));
+        let axis_from_y = cross::<S, BATCH>(a, S::Vector::from_f64_array([0.0, 1.0, 0.0]));
+        let use_x = axis_from_x
+            .squared_norm()
+            .greater_equal(&axis_from_y.squared_norm());
+        let orthogonal_axis = axis_from_x.select(&use_x, axis_from_y);
+        let antiparallel = S::Vector::block_vec2(S::zeros().to_vec(), orthogonal_axis);
+
+        let is_antiparallel = one_plus_cos.less_equal(&S::from_f64(EPS));
+        Self::from_params(&antiparallel.select(&is_antiparallel, regular).normalized())
+    }
+
+    // Shepperd's method, using the numerically most stable of the four quaternion candidates.
+    fn from_orthonormal_matrix(mat: &S::Matrix<3, 3>) -> Self {
+        let m = |r: usize, c: usize| mat.get_elem([r, c]);
+        let quarter = S::from_f64(0.25);
+        let safe_sqrt = |x: S| {
+            let is_tiny = x.less_equal(&S::from_f64(1e-12));
+            S::from_f64(1e-12).select(&is_tiny, x).sqrt()
+        };
+
+        let trace = m(0, 0) + m(1, 1) + m(2, 2);
+        let diagonal = [trace, m(0, 0), m(1, 1), m(2, 2)];
+        let one = S::from_f64(1.0);
+
+        let candidates: [S::Vector<4>; 4] = [
+            {
+                let w = safe_sqrt(one.clone() + m(0, 0) + m(1, 1) + m(2, 2)) * S::from_f64(0.5);
+                let f = quarter.clone() / w.clone();
+                S::Vector::from_array([
+                    w,
+                    (m(2, 1) - m(1, 2)) * f.clone(),
+                    (m(0, 2) - m(2, 0)) * f.clone(),
+                    (m(1, 0) - m(0, 1)) * f,
+                ])
+            },
+            {
+                let x = safe_sqrt(one.clone() + m(0, 0) - m(1, 1) - m(2, 2)) * S::from_f64(0.5);
+                let f = quarter.clone() / x.clone();
+                S::Vector::from_array([
+                    (m(2, 1) - m(1, 2)) * f.clone(),
+                    x,
+                    (m(0, 1) + m(1, 0)) * f.clone(),
+                    (m(0, 2) + m(2, 0)) * f,
+                ])
+            },
+            {
+                let y = safe_sqrt(one.clone() - m(0, 0) + m(1, 1) - m(2, 2)) * S::from_f64(0.5);
+                let f = quarter.clone() / y.clone();
+                S::Vector::from_array([
+                    (m(0, 2) - m(2, 0)) * f.clone(),
+                    (m(0, 1) + m(1, 0)) * f.clone(),
+                    y,
+                    (m(1, 2) + m(2, 1)) * f,
+                ])
+            },
+            {
+                let z = safe_sqrt(one - m(0, 0) - m(1, 1) + m(2, 2)) * S::from_f64(0.5);
+                let f = quarter / z.clone();
+                S::Vector::from_array([
+                    (m(1, 0) - m(0, 1)) * f.clone(),
+                    (m(0, 2) + m(2, 0)) * f.clone(),
+                    (m(1, 2) + m(2, 1)) * f,
+                    z,
+                ])
+            },
+        ];
+
+        let [c0, c1, c2, c3] = candidates;
+        let [d0, d1, d2, d3] = diagonal;
+        let mut best = c0;
+        let mut best_diagonal = d0;
+        for (candidate, d) in [(c1, d1), (c2, d2), (c3, d3)] {
+            let is_better = d.greater_equal(&best_diagonal);
+            best = candidate.select(&is_better, best);
+            best_diagonal = d.select(&is_better, best_diagonal);
+        }
+        Self::from_params(&best.normalized())
+    }
+}
+
+fn assert_valid_euler_sequence(sequence: [EulerAxis; 3]) {
+    assert!(
+        sequence[0] != sequence[1] && sequence[1] != sequence[2],
+        "consecutive axes of an Euler sequence must differ: {:?}",
+        sequence
+    );
+}
+
+// Orthogonal factor of the polar decomposition, using the scaled Newton iteration
+//
+//   X ← ½·(γ·X + X⁻ᵀ/γ),  γ = (‖X⁻¹‖_F / ‖X‖_F)^½.
+fn polar_orthogonal_factor<S: IsScalar<BATCH>, const BATCH: usize>(
+    mat: &S::Matrix<3, 3>,
+) -> S::Matrix<3, 3> {
+    const MAX_ITERATIONS: usize = 20;
+    const EPS: f64 = 1e-12;
+
+    let mut cols = [mat.get_col_vec(0), mat.get_col_vec(1), mat.get_col_vec(2)];
+    for _ in 0..MAX_ITERATIONS {
+        // X⁻ᵀ = cofactor(X) / det(X)
+        let cofactor = [
+            cross::<S, BATCH>(cols[1].clone(), cols[2].clone()),
+            cross::<S, BATCH>(cols[2].clone(), cols[0].clone()),
+            cross::<S, BATCH>(cols[0].clone(), cols[1].clone()),
+        ];
+        let det = cols[0].clone().dot(cofactor[0].clone());
+
+        let squared_frobenius =
+            |c: &[S::Vector<3>; 3]| c[0].squared_norm() + c[1].squared_norm() + c[2].squared_norm();
+        let gamma = (squared_frobenius(&cofactor)
+            / (det.clone() * det.clone() * squared_frobenius(&cols)))
+        .sqrt()
+        .sqrt();
+
+        let a = gamma.clone() * S::from_f64(0.5);
+        let b = S::from_f64(0.5) / (gamma * det);
+        let next: [S::Vector<3>; 3] =
+            core::array::from_fn(|i| cols[i].scaled(a.clone()) + cofactor[i].scaled(b.clone()));
+
+        let change = (next[0].clone() - cols[0].clone()).squared_norm()
+            + (next[1].clone() - cols[1].clone()).squared_norm()
+            + (next[2].clone() - cols[2].clone()).squared_norm();
+        cols = next;
+        if change.less_equal(&S::from_f64(EPS * EPS)).all() {
+            break;
+        }
+    }
+
+    S::Matrix::from_array2(core::array::from_fn(|r| {
+        core::array::from_fn(|c| cols[c].get_elem(r))
+    }))
+}
+
+#[cfg(feature = "alloc")]
+impl Rotation3<f64, 1> {
+    /// create rotation from a 3x3 rotation matrix
+    ///
+    /// Returns an error if the matrix is not orthogonal with determinant one, within 1e-6.
+    /// Use [Rotation3::from_rotation_matrix] to project an arbitrary matrix onto SO(3) instead.
+    pub fn try_from_matrix(mat: &MatF64<3, 3>) -> Result<Self, LieGroupError> {
+        check_rotation_matrix(mat, mat.determinant())?;
+        Ok(Self::from_rotation_matrix(mat))
+    }
+
+    /// create rotation from a unit quaternion [w, x, y, z], normalizing it first
+    ///
+    /// Returns an error if ||q| - 1| exceeds the tolerance.
+    pub fn try_from_params_normalized(
+        params: &VecF64<4>,
+        tolerance: f64,
+    ) -> Result<Self, LieGroupError> {
+        normalize_unit_params(params, tolerance)
+            .map(|params| Self::from_params(&params))
+            .map_err(|deviation| LieGroupError::NormalizationFailed {
+                params: format!("{:?}", params),
+                constraint: Rotation3Impl::<f64, 1>::params_constraint(),
+                deviation,
+                tolerance,
+            })
+    }
+}
+
 #[test]
 fn rotation3_prop_tests() {
     use crate::factor_lie_group::RealFactorLieGroupTest;
     use crate::real_lie_group::RealLieGroupTest;
     use sophus_core::calculus::dual::dual_scalar::DualScalar;
-    #[cfg(feature = "simd")]
     use sophus_core::calculus::dual::DualBatchScalar;
-    #[cfg(feature = "simd")]
     use sophus_core::linalg::BatchScalarF64;
 
     Rotation3::<f64, 1>::test_suite();
-    #[cfg(feature = "simd")]
+    Rotation3::<f32, 1>::test_suite();
     Rotation3::<BatchScalarF64<8>, 8>::test_suite();
     Rotation3::<DualScalar, 1>::test_suite();
-    #[cfg(feature = "simd")]
     Rotation3::<DualBatchScalar<8>, 8>::test_suite();
 
     Rotation3::<f64, 1>::run_real_tests();
-    #[cfg(feature = "simd")]
     Rotation3::<BatchScalarF64<8>, 8>::run_real_tests();
 
     Rotation3::<f64, 1>::run_real_factor_tests();
-    #[cfg(feature = "simd")]
     Rotation3::<BatchScalarF64<8>, 8>::run_real_factor_tests();
 }
+
+#[test]
+fn rotation3_constructor_tests() {
+    use crate::Isometry3;
+    use approx::assert_relative_eq;
+    use core::f64::consts::FRAC_PI_2;
+    use sophus_core::calculus::dual::dual_scalar::DualScalar;
+    use sophus_core::calculus::maps::VectorValuedMapFromVector;
+    #[cfg(feature = "alloc")]
+    use sophus_core::linalg::MatF64;
+    #[cfg(feature = "alloc")]
+    use sophus_core::linalg::VecF64;
+
+    // elementary rotations and axis-angle
+    let angle = 0.7;
+    let (c, s) = (angle.cos(), angle.sin());
+    assert_relative_eq!(
+        Rotation3::<f64, 1>::rot_x(angle).matrix(),
+        MatF64::<3, 3>::new(1.0, 0.0, 0.0, 0.0, c, -s, 0.0, s, c),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        Rotation3::<f64, 1>::rot_y(angle).matrix(),
+        MatF64::<3, 3>::new(c, 0.0, s, 0.0, 1.0, 0.0, -s, 0.0, c),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        Rotation3::<f64, 1>::rot_z(angle).matrix(),
+        MatF64::<3, 3>::new(c, -s, 0.0, s, c, 0.0, 0.0, 0.0, 1.0),
+        epsilon = 1e-12
+    );
+    let axis = VecF64::<3>::new(1.0, -2.0, 0.5);
+    let rotation = Rotation3::<f64, 1>::from_axis_angle(&axis, 2.0);
+    assert_relative_eq!(rotation.log(), axis.normalize() * 2.0, epsilon = 1e-12);
+    let (axis_out, angle_out) = rotation.axis_angle();
+    assert_relative_eq!(axis_out, axis.normalize(), epsilon = 1e-12);
+    assert_relative_eq!(angle_out, 2.0, epsilon = 1e-12);
+
+    // Euler angles
+    use EulerAxis::*;
+    let sequences = [
+        [X, Y, Z],
+        [X, Z, Y],
+        [Y, X, Z],
+        [Y, Z, X],
+        [Z, X, Y],
+        [Z, Y, X],
+        [X, Y, X],
+        [X, Z, X],
+        [Y, X, Y],
+        [Y, Z, Y],
+        [Z, X, Z],
+        [Z, Y, Z],
+    ];
+    let elementary = |axis: EulerAxis, angle: f64| match axis {
+        X => Rotation3::<f64, 1>::rot_x(angle),
+        Y => Rotation3::<f64, 1>::rot_y(angle),
+        Z => Rotation3::<f64, 1>::rot_z(angle),
+    };
+    for sequence in sequences {
+        let is_proper = sequence[0] == sequence[2];
+        for angles in [
+            VecF64::<3>::new(0.1, 0.2, 0.3),
+            VecF64::<3>::new(-2.5, 1.2, 3.0),
+            VecF64::<3>::new(1.5, 0.8, -0.4),
+        ] {
+            let rotation = Rotation3::<f64, 1>::from_euler_angles(&angles, sequence);
+            let expected = elementary(sequence[0], angles[0])
+                .group_mul(&elementary(sequence[1], angles[1]))
+                .group_mul(&elementary(sequence[2], angles[2]));
+            assert_relative_eq!(rotation.matrix(), expected.matrix(), epsilon = 1e-12);
+            assert_relative_eq!(rotation.euler_angles(sequence), angles, epsilon = 1e-10);
+        }
+
+        // gimbal lock
+        let middle = if is_proper { 0.0 } else { FRAC_PI_2 };
+        let rotation =
+            Rotation3::<f64, 1>::from_euler_angles(&VecF64::<3>::new(0.3, middle, 0.4), sequence);
+        let angles = rotation.euler_angles(sequence);
+        assert_eq!(angles[2], 0.0);
+        assert_relative_eq!(
+            Rotation3::<f64, 1>::from_euler_angles(&angles, sequence).matrix(),
+            rotation.matrix(),
+            epsilon = 1e-10
+        );
+    }
+
+    // roll-pitch-yaw
+    let (roll, pitch, yaw) = (0.1, -0.4, 2.0);
+    let rotation = Rotation3::<f64, 1>::from_rpy(roll, pitch, yaw);
+    let expected = Rotation3::rot_z(yaw)
+        .group_mul(&Rotation3::rot_y(pitch))
+        .group_mul(&Rotation3::rot_x(roll));
+    assert_relative_eq!(rotation.matrix(), expected.matrix(), epsilon = 1e-12);
+    assert_relative_eq!(
+        rotation.rpy(),
+        VecF64::<3>::new(roll, pitch, yaw),
+        epsilon = 1e-12
+    );
+
+    // projection of noisy matrices onto SO(3)
+    for omega in Rotation3Impl::<f64, 1>::tangent_examples() {
+        let rotation = Rotation3::<f64, 1>::exp(&omega);
+        let noisy = rotation.matrix()
+            + MatF64::<3, 3>::new(0.01, -0.02, 0.0, 0.03, 0.01, 0.02, -0.01, 0.0, 0.02);
+        let svd = noisy.svd(true, true);
+        let closest = svd.u.unwrap() * svd.v_t.unwrap();
+        assert_relative_eq!(
+            Rotation3::<f64, 1>::from_rotation_matrix(&noisy).matrix(),
+            closest,
+            epsilon = 1e-10
+        );
+        assert_relative_eq!(
+            Rotation3::<f64, 1>::from_rotation_matrix(&rotation.matrix()).matrix(),
+            rotation.matrix(),
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            Rotation3::<f64, 1>::from_rotation_matrix(&(rotation.matrix() * 3.0)).matrix(),
+            rotation.matrix(),
+            epsilon = 1e-12
+        );
+    }
+
+    // alignment of two vectors
+    let a = VecF64::<3>::new(1.0, 2.0, 3.0);
+    for b in [
+        VecF64::<3>::new(-1.0, 0.5, 0.2),
+        a * 2.0,
+        -a,
+        VecF64::<3>::new(-2.0, 0.0, 0.0),
+    ] {
+        for a in [a, VecF64::<3>::new(1.0, 0.0, 0.0)] {
+            let rotation = Rotation3::<f64, 1>::from_two_vectors(&a, &b);
+            assert_relative_eq!(
+                rotation.transform(&a.normalize()),
+                b.normalize(),
+                epsilon = 1e-10
+            );
+        }
+    }
+    // the rotation is minimal, i.e. about the axis a×b
+    let b = VecF64::<3>::new(-1.0, 0.5, 0.2);
+    let (axis, _) = Rotation3::<f64, 1>::from_two_vectors(&a, &b).axis_angle();
+    assert_relative_eq!(axis, a.cross(&b).normalize(), epsilon = 1e-10);
+
+    // look-at
+    let eye = VecF64::<3>::new(1.0, 2.0, 3.0);
+    let target = VecF64::<3>::new(-1.0, 4.0, 0.0);
+    let up = VecF64::<3>::new(0.0, 0.0, 1.0);
+    let world_from_camera = Isometry3::<f64, 1>::look_at(&eye, &target, &up);
+    let target_in_camera = world_from_camera.inverse().transform(&target);
+    assert_relative_eq!(
+        target_in_camera,
+        VecF64::<3>::new(0.0, 0.0, (target - eye).norm()),
+        epsilon = 1e-10
+    );
+    let up_in_camera = world_from_camera.rotation().inverse().transform(&up);
+    assert_relative_eq!(up_in_camera[0], 0.0, epsilon = 1e-10);
+    assert!(up_in_camera[1] < 0.0);
+
+    // all constructors are differentiable
+    let check_jacobian = |f: &dyn Fn(&VecF64<3>) -> VecF64<4>,
+                          df: &dyn Fn(
+        &<DualScalar as IsScalar<1>>::Vector<3>,
+    ) -> <DualScalar as IsScalar<1>>::Vector<4>,
+                          x: VecF64<3>| {
+        let numeric =
+            VectorValuedMapFromVector::<f64, 1>::static_sym_diff_quotient(|x| f(&x), x, 1e-6);
+        let auto = VectorValuedMapFromVector::<DualScalar, 1>::static_fw_autodiff(|x| df(&x), x);
+        assert_relative_eq!(numeric, auto, epsilon = 1e-5);
+    };
+    let x = VecF64::<3>::new(0.3, -0.2, 0.9);
+    check_jacobian(
+        &|x| *Rotation3::<f64, 1>::from_euler_angles(x, [Z, Y, X]).params(),
+        &|x| {
+            Rotation3::<DualScalar, 1>::from_euler_angles(x, [Z, Y, X])
+                .params()
+                .clone()
+        },
+        x,
+    );
+    check_jacobian(
+        &|x| *Rotation3::<f64, 1>::from_two_vectors(&a, x).params(),
+        &|x| {
+            Rotation3::<DualScalar, 1>::from_two_vectors(
+                &<DualScalar as IsScalar<1>>::Vector::<3>::from_real_vector(a),
+                x,
+            )
+            .params()
+            .clone()
+        },
+        x,
+    );
+    let noise = MatF64::<3, 3>::new(0.01, -0.02, 0.0, 0.03, 0.01, 0.02, -0.01, 0.0, 0.02);
+    check_jacobian(
+        &|x| {
+            *Rotation3::<f64, 1>::from_rotation_matrix(
+                &(Rotation3::<f64, 1>::exp(x).matrix() + noise),
+            )
+            .params()
+        },
+        &|x| {
+            Rotation3::<DualScalar, 1>::from_rotation_matrix(
+                &(Rotation3::<DualScalar, 1>::exp(x).matrix()
+                    + <DualScalar as IsScalar<1>>::Matrix::<3, 3>::from_real_matrix(noise)),
+            )
+            .params()
+            .clone()
+        },
+        x,
+    );
+    check_jacobian(
+        &|x| {
+            let rpy = Rotation3::<f64, 1>::exp(x).rpy();
+            VecF64::<4>::new(rpy[0], rpy[1], rpy[2], 0.0)
+        },
+        &|x| {
+            let rpy = Rotation3::<DualScalar, 1>::exp(x).rpy();
+            <DualScalar as IsScalar<1>>::Vector::<4>::block_vec2(rpy, DualScalar::zeros().to_vec())
+        },
+        x,
+    );
+}
@@ -0,0 +1,338 @@
+use crate::groups::rotation2::Rotation2Impl;
+use crate::groups::sim_details::MatVCoefficients;
+use crate::lie_group::LieGroup;
+use crate::prelude::*;
+use crate::traits::IsLieFactorGroupImpl;
+use crate::traits::IsLieGroupImpl;
+use crate::traits::IsRealLieFactorGroupImpl;
+use crate::traits::IsRealLieGroupImpl;
+use crate::Rotation2;
+#[cfg(feature = "alloc")]
+use alloc::borrow::ToOwned;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use sophus_core::manifold::traits::TangentImpl;
+use sophus_core::params::ParamsImpl;
+
+/// 2D scaled rotation group implementation struct - R+ x SO(2)
+///
+/// The group is parameterized by a non-zero complex number z. Its modulus |z| is the scale
+/// factor and its argument is the rotation angle. The tangent vector is (θ, σ) with θ being the
+/// rotation angle and σ the logarithm of the scale factor.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct RxSO2Impl<S: IsScalar<BATCH_SIZE>, const BATCH_SIZE: usize> {
+    phanton: PhantomData<S>,
+}
+
+impl<S: IsScalar<BATCH_SIZE>, const BATCH_SIZE: usize> ParamsImpl<S, 2, BATCH_SIZE>
+    for RxSO2Impl<S, BATCH_SIZE>
+{
+    #[cfg(feature = "alloc")]
+    fn params_examples() -> Vec<S::Vector<2>> {
+        let mut params = vec![];
+        for (i, log_scale) in [0.0, 0.5, -0.3, 1.0, -0.8, 0.0].iter().enumerate() {
+            let angle = i as f64 * core::f64::consts::PI / 4.0 - 0.5;
+            params.push(
+                RxSO2::<S, BATCH_SIZE>::exp(&S::Vector::<2>::from_f64_array([angle, *log_scale]))
+                    .params()
+                    .clone(),
+            );
+        }
+        params
+    }
+
+    #[cfg(feature = "alloc")]
+    fn invalid_params_examples() -> Vec<S::Vector<2>> {
+        vec![
+            S::Vector::<2>::from_array([S::from_f64(0.0), S::from_f64(0.0)]),
+            S::Vector::<2>::from_array([S::from_f64(1e-7), S::from_f64(0.0)]),
+        ]
+    }
+
+    fn are_params_valid(params: &S::Vector<2>) -> S::Mask {
+        params.squared_norm().greater_equal(&S::from_f64(1e-6))
+    }
+}
+
+impl<S: IsScalar<BATCH_SIZE>, const BATCH_SIZE: usize> TangentImpl<S, 2, BATCH_SIZE>
+    for RxSO2Impl<S, BATCH_SIZE>
+{
+    #[cfg(feature = "alloc")]
+    fn tangent_examples() -> Vec<S::Vector<2>> {
+        vec![
+            S::Vector::<2>::from_f64_array([0.0, 0.0]),
+            S::Vector::<2>::from_f64_array([1.0, 0.0]),
+            S::Vector::<2>::from_f64_array([0.0, 0.7]),
+            S::Vector::<2>::from_f64_array([-1.0, 0.5]),
+            S::Vector::<2>::from_f64_array([0.5, -0.3]),
+        ]
+    }
+}
+
+impl<S: IsScalar<BATCH_SIZE>, const BATCH_SIZE: usize> IsLieGroupImpl<S, 2, 2, 2, 2, BATCH_SIZE>
+    for RxSO2Impl<S, BATCH_SIZE>
+{
+    type GenG<S2: IsScalar<BATCH_SIZE>> = RxSO2Impl<S2, BATCH_SIZE>;
+    type RealG = RxSO2Impl<S::RealScalar, BATCH_SIZE>;
+    #[cfg(feature = "alloc")]
+    type DualG = RxSO2Impl<S::DualScalar, BATCH_SIZE>;
+
+    const IS_ORIGIN_PRESERVING: bool = true;
+    const IS_AXIS_DIRECTION_PRESERVING: bool = false;
+    const IS_DIRECTION_VECTOR_PRESERVING: bool = false;
+    const IS_SHAPE_PRESERVING: bool = true;
+    const IS_DISTANCE_PRESERVING: bool = false;
+    const IS_PARALLEL_LINE_PRESERVING: bool = true;
+
+    #[cfg(feature = "alloc")]
+    fn params_constraint() -> String {
+        "non-zero complex number, |z|² ≥ 1e-6".to_owned()
+    }
+
+    fn identity_params() -> S::Vector<2> {
+        S::Vector::<2>::from_array([S::ones(), S::zeros()])
+    }
+
+    fn adj(_params: &S::Vector<2>) -> S::Matrix<2, 2> {
+        S::Matrix::<2, 2>::identity()
+    }
+
+    fn exp(omega: &S::Vector<2>) -> S::Vector<2> {
+        // angle and log-scale to complex number
+        let angle = omega.get_elem(0);
+        let scale = omega.get_elem(1).exp();
+        S::Vector::<2>::from_array([scale.clone() * angle.clone().cos(), scale * angle.sin()])
+    }
+
+    fn log(params: &S::Vector<2>) -> S::Vector<2> {
+        // complex number to angle and log-scale
+        let angle = params.get_elem(1).atan2(params.get_elem(0));
+        let log_scale = S::from_f64(0.5) * params.squared_norm().ln();
+        S::Vector::<2>::from_array([angle, log_scale])
+    }
+
+    fn hat(omega: &S::Vector<2>) -> S::Matrix<2, 2> {
+        let angle = omega.get_elem(0);
+        let log_scale = omega.get_elem(1);
+        S::Matrix::<2, 2>::from_array2([[log_scale.clone(), -angle.clone()], [angle, log_scale]])
+    }
+
+    fn vee(hat: &S::Matrix<2, 2>) -> S::Vector<2> {
+        S::Vector::<2>::from_array([hat.get_elem([1, 0]), hat.get_elem([0, 0])])
+    }
+
+    fn group_mul(params1: &S::Vector<2>, params2: &S::Vector<2>) -> S::Vector<2> {
+        Rotation2Impl::<S, BATCH_SIZE>::matrix(params1) * params2.clone()
+    }
+
+    fn inverse(params: &S::Vector<2>) -> S::Vector<2> {
+        let squared_norm = params.squared_norm();
+        S::Vector::<2>::from_array([
+            params.get_elem(0) / squared_norm.clone(),
+            -params.get_elem(1) / squared_norm,
+        ])
+    }
+
+    fn transform(params: &S::Vector<2>, point: &S::Vector<2>) -> S::Vector<2> {
+        Self::matrix(params) * point.clone()
+    }
+
+    fn to_ambient(params: &S::Vector<2>) -> S::Vector<2> {
+        params.clone()
+    }
+
+    fn compact(params: &S::Vector<2>) -> S::Matrix<2, 2> {
+        Self::matrix(params)
+    }
+
+    fn matrix(params: &S::Vector<2>) -> S::Matrix<2, 2> {
+        // scaled rotation matrix
+        Rotation2Impl::<S, BATCH_SIZE>::matrix(params)
+    }
+
+    fn ad(_tangent: &S::Vector<2>) -> S::Matrix<2, 2> {
+        S::Matrix::zeros()
+    }
+
+    fn jl(_tangent: &S::Vector<2>) -> S::Matrix<2, 2> {
+        S::Matrix::<2, 2>::identity()
+    }
+
+    fn jl_inv(_tangent: &S::Vector<2>) -> S::Matrix<2, 2> {
+        S::Matrix::<2, 2>::identity()
+    }
+}
+
+impl<S: IsRealScalar<BATCH_SIZE>, const BATCH_SIZE: usize>
+    IsRealLieGroupImpl<S, 2, 2, 2, 2, BATCH_SIZE> for RxSO2Impl<S, BATCH_SIZE>
+{
+    fn dx_exp_x_at_0() -> S::Matrix<2, 2> {
+        S::Matrix::from_f64_array2([[0.0, 1.0], [1.0, 0.0]])
+    }
+
+    fn dx_exp_x_times_point_at_0(point: S::Vector<2>) -> S::Matrix<2, 2> {
+        S::Matrix::from_array2([
+            [-point.get_elem(1), point.get_elem(0)],
+            [point.get_elem(0), point.get_elem(1)],
+        ])
+    }
+
+    fn dx_exp(tangent: &S::Vector<2>) -> S::Matrix<2, 2> {
+        let z = Self::exp(tangent);
+        S::Matrix::<2, 2>::from_array2([
+            [-z.get_elem(1), z.get_elem(0)],
+            [z.get_elem(0), z.get_elem(1)],
+        ])
+    }
+
+    fn dx_log_x(params: &S::Vector<2>) -> S::Matrix<2, 2> {
+        let x_0 = params.get_elem(0);
+        let x_1 = params.get_elem(1);
+        let x_sq = x_0 * x_0 + x_1 * x_1;
+        S::Matrix::from_array2([[-x_1 / x_sq, x_0 / x_sq], [x_0 / x_sq, x_1 / x_sq]])
+    }
+
+    fn da_a_mul_b(_a: &S::Vector<2>, b: &S::Vector<2>) -> S::Matrix<2, 2> {
+        Self::matrix(b)
+    }
+
+    fn db_a_mul_b(a: &S::Vector<2>, _b: &S::Vector<2>) -> S::Matrix<2, 2> {
+        Self::matrix(a)
+    }
+
+    fn has_shortest_path_ambiguity(params: &S::Vector<2>) -> S::Mask {
+        (Self::log(params).get_elem(0).abs() - S::from_f64(core::f64::consts::PI))
+            .abs()
+            .less_equal(&S::from_f64(1e-5))
+    }
+}
+
+/// 2d scaled rotation group - R+ x SO(2)
+pub type RxSO2<S, const B: usize> = LieGroup<S, 2, 2, 2, 2, B, RxSO2Impl<S, B>>;
+
+impl<S: IsScalar<BATCH_SIZE>, const BATCH_SIZE: usize> RxSO2<S, BATCH_SIZE> {
+    /// create scaled rotation from scale factor and rotation
+    pub fn from_scale_and_rotation(scale: S, rotation: &Rotation2<S, BATCH_SIZE>) -> Self {
+        Self::from_params(&rotation.params().scaled(scale))
+    }
+
+    /// get scale factor
+    pub fn scale(&self) -> S {
+        self.params().norm()
+    }
+
+    /// get rotation
+    pub fn rotation(&self) -> Rotation2<S, BATCH_SIZE> {
+        Rotation2::from_params(&self.params().normalized())
+    }
+}
+
+impl<S: IsScalar<BATCH_SIZE>, const BATCH_SIZE: usize> IsLieFactorGroupImpl<S, 2, 2, 2, BATCH_SIZE>
+    for RxSO2Impl<S, BATCH_SIZE>
+{
+    type GenFactorG<S2: IsScalar<BATCH_SIZE>> = RxSO2Impl<S2, BATCH_SIZE>;
+    type RealFactorG = RxSO2Impl<S::RealScalar, BATCH_SIZE>;
+    #[cfg(feature = "alloc")]
+    type DualFactorG = RxSO2Impl<S::DualScalar, BATCH_SIZE>;
+
+    fn mat_v(tangent: &S::Vector<2>) -> S::Matrix<2, 2> {
+        // V is the matrix representation of the complex number F(σ + iθ)
+        let theta = tangent.get_elem(0);
+        let theta_sq = theta.clone() * theta.clone();
+        let coeffs = MatVCoefficients::<S, BATCH_SIZE>::new(tangent.get_elem(1), theta_sq.clone());
+
+        let f_re = coeffs.f_re(theta_sq);
+        let f_im = theta * coeffs.f_im_by_theta();
+        S::Matrix::<2, 2>::from_array2([[f_re.clone(), -f_im.clone()], [f_im, f_re]])
+    }
+
+    fn mat_v_inverse(tangent: &S::Vector<2>) -> S::Matrix<2, 2> {
+        let theta = tangent.get_elem(0);
+        let theta_sq = theta.clone() * theta.clone();
+        let coeffs = MatVCoefficients::<S, BATCH_SIZE>::new(tangent.get_elem(1), theta_sq.clone());
+
+        let f_re = coeffs.f_re(theta_sq);
+        let f_im = theta * coeffs.f_im_by_theta();
+        let f_sq_norm = f_re.clone() * f_re.clone() + f_im.clone() * f_im.clone();
+        S::Matrix::<2, 2>::from_array2([
+            [
+                f_re.clone() / f_sq_norm.clone(),
+                f_im.clone() / f_sq_norm.clone(),
+            ],
+            [-f_im / f_sq_norm.clone(), f_re / f_sq_norm],
+        ])
+    }
+
+    fn adj_of_translation(_params: &S::Vector<2>, point: &S::Vector<2>) -> S::Matrix<2, 2> {
+        Self::ad_of_translation(point)
+    }
+
+    fn ad_of_translation(point: &S::Vector<2>) -> S::Matrix<2, 2> {
+        S::Matrix::<2, 2>::from_array2([
+            [point.get_elem(1), -point.get_elem(0)],
+            [-point.get_elem(0), -point.get_elem(1)],
+        ])
+    }
+}
+
+impl<S: IsRealScalar<BATCH_SIZE>, const BATCH_SIZE: usize>
+    IsRealLieFactorGroupImpl<S, 2, 2, 2, BATCH_SIZE> for RxSO2Impl<S, BATCH_SIZE>
+{
+    fn dx_mat_v(tangent: &S::Vector<2>) -> [S::Matrix<2, 2>; 2] {
+        // ∂F/∂σ = G and ∂F/∂θ = i·G with G = F'
+        let theta = tangent.get_elem(0);
+        let theta_sq = theta * theta;
+        let coeffs = MatVCoefficients::<S, BATCH_SIZE>::new(tangent.get_elem(1), theta_sq);
+
+        let g_re = coeffs.g_re(theta_sq);
+        let g_im = theta * coeffs.g_im_by_theta();
+        [
+            S::Matrix::<2, 2>::from_array2([[-g_im, -g_re], [g_re, -g_im]]),
+            S::Matrix::<2, 2>::from_array2([[g_re, -g_im], [g_im, g_re]]),
+        ]
+    }
+
+    fn dparams_matrix_times_point(_params: &S::Vector<2>, point: &S::Vector<2>) -> S::Matrix<2, 2> {
+        let px = point.get_elem(0);
+        let py = point.get_elem(1);
+        S::Matrix::from_array2([[px, -py], [py, px]])
+    }
+
+    fn dx_mat_v_inverse(tangent: &S::Vector<2>) -> [S::Matrix<2, 2>; 2] {
+        let mat_v_inverse = Self::mat_v_inverse(tangent);
+        Self::dx_mat_v(tangent).map(|dx_mat_v| {
+            -mat_v_inverse
+                .mat_mul(dx_mat_v)
+                .mat_mul(mat_v_inverse.clone())
+        })
+    }
+}
+
+#[test]
+fn rxso2_prop_tests() {
+    use crate::factor_lie_group::RealFactorLieGroupTest;
+    use crate::real_lie_group::RealLieGroupTest;
+    use sophus_core::calculus::dual::dual_scalar::DualScalar;
+
+    use sophus_core::calculus::dual::DualBatchScalar;
+
+    use sophus_core::linalg::BatchScalarF64;
+
+    RxSO2::<f64, 1>::test_suite();
+    RxSO2::<f32, 1>::test_suite();
+    RxSO2::<BatchScalarF64<8>, 8>::test_suite();
+
+    RxSO2::<DualScalar, 1>::test_suite();
+    RxSO2::<DualBatchScalar<8>, 8>::test_suite();
+
+    RxSO2::<f64, 1>::run_real_tests();
+    RxSO2::<BatchScalarF64<8>, 8>::run_real_tests();
+
+    RxSO2::<f64, 1>::run_real_factor_tests();
+    RxSO2::<BatchScalarF64<8>, 8>::run_real_factor_tests();
+}
@@ -0,0 +1,466 @@
+use crate::groups::rotation3::Rotation3Impl;
+use crate::groups::sim_details::MatVCoefficients;
+use crate::lie_group::LieGroup;
+use crate::prelude::*;
+use crate::traits::IsLieFactorGroupImpl;
+use crate::traits::IsLieGroupImpl;
+use crate::traits::IsRealLieFactorGroupImpl;
+use crate::traits::IsRealLieGroupImpl;
+use crate::Rotation3;
+#[cfg(feature = "alloc")]
+use alloc::borrow::ToOwned;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use sophus_core::linalg::vector::cross;
+use sophus_core::manifold::traits::TangentImpl;
+use sophus_core::params::ParamsImpl;
+
+/// 3d scaled rotation implementation - R+ x SO(3)
+///
+/// The group is parameterized by a non-zero quaternion q. Its squared norm |q|² is the scale
+/// factor and the unit quaternion q/|q| represents the rotation. The tangent vector is (ω, σ)
+/// with ω being the rotation vector and σ the logarithm of the scale factor.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct RxSO3Impl<S: IsScalar<BATCH>, const BATCH: usize> {
+    phantom: PhantomData<S>,
+}
+
+impl<S: IsScalar<BATCH>, const BATCH: usize> RxSO3Impl<S, BATCH> {
+    fn rotation_tangent(tangent: &S::Vector<4>) -> S::Vector<3> {
+        tangent.get_fixed_subvec::<3>(0)
+    }
+
+    fn log_scale(tangent: &S::Vector<4>) -> S {
+        tangent.get_elem(3)
+    }
+}
+
+impl<S: IsScalar<BATCH>, const BATCH: usize> ParamsImpl<S, 4, BATCH> for RxSO3Impl<S, BATCH> {
+    #[cfg(feature = "alloc")]
+    fn params_examples() -> Vec<S::Vector<4>> {
+        vec![
+            RxSO3::<S, BATCH>::exp(&S::Vector::<4>::from_f64_array([0.0, 0.0, 0.0, 0.0]))
+                .params()
+                .clone(),
+            RxSO3::<S, BATCH>::exp(&S::Vector::<4>::from_f64_array([0.0, 0.0, 0.0, 0.5]))
+                .params()
+                .clone(),
+            RxSO3::<S, BATCH>::exp(&S::Vector::<4>::from_f64_array([0.1, 0.5, -0.1, 0.2]))
+                .params()
+                .clone(),
+            RxSO3::<S, BATCH>::exp(&S::Vector::<4>::from_f64_array([0.0, 0.2, 1.0, -0.3]))
+                .params()
+                .clone(),
+            RxSO3::<S, BATCH>::exp(&S::Vector::<4>::from_f64_array([-0.2, 0.0, 0.8, 0.0]))
+                .params()
+                .clone(),
+        ]
+    }
+
+    #[cfg(feature = "alloc")]
+    fn invalid_params_examples() -> Vec<S::Vector<4>> {
+        vec![
+            S::Vector::<4>::from_f64_array([0.0, 0.0, 0.0, 0.0]),
+            S::Vector::<4>::from_f64_array([1e-4, 0.0, 0.0, 0.0]),
+        ]
+    }
+
+    fn are_params_valid(params: &S::Vector<4>) -> S::Mask {
+        params.squared_norm().greater_equal(&S::from_f64(1e-6))
+    }
+}
+
+impl<S: IsScalar<BATCH>, const BATCH: usize> TangentImpl<S, 4, BATCH> for RxSO3Impl<S, BATCH> {
+    #[cfg(feature = "alloc")]
+    fn tangent_examples() -> Vec<S::Vector<4>> {
+        vec![
+            S::Vector::<4>::from_f64_array([0.0, 0.0, 0.0, 0.0]),
+            S::Vector::<4>::from_f64_array([1.0, 0.0, 0.0, 0.0]),
+            S::Vector::<4>::from_f64_array([0.0, 1.0, 0.0, 0.3]),
+            S::Vector::<4>::from_f64_array([0.0, 0.0, 0.0, -0.5]),
+            S::Vector::<4>::from_f64_array([0.5, 0.5, 0.1, 0.2]),
+            S::Vector::<4>::from_f64_array([-0.1, -0.5, -0.5, -0.3]),
+        ]
+    }
+}
+
+impl<S: IsScalar<BATCH>, const BATCH: usize> IsLieGroupImpl<S, 4, 4, 3, 3, BATCH>
+    for RxSO3Impl<S, BATCH>
+{
+    const IS_ORIGIN_PRESERVING: bool = true;
+    const IS_AXIS_DIRECTION_PRESERVING: bool = false;
+    const IS_DIRECTION_VECTOR_PRESERVING: bool = false;
+    const IS_SHAPE_PRESERVING: bool = true;
+    const IS_DISTANCE_PRESERVING: bool = false;
+    const IS_PARALLEL_LINE_PRESERVING: bool = true;
+
+    #[cfg(feature = "alloc")]
+    fn params_constraint() -> String {
+        "non-zero quaternion, |q|² ≥ 1e-6".to_owned()
+    }
+
+    fn identity_params() -> S::Vector<4> {
+        S::Vector::<4>::from_f64_array([1.0, 0.0, 0.0, 0.0])
+    }
+
+    fn adj(params: &S::Vector<4>) -> S::Matrix<4, 4> {
+        S::Matrix::block_mat2x2::<3, 1, 3, 1>(
+            (
+                Rotation3Impl::<S, BATCH>::matrix(&params.normalized()),
+                S::Matrix::<3, 1>::zeros(),
+            ),
+            (S::Matrix::<1, 3>::zeros(), S::Matrix::<1, 1>::identity()),
+        )
+    }
+
+    fn exp(omega: &S::Vector<4>) -> S::Vector<4> {
+        // q = exp(σ/2) * exp(ω), such that |q|² = exp(σ)
+        let scale_sqrt = (S::from_f64(0.5) * Self::log_scale(omega)).exp();
+        Rotation3Impl::<S, BATCH>::exp(&Self::rotation_tangent(omega)).scaled(scale_sqrt)
+    }
+
+    fn log(params: &S::Vector<4>) -> S::Vector<4> {
+        // Note: the SO(3) logarithm does not depend on the norm of the quaternion
+        let omega = Rotation3Impl::<S, BATCH>::log(params);
+        let log_scale = params.squared_norm().ln();
+        S::Vector::block_vec2(omega, log_scale.to_vec())
+    }
+
+    fn hat(omega: &S::Vector<4>) -> S::Matrix<3, 3> {
+        Rotation3Impl::<S, BATCH>::hat(&Self::rotation_tangent(omega))
+            + S::Matrix::<3, 3>::identity().scaled(Self::log_scale(omega))
+    }
+
+    fn vee(omega_hat: &S::Matrix<3, 3>) -> S::Vector<4> {
+        S::Vector::<4>::from_array([
+            omega_hat.get_elem([2, 1]),
+            omega_hat.get_elem([0, 2]),
+            omega_hat.get_elem([1, 0]),
+            omega_hat.get_elem([0, 0]),
+        ])
+    }
+
+    fn inverse(params: &S::Vector<4>) -> S::Vector<4> {
+        let squared_norm = params.squared_norm();
+        S::Vector::from_array([
+            params.get_elem(0) / squared_norm.clone(),
+            -params.get_elem(1) / squared_norm.clone(),
+            -params.get_elem(2) / squared_norm.clone(),
+            -params.get_elem(3) / squared_norm,
+        ])
+    }
+
+    fn transform(params: &S::Vector<4>, point: &S::Vector<3>) -> S::Vector<3> {
+        Self::matrix(params) * point.clone()
+    }
+
+    fn to_ambient(point: &S::Vector<3>) -> S::Vector<3> {
+        point.clone()
+    }
+
+    fn compact(params: &S::Vector<4>) -> S::Matrix<3, 3> {
+        Self::matrix(params)
+    }
+
+    fn matrix(params: &S::Vector<4>) -> S::Matrix<3, 3> {
+        // scaled rotation matrix: (w² - v·v) I + 2 v vᵀ + 2 w [v]ₓ
+        let w = params.get_elem(0);
+        let x = params.get_elem(1);
+        let y = params.get_elem(2);
+        let z = params.get_elem(3);
+
+        let ww = w.clone() * w.clone();
+        let xx = x.clone() * x.clone();
+        let yy = y.clone() * y.clone();
+        let zz = z.clone() * z.clone();
+        let two = S::from_f64(2.0);
+        let xy = two.clone() * x.clone() * y.clone();
+        let xz = two.clone() * x.clone() * z.clone();
+        let yz = two.clone() * y.clone() * z.clone();
+        let wx = two.clone() * w.clone() * x;
+        let wy = two.clone() * w.clone() * y;
+        let wz = two * w * z;
+
+        S::Matrix::from_array2([
+            [
+                ww.clone() + xx.clone() - yy.clone() - zz.clone(),
+                xy.clone() - wz.clone(),
+                xz.clone() + wy.clone(),
+            ],
+            [
+                xy + wz,
+                ww.clone() - xx.clone() + yy.clone() - zz.clone(),
+                yz.clone() - wx.clone(),
+            ],
+            [xz - wy, yz + wx, ww - xx - yy + zz],
+        ])
+    }
+
+    fn ad(omega: &S::Vector<4>) -> S::Matrix<4, 4> {
+        S::Matrix::block_mat2x2::<3, 1, 3, 1>(
+            (
+                Rotation3Impl::<S, BATCH>::hat(&Self::rotation_tangent(omega)),
+                S::Matrix::<3, 1>::zeros(),
+            ),
+            (S::Matrix::<1, 3>::zeros(), S::Matrix::<1, 1>::zeros()),
+        )
+    }
+
+    fn jl(omega: &S::Vector<4>) -> S::Matrix<4, 4> {
+        // scale commutes with rotation, hence the Jacobian is block diagonal
+        S::Matrix::block_mat2x2::<3, 1, 3, 1>(
+            (
+                Rotation3Impl::<S, BATCH>::jl(&Self::rotation_tangent(omega)),
+                S::Matrix::<3, 1>::zeros(),
+            ),
+            (S::Matrix::<1, 3>::zeros(), S::Matrix::<1, 1>::identity()),
+        )
+    }
+
+    fn jl_inv(omega: &S::Vector<4>) -> S::Matrix<4, 4> {
+        S::Matrix::block_mat2x2::<3, 1, 3, 1>(
+            (
+                Rotation3Impl::<S, BATCH>::jl_inv(&Self::rotation_tangent(omega)),
+                S::Matrix::<3, 1>::zeros(),
+            ),
+            (S::Matrix::<1, 3>::zeros(), S::Matrix::<1, 1>::identity()),
+        )
+    }
+
+    type GenG<S2: IsScalar<BATCH>> = RxSO3Impl<S2, BATCH>;
+    type RealG = RxSO3Impl<S::RealScalar, BATCH>;
+    #[cfg(feature = "alloc")]
+    type DualG = RxSO3Impl<S::DualScalar, BATCH>;
+
+    fn group_mul(lhs_params: &S::Vector<4>, rhs_params: &S::Vector<4>) -> S::Vector<4> {
+        let lhs_re = lhs_params.get_elem(0);
+        let rhs_re = rhs_params.get_elem(0);
+
+        let lhs_ivec = lhs_params.get_fixed_subvec::<3>(1);
+        let rhs_ivec = rhs_params.get_fixed_subvec::<3>(1);
+
+        let re = lhs_re.clone() * rhs_re.clone() - lhs_ivec.clone().dot(rhs_ivec.clone());
+        let ivec = rhs_ivec.scaled(lhs_re)
+            + lhs_ivec.scaled(rhs_re)
+            + cross::<S, BATCH>(lhs_ivec, rhs_ivec);
+
+        S::Vector::block_vec2(re.to_vec(), ivec)
+    }
+}
+
+impl<S: IsRealScalar<BATCH>, const BATCH: usize> IsRealLieGroupImpl<S, 4, 4, 3, 3, BATCH>
+    for RxSO3Impl<S, BATCH>
+{
+    fn dx_exp_x_at_0() -> S::Matrix<4, 4> {
+        S::Matrix::from_f64_array2([
+            [0.0, 0.0, 0.0, 0.5],
+            [0.5, 0.0, 0.0, 0.0],
+            [0.0, 0.5, 0.0, 0.0],
+            [0.0, 0.0, 0.5, 0.0],
+        ])
+    }
+
+    fn da_a_mul_b(a: &S::Vector<4>, b: &S::Vector<4>) -> S::Matrix<4, 4> {
+        Rotation3Impl::<S, BATCH>::da_a_mul_b(a, b)
+    }
+
+    fn db_a_mul_b(a: &S::Vector<4>, b: &S::Vector<4>) -> S::Matrix<4, 4> {
+        Rotation3Impl::<S, BATCH>::db_a_mul_b(a, b)
+    }
+
+    fn dx_exp_x_times_point_at_0(point: S::Vector<3>) -> S::Matrix<3, 4> {
+        S::Matrix::block_mat1x2::<3, 1>(
+            Rotation3Impl::<S, BATCH>::hat(&-point.clone()),
+            point.to_mat(),
+        )
+    }
+
+    fn dx_exp(omega: &S::Vector<4>) -> S::Matrix<4, 4> {
+        let scale_sqrt = (S::from_f64(0.5) * Self::log_scale(omega)).exp();
+        S::Matrix::block_mat1x2::<3, 1>(
+            Rotation3Impl::<S, BATCH>::dx_exp(&Self::rotation_tangent(omega)).scaled(scale_sqrt),
+            Self::exp(omega).scaled(S::from_f64(0.5)).to_mat(),
+        )
+    }
+
+    fn dx_log_x(params: &S::Vector<4>) -> S::Matrix<4, 4> {
+        let squared_norm = params.squared_norm();
+        let norm = squared_norm.sqrt();
+        let unit_params = params.scaled(S::ones() / norm);
+
+        // derivative of the normalization q / |q|
+        let dx_unit_params = (S::Matrix::<4, 4>::identity()
+            - unit_params.clone().outer(unit_params.clone()))
+        .scaled(S::ones() / norm);
+
+        S::Matrix::block_mat2x1::<3, 1>(
+            Rotation3Impl::<S, BATCH>::dx_log_x(&unit_params).mat_mul(dx_unit_params),
+            S::Matrix::<1, 4>::from_array2([[
+                S::from_f64(2.0) * params.get_elem(0) / squared_norm,
+                S::from_f64(2.0) * params.get_elem(1) / squared_norm,
+                S::from_f64(2.0) * params.get_elem(2) / squared_norm,
+                S::from_f64(2.0) * params.get_elem(3) / squared_norm,
+            ]]),
+        )
+    }
+
+    fn has_shortest_path_ambiguity(params: &S::Vector<4>) -> S::Mask {
+        Rotation3Impl::<S, BATCH>::has_shortest_path_ambiguity(params)
+    }
+}
+
+impl<S: IsScalar<BATCH>, const BATCH: usize> IsLieFactorGroupImpl<S, 4, 4, 3, BATCH>
+    for RxSO3Impl<S, BATCH>
+{
+    type GenFactorG<S2: IsScalar<BATCH>> = RxSO3Impl<S2, BATCH>;
+    type RealFactorG = RxSO3Impl<S::RealScalar, BATCH>;
+    #[cfg(feature = "alloc")]
+    type DualFactorG = RxSO3Impl<S::DualScalar, BATCH>;
+
+    fn mat_v(omega: &S::Vector<4>) -> S::Matrix<3, 3> {
+        let rotation_tangent = Self::rotation_tangent(omega);
+        let theta_sq = rotation_tangent.squared_norm();
+        let coeffs = MatVCoefficients::<S, BATCH>::new(Self::log_scale(omega), theta_sq);
+
+        let mat_omega: S::Matrix<3, 3> = Rotation3Impl::<S, BATCH>::hat(&rotation_tangent);
+        let mat_omega_sq = mat_omega.clone().mat_mul(mat_omega.clone());
+
+        S::Matrix::<3, 3>::identity().scaled(coeffs.c)
+            + mat_omega.scaled(coeffs.a)
+            + mat_omega_sq.scaled(coeffs.b)
+    }
+
+    fn mat_v_inverse(omega: &S::Vector<4>) -> S::Matrix<3, 3> {
+        let rotation_tangent = Self::rotation_tangent(omega);
+        let theta_sq = rotation_tangent.squared_norm();
+        let coeffs = MatVCoefficients::<S, BATCH>::new(Self::log_scale(omega), theta_sq.clone());
+        let (x, y, z) = coeffs.inverse(theta_sq);
+
+        let mat_omega: S::Matrix<3, 3> = Rotation3Impl::<S, BATCH>::hat(&rotation_tangent);
+        let mat_omega_sq = mat_omega.clone().mat_mul(mat_omega.clone());
+
+        S::Matrix::<3, 3>::identity().scaled(x) + mat_omega.scaled(y) + mat_omega_sq.scaled(z)
+    }
+
+    fn adj_of_translation(params: &S::Vector<4>, point: &S::Vector<3>) -> S::Matrix<3, 4> {
+        S::Matrix::block_mat1x2::<3, 1>(
+            Rotation3Impl::<S, BATCH>::hat(point)
+                .mat_mul(Rotation3Impl::<S, BATCH>::matrix(&params.normalized())),
+            (-point.clone()).to_mat(),
+        )
+    }
+
+    fn ad_of_translation(point: &S::Vector<3>) -> S::Matrix<3, 4> {
+        S::Matrix::block_mat1x2::<3, 1>(
+            Rotation3Impl::<S, BATCH>::hat(point),
+            (-point.clone()).to_mat(),
+        )
+    }
+}
+
+impl<S: IsRealScalar<BATCH>, const BATCH: usize> IsRealLieFactorGroupImpl<S, 4, 4, 3, BATCH>
+    for RxSO3Impl<S, BATCH>
+{
+    fn dx_mat_v(omega: &S::Vector<4>) -> [S::Matrix<3, 3>; 4] {
+        let rotation_tangent = Self::rotation_tangent(omega);
+        let theta_sq = rotation_tangent.squared_norm();
+        let coeffs = MatVCoefficients::<S, BATCH>::new(Self::log_scale(omega), theta_sq);
+
+        let mat_omega: S::Matrix<3, 3> = Rotation3Impl::<S, BATCH>::hat(&rotation_tangent);
+        let mat_omega_sq = mat_omega.clone().mat_mul(mat_omega.clone());
+
+        let set = |i| -> S::Matrix<3, 3> {
+            // derivative of Ω with respect to ω_i
+            let mut unit = S::Vector::<3>::zeros();
+            unit.set_elem(i, S::ones());
+            let dx_mat_omega: S::Matrix<3, 3> = Rotation3Impl::<S, BATCH>::hat(&unit);
+
+            let omega_i = rotation_tangent.get_elem(i);
+            mat_omega.scaled(omega_i * coeffs.da_dtheta_by_theta)
+                + mat_omega_sq.scaled(omega_i * coeffs.db_dtheta_by_theta)
+                + dx_mat_omega.scaled(coeffs.a)
+                + (dx_mat_omega.mat_mul(mat_omega.clone())
+                    + mat_omega.mat_mul(dx_mat_omega.clone()))
+                .scaled(coeffs.b)
+        };
+
+        [
+            set(0),
+            set(1),
+            set(2),
+            S::Matrix::<3, 3>::identity().scaled(coeffs.dc_dsigma)
+                + mat_omega.scaled(coeffs.da_dsigma)
+                + mat_omega_sq.scaled(coeffs.db_dsigma),
+        ]
+    }
+
+    fn dparams_matrix_times_point(params: &S::Vector<4>, point: &S::Vector<3>) -> S::Matrix<3, 4> {
+        let re = params.get_elem(0);
+        let ivec = params.get_fixed_subvec::<3>(1);
+        let two = S::from_f64(2.0);
+
+        let dre = (point.scaled(re) + cross::<S, BATCH>(ivec.clone(), point.clone())).scaled(two);
+        let divec = (S::Matrix::<3, 3>::identity().scaled(ivec.clone().dot(point.clone()))
+            + ivec.clone().outer(point.clone())
+            - point.clone().outer(ivec)
+            - Rotation3Impl::<S, BATCH>::hat(point).scaled(re))
+        .scaled(two);
+
+        S::Matrix::block_mat1x2::<1, 3>(dre.to_mat(), divec)
+    }
+
+    fn dx_mat_v_inverse(omega: &S::Vector<4>) -> [S::Matrix<3, 3>; 4] {
+        let mat_v_inverse = Self::mat_v_inverse(omega);
+        Self::dx_mat_v(omega).map(|dx_mat_v| {
+            -mat_v_inverse
+                .mat_mul(dx_mat_v)
+                .mat_mul(mat_v_inverse.clone())
+        })
+    }
+}
+
+/// 3d scaled rotation group - R+ x SO(3)
+pub type RxSO3<S, const BATCH: usize> = LieGroup<S, 4, 4, 3, 3, BATCH, RxSO3Impl<S, BATCH>>;
+
+impl<S: IsScalar<BATCH>, const BATCH: usize> RxSO3<S, BATCH> {
+    /// create scaled rotation from scale factor and rotation
+    pub fn from_scale_and_rotation(scale: S, rotation: &Rotation3<S, BATCH>) -> Self {
+        Self::from_params(&rotation.params().scaled(scale.sqrt()))
+    }
+
+    /// get scale factor
+    pub fn scale(&self) -> S {
+        self.params().squared_norm()
+    }
+
+    /// get rotation
+    pub fn rotation(&self) -> Rotation3<S, BATCH> {
+        Rotation3::from_params(&self.params().normalized())
+    }
+}
+
+#[test]
+fn rxso3_prop_tests() {
+    use crate::factor_lie_group::RealFactorLieGroupTest;
+    use crate::real_lie_group::RealLieGroupTest;
+    use sophus_core::calculus::dual::dual_scalar::DualScalar;
+    use sophus_core::calculus::dual::DualBatchScalar;
+    use sophus_core::linalg::BatchScalarF64;
+
+    RxSO3::<f64, 1>::test_suite();
+    RxSO3::<f32, 1>::test_suite();
+    RxSO3::<BatchScalarF64<8>, 8>::test_suite();
+    RxSO3::<DualScalar, 1>::test_suite();
+    RxSO3::<DualBatchScalar<8>, 8>::test_suite();
+
+    RxSO3::<f64, 1>::run_real_tests();
+    RxSO3::<BatchScalarF64<8>, 8>::run_real_tests();
+
+    RxSO3::<f64, 1>::run_real_factor_tests();
+    RxSO3::<BatchScalarF64<8>, 8>::run_real_factor_tests();
+}
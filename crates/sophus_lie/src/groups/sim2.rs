@@ -0,0 +1,47 @@
+use crate::groups::rxso2::RxSO2Impl;
+use crate::groups::translation_product_product::TranslationProductGroupImpl;
+use crate::lie_group::LieGroup;
+use crate::prelude::*;
+use crate::RxSO2;
+
+/// 2D similarity group implementation struct - Sim(2)
+pub type Sim2Impl<S, const BATCH: usize> =
+    TranslationProductGroupImpl<S, 4, 4, 2, 3, 2, 2, BATCH, RxSO2Impl<S, BATCH>>;
+
+/// 2D similarity group - Sim(2)
+pub type Sim2<S, const BATCH: usize> = LieGroup<S, 4, 4, 2, 3, BATCH, Sim2Impl<S, BATCH>>;
+
+impl<S: IsScalar<BATCH>, const BATCH: usize> Sim2<S, BATCH> {
+    /// create similarity from translation and scaled rotation
+    pub fn from_translation_and_rxso2(translation: &S::Vector<2>, rxso2: &RxSO2<S, BATCH>) -> Self {
+        Self::from_translation_and_factor(translation, rxso2)
+    }
+
+    /// set scaled rotation
+    pub fn set_rxso2(&mut self, rxso2: &RxSO2<S, BATCH>) {
+        self.set_factor(rxso2)
+    }
+
+    /// get scaled rotation
+    pub fn rxso2(&self) -> RxSO2<S, BATCH> {
+        self.factor()
+    }
+}
+
+#[test]
+fn sim2_prop_tests() {
+    use crate::real_lie_group::RealLieGroupTest;
+    use sophus_core::calculus::dual::dual_scalar::DualScalar;
+
+    use sophus_core::calculus::dual::dual_batch_scalar::DualBatchScalar;
+    use sophus_core::linalg::BatchScalarF64;
+
+    Sim2::<f64, 1>::test_suite();
+    Sim2::<f32, 1>::test_suite();
+    Sim2::<BatchScalarF64<8>, 8>::test_suite();
+    Sim2::<DualScalar, 1>::test_suite();
+    Sim2::<DualBatchScalar<8>, 8>::test_suite();
+
+    Sim2::<f64, 1>::run_real_tests();
+    Sim2::<BatchScalarF64<8>, 8>::run_real_tests();
+}
@@ -0,0 +1,46 @@
+use super::rxso3::RxSO3Impl;
+use super::translation_product_product::TranslationProductGroupImpl;
+use crate::lie_group::LieGroup;
+use crate::prelude::*;
+use crate::RxSO3;
+
+/// 3D similarity group implementation struct - Sim(3)
+pub type Sim3Impl<S, const BATCH: usize> =
+    TranslationProductGroupImpl<S, 7, 7, 3, 4, 4, 4, BATCH, RxSO3Impl<S, BATCH>>;
+/// 3d similarity group - Sim(3)
+pub type Sim3<S, const BATCH: usize> = LieGroup<S, 7, 7, 3, 4, BATCH, Sim3Impl<S, BATCH>>;
+
+impl<S: IsScalar<BATCH>, const BATCH: usize> Sim3<S, BATCH> {
+    /// create similarity from translation and scaled rotation
+    pub fn from_translation_and_rxso3(translation: &S::Vector<3>, rxso3: &RxSO3<S, BATCH>) -> Self {
+        Self::from_translation_and_factor(translation, rxso3)
+    }
+
+    /// set scaled rotation
+    pub fn set_rxso3(&mut self, rxso3: &RxSO3<S, BATCH>) {
+        self.set_factor(rxso3)
+    }
+
+    /// get scaled rotation
+    pub fn rxso3(&self) -> RxSO3<S, BATCH> {
+        self.factor()
+    }
+}
+
+#[test]
+fn sim3_prop_tests() {
+    use crate::real_lie_group::RealLieGroupTest;
+    use sophus_core::calculus::dual::dual_scalar::DualScalar;
+
+    use sophus_core::calculus::dual::DualBatchScalar;
+    use sophus_core::linalg::BatchScalarF64;
+
+    Sim3::<f64, 1>::test_suite();
+    Sim3::<f32, 1>::test_suite();
+    Sim3::<BatchScalarF64<8>, 8>::test_suite();
+    Sim3::<DualScalar, 1>::test_suite();
+    Sim3::<DualBatchScalar<8>, 8>::test_suite();
+
+    Sim3::<f64, 1>::run_real_tests();
+    Sim3::<BatchScalarF64<8>, 8>::run_real_tests();
+}
@@ -0,0 +1,151 @@
+use crate::prelude::*;
+
+/// Coefficients of the V matrix of the scaled rotation groups RxSO(2) and RxSO(3)
+///
+/// Let Ω be the rotational part of a Lie algebra element, σ its log-scale and θ the rotation
+/// angle. The V matrix, used by the exponential and logarithmic map of Sim(2) and Sim(3), is
+///
+///   V(Ω, σ) = ∫₀¹ exp(s·σ)·exp(s·Ω) ds = c·I + a·Ω + b·Ω²
+///
+/// This struct holds the coefficients a, b, c as well as their partial derivatives with respect
+/// to σ and θ. Since a and b are even functions of θ, their θ-derivatives are stored divided by θ.
+pub(crate) struct MatVCoefficients<S: IsScalar<BATCH>, const BATCH: usize> {
+    /// coefficient of Ω
+    pub a: S,
+    /// coefficient of Ω²
+    pub b: S,
+    /// coefficient of I
+    pub c: S,
+    /// ∂a/∂σ
+    pub da_dsigma: S,
+    /// ∂b/∂σ
+    pub db_dsigma: S,
+    /// ∂c/∂σ
+    pub dc_dsigma: S,
+    /// (∂a/∂θ) / θ
+    pub da_dtheta_by_theta: S,
+    /// (∂b/∂θ) / θ
+    pub db_dtheta_by_theta: S,
+}
+
+impl<S: IsScalar<BATCH>, const BATCH: usize> MatVCoefficients<S, BATCH> {
+    /// Computes the coefficients given the log-scale σ and the squared rotation angle θ².
+    pub fn new(sigma: S, theta_sq: S) -> Self {
+        let [i0, i1, i2, i3, i4] = Self::exp_moments(sigma.clone());
+
+        // Taylor expansion in θ², used for small rotation angles:
+        //
+        //   a = I₁ - θ²·I₃/6,   b = I₂/2 - θ²·I₄/24
+        let a0 = i1.clone() - theta_sq.clone() * i3.clone() * S::from_f64(1.0 / 6.0);
+        let b0 =
+            S::from_f64(0.5) * i2.clone() - theta_sq.clone() * i4.clone() * S::from_f64(1.0 / 24.0);
+        let da_dsigma0 = i2.clone() - theta_sq.clone() * i4.clone() * S::from_f64(1.0 / 6.0);
+        let db_dsigma0 = S::from_f64(0.5) * i3.clone();
+        let da_dtheta_by_theta0 = S::from_f64(-1.0 / 3.0) * i3;
+        let db_dtheta_by_theta0 = S::from_f64(-1.0 / 12.0) * i4;
+
+        // Closed form using the complex number z = σ + iθ:
+        //
+        //   F(z) = ∫₀¹ exp(s·z) ds = (exp(z) - 1) / z,   with  Re F = c - θ²·b,  Im F = θ·a
+        //   G(z) = F'(z) = (exp(z) - F(z)) / z
+        let theta = theta_sq.clone().sqrt();
+        let exp_sigma = sigma.clone().exp();
+        let exp_z_re = exp_sigma.clone() * theta.clone().cos();
+        let exp_z_im = exp_sigma * theta.clone().sin();
+        let z_sq_norm = sigma.clone() * sigma.clone() + theta_sq.clone();
+
+        let complex_div_by_z = |re: S, im: S| -> (S, S) {
+            (
+                (re.clone() * sigma.clone() + im.clone() * theta.clone()) / z_sq_norm.clone(),
+                (im * sigma.clone() - re * theta.clone()) / z_sq_norm.clone(),
+            )
+        };
+        let (f_re, f_im) = complex_div_by_z(exp_z_re.clone() - S::ones(), exp_z_im.clone());
+        let (g_re, g_im) = complex_div_by_z(exp_z_re - f_re.clone(), exp_z_im - f_im.clone());
+
+        let a = f_im / theta.clone();
+        let b = (i0.clone() - f_re) / theta_sq.clone();
+        let da_dsigma = g_im / theta;
+        let db_dsigma = (i1.clone() - g_re.clone()) / theta_sq.clone();
+        let da_dtheta_by_theta = (g_re - a.clone()) / theta_sq.clone();
+        let db_dtheta_by_theta =
+            (da_dsigma.clone() - S::from_f64(2.0) * b.clone()) / theta_sq.clone();
+
+        let near_zero = theta_sq.less_equal(&S::from_f64(1e-6));
+
+        Self {
+            a: a0.select(&near_zero, a),
+            b: b0.select(&near_zero, b),
+            c: i0,
+            da_dsigma: da_dsigma0.select(&near_zero, da_dsigma),
+            db_dsigma: db_dsigma0.select(&near_zero, db_dsigma),
+            dc_dsigma: i1,
+            da_dtheta_by_theta: da_dtheta_by_theta0.select(&near_zero, da_dtheta_by_theta),
+            db_dtheta_by_theta: db_dtheta_by_theta0.select(&near_zero, db_dtheta_by_theta),
+        }
+    }
+
+    /// Real part of F(z) = ∫₀¹ exp(s·(σ + iθ)) ds
+    pub fn f_re(&self, theta_sq: S) -> S {
+        self.c.clone() - theta_sq * self.b.clone()
+    }
+
+    /// Imaginary part of F(z) divided by θ
+    pub fn f_im_by_theta(&self) -> S {
+        self.a.clone()
+    }
+
+    /// Real part of F'(z)
+    pub fn g_re(&self, theta_sq: S) -> S {
+        self.dc_dsigma.clone() - theta_sq * self.db_dsigma.clone()
+    }
+
+    /// Imaginary part of F'(z) divided by θ
+    pub fn g_im_by_theta(&self) -> S {
+        self.da_dsigma.clone()
+    }
+
+    /// Coefficients (x, y, z) of the inverse V matrix: V⁻¹ = x·I + y·Ω + z·Ω²
+    ///
+    /// This uses Ω³ = -θ²·Ω, and is well-defined for θ < 2π.
+    pub fn inverse(&self, theta_sq: S) -> (S, S, S) {
+        let f_re = self.f_re(theta_sq.clone());
+        let f_sq_norm = f_re.clone() * f_re.clone() + theta_sq * self.a.clone() * self.a.clone();
+
+        let x = S::ones() / self.c.clone();
+        let y = -self.a.clone() / f_sq_norm.clone();
+        let z = (self.a.clone() * self.a.clone() - self.b.clone() * f_re)
+            / (self.c.clone() * f_sq_norm);
+        (x, y, z)
+    }
+
+    /// Moments I_k(σ) = ∫₀¹ sᵏ·exp(s·σ) ds for k = 0, ..., 4
+    ///
+    /// For small |σ| the Taylor series is used, since the recursion
+    /// I_k = (exp(σ) - k·I_{k-1}) / σ is numerically unstable.
+    fn exp_moments(sigma: S) -> [S; 5] {
+        let near_zero = sigma.clone().abs().less_equal(&S::from_f64(0.1));
+
+        let series = |k: usize| -> S {
+            let mut sum = S::zeros();
+            let mut sigma_pow_by_factorial = S::ones();
+            for n in 0..7 {
+                sum += sigma_pow_by_factorial.clone() / S::from_f64((k + n + 1) as f64);
+                sigma_pow_by_factorial =
+                    sigma_pow_by_factorial * sigma.clone() / S::from_f64((n + 1) as f64);
+            }
+            sum
+        };
+        let exp_sigma = sigma.clone().exp();
+        let recursion = |k: usize, prev: S| -> S {
+            (exp_sigma.clone() - S::from_f64(k as f64) * prev) / sigma.clone()
+        };
+
+        let i0 = series(0).select(&near_zero, (exp_sigma.clone() - S::ones()) / sigma.clone());
+        let i1 = series(1).select(&near_zero, recursion(1, i0.clone()));
+        let i2 = series(2).select(&near_zero, recursion(2, i1.clone()));
+        let i3 = series(3).select(&near_zero, recursion(3, i2.clone()));
+        let i4 = series(4).select(&near_zero, recursion(4, i3.clone()));
+        [i0, i1, i2, i3, i4]
+    }
+}
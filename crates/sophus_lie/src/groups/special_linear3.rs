@@ -0,0 +1,530 @@
+use crate::groups::jacobian_details::left_jacobian_from_series;
+use crate::groups::jacobian_details::left_jacobian_inverse_from_series;
+use crate::groups::jacobian_details::SERIES_ORDER;
+use crate::lie_group::LieGroup;
+#[cfg(feature = "alloc")]
+use crate::lie_group_error::LieGroupError;
+use crate::prelude::*;
+use crate::traits::IsLieGroupImpl;
+use crate::traits::IsRealLieGroupImpl;
+#[cfg(feature = "alloc")]
+use alloc::borrow::ToOwned;
+#[cfg(feature = "alloc")]
+use alloc::format;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+#[cfg(feature = "alloc")]
+use sophus_core::linalg::MatF64;
+#[cfg(feature = "alloc")]
+use sophus_core::linalg::VecF64;
+use sophus_core::manifold::traits::TangentImpl;
+use sophus_core::params::ParamsImpl;
+
+/// 3x3 special linear group implementation - SL(3)
+///
+/// The group of 3x3 matrices with determinant one. It acts on homogeneous 2d points, i.e. its
+/// elements are planar homographies. The parameters are the nine matrix entries in column-major
+/// order. The tangent vector is (t_x, t_y, rotation, scale, aspect, shear, p_x, p_y) with the
+/// following Lie algebra generators:
+///
+///   [[s + a, -r + h, t_x],
+///    [r + h,  s - a, t_y],
+///    [p_x,    p_y,   -2s]].
+#[derive(Debug, Copy, Clone, Default)]
+pub struct SpecialLinear3Impl<S: IsScalar<BATCH>, const BATCH: usize> {
+    phantom: PhantomData<S>,
+}
+
+impl<S: IsScalar<BATCH>, const BATCH: usize> SpecialLinear3Impl<S, BATCH> {
+    fn params_from_matrix(mat: &S::Matrix<3, 3>) -> S::Vector<9> {
+        S::Vector::from_array([
+            mat.get_elem([0, 0]),
+            mat.get_elem([1, 0]),
+            mat.get_elem([2, 0]),
+            mat.get_elem([0, 1]),
+            mat.get_elem([1, 1]),
+            mat.get_elem([2, 1]),
+            mat.get_elem([0, 2]),
+            mat.get_elem([1, 2]),
+            mat.get_elem([2, 2]),
+        ])
+    }
+
+    fn generators() -> [S::Matrix<3, 3>; 8] {
+        [
+            S::Matrix::from_f64_array2([[0.0, 0.0, 1.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0]]),
+            S::Matrix::from_f64_array2([[0.0, 0.0, 0.0], [0.0, 0.0, 1.0], [0.0, 0.0, 0.0]]),
+            S::Matrix::from_f64_array2([[0.0, -1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 0.0]]),
+            S::Matrix::from_f64_array2([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, -2.0]]),
+            S::Matrix::from_f64_array2([[1.0, 0.0, 0.0], [0.0, -1.0, 0.0], [0.0, 0.0, 0.0]]),
+            S::Matrix::from_f64_array2([[0.0, 1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 0.0]]),
+            S::Matrix::from_f64_array2([[0.0, 0.0, 0.0], [0.0, 0.0, 0.0], [1.0, 0.0, 0.0]]),
+            S::Matrix::from_f64_array2([[0.0, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 1.0, 0.0]]),
+        ]
+    }
+
+    fn matrix_from_columns(cols: [S::Vector<8>; 8]) -> S::Matrix<8, 8> {
+        S::Matrix::from_array2(core::array::from_fn(|r| {
+            core::array::from_fn(|c| cols[c].get_elem(r))
+        }))
+    }
+
+    fn squared_frobenius_norm(mat: &S::Matrix<3, 3>) -> S {
+        let mut sum = S::zeros();
+        for r in 0..3 {
+            for c in 0..3 {
+                sum += mat.get_elem([r, c]) * mat.get_elem([r, c]);
+            }
+        }
+        sum
+    }
+
+    fn determinant(mat: &S::Matrix<3, 3>) -> S {
+        let m = |r, c| mat.get_elem([r, c]);
+        m(0, 0) * (m(1, 1) * m(2, 2) - m(1, 2) * m(2, 1))
+            - m(0, 1) * (m(1, 0) * m(2, 2) - m(1, 2) * m(2, 0))
+            + m(0, 2) * (m(1, 0) * m(2, 1) - m(1, 1) * m(2, 0))
+    }
+
+    fn inverse_matrix(mat: &S::Matrix<3, 3>) -> S::Matrix<3, 3> {
+        // adjugate divided by determinant
+        let m = |r, c| mat.get_elem([r, c]);
+        let det = Self::determinant(mat);
+        S::Matrix::from_array2([
+            [
+                m(1, 1) * m(2, 2) - m(1, 2) * m(2, 1),
+                m(0, 2) * m(2, 1) - m(0, 1) * m(2, 2),
+                m(0, 1) * m(1, 2) - m(0, 2) * m(1, 1),
+            ],
+            [
+                m(1, 2) * m(2, 0) - m(1, 0) * m(2, 2),
+                m(0, 0) * m(2, 2) - m(0, 2) * m(2, 0),
+                m(0, 2) * m(1, 0) - m(0, 0) * m(1, 2),
+            ],
+            [
+                m(1, 0) * m(2, 1) - m(1, 1) * m(2, 0),
+                m(0, 1) * m(2, 0) - m(0, 0) * m(2, 1),
+                m(0, 0) * m(1, 1) - m(0, 1) * m(1, 0),
+            ],
+        ])
+        .scaled(S::ones() / det)
+    }
+
+    /// Matrix exponential, plus its directional derivatives along `dx`.
+    ///
+    /// Uses scaling and squaring with a Taylor expansion of order 12.
+    fn exp_matrix_and_derivatives<const N: usize>(
+        x: &S::Matrix<3, 3>,
+        dx: [S::Matrix<3, 3>; N],
+    ) -> (S::Matrix<3, 3>, [S::Matrix<3, 3>; N]) {
+        let norm = Self::squared_frobenius_norm(x).sqrt();
+        let mut num_squarings = 0;
+        let mut scale = 1.0;
+        while num_squarings < 64
+            && (norm.clone() * S::from_f64(scale))
+                .greater_equal(&S::from_f64(0.5))
+                .any()
+        {
+            num_squarings += 1;
+            scale *= 0.5;
+        }
+        let x = x.scaled(S::from_f64(scale));
+        let dx = dx.map(|d| d.scaled(S::from_f64(scale)));
+
+        let mut exp_x = S::Matrix::<3, 3>::identity();
+        let mut dexp_x: [S::Matrix<3, 3>; N] = core::array::from_fn(|_| S::Matrix::zeros());
+        let mut term = S::Matrix::<3, 3>::identity();
+        let mut dterm = dexp_x.clone();
+        for n in 1..=12 {
+            let inv_n = S::from_f64(1.0 / n as f64);
+            for i in 0..dx.len() {
+                dterm[i] = (dterm[i].mat_mul(x.clone()) + term.mat_mul(dx[i].clone()))
+                    .scaled(inv_n.clone());
+                dexp_x[i] = dexp_x[i].clone() + dterm[i].clone();
+            }
+            term = term.mat_mul(x.clone()).scaled(inv_n);
+            exp_x = exp_x + term.clone();
+        }
+
+        for _ in 0..num_squarings {
+            for d in dexp_x.iter_mut() {
+                *d = d.mat_mul(exp_x.clone()) + exp_x.mat_mul(d.clone());
+            }
+            exp_x = exp_x.mat_mul(exp_x.clone());
+        }
+        (exp_x, dexp_x)
+    }
+
+    /// Principal matrix logarithm, plus its directional derivatives along `dmat`.
+    ///
+    /// Uses inverse scaling and squaring: square roots are taken using the Denman–Beavers
+    /// iteration until the matrix is close to identity, then the Mercator series is applied.
+    fn log_matrix_and_derivatives<const N: usize>(
+        mat: &S::Matrix<3, 3>,
+        dmat: [S::Matrix<3, 3>; N],
+    ) -> (S::Matrix<3, 3>, [S::Matrix<3, 3>; N]) {
+        let identity = S::Matrix::<3, 3>::identity();
+        let mut a = mat.clone();
+        let mut da = dmat;
+        let mut num_square_roots = 0;
+
+        while num_square_roots < 32
+            && Self::squared_frobenius_norm(&(a.clone() - identity.clone()))
+                .greater_equal(&S::from_f64(0.25 * 0.25))
+                .any()
+        {
+            let mut y = a.clone();
+            let mut z = identity.clone();
+            let mut dy = da.clone();
+            let mut dz: [S::Matrix<3, 3>; N] = core::array::from_fn(|_| S::Matrix::zeros());
+            for _ in 0..64 {
+                let y_inv = Self::inverse_matrix(&y);
+                let z_inv = Self::inverse_matrix(&z);
+                let half = S::from_f64(0.5);
+                for i in 0..da.len() {
+                    let dy_i = (dy[i].clone()
+                        - z_inv.mat_mul(dz[i].clone()).mat_mul(z_inv.clone()))
+                    .scaled(half.clone());
+                    dz[i] = (dz[i].clone() - y_inv.mat_mul(dy[i].clone()).mat_mul(y_inv.clone()))
+                        .scaled(half.clone());
+                    dy[i] = dy_i;
+                }
+                let y_next = (y.clone() + z_inv).scaled(half.clone());
+                z = (z + y_inv).scaled(half);
+                let converged = Self::squared_frobenius_norm(&(y_next.clone() - y))
+                    .less_equal(&S::from_f64(1e-28))
+                    .all();
+                y = y_next;
+                if converged {
+                    break;
+                }
+            }
+            a = y;
+            da = dy;
+            num_square_roots += 1;
+        }
+
+        // log(I + X) = X - X²/2 + X³/3 - ...
+        let x = a - identity;
+        let mut log_a = S::Matrix::<3, 3>::zeros();
+        let mut dlog_a: [S::Matrix<3, 3>; N] = core::array::from_fn(|_| S::Matrix::zeros());
+        let mut x_pow = x.clone();
+        let mut dx_pow = da.clone();
+        for n in 1..=24 {
+            let sign = if n % 2 == 1 { 1.0 } else { -1.0 };
+            let factor = S::from_f64(sign / n as f64);
+            log_a = log_a + x_pow.scaled(factor.clone());
+            for i in 0..da.len() {
+                dlog_a[i] = dlog_a[i].clone() + dx_pow[i].scaled(factor.clone());
+                dx_pow[i] = dx_pow[i].mat_mul(x.clone()) + x_pow.mat_mul(da[i].clone());
+            }
+            x_pow = x_pow.mat_mul(x.clone());
+        }
+
+        let scale = S::from_f64((1_u64 << num_square_roots) as f64);
+        (
+            log_a.scaled(scale.clone()),
+            dlog_a.map(|d| d.scaled(scale.clone())),
+        )
+    }
+}
+
+impl<S: IsScalar<BATCH>, const BATCH: usize> ParamsImpl<S, 9, BATCH>
+    for SpecialLinear3Impl<S, BATCH>
+{
+    #[cfg(feature = "alloc")]
+    fn params_examples() -> Vec<S::Vector<9>> {
+        vec![
+            SpecialLinear3::<S, BATCH>::exp(&S::Vector::<8>::zeros())
+                .params()
+                .clone(),
+            SpecialLinear3::<S, BATCH>::exp(&S::Vector::<8>::from_f64_array([
+                0.1, 0.2, 0.3, 0.05, -0.1, 0.1, 0.01, -0.02,
+            ]))
+            .params()
+            .clone(),
+            SpecialLinear3::<S, BATCH>::exp(&S::Vector::<8>::from_f64_array([
+                -0.3, 0.5, -0.7, 0.1, 0.2, -0.1, 0.05, 0.03,
+            ]))
+            .params()
+            .clone(),
+            SpecialLinear3::<S, BATCH>::exp(&S::Vector::<8>::from_f64_array([
+                1.0, 0.0, 0.5, -0.2, 0.0, 0.3, -0.1, 0.1,
+            ]))
+            .params()
+            .clone(),
+        ]
+    }
+
+    #[cfg(feature = "alloc")]
+    fn invalid_params_examples() -> Vec<S::Vector<9>> {
+        vec![
+            S::Vector::<9>::zeros(),
+            S::Vector::<9>::from_f64_array([2.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 2.0]),
+            S::Vector::<9>::from_f64_array([1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0]),
+        ]
+    }
+
+    fn are_params_valid(params: &S::Vector<9>) -> S::Mask {
+        (Self::determinant(&Self::matrix(params)) - S::from_f64(1.0))
+            .abs()
+            .less_equal(&S::from_f64(1e-6))
+    }
+}
+
+impl<S: IsScalar<BATCH>, const BATCH: usize> TangentImpl<S, 8, BATCH>
+    for SpecialLinear3Impl<S, BATCH>
+{
+    #[cfg(feature = "alloc")]
+    fn tangent_examples() -> Vec<S::Vector<8>> {
+        vec![
+            S::Vector::<8>::from_f64_array([0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]),
+            S::Vector::<8>::from_f64_array([1.0, 0.5, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]),
+            S::Vector::<8>::from_f64_array([0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0]),
+            S::Vector::<8>::from_f64_array([0.0, 0.0, 0.0, 0.3, -0.2, 0.1, 0.0, 0.0]),
+            S::Vector::<8>::from_f64_array([0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.2, -0.1]),
+            S::Vector::<8>::from_f64_array([0.5, -0.2, 0.4, 0.1, 0.1, -0.2, 0.05, 0.1]),
+        ]
+    }
+}
+
+impl<S: IsScalar<BATCH>, const BATCH: usize> IsLieGroupImpl<S, 8, 9, 3, 3, BATCH>
+    for SpecialLinear3Impl<S, BATCH>
+{
+    type GenG<S2: IsScalar<BATCH>> = SpecialLinear3Impl<S2, BATCH>;
+    type RealG = SpecialLinear3Impl<S::RealScalar, BATCH>;
+    #[cfg(feature = "alloc")]
+    type DualG = SpecialLinear3Impl<S::DualScalar, BATCH>;
+
+    const IS_ORIGIN_PRESERVING: bool = true;
+    const IS_AXIS_DIRECTION_PRESERVING: bool = false;
+    const IS_DIRECTION_VECTOR_PRESERVING: bool = false;
+    const IS_SHAPE_PRESERVING: bool = false;
+    const IS_DISTANCE_PRESERVING: bool = false;
+    const IS_PARALLEL_LINE_PRESERVING: bool = false;
+
+    #[cfg(feature = "alloc")]
+    fn params_constraint() -> String {
+        "unit determinant, det(A) = 1 (within 1e-6)".to_owned()
+    }
+
+    fn identity_params() -> S::Vector<9> {
+        S::Vector::<9>::from_f64_array([1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0])
+    }
+
+    fn adj(params: &S::Vector<9>) -> S::Matrix<8, 8> {
+        let mat = Self::matrix(params);
+        let mat_inv = Self::inverse_matrix(&mat);
+        Self::matrix_from_columns(
+            Self::generators()
+                .map(|generator| Self::vee(&mat.mat_mul(generator).mat_mul(mat_inv.clone()))),
+        )
+    }
+
+    fn ad(tangent: &S::Vector<8>) -> S::Matrix<8, 8> {
+        let hat = Self::hat(tangent);
+        Self::matrix_from_columns(Self::generators().map(|generator| {
+            Self::vee(&(hat.mat_mul(generator.clone()) - generator.mat_mul(hat.clone())))
+        }))
+    }
+
+    fn jl(tangent: &S::Vector<8>) -> S::Matrix<8, 8> {
+        left_jacobian_from_series::<S, 8, BATCH>(&Self::ad(tangent), SERIES_ORDER)
+    }
+
+    fn jl_inv(tangent: &S::Vector<8>) -> S::Matrix<8, 8> {
+        left_jacobian_inverse_from_series::<S, 8, BATCH>(&Self::ad(tangent), SERIES_ORDER)
+    }
+
+    fn exp(omega: &S::Vector<8>) -> S::Vector<9> {
+        Self::params_from_matrix(&Self::exp_matrix_and_derivatives(&Self::hat(omega), []).0)
+    }
+
+    fn log(params: &S::Vector<9>) -> S::Vector<8> {
+        Self::vee(&Self::log_matrix_and_derivatives(&Self::matrix(params), []).0)
+    }
+
+    fn hat(omega: &S::Vector<8>) -> S::Matrix<3, 3> {
+        let o = |i| omega.get_elem(i);
+        S::Matrix::from_array2([
+            [o(3) + o(4), -o(2) + o(5), o(0)],
+            [o(2) + o(5), o(3) - o(4), o(1)],
+            [o(6), o(7), S::from_f64(-2.0) * o(3)],
+        ])
+    }
+
+    fn vee(hat: &S::Matrix<3, 3>) -> S::Vector<8> {
+        let m = |r, c| hat.get_elem([r, c]);
+        let half = S::from_f64(0.5);
+        S::Vector::from_array([
+            m(0, 2),
+            m(1, 2),
+            half.clone() * (m(1, 0) - m(0, 1)),
+            S::from_f64(-0.5) * m(2, 2),
+            half.clone() * (m(0, 0) - m(1, 1)),
+            half * (m(1, 0) + m(0, 1)),
+            m(2, 0),
+            m(2, 1),
+        ])
+    }
+
+    fn group_mul(params1: &S::Vector<9>, params2: &S::Vector<9>) -> S::Vector<9> {
+        let mut mat = Self::matrix(params1).mat_mul(Self::matrix(params2));
+
+        let det = Self::determinant(&mat);
+        if (det.clone() - S::from_f64(1.0))
+            .abs()
+            .greater_equal(&S::from_f64(1e-7))
+            .any()
+        {
+            // project back onto SL(3) by dividing by the cube root of the determinant
+            mat = mat.scaled((S::from_f64(-1.0 / 3.0) * det.ln()).exp());
+        }
+        Self::params_from_matrix(&mat)
+    }
+
+    fn inverse(params: &S::Vector<9>) -> S::Vector<9> {
+        Self::params_from_matrix(&Self::inverse_matrix(&Self::matrix(params)))
+    }
+
+    fn transform(params: &S::Vector<9>, point: &S::Vector<3>) -> S::Vector<3> {
+        Self::matrix(params) * point.clone()
+    }
+
+    fn to_ambient(point: &S::Vector<3>) -> S::Vector<3> {
+        // already in homogeneous coordinates
+        point.clone()
+    }
+
+    fn compact(params: &S::Vector<9>) -> S::Matrix<3, 3> {
+        Self::matrix(params)
+    }
+
+    fn matrix(params: &S::Vector<9>) -> S::Matrix<3, 3> {
+        let p = |i| params.get_elem(i);
+        S::Matrix::from_array2([[p(0), p(3), p(6)], [p(1), p(4), p(7)], [p(2), p(5), p(8)]])
+    }
+}
+
+impl<S: IsRealScalar<BATCH>, const BATCH: usize> IsRealLieGroupImpl<S, 8, 9, 3, 3, BATCH>
+    for SpecialLinear3Impl<S, BATCH>
+{
+    fn dx_exp_x_at_0() -> S::Matrix<9, 8> {
+        let mut dx = S::Matrix::<9, 8>::zeros();
+        for (i, generator) in Self::generators().iter().enumerate() {
+            dx.set_col_vec(i, Self::params_from_matrix(generator));
+        }
+        dx
+    }
+
+    fn dx_exp_x_times_point_at_0(point: S::Vector<3>) -> S::Matrix<3, 8> {
+        let mut dx = S::Matrix::<3, 8>::zeros();
+        for (i, generator) in Self::generators().into_iter().enumerate() {
+            dx.set_col_vec(i, generator * point.clone());
+        }
+        dx
+    }
+
+    fn dx_exp(tangent: &S::Vector<8>) -> S::Matrix<9, 8> {
+        let (_, dexp) = Self::exp_matrix_and_derivatives(&Self::hat(tangent), Self::generators());
+        let mut dx = S::Matrix::<9, 8>::zeros();
+        for (i, d) in dexp.iter().enumerate() {
+            dx.set_col_vec(i, Self::params_from_matrix(d));
+        }
+        dx
+    }
+
+    fn dx_log_x(params: &S::Vector<9>) -> S::Matrix<8, 9> {
+        // column-major unit directions, matching the parameter layout
+        let directions: [S::Matrix<3, 3>; 9] = core::array::from_fn(|j| {
+            let mut e = S::Matrix::<3, 3>::zeros();
+            e.set_elem([j % 3, j / 3], S::ones());
+            e
+        });
+        let (_, dlog) = Self::log_matrix_and_derivatives(&Self::matrix(params), directions);
+        let mut dx = S::Matrix::<8, 9>::zeros();
+        for (j, d) in dlog.iter().enumerate() {
+            dx.set_col_vec(j, Self::vee(d));
+        }
+        dx
+    }
+
+    fn da_a_mul_b(_a: &S::Vector<9>, b: &S::Vector<9>) -> S::Matrix<9, 9> {
+        // (AB)_rc = sum_k A_rk B_kc
+        let mat_b = Self::matrix(b);
+        let mut dx = S::Matrix::<9, 9>::zeros();
+        for r in 0..3 {
+            for c in 0..3 {
+                for k in 0..3 {
+                    dx.set_elem([3 * c + r, 3 * k + r], mat_b.get_elem([k, c]));
+                }
+            }
+        }
+        dx
+    }
+
+    fn db_a_mul_b(a: &S::Vector<9>, _b: &S::Vector<9>) -> S::Matrix<9, 9> {
+        let mat_a = Self::matrix(a);
+        let mut dx = S::Matrix::<9, 9>::zeros();
+        for r in 0..3 {
+            for c in 0..3 {
+                for k in 0..3 {
+                    dx.set_elem([3 * c + r, 3 * c + k], mat_a.get_elem([r, k]));
+                }
+            }
+        }
+        dx
+    }
+
+    fn has_shortest_path_ambiguity(params: &S::Vector<9>) -> S::Mask {
+        // The principal logarithm does not exist for matrices with negative real eigenvalues,
+        // which is detected by a failed exp/log round trip.
+        let round_trip = Self::exp(&Self::log(params));
+        Self::squared_frobenius_norm(&(Self::matrix(&round_trip) - Self::matrix(params)))
+            .greater_equal(&S::from_f64(1e-10))
+    }
+}
+
+/// 3x3 special linear group - SL(3), i.e. homographies acting on homogeneous 2d points
+pub type SpecialLinear3<S, const BATCH: usize> =
+    LieGroup<S, 8, 9, 3, 3, BATCH, SpecialLinear3Impl<S, BATCH>>;
+
+#[cfg(feature = "alloc")]
+impl SpecialLinear3<f64, 1> {
+    /// create element from a 3x3 matrix with unit determinant
+    ///
+    /// Returns an error if the determinant deviates from one by more than 1e-6.
+    pub fn try_from_matrix(mat: &MatF64<3, 3>) -> Result<Self, LieGroupError> {
+        // the parameters are the matrix entries in column-major order
+        let params = VecF64::<9>::from_column_slice(mat.as_slice());
+        if !SpecialLinear3Impl::<f64, 1>::are_params_valid(&params) {
+            return Err(LieGroupError::InvalidMatrix {
+                matrix: format!("{:?}", mat),
+                constraint: SpecialLinear3Impl::<f64, 1>::params_constraint(),
+            });
+        }
+        Ok(Self::from_params(&params))
+    }
+}
+
+#[test]
+fn special_linear3_prop_tests() {
+    use crate::real_lie_group::RealLieGroupTest;
+    use sophus_core::calculus::dual::dual_scalar::DualScalar;
+    use sophus_core::calculus::dual::DualBatchScalar;
+    use sophus_core::linalg::BatchScalarF64;
+
+    SpecialLinear3::<f64, 1>::test_suite();
+    SpecialLinear3::<f32, 1>::test_suite();
+    SpecialLinear3::<BatchScalarF64<8>, 8>::test_suite();
+    SpecialLinear3::<DualScalar, 1>::test_suite();
+    SpecialLinear3::<DualBatchScalar<8>, 8>::test_suite();
+
+    SpecialLinear3::<f64, 1>::run_real_tests();
+    SpecialLinear3::<BatchScalarF64<8>, 8>::run_real_tests();
+}
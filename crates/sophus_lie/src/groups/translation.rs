@@ -0,0 +1,217 @@
+use crate::lie_group::LieGroup;
+use crate::prelude::*;
+use crate::traits::IsLieGroupImpl;
+use crate::traits::IsRealLieGroupImpl;
+#[cfg(feature = "alloc")]
+use alloc::borrow::ToOwned;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use sophus_core::manifold::traits::TangentImpl;
+use sophus_core::params::ParamsImpl;
+#[cfg(feature = "alloc")]
+use sophus_core::points::example_points;
+
+/// translation group implementation struct - R^n
+///
+/// The commutative group of translations of the Euclidean vector space, with AMBIENT = POINT + 1.
+/// Its parameters and its tangent vector are the translation itself.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct TranslationGroupImpl<
+    S: IsScalar<BATCH>,
+    const POINT: usize,
+    const AMBIENT: usize,
+    const BATCH: usize,
+> {
+    phantom: PhantomData<S>,
+}
+
+impl<S: IsScalar<BATCH>, const POINT: usize, const AMBIENT: usize, const BATCH: usize>
+    ParamsImpl<S, POINT, BATCH> for TranslationGroupImpl<S, POINT, AMBIENT, BATCH>
+{
+    fn are_params_valid(_params: &S::Vector<POINT>) -> S::Mask {
+        S::Mask::all_true()
+    }
+
+    #[cfg(feature = "alloc")]
+    fn params_examples() -> Vec<S::Vector<POINT>> {
+        example_points::<S, POINT, BATCH>()
+    }
+
+    #[cfg(feature = "alloc")]
+    fn invalid_params_examples() -> Vec<S::Vector<POINT>> {
+        vec![]
+    }
+}
+
+impl<S: IsScalar<BATCH>, const POINT: usize, const AMBIENT: usize, const BATCH: usize>
+    TangentImpl<S, POINT, BATCH> for TranslationGroupImpl<S, POINT, AMBIENT, BATCH>
+{
+    #[cfg(feature = "alloc")]
+    fn tangent_examples() -> Vec<S::Vector<POINT>> {
+        let mut examples = vec![S::Vector::zeros()];
+        examples.extend(example_points::<S, POINT, BATCH>());
+        examples
+    }
+}
+
+impl<S: IsScalar<BATCH>, const POINT: usize, const AMBIENT: usize, const BATCH: usize>
+    IsLieGroupImpl<S, POINT, POINT, POINT, AMBIENT, BATCH>
+    for TranslationGroupImpl<S, POINT, AMBIENT, BATCH>
+{
+    type GenG<S2: IsScalar<BATCH>> = TranslationGroupImpl<S2, POINT, AMBIENT, BATCH>;
+    type RealG = TranslationGroupImpl<S::RealScalar, POINT, AMBIENT, BATCH>;
+    #[cfg(feature = "alloc")]
+    type DualG = TranslationGroupImpl<S::DualScalar, POINT, AMBIENT, BATCH>;
+
+    const IS_ORIGIN_PRESERVING: bool = false;
+    const IS_AXIS_DIRECTION_PRESERVING: bool = true;
+    const IS_DIRECTION_VECTOR_PRESERVING: bool = true;
+    const IS_SHAPE_PRESERVING: bool = true;
+    const IS_DISTANCE_PRESERVING: bool = true;
+    const IS_PARALLEL_LINE_PRESERVING: bool = true;
+
+    #[cfg(feature = "alloc")]
+    fn params_constraint() -> String {
+        "none, all parameters are valid".to_owned()
+    }
+
+    fn identity_params() -> S::Vector<POINT> {
+        S::Vector::zeros()
+    }
+
+    fn adj(_params: &S::Vector<POINT>) -> S::Matrix<POINT, POINT> {
+        S::Matrix::identity()
+    }
+
+    fn ad(_tangent: &S::Vector<POINT>) -> S::Matrix<POINT, POINT> {
+        S::Matrix::zeros()
+    }
+
+    fn exp(omega: &S::Vector<POINT>) -> S::Vector<POINT> {
+        omega.clone()
+    }
+
+    fn log(params: &S::Vector<POINT>) -> S::Vector<POINT> {
+        params.clone()
+    }
+
+    fn hat(omega: &S::Vector<POINT>) -> S::Matrix<AMBIENT, AMBIENT> {
+        S::Matrix::block_mat2x2::<POINT, 1, POINT, 1>(
+            (S::Matrix::zeros(), omega.clone().to_mat()),
+            (S::Matrix::zeros(), S::Matrix::zeros()),
+        )
+    }
+
+    fn vee(hat: &S::Matrix<AMBIENT, AMBIENT>) -> S::Vector<POINT> {
+        hat.get_fixed_submat::<POINT, 1>(0, POINT).get_col_vec(0)
+    }
+
+    fn jl(_tangent: &S::Vector<POINT>) -> S::Matrix<POINT, POINT> {
+        S::Matrix::identity()
+    }
+
+    fn jl_inv(_tangent: &S::Vector<POINT>) -> S::Matrix<POINT, POINT> {
+        S::Matrix::identity()
+    }
+
+    fn group_mul(params1: &S::Vector<POINT>, params2: &S::Vector<POINT>) -> S::Vector<POINT> {
+        params1.clone() + params2.clone()
+    }
+
+    fn inverse(params: &S::Vector<POINT>) -> S::Vector<POINT> {
+        -params.clone()
+    }
+
+    fn transform(params: &S::Vector<POINT>, point: &S::Vector<POINT>) -> S::Vector<POINT> {
+        point.clone() + params.clone()
+    }
+
+    fn to_ambient(params: &S::Vector<POINT>) -> S::Vector<AMBIENT> {
+        S::Vector::block_vec2(params.clone(), S::Vector::<1>::zeros())
+    }
+
+    fn compact(params: &S::Vector<POINT>) -> S::Matrix<POINT, AMBIENT> {
+        S::Matrix::block_mat1x2::<POINT, 1>(S::Matrix::identity(), params.clone().to_mat())
+    }
+
+    fn matrix(params: &S::Vector<POINT>) -> S::Matrix<AMBIENT, AMBIENT> {
+        S::Matrix::block_mat2x2::<POINT, 1, POINT, 1>(
+            (S::Matrix::identity(), params.clone().to_mat()),
+            (
+                S::Matrix::<1, POINT>::zeros(),
+                S::Matrix::<1, 1>::identity(),
+            ),
+        )
+    }
+}
+
+impl<S: IsRealScalar<BATCH>, const POINT: usize, const AMBIENT: usize, const BATCH: usize>
+    IsRealLieGroupImpl<S, POINT, POINT, POINT, AMBIENT, BATCH>
+    for TranslationGroupImpl<S, POINT, AMBIENT, BATCH>
+{
+    fn da_a_mul_b(_a: &S::Vector<POINT>, _b: &S::Vector<POINT>) -> S::Matrix<POINT, POINT> {
+        S::Matrix::identity()
+    }
+
+    fn db_a_mul_b(_a: &S::Vector<POINT>, _b: &S::Vector<POINT>) -> S::Matrix<POINT, POINT> {
+        S::Matrix::identity()
+    }
+
+    fn dx_exp(_tangent: &S::Vector<POINT>) -> S::Matrix<POINT, POINT> {
+        S::Matrix::identity()
+    }
+
+    fn dx_exp_x_at_0() -> S::Matrix<POINT, POINT> {
+        S::Matrix::identity()
+    }
+
+    fn dx_log_x(_params: &S::Vector<POINT>) -> S::Matrix<POINT, POINT> {
+        S::Matrix::identity()
+    }
+
+    fn dx_exp_x_times_point_at_0(_point: S::Vector<POINT>) -> S::Matrix<POINT, POINT> {
+        S::Matrix::identity()
+    }
+
+    fn has_shortest_path_ambiguity(_params: &S::Vector<POINT>) -> S::Mask {
+        S::Mask::all_false()
+    }
+}
+
+/// 2d translation group implementation struct - R^2
+pub type Translation2Impl<S, const BATCH: usize> = TranslationGroupImpl<S, 2, 3, BATCH>;
+/// 2d translation group - R^2
+pub type Translation2<S, const BATCH: usize> =
+    LieGroup<S, 2, 2, 2, 3, BATCH, Translation2Impl<S, BATCH>>;
+
+/// 3d translation group implementation struct - R^3
+pub type Translation3Impl<S, const BATCH: usize> = TranslationGroupImpl<S, 3, 4, BATCH>;
+/// 3d translation group - R^3
+pub type Translation3<S, const BATCH: usize> =
+    LieGroup<S, 3, 3, 3, 4, BATCH, Translation3Impl<S, BATCH>>;
+
+#[test]
+fn translation_prop_tests() {
+    use crate::real_lie_group::RealLieGroupTest;
+    use sophus_core::calculus::dual::dual_scalar::DualScalar;
+
+    use sophus_core::calculus::dual::dual_batch_scalar::DualBatchScalar;
+    use sophus_core::linalg::BatchScalarF64;
+
+    Translation2::<f64, 1>::test_suite();
+    Translation2::<f32, 1>::test_suite();
+    Translation3::<f64, 1>::test_suite();
+    Translation3::<f32, 1>::test_suite();
+    Translation3::<BatchScalarF64<8>, 8>::test_suite();
+    Translation3::<DualScalar, 1>::test_suite();
+    Translation3::<DualBatchScalar<8>, 8>::test_suite();
+
+    Translation2::<f64, 1>::run_real_tests();
+    Translation3::<f64, 1>::run_real_tests();
+    Translation3::<BatchScalarF64<8>, 8>::run_real_tests();
+}
@@ -1,11 +1,21 @@
+use crate::groups::jacobian_details::left_jacobian_upper_right_block;
 use crate::lie_group::LieGroup;
 use crate::prelude::*;
 use crate::traits::IsLieFactorGroupImpl;
 use crate::traits::IsLieGroupImpl;
 use crate::traits::IsRealLieFactorGroupImpl;
 use crate::traits::IsRealLieGroupImpl;
+#[cfg(feature = "alloc")]
+use alloc::format;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 use sophus_core::manifold::traits::TangentImpl;
 use sophus_core::params::ParamsImpl;
+#[cfg(feature = "alloc")]
 use sophus_core::points::example_points;
 
 /// implementation of a translation product group
@@ -23,7 +33,7 @@ pub struct TranslationProductGroupImpl<
     const BATCH: usize,
     F: IsLieFactorGroupImpl<S, SDOF, SPARAMS, POINT, BATCH>,
 > {
-    phantom: std::marker::PhantomData<(S, F)>,
+    phantom: core::marker::PhantomData<(S, F)>,
 }
 
 impl<
@@ -74,6 +84,17 @@ impl<
         S::Vector::block_vec2(translation.clone(), factor_tangent.clone())
     }
 
+    /// upper right block Q of the left Jacobian [[V, Q], [0, J]]
+    fn jl_translation_block(tangent: &S::Vector<DOF>) -> S::Matrix<POINT, SDOF> {
+        let factor_tangent = Self::factor_tangent(tangent);
+        left_jacobian_upper_right_block::<S, POINT, SDOF, BATCH>(
+            &F::hat(&factor_tangent),
+            &F::ad_of_translation(&Self::translation_tangent(tangent)),
+            &F::ad(&factor_tangent),
+        )
+    }
+
+    #[cfg(feature = "alloc")]
     fn translation_examples() -> Vec<S::Vector<POINT>> {
         example_points::<S, POINT, BATCH>()
     }
@@ -96,6 +117,7 @@ impl<
         F::are_params_valid(&Self::factor_params(params))
     }
 
+    #[cfg(feature = "alloc")]
     fn params_examples() -> Vec<S::Vector<PARAMS>> {
         let mut examples = vec![];
         for factor_params in F::params_examples() {
@@ -106,6 +128,7 @@ impl<
         examples
     }
 
+    #[cfg(feature = "alloc")]
     fn invalid_params_examples() -> Vec<S::Vector<PARAMS>> {
         vec![Self::params_from(
             &S::Vector::zeros(),
@@ -127,6 +150,7 @@ impl<
     > TangentImpl<S, DOF, BATCH>
     for TranslationProductGroupImpl<S, DOF, PARAMS, POINT, AMBIENT, SDOF, SPARAMS, BATCH, F>
 {
+    #[cfg(feature = "alloc")]
     fn tangent_examples() -> Vec<S::Vector<DOF>> {
         let mut examples = vec![];
         for group_tangent in F::tangent_examples() {
@@ -158,6 +182,11 @@ impl<
     const IS_DISTANCE_PRESERVING: bool = Factor::IS_DISTANCE_PRESERVING;
     const IS_PARALLEL_LINE_PRESERVING: bool = true;
 
+    #[cfg(feature = "alloc")]
+    fn params_constraint() -> String {
+        format!("factor: {}", Factor::params_constraint())
+    }
+
     fn identity_params() -> S::Vector<PARAMS> {
         Self::params_from(&S::Vector::zeros(), &Factor::identity_params())
     }
@@ -275,6 +304,31 @@ impl<
         )
     }
 
+    fn jl(tangent: &S::Vector<DOF>) -> S::Matrix<DOF, DOF> {
+        let factor_tangent = Self::factor_tangent(tangent);
+        S::Matrix::block_mat2x2::<POINT, SDOF, POINT, SDOF>(
+            (
+                Factor::mat_v(&factor_tangent),
+                Self::jl_translation_block(tangent),
+            ),
+            (S::Matrix::zeros(), Factor::jl(&factor_tangent)),
+        )
+    }
+
+    fn jl_inv(tangent: &S::Vector<DOF>) -> S::Matrix<DOF, DOF> {
+        // [[V, Q], [0, J]]⁻¹ = [[V⁻¹, -V⁻¹·Q·J⁻¹], [0, J⁻¹]]
+        let factor_tangent = Self::factor_tangent(tangent);
+        let mat_v_inv = Factor::mat_v_inverse(&factor_tangent);
+        let factor_jl_inv = Factor::jl_inv(&factor_tangent);
+        let block = -mat_v_inv
+            .mat_mul(Self::jl_translation_block(tangent))
+            .mat_mul(factor_jl_inv.clone());
+        S::Matrix::block_mat2x2::<POINT, SDOF, POINT, SDOF>(
+            (mat_v_inv, block),
+            (S::Matrix::zeros(), factor_jl_inv),
+        )
+    }
+
     type GenG<S2: IsScalar<BATCH>> = TranslationProductGroupImpl<
         S2,
         DOF,
@@ -299,6 +353,7 @@ impl<
         Factor::GenFactorG<S::RealScalar>,
     >;
 
+    #[cfg(feature = "alloc")]
     type DualG = TranslationProductGroupImpl<
         S::DualScalar,
         DOF,
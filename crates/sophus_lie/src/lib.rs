@@ -1,14 +1,32 @@
+#![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(feature = "simd", feature(portable_simd))]
 #![deny(missing_docs)]
 #![allow(clippy::needless_range_loop)]
 //! Lie groups crate - part of the sophus-rs project
+//!
+//! Without the default `std` feature, the crate is `no_std`: the f64 Lie groups and their
+//! analytic Jacobians are always available, while splines and the dual number based Jacobians
+//! need the `alloc` feature, and random sampling needs `std`.
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 /// Lie groups
 pub mod groups;
+pub use crate::groups::direct_product::Isometry3xIsometry3;
+pub use crate::groups::direct_product::Rotation3xTranslation3;
+pub use crate::groups::extended_isometry3::ExtendedIsometry3;
 pub use crate::groups::isometry2::Isometry2;
 pub use crate::groups::isometry3::Isometry3;
 pub use crate::groups::rotation2::Rotation2;
 pub use crate::groups::rotation3::Rotation3;
+pub use crate::groups::rxso2::RxSO2;
+pub use crate::groups::rxso3::RxSO3;
+pub use crate::groups::sim2::Sim2;
+pub use crate::groups::sim3::Sim3;
+pub use crate::groups::special_linear3::SpecialLinear3;
+pub use crate::groups::translation::Translation2;
+pub use crate::groups::translation::Translation3;
 
 /// Lie groups
 pub mod lie_group;
@@ -17,12 +35,51 @@ pub use crate::lie_group::LieGroup;
 /// Lie groups
 pub mod factor_lie_group;
 
+/// Lie bracket, Baker-Campbell-Hausdorff series and dexp series
+pub mod lie_algebra;
+
 /// Lie group as a manifold
 pub mod lie_group_manifold;
 
+/// Lie group B-splines
+#[cfg(feature = "alloc")]
+pub mod lie_group_spline;
+
+/// Least-squares fitting of Lie group B-splines to timestamped poses
+#[cfg(feature = "alloc")]
+pub mod lie_group_spline_fit;
+
+/// Fréchet mean of Lie group elements
+pub mod lie_group_mean;
+
+/// Lie group error type and fallible constructors
+#[cfg(feature = "alloc")]
+pub mod lie_group_error;
+#[cfg(feature = "alloc")]
+pub use crate::lie_group_error::LieGroupError;
+
+/// Lie group element with covariance
+pub mod lie_group_with_covariance;
+pub use crate::lie_group_with_covariance::LieGroupWithCovariance;
+
+/// Random sampling on Lie groups
+#[cfg(feature = "std")]
+pub mod lie_group_sampling;
+
+/// Conversions from and to nalgebra geometry types
+#[cfg(feature = "alloc")]
+pub mod nalgebra_interop;
+
+/// Kabsch / Umeyama point-set alignment
+pub mod point_alignment;
+
 /// Lie group traits
 pub mod traits;
 
+/// Conformance test kit for Lie group implementations
+#[cfg(feature = "conformance")]
+pub mod conformance;
+
 /// Real lie group
 pub mod real_lie_group;
 
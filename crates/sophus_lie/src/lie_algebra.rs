@@ -0,0 +1,274 @@
+#[cfg(feature = "alloc")]
+use crate::groups::jacobian_details::bernoulli_by_factorial;
+use crate::groups::jacobian_details::left_jacobian_from_series;
+use crate::groups::jacobian_details::left_jacobian_inverse_from_series;
+#[cfg(feature = "alloc")]
+use crate::groups::jacobian_details::MAX_SERIES_ORDER;
+use crate::lie_group::LieGroup;
+use crate::prelude::*;
+use crate::traits::IsLieGroupImpl;
+#[cfg(feature = "alloc")]
+use alloc::vec;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// all compositions of n into `num_parts` positive integers
+#[cfg(feature = "alloc")]
+fn compositions(n: usize, num_parts: usize) -> Vec<Vec<usize>> {
+    if num_parts == 1 {
+        return vec![vec![n]];
+    }
+    let mut result = vec![];
+    for first in 1..=(n + 1).saturating_sub(num_parts) {
+        for mut rest in compositions(n - first, num_parts - 1) {
+            rest.insert(0, first);
+            result.push(rest);
+        }
+    }
+    result
+}
+
+impl<
+        S: IsScalar<BATCH>,
+        const DOF: usize,
+        const PARAMS: usize,
+        const POINT: usize,
+        const AMBIENT: usize,
+        const BATCH: usize,
+        G: IsLieGroupImpl<S, DOF, PARAMS, POINT, AMBIENT, BATCH>,
+    > LieGroup<S, DOF, PARAMS, POINT, AMBIENT, BATCH, G>
+{
+    /// Lie bracket of two tangent vectors
+    ///
+    /// [a, b] = vee(hat(a)·hat(b) - hat(b)·hat(a)) = ad(a)·b
+    pub fn bracket(a: &S::Vector<DOF>, b: &S::Vector<DOF>) -> S::Vector<DOF> {
+        G::ad(a) * b.clone()
+    }
+
+    /// Baker-Campbell-Hausdorff series, truncated after the terms of the given order
+    ///
+    /// Approximates log(exp(a)·exp(b)) by the sum of all terms of order 1 to `order` in a and b:
+    ///
+    ///   a + b + ½[a, b] + 1/12([a, [a, b]] + [b, [b, a]]) - 1/24 [b, [a, [a, b]]] + …
+    ///
+    /// The homogeneous terms Zₙ are computed with Varadarajan's recursion, starting at
+    /// Z₁ = a + b:
+    ///
+    ///   (n+1)·Zₙ₊₁ = ½[a - b, Zₙ] + Σₚ B₂ₚ/(2p)! Σ [Zₖ₁, [… [Zₖ₂ₚ, a + b] …]]
+    ///
+    /// where the inner sum runs over all k₁ + … + k₂ₚ = n with kᵢ ≥ 1.
+    ///
+    /// Panics if order is zero or exceeds 64.
+    #[cfg(feature = "alloc")]
+    pub fn bch(a: &S::Vector<DOF>, b: &S::Vector<DOF>, order: usize) -> S::Vector<DOF> {
+        assert!(
+            order >= 1,
+            "the BCH series must at least include the first order"
+        );
+        assert!(
+            order <= MAX_SERIES_ORDER,
+            "BCH order {} exceeds the maximum of {}",
+            order,
+            MAX_SERIES_ORDER
+        );
+        let bernoulli_by_factorial = bernoulli_by_factorial();
+        let sum = a.clone() + b.clone();
+        let difference = a.clone() - b.clone();
+
+        // z[n - 1] holds the homogeneous term Zₙ
+        let mut z: Vec<S::Vector<DOF>> = vec![sum.clone()];
+        for n in 1..order {
+            let mut next = Self::bracket(&difference, &z[n - 1]).scaled(S::from_f64(0.5));
+            for p in 1..=n / 2 {
+                let coefficient = S::from_f64(bernoulli_by_factorial[2 * p]);
+                for composition in compositions(n, 2 * p) {
+                    let mut nested = sum.clone();
+                    for k in composition.iter().rev() {
+                        nested = Self::bracket(&z[k - 1], &nested);
+                    }
+                    next = next + nested.scaled(coefficient.clone());
+                }
+            }
+            z.push(next.scaled(S::from_f64(1.0 / (n + 1) as f64)));
+        }
+
+        let mut result = S::Vector::<DOF>::zeros();
+        for z_n in z {
+            result = result + z_n;
+        }
+        result
+    }
+
+    /// power series of the differential of the exponential map, truncated after ad(x)^order
+    ///
+    /// dexp(x) = Σₖ ad(x)ᵏ / (k+1)! = (exp(ad(x)) - I) / ad(x),
+    ///
+    /// which converges to the left Jacobian [LieGroup::jl] as the order increases.
+    pub fn dexp(tangent: &S::Vector<DOF>, order: usize) -> S::Matrix<DOF, DOF> {
+        left_jacobian_from_series::<S, DOF, BATCH>(&G::ad(tangent), order)
+    }
+
+    /// power series of the inverse of dexp, truncated after ad(x)^order
+    ///
+    /// dexp⁻¹(x) = Σₖ Bₖ/k! ad(x)ᵏ = ad(x) / (exp(ad(x)) - I),
+    ///
+    /// with the Bernoulli numbers Bₖ. It converges to [LieGroup::jl_inv] as the order
+    /// increases, provided the eigenvalues of ad(x) are smaller than 2π in magnitude.
+    ///
+    /// Panics if order exceeds 64.
+    pub fn dexp_inv(tangent: &S::Vector<DOF>, order: usize) -> S::Matrix<DOF, DOF> {
+        left_jacobian_inverse_from_series::<S, DOF, BATCH>(&G::ad(tangent), order)
+    }
+}
+
+#[test]
+fn lie_algebra_tests() {
+    use crate::groups::direct_product::Rotation3xTranslation3Impl;
+    use crate::groups::extended_isometry3::ExtendedIsometry3Impl;
+    use crate::groups::isometry2::Isometry2Impl;
+    use crate::groups::isometry3::Isometry3Impl;
+    use crate::groups::rotation2::Rotation2Impl;
+    use crate::groups::rotation3::Rotation3Impl;
+    use crate::groups::rxso2::RxSO2Impl;
+    use crate::groups::rxso3::RxSO3Impl;
+    use crate::groups::sim2::Sim2Impl;
+    use crate::groups::sim3::Sim3Impl;
+    use crate::groups::special_linear3::SpecialLinear3Impl;
+    use crate::groups::translation::Translation3Impl;
+    use approx::assert_relative_eq;
+    use sophus_core::linalg::VecF64;
+
+    // Bₙ / n!, with Bₙ being the Bernoulli numbers
+    let bernoulli_by_factorial = bernoulli_by_factorial();
+    let mut factorial = 1.0;
+    for (n, expected) in [
+        1.0,
+        -1.0 / 2.0,
+        1.0 / 6.0,
+        0.0,
+        -1.0 / 30.0,
+        0.0,
+        1.0 / 42.0,
+        0.0,
+        -1.0 / 30.0,
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        factorial *= n.max(1) as f64;
+        assert_relative_eq!(
+            bernoulli_by_factorial[n] * factorial,
+            expected,
+            epsilon = 1e-12
+        );
+    }
+    // B₂ₖ / (2k)! = (-1)ᵏ⁺¹·2·ζ(2k) / (2π)²ᵏ, with ζ(64) = 1 in double precision
+    assert_relative_eq!(
+        bernoulli_by_factorial[MAX_SERIES_ORDER],
+        -2.0 / (2.0 * core::f64::consts::PI).powi(64),
+        max_relative = 1e-9
+    );
+    assert_eq!(compositions(4, 2), vec![vec![1, 3], vec![2, 2], vec![3, 1]]);
+
+    fn run_lie_algebra_tests<
+        const DOF: usize,
+        const PARAMS: usize,
+        const POINT: usize,
+        const AMBIENT: usize,
+        G: IsLieGroupImpl<f64, DOF, PARAMS, POINT, AMBIENT, 1>,
+    >() {
+        type Group<
+            const DOF: usize,
+            const PARAMS: usize,
+            const POINT: usize,
+            const AMBIENT: usize,
+            G,
+        > = LieGroup<f64, DOF, PARAMS, POINT, AMBIENT, 1, G>;
+
+        // tangent examples, rescaled to the given norm
+        let scaled_examples = |norm: f64| -> Vec<VecF64<DOF>> {
+            G::tangent_examples()
+                .into_iter()
+                .filter(|x| x.norm() > 1e-6)
+                .map(|x| x * (norm / x.norm()))
+                .collect()
+        };
+
+        let examples = scaled_examples(0.1);
+        for (i, a) in examples.iter().enumerate() {
+            let b = &examples[(i + 1) % examples.len()];
+
+            let hat_a = Group::<DOF, PARAMS, POINT, AMBIENT, G>::hat(a);
+            let hat_b = Group::<DOF, PARAMS, POINT, AMBIENT, G>::hat(b);
+            assert_relative_eq!(
+                Group::<DOF, PARAMS, POINT, AMBIENT, G>::bracket(a, b),
+                Group::<DOF, PARAMS, POINT, AMBIENT, G>::vee(&(hat_a * hat_b - hat_b * hat_a)),
+                epsilon = 1e-10
+            );
+
+            let bracket_ab = Group::<DOF, PARAMS, POINT, AMBIENT, G>::bracket(a, b);
+            assert_relative_eq!(
+                Group::<DOF, PARAMS, POINT, AMBIENT, G>::bch(a, b, 2),
+                a + b + bracket_ab * 0.5,
+                epsilon = 1e-12
+            );
+            let bracket_a_ab = Group::<DOF, PARAMS, POINT, AMBIENT, G>::bracket(a, &bracket_ab);
+            let bracket_b_ab = Group::<DOF, PARAMS, POINT, AMBIENT, G>::bracket(b, &bracket_ab);
+            assert_relative_eq!(
+                Group::<DOF, PARAMS, POINT, AMBIENT, G>::bch(a, b, 4),
+                a + b + bracket_ab * 0.5 + (bracket_a_ab - bracket_b_ab) / 12.0
+                    - Group::<DOF, PARAMS, POINT, AMBIENT, G>::bracket(b, &bracket_a_ab) / 24.0,
+                epsilon = 1e-12
+            );
+
+            // the truncation error of order n is O(|a|ⁿ⁺¹ + |b|ⁿ⁺¹)
+            let exact = Group::<DOF, PARAMS, POINT, AMBIENT, G>::exp(a)
+                .group_mul(&Group::exp(b))
+                .log();
+            let mut previous_error = f64::INFINITY;
+            for order in 1..=6 {
+                let error =
+                    (Group::<DOF, PARAMS, POINT, AMBIENT, G>::bch(a, b, order) - exact).norm();
+                assert!(
+                    error <= 4.0 * 0.2_f64.powi(order as i32 + 1),
+                    "order {order}: {error}"
+                );
+                assert!(error <= previous_error + 1e-14);
+                previous_error = error;
+            }
+        }
+
+        // the series converge to the closed form left Jacobian and its inverse
+        for x in scaled_examples(0.5) {
+            assert_relative_eq!(
+                Group::<DOF, PARAMS, POINT, AMBIENT, G>::dexp(&x, 25),
+                Group::<DOF, PARAMS, POINT, AMBIENT, G>::jl(&x),
+                epsilon = 1e-9
+            );
+            assert_relative_eq!(
+                Group::<DOF, PARAMS, POINT, AMBIENT, G>::dexp_inv(&x, 25),
+                Group::<DOF, PARAMS, POINT, AMBIENT, G>::jl_inv(&x),
+                epsilon = 1e-9
+            );
+            assert_relative_eq!(
+                Group::<DOF, PARAMS, POINT, AMBIENT, G>::dexp(&x, 25)
+                    * Group::<DOF, PARAMS, POINT, AMBIENT, G>::dexp_inv(&x, 25),
+                nalgebra::SMatrix::<f64, DOF, DOF>::identity(),
+                epsilon = 1e-9
+            );
+        }
+    }
+
+    run_lie_algebra_tests::<1, 2, 2, 2, Rotation2Impl<f64, 1>>();
+    run_lie_algebra_tests::<3, 4, 3, 3, Rotation3Impl<f64, 1>>();
+    run_lie_algebra_tests::<2, 2, 2, 2, RxSO2Impl<f64, 1>>();
+    run_lie_algebra_tests::<4, 4, 3, 3, RxSO3Impl<f64, 1>>();
+    run_lie_algebra_tests::<3, 4, 2, 3, Isometry2Impl<f64, 1>>();
+    run_lie_algebra_tests::<6, 7, 3, 4, Isometry3Impl<f64, 1>>();
+    run_lie_algebra_tests::<4, 4, 2, 3, Sim2Impl<f64, 1>>();
+    run_lie_algebra_tests::<7, 7, 3, 4, Sim3Impl<f64, 1>>();
+    run_lie_algebra_tests::<9, 10, 3, 5, ExtendedIsometry3Impl<f64, 1>>();
+    run_lie_algebra_tests::<8, 9, 3, 3, SpecialLinear3Impl<f64, 1>>();
+    run_lie_algebra_tests::<3, 3, 3, 4, Translation3Impl<f64, 1>>();
+    run_lie_algebra_tests::<6, 7, 6, 7, Rotation3xTranslation3Impl<f64, 1>>();
+}
@@ -1,10 +1,16 @@
 use super::traits::IsLieGroupImpl;
 use crate::prelude::*;
+#[cfg(feature = "alloc")]
+use alloc::vec;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
 use approx::assert_relative_eq;
+#[cfg(feature = "std")]
 use assertables::assert_le_as_result;
+use core::fmt::Debug;
 use sophus_core::manifold::traits::TangentImpl;
 use sophus_core::params::ParamsImpl;
-use std::fmt::Debug;
 
 /// Lie group
 #[derive(Debug, Copy, Clone, Default)]
@@ -18,7 +24,7 @@ pub struct LieGroup<
     G: IsLieGroupImpl<S, DOF, PARAMS, POINT, AMBIENT, BATCH_SIZE>,
 > {
     pub(crate) params: S::Vector<PARAMS>,
-    phantom: std::marker::PhantomData<G>,
+    phantom: core::marker::PhantomData<G>,
 }
 
 impl<
@@ -36,10 +42,12 @@ impl<
         G::are_params_valid(params)
     }
 
+    #[cfg(feature = "alloc")]
     fn params_examples() -> Vec<S::Vector<PARAMS>> {
         G::params_examples()
     }
 
+    #[cfg(feature = "alloc")]
     fn invalid_params_examples() -> Vec<S::Vector<PARAMS>> {
         G::invalid_params_examples()
     }
@@ -63,7 +71,7 @@ impl<
         );
         Self {
             params: params.clone(),
-            phantom: std::marker::PhantomData,
+            phantom: core::marker::PhantomData,
         }
     }
 
@@ -76,6 +84,48 @@ impl<
     }
 }
 
+/// Serializes the group element as its parameter array, e.g. [w, x, y, z] for Rotation3.
+#[cfg(feature = "serde")]
+impl<
+        S: IsScalar<BATCH_SIZE>,
+        const DOF: usize,
+        const PARAMS: usize,
+        const POINT: usize,
+        const AMBIENT: usize,
+        const BATCH_SIZE: usize,
+        G: IsLieGroupImpl<S, DOF, PARAMS, POINT, AMBIENT, BATCH_SIZE>,
+    > serde::Serialize for LieGroup<S, DOF, PARAMS, POINT, AMBIENT, BATCH_SIZE, G>
+where
+    S::Vector<PARAMS>: serde::Serialize,
+{
+    fn serialize<Ser: serde::Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        self.params.serialize(serializer)
+    }
+}
+
+/// Deserializes the group element from its parameter array.
+///
+/// Fails if the parameters are invalid, see [LieGroup::try_from_params].
+#[cfg(feature = "serde")]
+impl<
+        'de,
+        S: IsScalar<BATCH_SIZE>,
+        const DOF: usize,
+        const PARAMS: usize,
+        const POINT: usize,
+        const AMBIENT: usize,
+        const BATCH_SIZE: usize,
+        G: IsLieGroupImpl<S, DOF, PARAMS, POINT, AMBIENT, BATCH_SIZE>,
+    > serde::Deserialize<'de> for LieGroup<S, DOF, PARAMS, POINT, AMBIENT, BATCH_SIZE, G>
+where
+    S::Vector<PARAMS>: serde::Deserialize<'de>,
+{
+    fn deserialize<De: serde::Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+        let params = S::Vector::<PARAMS>::deserialize(deserializer)?;
+        Self::try_from_params(&params).map_err(serde::de::Error::custom)
+    }
+}
+
 impl<
         S: IsScalar<BATCH_SIZE>,
         const DOF: usize,
@@ -86,6 +136,7 @@ impl<
         G: IsLieGroupImpl<S, DOF, PARAMS, POINT, AMBIENT, BATCH_SIZE>,
     > TangentImpl<S, DOF, BATCH_SIZE> for LieGroup<S, DOF, PARAMS, POINT, AMBIENT, BATCH_SIZE, G>
 {
+    #[cfg(feature = "alloc")]
     fn tangent_examples() -> Vec<<S as IsScalar<BATCH_SIZE>>::Vector<DOF>> {
         G::tangent_examples()
     }
@@ -105,6 +156,7 @@ impl<
     type G = G;
     type GenG<S2: IsScalar<BATCH_SIZE>> = G::GenG<S2>;
     type RealG = G::RealG;
+    #[cfg(feature = "alloc")]
     type DualG = G::DualG;
 
     type GenGroup<
@@ -112,6 +164,7 @@ impl<
         G2: IsLieGroupImpl<S2, DOF, PARAMS, POINT, AMBIENT, BATCH_SIZE>,
     > = LieGroup<S2, DOF, PARAMS, POINT, AMBIENT, BATCH_SIZE, G2>;
     type RealGroup = Self::GenGroup<S::RealScalar, G::RealG>;
+    #[cfg(feature = "alloc")]
     type DualGroup = Self::GenGroup<S::DualScalar, G::DualG>;
 
     const DOF: usize = DOF;
@@ -173,6 +226,13 @@ impl<
         Self::from_params(&G::inverse(&self.params))
     }
 
+    /// geodesic interpolation: a · exp(t · log(a⁻¹ · b))
+    ///
+    /// Returns a for t=0 and b for t=1.
+    pub fn interpolate(a: &Self, b: &Self, t: S) -> Self {
+        a.group_mul(&Self::exp(&a.inverse().group_mul(b).log().scaled(t)))
+    }
+
     /// transform a point
     pub fn transform(&self, point: &S::Vector<POINT>) -> S::Vector<POINT> {
         G::transform(&self.params, point)
@@ -198,6 +258,38 @@ impl<
         G::ad(tangent)
     }
 
+    /// left Jacobian of the exponential map: exp(x + δ) ≈ exp(jl(x)·δ) · exp(x)
+    pub fn jl(tangent: &S::Vector<DOF>) -> S::Matrix<DOF, DOF> {
+        G::jl(tangent)
+    }
+
+    /// inverse of the left Jacobian
+    pub fn jl_inv(tangent: &S::Vector<DOF>) -> S::Matrix<DOF, DOF> {
+        G::jl_inv(tangent)
+    }
+
+    /// right Jacobian of the exponential map: exp(x + δ) ≈ exp(x) · exp(jr(x)·δ)
+    pub fn jr(tangent: &S::Vector<DOF>) -> S::Matrix<DOF, DOF> {
+        G::jr(tangent)
+    }
+
+    /// inverse of the right Jacobian
+    pub fn jr_inv(tangent: &S::Vector<DOF>) -> S::Matrix<DOF, DOF> {
+        G::jr_inv(tangent)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<
+        S: IsScalar<BATCH_SIZE>,
+        const DOF: usize,
+        const PARAMS: usize,
+        const POINT: usize,
+        const AMBIENT: usize,
+        const BATCH_SIZE: usize,
+        G: IsLieGroupImpl<S, DOF, PARAMS, POINT, AMBIENT, BATCH_SIZE>,
+    > LieGroup<S, DOF, PARAMS, POINT, AMBIENT, BATCH_SIZE, G>
+{
     /// group element examples
     pub fn element_examples() -> Vec<Self> {
         let mut elements = vec![];
@@ -206,7 +298,19 @@ impl<
         }
         elements
     }
+}
 
+#[cfg(feature = "std")]
+impl<
+        S: IsScalar<BATCH_SIZE>,
+        const DOF: usize,
+        const PARAMS: usize,
+        const POINT: usize,
+        const AMBIENT: usize,
+        const BATCH_SIZE: usize,
+        G: IsLieGroupImpl<S, DOF, PARAMS, POINT, AMBIENT, BATCH_SIZE>,
+    > LieGroup<S, DOF, PARAMS, POINT, AMBIENT, BATCH_SIZE, G>
+{
     fn presentability_tests() {
         if G::IS_ORIGIN_PRESERVING {
             for g in &Self::element_examples() {
@@ -215,7 +319,7 @@ impl<
                 approx::assert_abs_diff_eq!(
                     g.transform(&o).real_vector(),
                     o.real_vector(),
-                    epsilon = 0.0001
+                    epsilon = S::Float::test_tolerance(0.0001)
                 );
             }
         } else {
@@ -252,7 +356,7 @@ impl<
                 assert_relative_eq!(
                     mat_adj_x.real_vector(),
                     mat_adj_x2.real_vector(),
-                    epsilon = 0.0001
+                    epsilon = S::Float::test_tolerance(0.0001)
                 );
             }
         }
@@ -267,7 +371,7 @@ impl<
                 assert_relative_eq!(
                     ad_a_b.real_vector(),
                     lie_bracket_a_b.real_vector(),
-                    epsilon = 0.0001
+                    epsilon = S::Float::test_tolerance(0.0001)
                 );
             }
         }
@@ -281,11 +385,15 @@ impl<
             let matrix_before = *g.compact().real_matrix();
             let matrix_after = *Self::exp(&g.log()).compact().real_matrix();
 
-            assert_relative_eq!(matrix_before, matrix_after, epsilon = 0.0001);
+            assert_relative_eq!(
+                matrix_before,
+                matrix_after,
+                epsilon = S::Float::test_tolerance(0.0001)
+            );
 
             let t = *g.clone().inverse().log().real_vector();
             let t2 = -(*g.log().real_vector());
-            assert_relative_eq!(t, t2, epsilon = 0.0001);
+            assert_relative_eq!(t, t2, epsilon = S::Float::test_tolerance(0.0001));
         }
         for omega in &tangent_examples {
             let exp_inverse = Self::exp(omega).inverse();
@@ -295,7 +403,7 @@ impl<
             assert_relative_eq!(
                 exp_inverse.compact(),
                 exp_neg_omega.compact(),
-                epsilon = 0.0001
+                epsilon = S::Float::test_tolerance(0.0001)
             );
         }
     }
@@ -307,7 +415,7 @@ impl<
             assert_relative_eq!(
                 omega.real_vector(),
                 Self::vee(&Self::hat(omega)).real_vector(),
-                epsilon = 0.0001
+                epsilon = S::Float::test_tolerance(0.0001)
             );
         }
     }
@@ -323,7 +431,7 @@ impl<
                     assert_relative_eq!(
                         left_hugging.compact(),
                         right_hugging.compact(),
-                        epsilon = 0.0001
+                        epsilon = S::Float::test_tolerance(0.0001)
                     );
                 }
             }
@@ -335,7 +443,7 @@ impl<
                 assert_relative_eq!(
                     daz_from_foo_transform_1.compact(),
                     daz_from_foo_transform_2.compact(),
-                    epsilon = 0.0001
+                    epsilon = S::Float::test_tolerance(0.0001)
                 );
             }
         }
@@ -356,3 +464,63 @@ impl<
         Self::adjoint_tests();
     }
 }
+
+#[cfg(feature = "serde")]
+#[test]
+fn lie_group_serde_tests() {
+    use crate::Isometry2;
+    use crate::Isometry3;
+    use crate::Rotation2;
+    use crate::Rotation3;
+    use crate::Sim3;
+    use approx::assert_relative_eq;
+    use sophus_core::linalg::VecF64;
+
+    fn round_trip<
+        const DOF: usize,
+        const PARAMS: usize,
+        const POINT: usize,
+        const AMBIENT: usize,
+        G: IsLieGroupImpl<f64, DOF, PARAMS, POINT, AMBIENT, 1>,
+    >() {
+        for g in LieGroup::<f64, DOF, PARAMS, POINT, AMBIENT, 1, G>::element_examples() {
+            let json = serde_json::to_string(&g).unwrap();
+            let g2: LieGroup<f64, DOF, PARAMS, POINT, AMBIENT, 1, G> =
+                serde_json::from_str(&json).unwrap();
+            assert_relative_eq!(g2.params(), g.params(), epsilon = 1e-15);
+        }
+    }
+    round_trip::<1, 2, 2, 2, crate::groups::rotation2::Rotation2Impl<f64, 1>>();
+    round_trip::<3, 4, 3, 3, crate::groups::rotation3::Rotation3Impl<f64, 1>>();
+    round_trip::<3, 4, 2, 3, crate::groups::isometry2::Isometry2Impl<f64, 1>>();
+    round_trip::<6, 7, 3, 4, crate::groups::isometry3::Isometry3Impl<f64, 1>>();
+    round_trip::<7, 7, 3, 4, crate::groups::sim3::Sim3Impl<f64, 1>>();
+
+    // stable representation: the parameter array
+    assert_eq!(
+        serde_json::to_string(&Rotation3::<f64, 1>::identity()).unwrap(),
+        "[1.0,0.0,0.0,0.0]"
+    );
+    assert_eq!(
+        serde_json::to_string(&Isometry2::<f64, 1>::from_translation_and_rotation(
+            &VecF64::<2>::new(1.0, 2.0),
+            &Rotation2::identity()
+        ))
+        .unwrap(),
+        "[1.0,2.0,1.0,0.0]"
+    );
+    let pose: Isometry3<f64, 1> =
+        serde_json::from_str("[1.0, 2.0, 3.0, 0.0, 0.0, 0.0, 1.0]").unwrap();
+    assert_relative_eq!(pose.translation(), VecF64::<3>::new(1.0, 2.0, 3.0));
+    assert_relative_eq!(
+        pose.rotation().matrix(),
+        Rotation3::<f64, 1>::rot_z(core::f64::consts::PI).matrix(),
+        epsilon = 1e-12
+    );
+    let _: Sim3<f64, 1> = serde_json::from_str("[0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0]").unwrap();
+
+    // invalid parameters and wrong lengths are rejected
+    let err = serde_json::from_str::<Rotation3<f64, 1>>("[2.0, 0.0, 0.0, 0.0]").unwrap_err();
+    assert!(err.to_string().contains("unit quaternion"));
+    assert!(serde_json::from_str::<Rotation3<f64, 1>>("[1.0, 0.0, 0.0]").is_err());
+}
@@ -0,0 +1,320 @@
+use crate::lie_group::LieGroup;
+use crate::prelude::*;
+use crate::traits::IsLieGroupImpl;
+use alloc::borrow::ToOwned;
+use alloc::format;
+use alloc::string::String;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use sophus_core::linalg::MatF64;
+use sophus_core::linalg::VecF64;
+
+/// Error when constructing a Lie group element from untrusted data
+#[derive(Debug, Clone, PartialEq)]
+pub enum LieGroupError {
+    /// the parameters violate the constraint of the group
+    InvalidParams {
+        /// the offending parameters
+        params: String,
+        /// the violated constraint
+        constraint: String,
+    },
+    /// the matrix is not an element of the group
+    InvalidMatrix {
+        /// the offending matrix
+        matrix: String,
+        /// the violated constraint
+        constraint: String,
+    },
+    /// the parameters are too far from the group manifold to be normalized
+    NormalizationFailed {
+        /// the offending parameters
+        params: String,
+        /// the violated constraint
+        constraint: String,
+        /// deviation from the constraint, e.g. ||q| - 1| for a quaternion q
+        deviation: f64,
+        /// the maximal deviation which is normalized
+        tolerance: f64,
+    },
+}
+
+impl Display for LieGroupError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            LieGroupError::InvalidParams { params, constraint } => {
+                write!(f, "invalid parameters {params}, expected {constraint}")
+            }
+            LieGroupError::InvalidMatrix { matrix, constraint } => {
+                write!(f, "invalid matrix {matrix}, expected {constraint}")
+            }
+            LieGroupError::NormalizationFailed {
+                params,
+                constraint,
+                deviation,
+                tolerance,
+            } => write!(
+                f,
+                "cannot normalize parameters {params} to {constraint}: \
+                 deviation {deviation:e} exceeds tolerance {tolerance:e}"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LieGroupError {}
+
+/// normalize a unit complex number or unit quaternion, if its norm is within tolerance of one
+///
+/// Returns the deviation ||v| - 1| as error otherwise.
+pub(crate) fn normalize_unit_params<const N: usize>(
+    params: &VecF64<N>,
+    tolerance: f64,
+) -> Result<VecF64<N>, f64> {
+    let norm = params.norm();
+    let deviation = (norm - 1.0).abs();
+    // also rejects NaN
+    if deviation.is_nan() || deviation > tolerance {
+        return Err(deviation);
+    }
+    Ok(params / norm)
+}
+
+/// check that the matrix is a rotation matrix, RᵀR = I and det(R) = 1, within 1e-6
+///
+/// The determinant is passed in, since it is only available for fixed dimensions.
+pub(crate) fn check_rotation_matrix<const N: usize>(
+    mat: &MatF64<N, N>,
+    determinant: f64,
+) -> Result<(), LieGroupError> {
+    let orthogonality_error = (mat.transpose() * mat - MatF64::<N, N>::identity())
+        .abs()
+        .max();
+    // also rejects NaN
+    if orthogonality_error.is_nan() || orthogonality_error > 1e-6 || determinant <= 0.0 {
+        return Err(LieGroupError::InvalidMatrix {
+            matrix: format!("{:?}", mat),
+            constraint: "rotation matrix, RᵀR = I (within 1e-6) and det(R) = 1".to_owned(),
+        });
+    }
+    Ok(())
+}
+
+/// check that the last row of a homogeneous transformation matrix is [0, …, 0, 1], within 1e-6
+pub(crate) fn check_homogeneous_last_row<const N: usize>(
+    mat: &MatF64<N, N>,
+) -> Result<(), LieGroupError> {
+    let mut expected = MatF64::<1, N>::zeros();
+    expected[N - 1] = 1.0;
+    let error = (mat.fixed_view::<1, N>(N - 1, 0) - expected).abs().max();
+    if error.is_nan() || error > 1e-6 {
+        return Err(LieGroupError::InvalidMatrix {
+            matrix: format!("{:?}", mat),
+            constraint: "homogeneous transformation, last row = [0, …, 0, 1] (within 1e-6)"
+                .to_owned(),
+        });
+    }
+    Ok(())
+}
+
+impl<
+        S: IsScalar<BATCH_SIZE>,
+        const DOF: usize,
+        const PARAMS: usize,
+        const POINT: usize,
+        const AMBIENT: usize,
+        const BATCH_SIZE: usize,
+        G: IsLieGroupImpl<S, DOF, PARAMS, POINT, AMBIENT, BATCH_SIZE>,
+    > LieGroup<S, DOF, PARAMS, POINT, AMBIENT, BATCH_SIZE, G>
+{
+    /// create group element from parameters
+    ///
+    /// In contrast to [HasParams::from_params], which panics, this returns an error if the
+    /// parameters are invalid, e.g. a non-unit quaternion for Rotation3.
+    pub fn try_from_params(params: &S::Vector<PARAMS>) -> Result<Self, LieGroupError> {
+        if !G::are_params_valid(params).all() {
+            return Err(LieGroupError::InvalidParams {
+                params: format!("{:?}", params.real_vector()),
+                constraint: G::params_constraint(),
+            });
+        }
+        Ok(Self::from_params(params))
+    }
+}
+
+#[test]
+fn lie_group_error_tests() {
+    use crate::Isometry2;
+    use crate::Isometry3;
+    use crate::Rotation2;
+    use crate::Rotation3;
+    use crate::Rotation3xTranslation3;
+    use crate::SpecialLinear3;
+    use approx::assert_relative_eq;
+
+    fn run_try_from_params_tests<
+        const DOF: usize,
+        const PARAMS: usize,
+        const POINT: usize,
+        const AMBIENT: usize,
+        G: IsLieGroupImpl<f64, DOF, PARAMS, POINT, AMBIENT, 1>,
+    >() {
+        type Group<
+            const DOF: usize,
+            const PARAMS: usize,
+            const POINT: usize,
+            const AMBIENT: usize,
+            G,
+        > = LieGroup<f64, DOF, PARAMS, POINT, AMBIENT, 1, G>;
+
+        for params in G::params_examples() {
+            let g = Group::<DOF, PARAMS, POINT, AMBIENT, G>::try_from_params(&params).unwrap();
+            assert_eq!(g.params(), &params);
+        }
+        for params in G::invalid_params_examples() {
+            match Group::<DOF, PARAMS, POINT, AMBIENT, G>::try_from_params(&params) {
+                Err(LieGroupError::InvalidParams {
+                    params: reported,
+                    constraint,
+                }) => {
+                    assert_eq!(reported, format!("{:?}", params));
+                    assert_eq!(constraint, G::params_constraint());
+                }
+                other => panic!("expected InvalidParams, got {:?}", other),
+            }
+        }
+    }
+
+    use crate::groups::direct_product::Rotation3xTranslation3Impl;
+    use crate::groups::isometry3::Isometry3Impl;
+    use crate::groups::rotation2::Rotation2Impl;
+    use crate::groups::rotation3::Rotation3Impl;
+    use crate::groups::rxso3::RxSO3Impl;
+    use crate::groups::special_linear3::SpecialLinear3Impl;
+    run_try_from_params_tests::<1, 2, 2, 2, Rotation2Impl<f64, 1>>();
+    run_try_from_params_tests::<3, 4, 3, 3, Rotation3Impl<f64, 1>>();
+    run_try_from_params_tests::<4, 4, 3, 3, RxSO3Impl<f64, 1>>();
+    run_try_from_params_tests::<6, 7, 3, 4, Isometry3Impl<f64, 1>>();
+    run_try_from_params_tests::<8, 9, 3, 3, SpecialLinear3Impl<f64, 1>>();
+    run_try_from_params_tests::<6, 7, 6, 7, Rotation3xTranslation3Impl<f64, 1>>();
+
+    // descriptive error message
+    let err =
+        Rotation3::<f64, 1>::try_from_params(&VecF64::<4>::new(0.5, 0.0, 0.0, 0.0)).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid parameters [[0.5, 0.0, 0.0, 0.0]], expected unit quaternion, |q| = 1 (within 1e-6)"
+    );
+    let err = Rotation3xTranslation3::<f64, 1>::try_from_params(&VecF64::<7>::from_f64_array([
+        2.0, 0.0, 0.0, 0.0, 1.0, 2.0, 3.0,
+    ]))
+    .unwrap_err();
+    assert!(err.to_string().contains("first factor: unit quaternion"));
+
+    // normalization within tolerance
+    let q = VecF64::<4>::new(1.0, 0.2, -0.3, 0.1);
+    let q_slightly_off = q.normalize() * 1.001;
+    let rotation = Rotation3::<f64, 1>::try_from_params_normalized(&q_slightly_off, 0.01).unwrap();
+    assert_relative_eq!(rotation.params(), &q.normalize(), epsilon = 1e-12);
+    match Rotation3::<f64, 1>::try_from_params_normalized(&q, 0.01) {
+        Err(LieGroupError::NormalizationFailed {
+            deviation,
+            tolerance,
+            ..
+        }) => {
+            assert_relative_eq!(deviation, q.norm() - 1.0, epsilon = 1e-12);
+            assert_eq!(tolerance, 0.01);
+        }
+        other => panic!("expected NormalizationFailed, got {:?}", other),
+    }
+    assert!(Rotation3::<f64, 1>::try_from_params_normalized(
+        &VecF64::<4>::new(f64::NAN, 0.0, 0.0, 0.0),
+        0.01
+    )
+    .is_err());
+    let z = VecF64::<2>::new(0.6, 0.8) * 0.9999;
+    assert_relative_eq!(
+        Rotation2::<f64, 1>::try_from_params_normalized(&z, 1e-3)
+            .unwrap()
+            .params(),
+        &VecF64::<2>::new(0.6, 0.8),
+        epsilon = 1e-12
+    );
+    assert!(Rotation2::<f64, 1>::try_from_params_normalized(&(z * 2.0), 1e-3).is_err());
+    let isometry = Isometry3::<f64, 1>::try_from_params_normalized(
+        &VecF64::<7>::from_f64_array([1.0, 2.0, 3.0, 1.0001, 0.0, 0.0, 0.0]),
+        1e-3,
+    )
+    .unwrap();
+    assert_relative_eq!(
+        isometry.params(),
+        &VecF64::<7>::from_f64_array([1.0, 2.0, 3.0, 1.0, 0.0, 0.0, 0.0]),
+        epsilon = 1e-12
+    );
+    assert!(Isometry2::<f64, 1>::try_from_params_normalized(
+        &VecF64::<4>::from_f64_array([1.0, 2.0, 0.0, 0.0]),
+        1e-3,
+    )
+    .is_err());
+
+    // from matrices
+    let rotation = Rotation3::<f64, 1>::exp(&VecF64::<3>::new(0.3, -1.2, 0.7));
+    assert_relative_eq!(
+        Rotation3::<f64, 1>::try_from_matrix(&rotation.matrix())
+            .unwrap()
+            .matrix(),
+        rotation.matrix(),
+        epsilon = 1e-12
+    );
+    assert!(Rotation3::<f64, 1>::try_from_matrix(&(rotation.matrix() * 1.1)).is_err());
+    assert!(Rotation3::<f64, 1>::try_from_matrix(&(-rotation.matrix())).is_err());
+    let rotation2 = Rotation2::<f64, 1>::exp(&VecF64::<1>::new(2.5));
+    assert_relative_eq!(
+        Rotation2::<f64, 1>::try_from_matrix(&rotation2.matrix())
+            .unwrap()
+            .params(),
+        rotation2.params(),
+        epsilon = 1e-12
+    );
+    assert!(
+        Rotation2::<f64, 1>::try_from_matrix(&MatF64::<2, 2>::new(1.0, 0.0, 0.0, -1.0)).is_err()
+    );
+
+    let isometry2 = Isometry2::<f64, 1>::exp(&VecF64::<3>::new(1.0, -2.0, 0.4));
+    assert_relative_eq!(
+        Isometry2::<f64, 1>::try_from_matrix(&isometry2.matrix())
+            .unwrap()
+            .params(),
+        isometry2.params(),
+        epsilon = 1e-12
+    );
+    let isometry3 = Isometry3::<f64, 1>::exp(&VecF64::<6>::new(1.0, -2.0, 0.4, 0.1, 0.2, -0.3));
+    assert_relative_eq!(
+        Isometry3::<f64, 1>::try_from_matrix(&isometry3.matrix())
+            .unwrap()
+            .matrix(),
+        isometry3.matrix(),
+        epsilon = 1e-12
+    );
+    let mut not_affine = isometry3.matrix();
+    not_affine[(3, 0)] = 0.1;
+    match Isometry3::<f64, 1>::try_from_matrix(&not_affine) {
+        Err(LieGroupError::InvalidMatrix { constraint, .. }) => {
+            assert!(constraint.contains("last row"));
+        }
+        other => panic!("expected InvalidMatrix, got {:?}", other),
+    }
+
+    let homography = SpecialLinear3::<f64, 1>::exp(&VecF64::<8>::from_f64_array([
+        0.1, -0.2, 0.3, 0.05, 0.1, -0.1, 0.01, 0.02,
+    ]));
+    assert_relative_eq!(
+        SpecialLinear3::<f64, 1>::try_from_matrix(&homography.matrix())
+            .unwrap()
+            .matrix(),
+        homography.matrix(),
+        epsilon = 1e-12
+    );
+    assert!(SpecialLinear3::<f64, 1>::try_from_matrix(&(homography.matrix() * 2.0)).is_err());
+}
@@ -1,8 +1,10 @@
 use crate::lie_group::LieGroup;
 use crate::prelude::*;
 use crate::traits::IsLieGroupImpl;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt::Debug;
 use sophus_core::params::ParamsImpl;
-use std::fmt::Debug;
 
 /// Left group manifold
 #[derive(Debug, Clone)]
@@ -33,10 +35,12 @@ impl<
         G::are_params_valid(params)
     }
 
+    #[cfg(feature = "alloc")]
     fn params_examples() -> Vec<<S as IsScalar<BATCH_SIZE>>::Vector<PARAMS>> {
         G::params_examples()
     }
 
+    #[cfg(feature = "alloc")]
     fn invalid_params_examples() -> Vec<<S as IsScalar<BATCH_SIZE>>::Vector<PARAMS>> {
         G::invalid_params_examples()
     }
@@ -0,0 +1,277 @@
+use crate::lie_group::LieGroup;
+use crate::prelude::*;
+use crate::traits::IsLieGroupImpl;
+#[cfg(feature = "alloc")]
+use alloc::vec;
+use core::fmt::Display;
+use core::fmt::Formatter;
+
+/// Parameters of the iterative Fréchet mean
+#[derive(Clone, Copy, Debug)]
+pub struct FrechetMeanParams {
+    /// The iteration stops once the norm of the tangent space update is below this tolerance.
+    pub tolerance: f64,
+    /// maximum number of iterations
+    pub max_iterations: usize,
+}
+
+impl Default for FrechetMeanParams {
+    fn default() -> Self {
+        Self {
+            tolerance: 1e-10,
+            max_iterations: 50,
+        }
+    }
+}
+
+/// Error when computing the Fréchet mean
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum FrechetMeanError {
+    /// no elements were given
+    EmptyInput,
+    /// the number of weights does not match the number of elements
+    LengthMismatch {
+        /// number of elements
+        num_elements: usize,
+        /// number of weights
+        num_weights: usize,
+    },
+    /// a weight is negative or not finite
+    InvalidWeight {
+        /// index of the offending weight
+        index: usize,
+        /// the offending weight
+        weight: f64,
+    },
+    /// all weights are zero
+    ZeroWeightSum,
+}
+
+impl Display for FrechetMeanError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            FrechetMeanError::EmptyInput => write!(f, "cannot compute the mean of zero elements"),
+            FrechetMeanError::LengthMismatch {
+                num_elements,
+                num_weights,
+            } => write!(
+                f,
+                "number of weights {num_weights} does not match number of elements {num_elements}"
+            ),
+            FrechetMeanError::InvalidWeight { index, weight } => {
+                write!(
+                    f,
+                    "weight {weight} at index {index} is negative or not finite"
+                )
+            }
+            FrechetMeanError::ZeroWeightSum => write!(f, "all weights are zero"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FrechetMeanError {}
+
+/// Weighted Fréchet mean of a set of Lie group elements
+#[derive(Clone, Debug)]
+pub struct FrechetMean<
+    S: IsScalar<BATCH_SIZE>,
+    const DOF: usize,
+    const PARAMS: usize,
+    const POINT: usize,
+    const AMBIENT: usize,
+    const BATCH_SIZE: usize,
+    G: IsLieGroupImpl<S, DOF, PARAMS, POINT, AMBIENT, BATCH_SIZE>,
+> {
+    /// the mean
+    pub mean: LieGroup<S, DOF, PARAMS, POINT, AMBIENT, BATCH_SIZE, G>,
+    /// Weighted covariance of the samples in the tangent space at the mean.
+    ///
+    /// The samples are expressed as left perturbations of the mean: xᵢ = exp(ξᵢ) · mean, and the
+    /// covariance is Σᵢ wᵢ·ξᵢ·ξᵢᵀ / Σᵢ wᵢ. This is the convention of [crate::LieGroupWithCovariance]
+    /// and [LieGroup::sample_gaussian].
+    pub covariance: S::Matrix<DOF, DOF>,
+    /// number of iterations performed
+    pub num_iterations: usize,
+    /// true if the tolerance was reached within the maximum number of iterations
+    pub converged: bool,
+}
+
+impl<
+        S: IsRealScalar<BATCH_SIZE>,
+        const DOF: usize,
+        const PARAMS: usize,
+        const POINT: usize,
+        const AMBIENT: usize,
+        const BATCH_SIZE: usize,
+        G: IsLieGroupImpl<S, DOF, PARAMS, POINT, AMBIENT, BATCH_SIZE>,
+    > LieGroup<S, DOF, PARAMS, POINT, AMBIENT, BATCH_SIZE, G>
+{
+    /// Weighted Fréchet (Karcher) mean
+    ///
+    /// Starting at the element with the largest weight, the mean is refined iteratively by
+    ///
+    ///   mean ← mean · exp(Σᵢ wᵢ·log(mean⁻¹ · xᵢ) / Σᵢ wᵢ)
+    ///
+    /// until the update is below the tolerance or the iteration cap is reached. Returns an error if
+    /// there are no elements, if the number of weights does not match, or if the weights are not
+    /// non-negative and finite with a positive sum.
+    pub fn weighted_frechet_mean(
+        elements: &[Self],
+        weights: &[f64],
+        params: FrechetMeanParams,
+    ) -> Result<FrechetMean<S, DOF, PARAMS, POINT, AMBIENT, BATCH_SIZE, G>, FrechetMeanError> {
+        if elements.is_empty() {
+            return Err(FrechetMeanError::EmptyInput);
+        }
+        if elements.len() != weights.len() {
+            return Err(FrechetMeanError::LengthMismatch {
+                num_elements: elements.len(),
+                num_weights: weights.len(),
+            });
+        }
+        for (index, weight) in weights.iter().enumerate() {
+            if !(weight.is_finite() && *weight >= 0.0) {
+                return Err(FrechetMeanError::InvalidWeight {
+                    index,
+                    weight: *weight,
+                });
+            }
+        }
+        let weight_sum: f64 = weights.iter().sum();
+        if weight_sum <= 0.0 {
+            return Err(FrechetMeanError::ZeroWeightSum);
+        }
+
+        let mut start_idx = 0;
+        for (i, w) in weights.iter().enumerate() {
+            if *w > weights[start_idx] {
+                start_idx = i;
+            }
+        }
+        let mut mean = elements[start_idx].clone();
+
+        let weighted_tangent_mean = |mean: &Self| {
+            let mut tangent_mean = S::Vector::<DOF>::zeros();
+            for (x, w) in elements.iter().zip(weights.iter()) {
+                tangent_mean = tangent_mean
+                    + mean
+                        .inverse()
+                        .group_mul(x)
+                        .log()
+                        .scaled(S::from_f64(*w / weight_sum));
+            }
+            tangent_mean
+        };
+
+        let mut num_iterations = 0;
+        let mut converged = false;
+        while num_iterations < params.max_iterations {
+            let delta = weighted_tangent_mean(&mean);
+            mean = mean.group_mul(&Self::exp(&delta));
+            num_iterations += 1;
+
+            if delta
+                .norm()
+                .less_equal(&S::from_f64(params.tolerance))
+                .all()
+            {
+                converged = true;
+                break;
+            }
+        }
+
+        // left perturbation: xᵢ = exp(ξᵢ) · mean
+        let mut covariance = S::Matrix::<DOF, DOF>::zeros();
+        for (x, w) in elements.iter().zip(weights.iter()) {
+            let xi = x.group_mul(&mean.inverse()).log();
+            covariance = covariance + xi.clone().outer(xi).scaled(S::from_f64(*w / weight_sum));
+        }
+
+        Ok(FrechetMean {
+            mean,
+            covariance,
+            num_iterations,
+            converged,
+        })
+    }
+
+    /// Fréchet (Karcher) mean with uniform weights, see [Self::weighted_frechet_mean]
+    #[cfg(feature = "alloc")]
+    pub fn frechet_mean(
+        elements: &[Self],
+        params: FrechetMeanParams,
+    ) -> Result<FrechetMean<S, DOF, PARAMS, POINT, AMBIENT, BATCH_SIZE, G>, FrechetMeanError> {
+        Self::weighted_frechet_mean(elements, &vec![1.0; elements.len()], params)
+    }
+}
+
+#[test]
+fn frechet_mean_tests() {
+    use crate::Isometry3;
+    use crate::Rotation3;
+    use approx::assert_relative_eq;
+    use sophus_core::linalg::VecF64;
+
+    // symmetric samples around a center, as left perturbations
+    let center = Isometry3::<f64, 1>::exp(&VecF64::<6>::new(0.5, -0.2, 1.0, 0.3, -0.4, 0.8));
+    let v = VecF64::<6>::new(0.1, 0.05, -0.1, 0.2, -0.1, 0.05);
+    let samples = vec![
+        Isometry3::exp(&v).group_mul(&center),
+        Isometry3::exp(&-v).group_mul(&center),
+    ];
+    let result = Isometry3::frechet_mean(&samples, FrechetMeanParams::default()).unwrap();
+    assert!(result.converged);
+    assert_relative_eq!(result.mean.matrix(), center.matrix(), epsilon = 1e-8);
+    assert_relative_eq!(result.covariance, v * v.transpose(), epsilon = 1e-8);
+
+    // for two elements, the weighted mean is on the geodesic
+    let a = Rotation3::<f64, 1>::exp(&VecF64::<3>::new(0.1, 0.2, 0.3));
+    let b = Rotation3::<f64, 1>::exp(&VecF64::<3>::new(-1.0, 0.5, 0.7));
+    for t in [0.0, 0.25, 0.5, 0.9] {
+        let result =
+            Rotation3::weighted_frechet_mean(&[a, b], &[1.0 - t, t], FrechetMeanParams::default())
+                .unwrap();
+        assert!(result.converged);
+        assert_relative_eq!(
+            result.mean.matrix(),
+            Rotation3::interpolate(&a, &b, t).matrix(),
+            epsilon = 1e-8
+        );
+    }
+
+    // the mean of a single element is the element itself
+    let result = Rotation3::frechet_mean(&[b], FrechetMeanParams::default()).unwrap();
+    assert_eq!(result.num_iterations, 1);
+    assert_relative_eq!(result.mean.matrix(), b.matrix(), epsilon = 1e-10);
+    assert_relative_eq!(
+        result.covariance,
+        nalgebra::Matrix3::zeros(),
+        epsilon = 1e-10
+    );
+
+    // invalid input
+    let params = FrechetMeanParams::default();
+    assert_eq!(
+        Rotation3::<f64, 1>::frechet_mean(&[], params).unwrap_err(),
+        FrechetMeanError::EmptyInput
+    );
+    assert_eq!(
+        Rotation3::weighted_frechet_mean(&[a, b], &[1.0], params).unwrap_err(),
+        FrechetMeanError::LengthMismatch {
+            num_elements: 2,
+            num_weights: 1
+        }
+    );
+    assert_eq!(
+        Rotation3::weighted_frechet_mean(&[a, b], &[1.0, -0.5], params).unwrap_err(),
+        FrechetMeanError::InvalidWeight {
+            index: 1,
+            weight: -0.5
+        }
+    );
+    assert_eq!(
+        Rotation3::weighted_frechet_mean(&[a, b], &[0.0, 0.0], params).unwrap_err(),
+        FrechetMeanError::ZeroWeightSum
+    );
+}
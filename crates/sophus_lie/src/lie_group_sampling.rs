@@ -0,0 +1,219 @@
+use crate::lie_group::LieGroup;
+use crate::lie_group_with_covariance::LieGroupWithCovariance;
+use crate::prelude::*;
+use crate::traits::IsLieGroupImpl;
+use crate::traits::IsRealLieGroupImpl;
+use crate::Rotation2;
+use crate::Rotation3;
+use sophus_core::linalg::MatF64;
+use sophus_core::linalg::VecF64;
+
+/// sample from the standard normal distribution N(0, 1), using the Box-Muller transform
+pub fn sample_standard_normal<R: rand::Rng + ?Sized>(rng: &mut R) -> f64 {
+    // u1 in (0, 1] to avoid ln(0)
+    let u1 = 1.0 - rng.gen::<f64>();
+    let u2 = rng.gen::<f64>();
+    (-2.0 * u1.ln()).sqrt() * (core::f64::consts::TAU * u2).cos()
+}
+
+/// sample a tangent vector from the zero-mean Gaussian N(0, Σ)
+///
+/// Panics if the covariance Σ is not positive definite.
+pub fn sample_tangent_gaussian<const DOF: usize, R: rand::Rng + ?Sized>(
+    rng: &mut R,
+    covariance: &MatF64<DOF, DOF>,
+) -> VecF64<DOF> {
+    let sqrt_covariance = covariance
+        .cholesky()
+        .expect("covariance must be positive definite")
+        .unpack();
+    let standard_normal = VecF64::<DOF>::from_fn(|_, _| sample_standard_normal(rng));
+    sqrt_covariance * standard_normal
+}
+
+impl<
+        const DOF: usize,
+        const PARAMS: usize,
+        const POINT: usize,
+        const AMBIENT: usize,
+        G: IsLieGroupImpl<f64, DOF, PARAMS, POINT, AMBIENT, 1>,
+    > LieGroup<f64, DOF, PARAMS, POINT, AMBIENT, 1, G>
+{
+    /// sample from a Gaussian distribution in the tangent space around `mean`
+    ///
+    /// Returns exp(ξ) · mean with ξ ~ N(0, Σ), i.e. the covariance Σ is expressed as a left
+    /// perturbation, consistent with [LieGroupWithCovariance]. Panics if Σ is not positive
+    /// definite.
+    pub fn sample_gaussian<R: rand::Rng + ?Sized>(
+        rng: &mut R,
+        mean: &Self,
+        covariance: &MatF64<DOF, DOF>,
+    ) -> Self {
+        Self::exp(&sample_tangent_gaussian(rng, covariance)).group_mul(mean)
+    }
+}
+
+impl<
+        const DOF: usize,
+        const PARAMS: usize,
+        const POINT: usize,
+        const AMBIENT: usize,
+        G: IsRealLieGroupImpl<f64, DOF, PARAMS, POINT, AMBIENT, 1>,
+    > LieGroupWithCovariance<DOF, PARAMS, POINT, AMBIENT, G>
+{
+    /// sample from the distribution, see [LieGroup::sample_gaussian]
+    pub fn sample<R: rand::Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+    ) -> LieGroup<f64, DOF, PARAMS, POINT, AMBIENT, 1, G> {
+        LieGroup::sample_gaussian(rng, &self.group, &self.covariance)
+    }
+}
+
+impl Rotation2<f64, 1> {
+    /// sample uniformly from SO(2)
+    pub fn sample_uniform<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+        let angle = core::f64::consts::TAU * rng.gen::<f64>();
+        Self::from_params(&VecF64::<2>::new(angle.cos(), angle.sin()))
+    }
+}
+
+impl Rotation3<f64, 1> {
+    /// sample uniformly from SO(3)
+    ///
+    /// Uses Shoemake's method, which draws a unit quaternion uniformly from the 3-sphere.
+    pub fn sample_uniform<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+        let u1 = rng.gen::<f64>();
+        let theta1 = core::f64::consts::TAU * rng.gen::<f64>();
+        let theta2 = core::f64::consts::TAU * rng.gen::<f64>();
+        let r1 = (1.0 - u1).sqrt();
+        let r2 = u1.sqrt();
+        Self::from_params(&VecF64::<4>::new(
+            r2 * theta2.cos(),
+            r1 * theta1.sin(),
+            r1 * theta1.cos(),
+            r2 * theta2.sin(),
+        ))
+    }
+}
+
+#[test]
+fn lie_group_sampling_tests() {
+    use crate::groups::extended_isometry3::ExtendedIsometry3Impl;
+    use crate::groups::isometry2::Isometry2Impl;
+    use crate::groups::isometry3::Isometry3Impl;
+    use crate::groups::rotation2::Rotation2Impl;
+    use crate::groups::rotation3::Rotation3Impl;
+    use crate::groups::rxso2::RxSO2Impl;
+    use crate::groups::rxso3::RxSO3Impl;
+    use crate::groups::sim2::Sim2Impl;
+    use crate::groups::sim3::Sim3Impl;
+    use crate::groups::special_linear3::SpecialLinear3Impl;
+    use crate::Isometry3;
+    use approx::assert_relative_eq;
+    use rand::prelude::*;
+
+    // randomized group axioms, supplementing the fixed params and tangent examples
+    fn run_randomized_tests<
+        const DOF: usize,
+        const PARAMS: usize,
+        const POINT: usize,
+        const AMBIENT: usize,
+        G: IsRealLieGroupImpl<f64, DOF, PARAMS, POINT, AMBIENT, 1>,
+    >(
+        rng: &mut StdRng,
+    ) {
+        type Group<
+            const DOF: usize,
+            const PARAMS: usize,
+            const POINT: usize,
+            const AMBIENT: usize,
+            G,
+        > = LieGroup<f64, DOF, PARAMS, POINT, AMBIENT, 1, G>;
+
+        let covariance = MatF64::<DOF, DOF>::identity() * 0.25;
+        let identity = Group::<DOF, PARAMS, POINT, AMBIENT, G>::identity();
+        for _ in 0..50 {
+            let a = Group::sample_gaussian(rng, &identity, &covariance);
+            let b = Group::sample_gaussian(rng, &a, &covariance);
+            let tangent = sample_tangent_gaussian(rng, &covariance);
+
+            assert_relative_eq!(
+                a.group_mul(&a.inverse()).matrix(),
+                identity.matrix(),
+                epsilon = 1e-9
+            );
+            assert_relative_eq!(
+                a.group_mul(&b).matrix(),
+                a.matrix() * b.matrix(),
+                epsilon = 1e-9
+            );
+            assert_relative_eq!(
+                a.group_mul(&Group::exp(&tangent))
+                    .group_mul(&a.inverse())
+                    .matrix(),
+                Group::<DOF, PARAMS, POINT, AMBIENT, G>::exp(&(a.adj() * tangent)).matrix(),
+                epsilon = 1e-8
+            );
+            if !a.has_shortest_path_ambiguity().any() {
+                assert_relative_eq!(
+                    Group::<DOF, PARAMS, POINT, AMBIENT, G>::exp(&a.log()).matrix(),
+                    a.matrix(),
+                    epsilon = 1e-8
+                );
+            }
+        }
+    }
+
+    let mut rng = StdRng::seed_from_u64(0);
+    run_randomized_tests::<1, 2, 2, 2, Rotation2Impl<f64, 1>>(&mut rng);
+    run_randomized_tests::<3, 4, 3, 3, Rotation3Impl<f64, 1>>(&mut rng);
+    run_randomized_tests::<2, 2, 2, 2, RxSO2Impl<f64, 1>>(&mut rng);
+    run_randomized_tests::<4, 4, 3, 3, RxSO3Impl<f64, 1>>(&mut rng);
+    run_randomized_tests::<3, 4, 2, 3, Isometry2Impl<f64, 1>>(&mut rng);
+    run_randomized_tests::<6, 7, 3, 4, Isometry3Impl<f64, 1>>(&mut rng);
+    run_randomized_tests::<4, 4, 2, 3, Sim2Impl<f64, 1>>(&mut rng);
+    run_randomized_tests::<7, 7, 3, 4, Sim3Impl<f64, 1>>(&mut rng);
+    run_randomized_tests::<9, 10, 3, 5, ExtendedIsometry3Impl<f64, 1>>(&mut rng);
+    run_randomized_tests::<8, 9, 3, 3, SpecialLinear3Impl<f64, 1>>(&mut rng);
+
+    const NUM_SAMPLES: usize = 20000;
+
+    // empirical covariance of the Gaussian samples
+    let mean = Isometry3::<f64, 1>::exp(&VecF64::<6>::new(0.5, -0.2, 1.0, 0.3, -0.4, 0.8));
+    let mut covariance = MatF64::<6, 6>::identity() * 0.01;
+    covariance[(0, 1)] = 0.005;
+    covariance[(1, 0)] = 0.005;
+    covariance[(3, 3)] = 0.04;
+    let distribution = LieGroupWithCovariance::new(mean, covariance);
+    let mut empirical_covariance = MatF64::<6, 6>::zeros();
+    for _ in 0..NUM_SAMPLES {
+        let xi = distribution
+            .sample(&mut rng)
+            .group_mul(&mean.inverse())
+            .log();
+        empirical_covariance += xi * xi.transpose() / NUM_SAMPLES as f64;
+    }
+    assert_relative_eq!(empirical_covariance, covariance, epsilon = 0.002);
+
+    // uniform samples have zero mean rotation matrix
+    let mut mean_matrix2 = MatF64::<2, 2>::zeros();
+    let mut mean_matrix3 = MatF64::<3, 3>::zeros();
+    for _ in 0..NUM_SAMPLES {
+        mean_matrix2 += Rotation2::sample_uniform(&mut rng).matrix() / NUM_SAMPLES as f64;
+        mean_matrix3 += Rotation3::sample_uniform(&mut rng).matrix() / NUM_SAMPLES as f64;
+    }
+    assert_relative_eq!(mean_matrix2, MatF64::<2, 2>::zeros(), epsilon = 0.02);
+    assert_relative_eq!(mean_matrix3, MatF64::<3, 3>::zeros(), epsilon = 0.02);
+
+    // the rotation angle of a uniform rotation has density (1 - cos θ)/π on [0, π]
+    let mut mean_angle = 0.0;
+    for _ in 0..NUM_SAMPLES {
+        mean_angle += Rotation3::sample_uniform(&mut rng).log().norm() / NUM_SAMPLES as f64;
+    }
+    assert_relative_eq!(
+        mean_angle,
+        core::f64::consts::FRAC_PI_2 + 2.0 / core::f64::consts::PI,
+        epsilon = 0.02
+    );
+}
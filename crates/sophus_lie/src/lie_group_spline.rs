@@ -0,0 +1,372 @@
+use crate::lie_group::LieGroup;
+use crate::prelude::*;
+use crate::traits::IsLieGroupImpl;
+use alloc::vec::Vec;
+use sophus_core::calculus::spline::spline_segment::CubicBasisFunction;
+use sophus_core::calculus::spline::CubicBSplineParams;
+use sophus_core::calculus::spline::IndexAndU;
+
+/// Segment of a cumulative cubic B-spline on a Lie group
+///
+/// Given the four control poses T₀, T₁, T₂, T₃, the segment is defined as
+///
+///   T(u) = T₀ · exp(b₀(u)·d₀) · exp(b₁(u)·d₁) · exp(b₂(u)·d₂),   with dⱼ = log(Tⱼ⁻¹ · Tⱼ₊₁)
+///
+/// and b(u) being the cumulative cubic basis function.
+#[derive(Clone, Debug)]
+pub struct LieGroupCubicBSplineSegment<
+    S: IsSingleScalar,
+    const DOF: usize,
+    const PARAMS: usize,
+    const POINT: usize,
+    const AMBIENT: usize,
+    G: IsLieGroupImpl<S, DOF, PARAMS, POINT, AMBIENT, 1>,
+> {
+    /// the four control poses
+    pub control_poses: [LieGroup<S, DOF, PARAMS, POINT, AMBIENT, 1, G>; 4],
+}
+
+impl<
+        S: IsSingleScalar,
+        const DOF: usize,
+        const PARAMS: usize,
+        const POINT: usize,
+        const AMBIENT: usize,
+        G: IsLieGroupImpl<S, DOF, PARAMS, POINT, AMBIENT, 1>,
+    > LieGroupCubicBSplineSegment<S, DOF, PARAMS, POINT, AMBIENT, G>
+{
+    fn deltas(&self) -> [S::Vector<DOF>; 3] {
+        core::array::from_fn(|j| {
+            self.control_poses[j]
+                .inverse()
+                .group_mul(&self.control_poses[j + 1])
+                .log()
+        })
+    }
+
+    /// Interpolate, for u in [0, 1]
+    pub fn interpolate(&self, u: S) -> LieGroup<S, DOF, PARAMS, POINT, AMBIENT, 1, G> {
+        let b = CubicBasisFunction::<S>::b(u);
+        let deltas = self.deltas();
+
+        let mut pose = self.control_poses[0].clone();
+        for (j, delta) in deltas.iter().enumerate() {
+            pose = pose.group_mul(&LieGroup::exp(&delta.scaled(b.get_elem(j))));
+        }
+        pose
+    }
+
+    /// Body velocity ω and body acceleration dω/dt, with hat(ω) = T(u)⁻¹ · dT(u)/dt
+    pub fn velocity_and_acceleration(&self, u: S, delta_t: S) -> (S::Vector<DOF>, S::Vector<DOF>) {
+        let b = CubicBasisFunction::<S>::b(u.clone());
+        let du_b = CubicBasisFunction::<S>::du_b(u.clone(), delta_t.clone());
+        let du2_b = CubicBasisFunction::<S>::du2_b(u, delta_t);
+        let deltas = self.deltas();
+
+        // Recursively, for the partial products T₀ · exp(b₀·d₀) ··· exp(bⱼ·dⱼ):
+        //
+        //   ωⱼ = Adj(exp(-bⱼ·dⱼ)) · ωⱼ₋₁ + b'ⱼ·dⱼ
+        //  dωⱼ = Adj(exp(-bⱼ·dⱼ)) · dωⱼ₋₁ + b''ⱼ·dⱼ + ad(ωⱼ) · b'ⱼ·dⱼ
+        let mut velocity = S::Vector::<DOF>::zeros();
+        let mut acceleration = S::Vector::<DOF>::zeros();
+        for (j, delta) in deltas.iter().enumerate() {
+            let adj_inv = LieGroup::<S, DOF, PARAMS, POINT, AMBIENT, 1, G>::exp(
+                &delta.scaled(-b.get_elem(j)),
+            )
+            .adj();
+            let du_delta = delta.scaled(du_b.get_elem(j));
+
+            velocity = adj_inv.clone() * velocity + du_delta.clone();
+            acceleration = adj_inv * acceleration
+                + delta.scaled(du2_b.get_elem(j))
+                + G::ad(&velocity) * du_delta;
+        }
+        (velocity, acceleration)
+    }
+
+    /// Jacobians of the interpolated pose with respect to the four control poses
+    ///
+    /// Both the control poses and the interpolated pose are perturbed from the left, i.e. the
+    /// k-th Jacobian is ∂ log(T(u; exp(δ)·Tₖ) · T(u)⁻¹) / ∂δ at δ = 0.
+    pub fn dxi_interpolate(&self, u: S) -> [S::Matrix<DOF, DOF>; 4] {
+        let b = CubicBasisFunction::<S>::b(u);
+        let deltas = self.deltas();
+
+        let mut dxi: [S::Matrix<DOF, DOF>; 4] = core::array::from_fn(|_| S::Matrix::zeros());
+        dxi[0] = S::Matrix::<DOF, DOF>::identity();
+
+        let mut partial_product = self.control_poses[0].clone();
+        for (j, delta) in deltas.iter().enumerate() {
+            let b_j = b.get_elem(j);
+            let scaled_delta = delta.scaled(b_j.clone());
+
+            // ∂dⱼ/∂δⱼ₊₁ = -∂dⱼ/∂δⱼ = jr_inv(dⱼ) · Adj(Tⱼ₊₁⁻¹)
+            let dx_delta = G::jr_inv(delta).mat_mul(self.control_poses[j + 1].inverse().adj());
+            let dx_pose = partial_product
+                .adj()
+                .mat_mul(G::jl(&scaled_delta).scaled(b_j))
+                .mat_mul(dx_delta);
+
+            dxi[j + 1] = dxi[j + 1].clone() + dx_pose.clone();
+            dxi[j] = dxi[j].clone() - dx_pose;
+
+            partial_product = partial_product.group_mul(&LieGroup::exp(&scaled_delta));
+        }
+        dxi
+    }
+}
+
+/// Cumulative cubic B-spline on a Lie group
+///
+/// This is the Lie group analog of [sophus_core::calculus::spline::CubicBSpline]. As there, the
+/// first and last control poses are duplicated, such that the spline is defined on
+/// [t0, t0 + N·delta_t] for N+1 control poses.
+#[derive(Clone, Debug)]
+pub struct LieGroupCubicBSpline<
+    S: IsSingleScalar,
+    const DOF: usize,
+    const PARAMS: usize,
+    const POINT: usize,
+    const AMBIENT: usize,
+    G: IsLieGroupImpl<S, DOF, PARAMS, POINT, AMBIENT, 1>,
+> {
+    /// control poses
+    pub control_poses: Vec<LieGroup<S, DOF, PARAMS, POINT, AMBIENT, 1, G>>,
+    /// delta between control poses
+    pub delta_t: S,
+    /// start time t0
+    pub t0: S,
+}
+
+impl<
+        S: IsSingleScalar + 'static,
+        const DOF: usize,
+        const PARAMS: usize,
+        const POINT: usize,
+        const AMBIENT: usize,
+        G: IsLieGroupImpl<S, DOF, PARAMS, POINT, AMBIENT, 1>,
+    > LieGroupCubicBSpline<S, DOF, PARAMS, POINT, AMBIENT, G>
+{
+    /// create a new cubic B-spline
+    pub fn new(
+        control_poses: Vec<LieGroup<S, DOF, PARAMS, POINT, AMBIENT, 1, G>>,
+        params: CubicBSplineParams<S>,
+    ) -> Self {
+        assert!(control_poses.len() >= 2);
+        Self {
+            control_poses,
+            delta_t: params.delta_t,
+            t0: params.t0,
+        }
+    }
+
+    /// interpolate pose at time t
+    pub fn interpolate(&self, t: S) -> LieGroup<S, DOF, PARAMS, POINT, AMBIENT, 1, G> {
+        let index_and_u = self.index_and_u(t);
+        self.segment(index_and_u.segment_idx)
+            .interpolate(index_and_u.u)
+    }
+
+    /// body velocity at time t
+    pub fn velocity(&self, t: S) -> S::Vector<DOF> {
+        self.velocity_and_acceleration(t).0
+    }
+
+    /// body acceleration at time t
+    pub fn acceleration(&self, t: S) -> S::Vector<DOF> {
+        self.velocity_and_acceleration(t).1
+    }
+
+    /// body velocity and acceleration at time t
+    pub fn velocity_and_acceleration(&self, t: S) -> (S::Vector<DOF>, S::Vector<DOF>) {
+        let index_and_u = self.index_and_u(t);
+        self.segment(index_and_u.segment_idx)
+            .velocity_and_acceleration(index_and_u.u, self.delta_t.clone())
+    }
+
+    /// derivative of the interpolated pose with respect to a control pose
+    ///
+    /// See [LieGroupCubicBSplineSegment::dxi_interpolate] for the perturbation convention.
+    pub fn dxi_interpolate(&self, t: S, control_pose_idx: usize) -> S::Matrix<DOF, DOF> {
+        let index_and_u = self.index_and_u(t);
+        let segment_dxi = self
+            .segment(index_and_u.segment_idx)
+            .dxi_interpolate(index_and_u.u);
+
+        let mut dxi = S::Matrix::<DOF, DOF>::zeros();
+        for (i, idx) in self
+            .idx_involved_in_segment(index_and_u.segment_idx)
+            .iter()
+            .enumerate()
+        {
+            if *idx == control_pose_idx {
+                dxi = dxi + segment_dxi[i].clone();
+            }
+        }
+        dxi
+    }
+
+    /// indices of the control poses involved at time t
+    pub fn idx_involved(&self, t: S) -> Vec<usize> {
+        let index_and_u = self.index_and_u(t);
+        self.idx_involved_in_segment(index_and_u.segment_idx)
+            .to_vec()
+    }
+
+    /// index and u
+    pub fn index_and_u(&self, t: S) -> IndexAndU<S> {
+        assert!(t.greater_equal(&self.t0).all());
+        assert!(t.less_equal(&self.t_max()).all());
+
+        let normalized_t: S = self.normalized_t(t);
+
+        let mut idx_and_u = IndexAndU::<S> {
+            segment_idx: normalized_t.i64_floor() as usize,
+            u: normalized_t.fract(),
+        };
+
+        let eps = 0.00001;
+        if idx_and_u.u.single_real_scalar() > eps || idx_and_u.segment_idx < self.num_segments() / 2
+        {
+            return idx_and_u;
+        }
+
+        idx_and_u.segment_idx -= 1;
+        idx_and_u.u += S::from_f64(1.0);
+        idx_and_u
+    }
+
+    /// normalized between [0, N]
+    pub fn normalized_t(&self, t: S) -> S {
+        (t - self.t0.clone()) / self.delta_t.clone()
+    }
+
+    /// number of segments
+    pub fn num_segments(&self) -> usize {
+        self.control_poses.len() - 1
+    }
+
+    /// t_max
+    pub fn t_max(&self) -> S {
+        self.t0.clone() + S::from_f64(self.num_segments() as f64) * self.delta_t.clone()
+    }
+
+    fn idx_involved_in_segment(&self, segment_idx: usize) -> [usize; 4] {
+        assert!(segment_idx < self.num_segments());
+
+        let idx_prev = if segment_idx == 0 { 0 } else { segment_idx - 1 };
+        let idx_2 = (segment_idx + 2).min(self.control_poses.len() - 1);
+        [idx_prev, segment_idx, segment_idx + 1, idx_2]
+    }
+
+    fn segment(
+        &self,
+        segment_idx: usize,
+    ) -> LieGroupCubicBSplineSegment<S, DOF, PARAMS, POINT, AMBIENT, G> {
+        LieGroupCubicBSplineSegment {
+            control_poses: self
+                .idx_involved_in_segment(segment_idx)
+                .map(|idx| self.control_poses[idx].clone()),
+        }
+    }
+}
+
+#[test]
+fn lie_group_spline_tests() {
+    use crate::groups::isometry3::Isometry3Impl;
+    use crate::groups::rotation3::Rotation3Impl;
+    use crate::traits::IsRealLieGroupImpl;
+    use approx::assert_relative_eq;
+    use sophus_core::calculus::maps::VectorValuedMapFromVector;
+    use sophus_core::linalg::VecF64;
+
+    fn run_spline_tests<
+        const DOF: usize,
+        const PARAMS: usize,
+        const POINT: usize,
+        const AMBIENT: usize,
+        G: IsRealLieGroupImpl<f64, DOF, PARAMS, POINT, AMBIENT, 1>,
+    >() {
+        // control poses, with moderate relative motion
+        let mut control_poses =
+            vec![LieGroup::<f64, DOF, PARAMS, POINT, AMBIENT, 1, G>::identity()];
+        for tangent in G::tangent_examples() {
+            let pose = control_poses.last().unwrap().clone();
+            control_poses.push(pose.group_mul(&LieGroup::exp(&(tangent * 0.5))));
+        }
+
+        // geodesic interpolation
+        for w in control_poses.windows(2) {
+            let (a, b) = (&w[0], &w[1]);
+            assert_relative_eq!(
+                LieGroup::interpolate(a, b, 0.0).matrix(),
+                a.matrix(),
+                epsilon = 0.0001
+            );
+            assert_relative_eq!(
+                LieGroup::interpolate(a, b, 1.0).matrix(),
+                b.matrix(),
+                epsilon = 0.0001
+            );
+        }
+
+        for (t0, delta_t) in [(0.0, 1.0), (1.5, 0.25)] {
+            let spline = LieGroupCubicBSpline::new(
+                control_poses.clone(),
+                CubicBSplineParams { delta_t, t0 },
+            );
+
+            let mut t = t0 + 0.01 * delta_t;
+            while t < spline.t_max() - 0.01 * delta_t {
+                let pose = spline.interpolate(t);
+                let (velocity, acceleration) = spline.velocity_and_acceleration(t);
+
+                let num_velocity = VectorValuedMapFromVector::<f64, 1>::static_sym_diff_quotient(
+                    |h: VecF64<1>| {
+                        pose.inverse()
+                            .group_mul(&spline.interpolate(t + h[0]))
+                            .log()
+                    },
+                    VecF64::<1>::zeros(),
+                    0.0001 * delta_t,
+                );
+                assert_relative_eq!(
+                    velocity,
+                    num_velocity.column(0).into_owned(),
+                    epsilon = 0.001
+                );
+
+                let num_acceleration =
+                    VectorValuedMapFromVector::<f64, 1>::static_sym_diff_quotient(
+                        |h: VecF64<1>| spline.velocity(t + h[0]),
+                        VecF64::<1>::zeros(),
+                        0.0001 * delta_t,
+                    );
+                assert_relative_eq!(
+                    acceleration,
+                    num_acceleration.column(0).into_owned(),
+                    epsilon = 0.001,
+                    max_relative = 0.001
+                );
+
+                for idx in spline.idx_involved(t) {
+                    let num_dxi = VectorValuedMapFromVector::<f64, 1>::static_sym_diff_quotient(
+                        |delta: VecF64<DOF>| {
+                            let mut perturbed = spline.clone();
+                            perturbed.control_poses[idx] =
+                                LieGroup::exp(&delta).group_mul(&control_poses[idx]);
+                            perturbed.interpolate(t).group_mul(&pose.inverse()).log()
+                        },
+                        VecF64::<DOF>::zeros(),
+                        0.0001,
+                    );
+                    assert_relative_eq!(spline.dxi_interpolate(t, idx), num_dxi, epsilon = 0.001);
+                }
+                t += 0.3 * delta_t;
+            }
+        }
+    }
+
+    run_spline_tests::<3, 4, 3, 3, Rotation3Impl<f64, 1>>();
+    run_spline_tests::<6, 7, 3, 4, Isometry3Impl<f64, 1>>();
+}
@@ -0,0 +1,218 @@
+use crate::lie_group::LieGroup;
+use crate::lie_group_spline::LieGroupCubicBSpline;
+use crate::traits::IsLieGroupImpl;
+use alloc::vec::Vec;
+use sophus_core::calculus::spline::spline_fit::knots_covering_samples;
+use sophus_core::calculus::spline::spline_fit::NormalEquations;
+use sophus_core::calculus::spline::spline_fit::SplineFitError;
+use sophus_core::calculus::spline::spline_fit::SplineFitParams;
+use sophus_core::calculus::spline::spline_fit::SplineFitStats;
+use sophus_core::linalg::MatF64;
+
+// Gauss-Newton stops after this many iterations, or once the update is below the tolerance.
+const MAX_ITERATIONS: usize = 50;
+const UPDATE_TOLERANCE: f64 = 1e-10;
+
+impl<
+        const DOF: usize,
+        const PARAMS: usize,
+        const POINT: usize,
+        const AMBIENT: usize,
+        G: IsLieGroupImpl<f64, DOF, PARAMS, POINT, AMBIENT, 1>,
+    > LieGroupCubicBSpline<f64, DOF, PARAMS, POINT, AMBIENT, G>
+{
+    /// Least squares fit of a cubic B-spline to timestamped poses
+    ///
+    /// This is the Lie group analog of [sophus_core::calculus::spline::BSpline::fit]. The control
+    /// poses minimize
+    ///
+    ///   ∑ₖ |log(T(tₖ) · Tₖ⁻¹)|²  +  λ · ∑ᵢ |(dᵢ - dᵢ₋₁) / delta_t²|²,   with dᵢ = log(Tᵢ⁻¹ · Tᵢ₊₁)
+    ///
+    /// and λ = params.smoothness_weight, using Gauss-Newton. The control poses are initialized
+    /// with the samples closest in time, and are updated from the left, consistent with
+    /// dxi_interpolate.
+    pub fn fit(
+        samples: &[(f64, LieGroup<f64, DOF, PARAMS, POINT, AMBIENT, 1, G>)],
+        params: SplineFitParams,
+    ) -> Result<(Self, SplineFitStats), SplineFitError> {
+        if samples.len() < 2 {
+            return Err(SplineFitError::NotEnoughSamples {
+                num_samples: samples.len(),
+                min_samples: 2,
+            });
+        }
+        let (spline_params, num_control_poses) =
+            knots_covering_samples(samples.iter().map(|s| s.0), &params)?;
+
+        // the samples need not be sorted by time
+        let mut sorted: Vec<usize> = (0..samples.len()).collect();
+        sorted.sort_by(|&a, &b| samples[a].0.total_cmp(&samples[b].0));
+        let control_poses = (0..num_control_poses)
+            .map(|i| {
+                let t = spline_params.t0 + i as f64 * spline_params.delta_t;
+                let next = sorted.partition_point(|&k| samples[k].0 < t);
+                let closest = sorted[next.saturating_sub(1)..(next + 1).min(samples.len())]
+                    .iter()
+                    .min_by(|&&a, &&b| {
+                        (samples[a].0 - t)
+                            .abs()
+                            .total_cmp(&(samples[b].0 - t).abs())
+                    })
+                    .unwrap();
+                samples[*closest].1.clone()
+            })
+            .collect();
+        let mut spline = Self::new(control_poses, spline_params);
+
+        for _ in 0..MAX_ITERATIONS {
+            // a sample depends on four consecutive control poses, the regularizer on three
+            let mut normal_equations = NormalEquations::<DOF>::new(num_control_poses, 3);
+
+            // r = log(T(t) · Tₖ⁻¹), and log(exp(a) · exp(r)) ≈ r + jl_inv(r) · a
+            for (t, pose) in samples {
+                let residual = spline.interpolate(*t).group_mul(&pose.inverse()).log();
+                let jl_inv = G::jl_inv(&residual);
+                let jacobians: Vec<(usize, MatF64<DOF, DOF>)> = spline
+                    .idx_involved(*t)
+                    .into_iter()
+                    .map(|i| (i, jl_inv * spline.dxi_interpolate(*t, i)))
+                    .collect();
+                normal_equations.add(&residual, &jacobians);
+            }
+
+            if params.smoothness_weight > 0.0 {
+                // ∂dᵢ/∂δᵢ₊₁ = -∂dᵢ/∂δᵢ = jr_inv(dᵢ) · Adj(Tᵢ₊₁⁻¹)
+                let deltas_and_jacobians: Vec<_> = spline
+                    .control_poses
+                    .windows(2)
+                    .map(|w| {
+                        let delta = w[0].inverse().group_mul(&w[1]).log();
+                        let dx_delta = G::jr_inv(&delta) * w[1].inverse().adj();
+                        (delta, dx_delta)
+                    })
+                    .collect();
+                for i in 1..num_control_poses - 1 {
+                    let (delta_a, dx_a) = &deltas_and_jacobians[i - 1];
+                    let (delta_b, dx_b) = &deltas_and_jacobians[i];
+                    normal_equations.add_smoothness(
+                        i,
+                        &(delta_b - delta_a),
+                        [*dx_a, -dx_a - dx_b, *dx_b],
+                        params.smoothness_weight / num_traits::Float::powi(params.delta_t, 4),
+                    );
+                }
+            }
+
+            let update = normal_equations.solve()?;
+            for (i, pose) in spline.control_poses.iter_mut().enumerate() {
+                let delta = update.fixed_rows::<DOF>(i * DOF).into_owned();
+                *pose = LieGroup::exp(&delta).group_mul(pose);
+            }
+            if update.amax() < UPDATE_TOLERANCE {
+                break;
+            }
+        }
+
+        let residual_norms: Vec<f64> = samples
+            .iter()
+            .map(|(t, pose)| {
+                spline
+                    .interpolate(*t)
+                    .group_mul(&pose.inverse())
+                    .log()
+                    .norm()
+            })
+            .collect();
+        Ok((spline, SplineFitStats::from_residual_norms(&residual_norms)))
+    }
+}
+
+#[test]
+fn lie_group_spline_fit_tests() {
+    use crate::groups::isometry2::Isometry2Impl;
+    use crate::groups::isometry3::Isometry3Impl;
+    use crate::groups::rotation3::Rotation3Impl;
+    use crate::traits::IsRealLieGroupImpl;
+    use approx::assert_relative_eq;
+    use sophus_core::calculus::spline::CubicBSplineParams;
+    use sophus_core::linalg::VecF64;
+
+    fn run_fit_tests<
+        const DOF: usize,
+        const PARAMS: usize,
+        const POINT: usize,
+        const AMBIENT: usize,
+        G: IsRealLieGroupImpl<f64, DOF, PARAMS, POINT, AMBIENT, 1>,
+    >() {
+        let mut control_poses =
+            vec![LieGroup::<f64, DOF, PARAMS, POINT, AMBIENT, 1, G>::identity()];
+        for tangent in G::tangent_examples().into_iter().take(5) {
+            let pose = control_poses.last().unwrap().clone();
+            control_poses.push(pose.group_mul(&LieGroup::exp(&(tangent * 0.3))));
+        }
+        let spline = LieGroupCubicBSpline::new(
+            control_poses.clone(),
+            CubicBSplineParams {
+                delta_t: 0.5,
+                t0: 2.0,
+            },
+        );
+        let fit_params = |smoothness_weight| SplineFitParams {
+            delta_t: 0.5,
+            smoothness_weight,
+        };
+        let times: Vec<f64> = (0..=(8 * spline.num_segments()))
+            .map(|k| 2.0 + 0.0625 * k as f64)
+            .collect();
+
+        // exact recovery from noise-free samples
+        let samples: Vec<_> = times.iter().map(|t| (*t, spline.interpolate(*t))).collect();
+        let (fitted, stats) = LieGroupCubicBSpline::fit(&samples, fit_params(0.0)).unwrap();
+        assert_eq!(stats.num_samples, samples.len());
+        assert_eq!(fitted.control_poses.len(), control_poses.len());
+        approx::assert_abs_diff_eq!(stats.max, 0.0, epsilon = 1e-8);
+        for (a, b) in fitted.control_poses.iter().zip(control_poses.iter()) {
+            assert_relative_eq!(a.matrix(), b.matrix(), epsilon = 1e-6);
+        }
+
+        // noisy samples: the residuals are at the noise level, and grow with the smoothness weight
+        let samples: Vec<_> = times
+            .iter()
+            .enumerate()
+            .map(|(k, t)| {
+                let noise = VecF64::<DOF>::from_fn(|i, _| 0.002 * ((7 * k + 3 * i) as f64).sin());
+                (*t, LieGroup::exp(&noise).group_mul(&spline.interpolate(*t)))
+            })
+            .collect();
+        let mut prev_rmse = 0.0;
+        for smoothness_weight in [0.0, 1e-5, 1.0] {
+            let (fitted, stats) =
+                LieGroupCubicBSpline::fit(&samples, fit_params(smoothness_weight)).unwrap();
+            assert!(stats.mean <= stats.rmse + 1e-12 && stats.rmse <= stats.max);
+            assert!(stats.rmse >= prev_rmse - 1e-12);
+            prev_rmse = stats.rmse;
+            if smoothness_weight <= 1e-5 {
+                assert!(stats.rmse < 0.01);
+                for t in &times {
+                    let error = fitted
+                        .interpolate(*t)
+                        .group_mul(&spline.interpolate(*t).inverse())
+                        .log();
+                    assert!(error.norm() < 0.01);
+                }
+            }
+        }
+
+        assert_eq!(
+            LieGroupCubicBSpline::fit(&samples[0..1], fit_params(0.0)).err(),
+            Some(SplineFitError::NotEnoughSamples {
+                num_samples: 1,
+                min_samples: 2
+            })
+        );
+    }
+
+    run_fit_tests::<3, 4, 2, 3, Isometry2Impl<f64, 1>>();
+    run_fit_tests::<3, 4, 3, 3, Rotation3Impl<f64, 1>>();
+    run_fit_tests::<6, 7, 3, 4, Isometry3Impl<f64, 1>>();
+}
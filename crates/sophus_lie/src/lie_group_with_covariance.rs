@@ -0,0 +1,259 @@
+use crate::lie_group::LieGroup;
+use crate::traits::IsRealLieGroupImpl;
+use sophus_core::linalg::MatF64;
+use sophus_core::linalg::VecF64;
+
+/// Lie group element with a tangent space covariance
+///
+/// The uncertainty is expressed as a left perturbation of the mean:
+///
+///   x = exp(ξ) · x̄,   ξ ~ N(0, Σ)
+///
+/// where x̄ is the group element and Σ the DOF×DOF covariance. This matches the update
+/// convention used during optimization. Use [Self::right_covariance] and
+/// [Self::from_right_covariance] to convert from / to the right perturbation convention
+/// x = x̄ · exp(ξ).
+///
+/// All operations propagate the covariance to first order and assume that the inputs are
+/// independent.
+#[derive(Debug, Copy, Clone)]
+pub struct LieGroupWithCovariance<
+    const DOF: usize,
+    const PARAMS: usize,
+    const POINT: usize,
+    const AMBIENT: usize,
+    G: IsRealLieGroupImpl<f64, DOF, PARAMS, POINT, AMBIENT, 1>,
+> {
+    /// the mean
+    pub group: LieGroup<f64, DOF, PARAMS, POINT, AMBIENT, 1, G>,
+    /// tangent space covariance, left perturbation
+    pub covariance: MatF64<DOF, DOF>,
+}
+
+impl<
+        const DOF: usize,
+        const PARAMS: usize,
+        const POINT: usize,
+        const AMBIENT: usize,
+        G: IsRealLieGroupImpl<f64, DOF, PARAMS, POINT, AMBIENT, 1>,
+    > LieGroupWithCovariance<DOF, PARAMS, POINT, AMBIENT, G>
+{
+    /// create from group element and left perturbation covariance
+    pub fn new(
+        group: LieGroup<f64, DOF, PARAMS, POINT, AMBIENT, 1, G>,
+        covariance: MatF64<DOF, DOF>,
+    ) -> Self {
+        Self { group, covariance }
+    }
+
+    /// create from group element and right perturbation covariance
+    ///
+    /// Since x̄ · exp(ξ) = exp(Adj(x̄)·ξ) · x̄, we have Σₗ = Adj(x̄) · Σᵣ · Adj(x̄)ᵀ.
+    pub fn from_right_covariance(
+        group: LieGroup<f64, DOF, PARAMS, POINT, AMBIENT, 1, G>,
+        right_covariance: MatF64<DOF, DOF>,
+    ) -> Self {
+        let adj = group.adj();
+        Self {
+            group,
+            covariance: adj * right_covariance * adj.transpose(),
+        }
+    }
+
+    /// covariance of the right perturbation, x = x̄ · exp(ξ)
+    pub fn right_covariance(&self) -> MatF64<DOF, DOF> {
+        let adj_inv = self.group.inverse().adj();
+        adj_inv * self.covariance * adj_inv.transpose()
+    }
+
+    /// group composition: self · rhs
+    ///
+    /// exp(ξₐ)·ā · exp(ξ_b)·b̄ = exp(ξₐ) · exp(Adj(ā)·ξ_b) · ā·b̄, hence
+    /// Σ = Σₐ + Adj(ā) · Σ_b · Adj(ā)ᵀ.
+    pub fn group_mul(&self, rhs: &Self) -> Self {
+        let adj = self.group.adj();
+        Self {
+            group: self.group.group_mul(&rhs.group),
+            covariance: self.covariance + adj * rhs.covariance * adj.transpose(),
+        }
+    }
+
+    /// group inverse
+    ///
+    /// (exp(ξ)·x̄)⁻¹ = exp(-Adj(x̄⁻¹)·ξ) · x̄⁻¹, hence Σ = Adj(x̄⁻¹) · Σ · Adj(x̄⁻¹)ᵀ.
+    pub fn inverse(&self) -> Self {
+        let group_inv = self.group.inverse();
+        let adj_inv = group_inv.adj();
+        Self {
+            group: group_inv,
+            covariance: adj_inv * self.covariance * adj_inv.transpose(),
+        }
+    }
+
+    /// relative pose: self⁻¹ · other
+    ///
+    /// (exp(ξₐ)·ā)⁻¹ · exp(ξ_b)·b̄ ≈ exp(Adj(ā⁻¹)·(ξ_b - ξₐ)) · ā⁻¹·b̄, hence
+    /// Σ = Adj(ā⁻¹) · (Σₐ + Σ_b) · Adj(ā⁻¹)ᵀ.
+    pub fn relative(&self, other: &Self) -> Self {
+        let group_inv = self.group.inverse();
+        let adj_inv = group_inv.adj();
+        Self {
+            group: group_inv.group_mul(&other.group),
+            covariance: adj_inv * (self.covariance + other.covariance) * adj_inv.transpose(),
+        }
+    }
+
+    /// transform a point, returning the transformed point and its covariance
+    ///
+    /// y = exp(ξ)·x̄·p = exp(ξ)·ȳ, hence Σ_y = J · Σ · Jᵀ with J = ∂(exp(ξ)·ȳ)/∂ξ at ξ = 0.
+    pub fn transform(&self, point: &VecF64<POINT>) -> (VecF64<POINT>, MatF64<POINT, POINT>) {
+        let transformed = self.group.transform(point);
+        let dx = LieGroup::<f64, DOF, PARAMS, POINT, AMBIENT, 1, G>::dx_exp_x_times_point_at_0(
+            transformed,
+        );
+        (transformed, dx * self.covariance * dx.transpose())
+    }
+}
+
+#[test]
+fn lie_group_with_covariance_tests() {
+    use crate::groups::isometry2::Isometry2Impl;
+    use crate::groups::isometry3::Isometry3Impl;
+    use crate::groups::rotation3::Rotation3Impl;
+    use crate::groups::sim3::Sim3Impl;
+    use crate::prelude::*;
+    use approx::assert_relative_eq;
+    use sophus_core::calculus::maps::VectorValuedMapFromVector;
+    use sophus_core::points::example_points;
+
+    fn run_covariance_tests<
+        const DOF: usize,
+        const PARAMS: usize,
+        const POINT: usize,
+        const AMBIENT: usize,
+        G: IsRealLieGroupImpl<f64, DOF, PARAMS, POINT, AMBIENT, 1>,
+    >() {
+        type Group<
+            const DOF: usize,
+            const PARAMS: usize,
+            const POINT: usize,
+            const AMBIENT: usize,
+            G,
+        > = LieGroup<f64, DOF, PARAMS, POINT, AMBIENT, 1, G>;
+
+        type GroupOp<
+            const DOF: usize,
+            const PARAMS: usize,
+            const POINT: usize,
+            const AMBIENT: usize,
+            G,
+        > = dyn Fn(
+            &Group<DOF, PARAMS, POINT, AMBIENT, G>,
+            &Group<DOF, PARAMS, POINT, AMBIENT, G>,
+        ) -> Group<DOF, PARAMS, POINT, AMBIENT, G>;
+
+        // symmetric positive definite example covariances
+        let covariance_examples = {
+            let mut a = MatF64::<DOF, DOF>::zeros();
+            let mut b = MatF64::<DOF, DOF>::zeros();
+            for r in 0..DOF {
+                for c in 0..DOF {
+                    a[(r, c)] = 0.1 * (((r * DOF + c) % 5) as f64 - 2.0);
+                    b[(r, c)] = 0.05 * ((r + 2 * c) % 3) as f64;
+                }
+            }
+            let identity = MatF64::<DOF, DOF>::identity();
+            [
+                a * a.transpose() + identity * 0.01,
+                b * b.transpose() + identity * 0.02,
+            ]
+        };
+
+        // first order propagation through f: Σ = Jₐ·Σₐ·Jₐᵀ + J_b·Σ_b·J_bᵀ, with numeric Jacobians
+        let numeric_covariance =
+            |f: &GroupOp<DOF, PARAMS, POINT, AMBIENT, G>,
+             a: &LieGroupWithCovariance<DOF, PARAMS, POINT, AMBIENT, G>,
+             b: &LieGroupWithCovariance<DOF, PARAMS, POINT, AMBIENT, G>| {
+                let f_inv = f(&a.group, &b.group).inverse();
+                let dx_a = VectorValuedMapFromVector::<f64, 1>::static_sym_diff_quotient(
+                    |xi: VecF64<DOF>| {
+                        f(&Group::exp(&xi).group_mul(&a.group), &b.group)
+                            .group_mul(&f_inv)
+                            .log()
+                    },
+                    VecF64::<DOF>::zeros(),
+                    0.0001,
+                );
+                let dx_b = VectorValuedMapFromVector::<f64, 1>::static_sym_diff_quotient(
+                    |xi: VecF64<DOF>| {
+                        f(&a.group, &Group::exp(&xi).group_mul(&b.group))
+                            .group_mul(&f_inv)
+                            .log()
+                    },
+                    VecF64::<DOF>::zeros(),
+                    0.0001,
+                );
+                dx_a * a.covariance * dx_a.transpose() + dx_b * b.covariance * dx_b.transpose()
+            };
+
+        let elements = Group::<DOF, PARAMS, POINT, AMBIENT, G>::element_examples();
+        for a in elements.iter().take(4) {
+            for b in elements.iter().take(4) {
+                if a.has_shortest_path_ambiguity().any() || b.has_shortest_path_ambiguity().any() {
+                    continue;
+                }
+                let a = LieGroupWithCovariance::new(a.clone(), covariance_examples[0]);
+                let b = LieGroupWithCovariance::new(b.clone(), covariance_examples[1]);
+
+                assert_relative_eq!(
+                    a.group_mul(&b).covariance,
+                    numeric_covariance(&|a, b| a.group_mul(b), &a, &b),
+                    epsilon = 0.0001
+                );
+                assert_relative_eq!(
+                    a.relative(&b).covariance,
+                    numeric_covariance(&|a, b| a.inverse().group_mul(b), &a, &b),
+                    epsilon = 0.0001
+                );
+                let b_zero = LieGroupWithCovariance::new(b.group.clone(), MatF64::zeros());
+                assert_relative_eq!(
+                    a.inverse().covariance,
+                    numeric_covariance(&|a, _| a.inverse(), &a, &b_zero),
+                    epsilon = 0.0001
+                );
+
+                // left and right perturbation round trip
+                assert_relative_eq!(
+                    LieGroupWithCovariance::from_right_covariance(
+                        a.group.clone(),
+                        a.right_covariance()
+                    )
+                    .covariance,
+                    a.covariance,
+                    epsilon = 1e-9
+                );
+            }
+
+            let a = LieGroupWithCovariance::new(a.clone(), covariance_examples[0]);
+            for point in example_points::<f64, POINT, 1>() {
+                let dx = VectorValuedMapFromVector::<f64, 1>::static_sym_diff_quotient(
+                    |xi: VecF64<DOF>| Group::exp(&xi).group_mul(&a.group).transform(&point),
+                    VecF64::<DOF>::zeros(),
+                    0.0001,
+                );
+                let (transformed, point_covariance) = a.transform(&point);
+                assert_relative_eq!(transformed, a.group.transform(&point), epsilon = 1e-10);
+                assert_relative_eq!(
+                    point_covariance,
+                    dx * a.covariance * dx.transpose(),
+                    epsilon = 0.0001
+                );
+            }
+        }
+    }
+
+    run_covariance_tests::<3, 4, 2, 3, Isometry2Impl<f64, 1>>();
+    run_covariance_tests::<3, 4, 3, 3, Rotation3Impl<f64, 1>>();
+    run_covariance_tests::<6, 7, 3, 4, Isometry3Impl<f64, 1>>();
+    run_covariance_tests::<7, 7, 3, 4, Sim3Impl<f64, 1>>();
+}
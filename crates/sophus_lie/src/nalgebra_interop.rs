@@ -0,0 +1,294 @@
+use crate::lie_group_error::LieGroupError;
+use crate::prelude::*;
+use crate::Isometry2;
+use crate::Isometry3;
+use crate::Rotation2;
+use crate::Rotation3;
+use alloc::format;
+use sophus_core::linalg::VecF64;
+
+// Storage order: sophus stores a unit quaternion as params [w, x, y, z], while nalgebra stores
+// the coordinates of a quaternion as [x, y, z, w]. Unit complex numbers are stored as
+// [re, im] = [cos(θ), sin(θ)] by both. Isometries are stored as params [translation; rotation].
+
+impl From<nalgebra::UnitQuaternion<f64>> for Rotation3<f64, 1> {
+    /// Re-normalizes the quaternion, to absorb numerical drift of the nalgebra type.
+    fn from(q: nalgebra::UnitQuaternion<f64>) -> Self {
+        let q = q.into_inner().normalize();
+        Rotation3::from_params(&VecF64::<4>::new(q.w, q.i, q.j, q.k))
+    }
+}
+
+impl From<Rotation3<f64, 1>> for nalgebra::UnitQuaternion<f64> {
+    fn from(rotation: Rotation3<f64, 1>) -> Self {
+        let params = rotation.params();
+        nalgebra::UnitQuaternion::new_unchecked(nalgebra::Quaternion::new(
+            params[0], params[1], params[2], params[3],
+        ))
+    }
+}
+
+impl TryFrom<nalgebra::Rotation3<f64>> for Rotation3<f64, 1> {
+    type Error = LieGroupError;
+
+    /// Fails if the matrix of the rotation is not orthogonal with determinant one, within 1e-6.
+    fn try_from(rotation: nalgebra::Rotation3<f64>) -> Result<Self, Self::Error> {
+        Rotation3::try_from_matrix(rotation.matrix())
+    }
+}
+
+impl From<Rotation3<f64, 1>> for nalgebra::Rotation3<f64> {
+    fn from(rotation: Rotation3<f64, 1>) -> Self {
+        nalgebra::Rotation3::from_matrix_unchecked(rotation.matrix())
+    }
+}
+
+impl From<nalgebra::UnitComplex<f64>> for Rotation2<f64, 1> {
+    /// Re-normalizes the complex number, to absorb numerical drift of the nalgebra type.
+    fn from(z: nalgebra::UnitComplex<f64>) -> Self {
+        let z = z.into_inner();
+        let norm = num_traits::Float::hypot(z.re, z.im);
+        Rotation2::from_params(&VecF64::<2>::new(z.re / norm, z.im / norm))
+    }
+}
+
+impl From<Rotation2<f64, 1>> for nalgebra::UnitComplex<f64> {
+    fn from(rotation: Rotation2<f64, 1>) -> Self {
+        let params = rotation.params();
+        nalgebra::UnitComplex::from_cos_sin_unchecked(params[0], params[1])
+    }
+}
+
+impl TryFrom<nalgebra::Rotation2<f64>> for Rotation2<f64, 1> {
+    type Error = LieGroupError;
+
+    /// Fails if the matrix of the rotation is not orthogonal with determinant one, within 1e-6.
+    fn try_from(rotation: nalgebra::Rotation2<f64>) -> Result<Self, Self::Error> {
+        Rotation2::try_from_matrix(rotation.matrix())
+    }
+}
+
+impl From<Rotation2<f64, 1>> for nalgebra::Rotation2<f64> {
+    fn from(rotation: Rotation2<f64, 1>) -> Self {
+        nalgebra::Rotation2::from_matrix_unchecked(rotation.matrix())
+    }
+}
+
+impl From<nalgebra::Isometry3<f64>> for Isometry3<f64, 1> {
+    fn from(isometry: nalgebra::Isometry3<f64>) -> Self {
+        Isometry3::from_translation_and_rotation(
+            &isometry.translation.vector,
+            &isometry.rotation.into(),
+        )
+    }
+}
+
+impl From<Isometry3<f64, 1>> for nalgebra::Isometry3<f64> {
+    fn from(isometry: Isometry3<f64, 1>) -> Self {
+        nalgebra::Isometry3::from_parts(isometry.translation().into(), isometry.rotation().into())
+    }
+}
+
+impl From<nalgebra::Isometry2<f64>> for Isometry2<f64, 1> {
+    fn from(isometry: nalgebra::Isometry2<f64>) -> Self {
+        Isometry2::from_translation_and_rotation(
+            &isometry.translation.vector,
+            &isometry.rotation.into(),
+        )
+    }
+}
+
+impl From<Isometry2<f64, 1>> for nalgebra::Isometry2<f64> {
+    fn from(isometry: Isometry2<f64, 1>) -> Self {
+        nalgebra::Isometry2::from_parts(isometry.translation().into(), isometry.rotation().into())
+    }
+}
+
+/// check that the similarity has unit scaling, |s - 1| ≤ 1e-6
+fn check_unit_scaling(scaling: f64, matrix: impl core::fmt::Debug) -> Result<(), LieGroupError> {
+    // also rejects NaN
+    if (scaling - 1.0).abs() <= 1e-6 {
+        return Ok(());
+    }
+    Err(LieGroupError::InvalidMatrix {
+        matrix: format!("{:?}", matrix),
+        constraint: format!("unit scaling, s = 1 (within 1e-6), but s = {scaling}"),
+    })
+}
+
+impl TryFrom<nalgebra::Similarity3<f64>> for Isometry3<f64, 1> {
+    type Error = LieGroupError;
+
+    /// Fails if the scaling of the similarity deviates from one by more than 1e-6.
+    fn try_from(similarity: nalgebra::Similarity3<f64>) -> Result<Self, Self::Error> {
+        check_unit_scaling(similarity.scaling(), similarity.to_homogeneous())?;
+        Ok(similarity.isometry.into())
+    }
+}
+
+impl From<Isometry3<f64, 1>> for nalgebra::Similarity3<f64> {
+    fn from(isometry: Isometry3<f64, 1>) -> Self {
+        nalgebra::Similarity3::from_isometry(isometry.into(), 1.0)
+    }
+}
+
+impl TryFrom<nalgebra::Similarity2<f64>> for Isometry2<f64, 1> {
+    type Error = LieGroupError;
+
+    /// Fails if the scaling of the similarity deviates from one by more than 1e-6.
+    fn try_from(similarity: nalgebra::Similarity2<f64>) -> Result<Self, Self::Error> {
+        check_unit_scaling(similarity.scaling(), similarity.to_homogeneous())?;
+        Ok(similarity.isometry.into())
+    }
+}
+
+impl From<Isometry2<f64, 1>> for nalgebra::Similarity2<f64> {
+    fn from(isometry: Isometry2<f64, 1>) -> Self {
+        nalgebra::Similarity2::from_isometry(isometry.into(), 1.0)
+    }
+}
+
+#[test]
+fn nalgebra_interop_tests() {
+    use approx::assert_relative_eq;
+    use sophus_core::linalg::MatF64;
+
+    // storage order: sophus params [w, x, y, z] vs. nalgebra coords [x, y, z, w]
+    {
+        let q = nalgebra::UnitQuaternion::from_quaternion(nalgebra::Quaternion::new(
+            0.5, 0.1, -0.3, 0.7,
+        ));
+        let rotation = Rotation3::<f64, 1>::from(q);
+        assert_eq!(rotation.params()[0], q.w);
+        assert_eq!(rotation.params()[1], q.i);
+        assert_eq!(rotation.params()[2], q.j);
+        assert_eq!(rotation.params()[3], q.k);
+        assert_eq!(q.coords[3], q.w);
+        assert_eq!(
+            VecF64::<4>::new(q.coords[3], q.coords[0], q.coords[1], q.coords[2]),
+            *rotation.params()
+        );
+
+        // same rotation, not just the same numbers
+        let point = VecF64::<3>::new(1.0, -2.0, 0.5);
+        assert_relative_eq!(rotation.transform(&point), q * point, epsilon = 1e-12);
+        assert_relative_eq!(
+            rotation.matrix(),
+            q.to_rotation_matrix().into_inner(),
+            epsilon = 1e-12
+        );
+
+        let q2: nalgebra::UnitQuaternion<f64> = rotation.into();
+        assert_eq!(q2, q);
+    }
+    {
+        let rotation = Rotation3::<f64, 1>::rot_z(0.3);
+        let q: nalgebra::UnitQuaternion<f64> = rotation.into();
+        assert_relative_eq!(q.w, (0.15f64).cos(), epsilon = 1e-12);
+        assert_relative_eq!(q.k, (0.15f64).sin(), epsilon = 1e-12);
+        assert_relative_eq!(q.coords[2], (0.15f64).sin(), epsilon = 1e-12);
+    }
+
+    // storage order: [re, im] = [cos(θ), sin(θ)] for both
+    {
+        let z = nalgebra::UnitComplex::new(0.7);
+        let rotation = Rotation2::<f64, 1>::from(z);
+        assert_relative_eq!(rotation.params()[0], (0.7f64).cos(), epsilon = 1e-12);
+        assert_relative_eq!(rotation.params()[1], (0.7f64).sin(), epsilon = 1e-12);
+        assert_relative_eq!(rotation.log()[0], z.angle(), epsilon = 1e-12);
+        let z2: nalgebra::UnitComplex<f64> = rotation.into();
+        assert_relative_eq!(z2.angle(), 0.7, epsilon = 1e-12);
+    }
+
+    // rotation matrices
+    {
+        let na_rotation = nalgebra::Rotation3::from_euler_angles(0.1, -0.4, 1.2);
+        let rotation = Rotation3::<f64, 1>::try_from(na_rotation).unwrap();
+        assert_relative_eq!(rotation.matrix(), *na_rotation.matrix(), epsilon = 1e-12);
+        let na_rotation2: nalgebra::Rotation3<f64> = rotation.into();
+        assert_relative_eq!(na_rotation2, na_rotation, epsilon = 1e-12);
+
+        let scaled = nalgebra::Rotation3::from_matrix_unchecked(na_rotation.matrix() * 2.0);
+        assert!(matches!(
+            Rotation3::<f64, 1>::try_from(scaled),
+            Err(LieGroupError::InvalidMatrix { .. })
+        ));
+
+        let na_rotation = nalgebra::Rotation2::new(-2.5);
+        let rotation = Rotation2::<f64, 1>::try_from(na_rotation).unwrap();
+        assert_relative_eq!(rotation.matrix(), *na_rotation.matrix(), epsilon = 1e-12);
+        let na_rotation2: nalgebra::Rotation2<f64> = rotation.into();
+        assert_relative_eq!(na_rotation2, na_rotation, epsilon = 1e-12);
+
+        let reflection =
+            nalgebra::Rotation2::from_matrix_unchecked(MatF64::<2, 2>::new(1.0, 0.0, 0.0, -1.0));
+        assert!(Rotation2::<f64, 1>::try_from(reflection).is_err());
+    }
+
+    // isometries: params [translation; rotation]
+    {
+        let na_isometry = nalgebra::Isometry3::new(
+            VecF64::<3>::new(1.0, 2.0, 3.0),
+            VecF64::<3>::new(0.2, -0.1, 0.6),
+        );
+        let isometry = Isometry3::<f64, 1>::from(na_isometry);
+        assert_eq!(isometry.translation(), na_isometry.translation.vector);
+        assert_relative_eq!(
+            isometry.params()[3],
+            na_isometry.rotation.w,
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            isometry.params()[4],
+            na_isometry.rotation.i,
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            isometry.matrix(),
+            na_isometry.to_homogeneous(),
+            epsilon = 1e-12
+        );
+        let point = VecF64::<3>::new(-1.0, 0.5, 2.0);
+        assert_relative_eq!(
+            isometry.transform(&point),
+            na_isometry.transform_point(&point.into()).coords,
+            epsilon = 1e-12
+        );
+        let na_isometry2: nalgebra::Isometry3<f64> = isometry.into();
+        assert_relative_eq!(na_isometry2, na_isometry, epsilon = 1e-12);
+
+        let na_isometry = nalgebra::Isometry2::new(VecF64::<2>::new(-1.0, 4.0), 1.1);
+        let isometry = Isometry2::<f64, 1>::from(na_isometry);
+        assert_eq!(isometry.translation(), na_isometry.translation.vector);
+        assert_relative_eq!(
+            isometry.matrix(),
+            na_isometry.to_homogeneous(),
+            epsilon = 1e-12
+        );
+        let na_isometry2: nalgebra::Isometry2<f64> = isometry.into();
+        assert_relative_eq!(na_isometry2, na_isometry, epsilon = 1e-12);
+    }
+
+    // similarities
+    {
+        let isometry = Isometry3::<f64, 1>::exp(&VecF64::<6>::new(0.1, 0.2, 0.3, 0.4, 0.5, 0.6));
+        let similarity: nalgebra::Similarity3<f64> = isometry.into();
+        assert_eq!(similarity.scaling(), 1.0);
+        let isometry2 = Isometry3::<f64, 1>::try_from(similarity).unwrap();
+        assert_relative_eq!(isometry2.params(), isometry.params(), epsilon = 1e-12);
+
+        let scaled = nalgebra::Similarity3::from_isometry(isometry.into(), 2.0);
+        assert!(matches!(
+            Isometry3::<f64, 1>::try_from(scaled),
+            Err(LieGroupError::InvalidMatrix { .. })
+        ));
+
+        let isometry = Isometry2::<f64, 1>::exp(&VecF64::<3>::new(0.1, 0.2, 0.3));
+        let similarity: nalgebra::Similarity2<f64> = isometry.into();
+        let isometry2 = Isometry2::<f64, 1>::try_from(similarity).unwrap();
+        assert_relative_eq!(isometry2.params(), isometry.params(), epsilon = 1e-12);
+
+        let scaled = nalgebra::Similarity2::from_isometry(isometry.into(), 0.5);
+        assert!(Isometry2::<f64, 1>::try_from(scaled).is_err());
+    }
+}
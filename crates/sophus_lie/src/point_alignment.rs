@@ -0,0 +1,451 @@
+use crate::prelude::*;
+use crate::Isometry2;
+use crate::Isometry3;
+use crate::Rotation2;
+use crate::Rotation3;
+use crate::RxSO2;
+use crate::RxSO3;
+use crate::Sim2;
+use crate::Sim3;
+use sophus_core::linalg::MatF64;
+use sophus_core::linalg::VecF64;
+
+/// Error returned by the point-set alignment functions
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PointAlignmentError {
+    /// source, target and weights do not have the same length
+    MismatchedLengths {
+        /// number of source points
+        num_source: usize,
+        /// number of target points
+        num_target: usize,
+        /// number of weights
+        num_weights: usize,
+    },
+    /// fewer points than required to determine the transformation
+    NotEnoughPoints {
+        /// number of given points
+        num_points: usize,
+        /// minimal number of points
+        min_points: usize,
+    },
+    /// weights are negative, non-finite or sum to zero
+    InvalidWeights,
+    /// the source points do not determine a unique transformation, e.g. they are collinear
+    DegenerateConfiguration,
+}
+
+impl core::fmt::Display for PointAlignmentError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PointAlignmentError::MismatchedLengths {
+                num_source,
+                num_target,
+                num_weights,
+            } => write!(
+                f,
+                "mismatched lengths: {} source points, {} target points, {} weights",
+                num_source, num_target, num_weights
+            ),
+            PointAlignmentError::NotEnoughPoints {
+                num_points,
+                min_points,
+            } => write!(
+                f,
+                "not enough points: got {}, need at least {}",
+                num_points, min_points
+            ),
+            PointAlignmentError::InvalidWeights => {
+                write!(f, "weights must be finite, non-negative and not all zero")
+            }
+            PointAlignmentError::DegenerateConfiguration => {
+                write!(f, "degenerate point configuration")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PointAlignmentError {}
+
+// Relative threshold on the singular values of the cross-covariance, and on the spread of the
+// source points. It does not depend on the unit of the points, e.g. metres or micrometres.
+const DEGENERACY_THRESHOLD: f64 = 1e-10;
+
+// Weighted first and second moments of the two point sets.
+struct AlignmentMoments<const DIM: usize> {
+    source_mean: VecF64<DIM>,
+    target_mean: VecF64<DIM>,
+    // Σᵢ wᵢ·(bᵢ - b̄)·(aᵢ - ā)ᵀ / Σᵢ wᵢ
+    cross_covariance: MatF64<DIM, DIM>,
+    // Σᵢ wᵢ·|aᵢ - ā|² / Σᵢ wᵢ
+    source_variance: f64,
+}
+
+impl<const DIM: usize> AlignmentMoments<DIM> {
+    fn new(
+        source: &[VecF64<DIM>],
+        target: &[VecF64<DIM>],
+        weights: Option<&[f64]>,
+        min_points: usize,
+        centered: bool,
+    ) -> Result<Self, PointAlignmentError> {
+        let num_weights = weights.map_or(source.len(), |w| w.len());
+        if source.len() != target.len() || source.len() != num_weights {
+            return Err(PointAlignmentError::MismatchedLengths {
+                num_source: source.len(),
+                num_target: target.len(),
+                num_weights,
+            });
+        }
+        if source.len() < min_points {
+            return Err(PointAlignmentError::NotEnoughPoints {
+                num_points: source.len(),
+                min_points,
+            });
+        }
+        let weight = |i: usize| weights.map_or(1.0, |w| w[i]);
+        if (0..source.len()).any(|i| !weight(i).is_finite() || weight(i) < 0.0) {
+            return Err(PointAlignmentError::InvalidWeights);
+        }
+        let weight_sum: f64 = (0..source.len()).map(weight).sum();
+        if weight_sum <= 0.0 {
+            return Err(PointAlignmentError::InvalidWeights);
+        }
+
+        let mut source_mean = VecF64::<DIM>::zeros();
+        let mut target_mean = VecF64::<DIM>::zeros();
+        if centered {
+            for i in 0..source.len() {
+                source_mean += source[i] * (weight(i) / weight_sum);
+                target_mean += target[i] * (weight(i) / weight_sum);
+            }
+        }
+
+        let mut cross_covariance = MatF64::<DIM, DIM>::zeros();
+        let mut source_variance = 0.0;
+        for i in 0..source.len() {
+            let a = source[i] - source_mean;
+            let b = target[i] - target_mean;
+            let w = weight(i) / weight_sum;
+            cross_covariance += b * a.transpose() * w;
+            source_variance += a.norm_squared() * w;
+        }
+
+        Ok(Self {
+            source_mean,
+            target_mean,
+            cross_covariance,
+            source_variance,
+        })
+    }
+
+    // Returns the scale c = tr(D·S) / σₐ², given tr(D·S).
+    fn scale(&self, trace: f64) -> Result<f64, PointAlignmentError> {
+        // the source points coincide, up to round-off relative to their mean
+        let coincident_variance =
+            DEGENERACY_THRESHOLD * DEGENERACY_THRESHOLD * self.source_mean.norm_squared();
+        if self.source_variance <= coincident_variance
+            || trace <= DEGENERACY_THRESHOLD * self.source_variance
+        {
+            return Err(PointAlignmentError::DegenerateConfiguration);
+        }
+        Ok(trace / self.source_variance)
+    }
+
+    // t = b̄ - c·R·ā
+    fn translation(&self, scaled_rotation: &MatF64<DIM, DIM>) -> VecF64<DIM> {
+        self.target_mean - scaled_rotation * self.source_mean
+    }
+}
+
+// Rotation maximizing tr(Rᵀ·H) together with tr(D·S) of the Umeyama solution.
+fn rotation3_from_cross_covariance(
+    cross_covariance: &MatF64<3, 3>,
+) -> Result<(Rotation3<f64, 1>, f64), PointAlignmentError> {
+    let svd = cross_covariance.svd(true, true);
+    let u = svd.u.unwrap();
+    let v_t = svd.v_t.unwrap();
+
+    // nalgebra does not sort the singular values
+    let mut sorted = [
+        svd.singular_values[0],
+        svd.singular_values[1],
+        svd.singular_values[2],
+    ];
+    sorted.sort_unstable_by(|a, b| b.total_cmp(a));
+    if sorted[0] == 0.0 || sorted[1] <= DEGENERACY_THRESHOLD * sorted[0] {
+        return Err(PointAlignmentError::DegenerateConfiguration);
+    }
+
+    // flip the direction of the smallest singular value in case of a reflection
+    let mut s = VecF64::<3>::repeat(1.0);
+    if (u * v_t).determinant() < 0.0 {
+        let smallest = svd.singular_values.imin();
+        s[smallest] = -1.0;
+    }
+    let mat_r = u * MatF64::<3, 3>::from_diagonal(&s) * v_t;
+    let trace = svd.singular_values.component_mul(&s).sum();
+
+    let q = nalgebra::UnitQuaternion::from_matrix(&mat_r);
+    let rotation = Rotation3::from_params(&VecF64::<4>::new(q.w, q.i, q.j, q.k));
+    Ok((rotation, trace))
+}
+
+// In 2D, the optimal rotation angle has a closed form solution.
+fn rotation2_from_cross_covariance(
+    cross_covariance: &MatF64<2, 2>,
+) -> Result<(Rotation2<f64, 1>, f64), PointAlignmentError> {
+    let cos_part = cross_covariance[(0, 0)] + cross_covariance[(1, 1)];
+    let sin_part = cross_covariance[(1, 0)] - cross_covariance[(0, 1)];
+    let trace = (cos_part * cos_part + sin_part * sin_part).sqrt();
+    let norm = cross_covariance.norm();
+    if norm == 0.0 || trace <= DEGENERACY_THRESHOLD * norm {
+        return Err(PointAlignmentError::DegenerateConfiguration);
+    }
+    let rotation = Rotation2::from_params(&VecF64::<2>::new(cos_part / trace, sin_part / trace));
+    Ok((rotation, trace))
+}
+
+/// Rotation R minimizing Σᵢ wᵢ·|bᵢ - R·aᵢ|²
+///
+/// Kabsch algorithm: `source` = {aᵢ}, `target` = {bᵢ}. If `weights` is None, all points are
+/// weighted equally.
+pub fn align_rotation3(
+    source: &[VecF64<3>],
+    target: &[VecF64<3>],
+    weights: Option<&[f64]>,
+) -> Result<Rotation3<f64, 1>, PointAlignmentError> {
+    let moments = AlignmentMoments::new(source, target, weights, 2, false)?;
+    Ok(rotation3_from_cross_covariance(&moments.cross_covariance)?.0)
+}
+
+/// Isometry T minimizing Σᵢ wᵢ·|bᵢ - T·aᵢ|²
+///
+/// Kabsch algorithm: `source` = {aᵢ}, `target` = {bᵢ}. If `weights` is None, all points are
+/// weighted equally.
+pub fn align_isometry3(
+    source: &[VecF64<3>],
+    target: &[VecF64<3>],
+    weights: Option<&[f64]>,
+) -> Result<Isometry3<f64, 1>, PointAlignmentError> {
+    let moments = AlignmentMoments::new(source, target, weights, 3, true)?;
+    let (rotation, _) = rotation3_from_cross_covariance(&moments.cross_covariance)?;
+    let translation = moments.translation(&rotation.matrix());
+    Ok(Isometry3::from_translation_and_rotation(
+        &translation,
+        &rotation,
+    ))
+}
+
+/// Similarity T minimizing Σᵢ wᵢ·|bᵢ - T·aᵢ|²
+///
+/// Umeyama algorithm: `source` = {aᵢ}, `target` = {bᵢ}. If `weights` is None, all points are
+/// weighted equally.
+pub fn align_sim3(
+    source: &[VecF64<3>],
+    target: &[VecF64<3>],
+    weights: Option<&[f64]>,
+) -> Result<Sim3<f64, 1>, PointAlignmentError> {
+    let moments = AlignmentMoments::new(source, target, weights, 3, true)?;
+    let (rotation, trace) = rotation3_from_cross_covariance(&moments.cross_covariance)?;
+    let scale = moments.scale(trace)?;
+    let translation = moments.translation(&(rotation.matrix() * scale));
+    Ok(Sim3::from_translation_and_rxso3(
+        &translation,
+        &RxSO3::from_scale_and_rotation(scale, &rotation),
+    ))
+}
+
+/// Rotation R minimizing Σᵢ wᵢ·|bᵢ - R·aᵢ|²
+///
+/// `source` = {aᵢ}, `target` = {bᵢ}. If `weights` is None, all points are weighted equally.
+pub fn align_rotation2(
+    source: &[VecF64<2>],
+    target: &[VecF64<2>],
+    weights: Option<&[f64]>,
+) -> Result<Rotation2<f64, 1>, PointAlignmentError> {
+    let moments = AlignmentMoments::new(source, target, weights, 1, false)?;
+    Ok(rotation2_from_cross_covariance(&moments.cross_covariance)?.0)
+}
+
+/// Isometry T minimizing Σᵢ wᵢ·|bᵢ - T·aᵢ|²
+///
+/// `source` = {aᵢ}, `target` = {bᵢ}. If `weights` is None, all points are weighted equally.
+pub fn align_isometry2(
+    source: &[VecF64<2>],
+    target: &[VecF64<2>],
+    weights: Option<&[f64]>,
+) -> Result<Isometry2<f64, 1>, PointAlignmentError> {
+    let moments = AlignmentMoments::new(source, target, weights, 2, true)?;
+    let (rotation, _) = rotation2_from_cross_covariance(&moments.cross_covariance)?;
+    let translation = moments.translation(&rotation.matrix());
+    Ok(Isometry2::from_translation_and_rotation(
+        &translation,
+        &rotation,
+    ))
+}
+
+/// Similarity T minimizing Σᵢ wᵢ·|bᵢ - T·aᵢ|²
+///
+/// Umeyama algorithm: `source` = {aᵢ}, `target` = {bᵢ}. If `weights` is None, all points are
+/// weighted equally.
+pub fn align_sim2(
+    source: &[VecF64<2>],
+    target: &[VecF64<2>],
+    weights: Option<&[f64]>,
+) -> Result<Sim2<f64, 1>, PointAlignmentError> {
+    let moments = AlignmentMoments::new(source, target, weights, 2, true)?;
+    let (rotation, trace) = rotation2_from_cross_covariance(&moments.cross_covariance)?;
+    let scale = moments.scale(trace)?;
+    let translation = moments.translation(&(rotation.matrix() * scale));
+    Ok(Sim2::from_translation_and_rxso2(
+        &translation,
+        &RxSO2::from_scale_and_rotation(scale, &rotation),
+    ))
+}
+
+#[test]
+fn point_alignment_tests() {
+    use approx::assert_relative_eq;
+
+    let source3 = vec![
+        VecF64::<3>::new(0.1, 0.0, 0.0),
+        VecF64::<3>::new(1.0, 4.0, 1.0),
+        VecF64::<3>::new(0.7, 5.0, 1.1),
+        VecF64::<3>::new(-1.0, 3.0, -0.5),
+        VecF64::<3>::new(2.0, -3.0, 1.0),
+    ];
+    let source2: Vec<VecF64<2>> = source3
+        .iter()
+        .map(|p| VecF64::<2>::new(p[0], p[1]))
+        .collect();
+
+    // 3d: exact recovery
+    let sim3 = Sim3::<f64, 1>::exp(&VecF64::<7>::from_f64_array([
+        0.5, -0.2, 1.0, 0.3, -0.4, 2.8, 0.2,
+    ]));
+    let target3: Vec<VecF64<3>> = source3.iter().map(|p| sim3.transform(p)).collect();
+    let result = align_sim3(&source3, &target3, None).unwrap();
+    assert_relative_eq!(result.matrix(), sim3.matrix(), epsilon = 1e-9);
+
+    let iso3 = Isometry3::<f64, 1>::exp(&VecF64::<6>::new(0.5, -0.2, 1.0, 0.3, -0.4, 2.8));
+    let target3: Vec<VecF64<3>> = source3.iter().map(|p| iso3.transform(p)).collect();
+    let result = align_isometry3(&source3, &target3, None).unwrap();
+    assert_relative_eq!(result.matrix(), iso3.matrix(), epsilon = 1e-9);
+
+    let rot3 = iso3.rotation();
+    let target3: Vec<VecF64<3>> = source3.iter().map(|p| rot3.transform(p)).collect();
+    let result = align_rotation3(&source3, &target3, None).unwrap();
+    assert_relative_eq!(result.matrix(), rot3.matrix(), epsilon = 1e-9);
+
+    // a rotation by π is recovered as well
+    let rot_pi = Rotation3::<f64, 1>::exp(&VecF64::<3>::new(0.0, core::f64::consts::PI, 0.0));
+    let target3: Vec<VecF64<3>> = source3.iter().map(|p| rot_pi.transform(p)).collect();
+    let result = align_rotation3(&source3, &target3, None).unwrap();
+    assert_relative_eq!(result.matrix(), rot_pi.matrix(), epsilon = 1e-9);
+
+    // an outlier with zero weight does not affect the result
+    let mut target3: Vec<VecF64<3>> = source3.iter().map(|p| iso3.transform(p)).collect();
+    let mut source3_with_outlier = source3.clone();
+    source3_with_outlier.push(VecF64::<3>::new(1.0, 1.0, 1.0));
+    target3.push(VecF64::<3>::new(10.0, -5.0, 3.0));
+    let weights = [1.0, 2.0, 0.5, 1.0, 3.0, 0.0];
+    let result = align_isometry3(&source3_with_outlier, &target3, Some(&weights)).unwrap();
+    assert_relative_eq!(result.matrix(), iso3.matrix(), epsilon = 1e-9);
+
+    // a reflection is never returned
+    let mirrored: Vec<VecF64<3>> = source3
+        .iter()
+        .map(|p| VecF64::<3>::new(p[0], p[1], -p[2]))
+        .collect();
+    let result = align_rotation3(&source3, &mirrored, None).unwrap();
+    assert_relative_eq!(result.matrix().determinant(), 1.0, epsilon = 1e-9);
+
+    // 2d: exact recovery
+    let sim2 = Sim2::<f64, 1>::exp(&VecF64::<4>::new(0.5, -0.2, 2.5, -0.3));
+    let target2: Vec<VecF64<2>> = source2.iter().map(|p| sim2.transform(p)).collect();
+    let result = align_sim2(&source2, &target2, None).unwrap();
+    assert_relative_eq!(result.matrix(), sim2.matrix(), epsilon = 1e-9);
+
+    let iso2 = Isometry2::<f64, 1>::exp(&VecF64::<3>::new(0.5, -0.2, -3.0));
+    let target2: Vec<VecF64<2>> = source2.iter().map(|p| iso2.transform(p)).collect();
+    let weights = [1.0, 2.0, 0.5, 1.0, 3.0];
+    let result = align_isometry2(&source2, &target2, Some(&weights)).unwrap();
+    assert_relative_eq!(result.matrix(), iso2.matrix(), epsilon = 1e-9);
+
+    let rot2 = iso2.rotation();
+    let target2: Vec<VecF64<2>> = source2.iter().map(|p| rot2.transform(p)).collect();
+    let result = align_rotation2(&source2[1..2], &target2[1..2], None).unwrap();
+    assert_relative_eq!(result.matrix(), rot2.matrix(), epsilon = 1e-9);
+
+    // the degeneracy thresholds are relative, hence millimetre and micrometre scale points are
+    // aligned as well
+    for unit in [1e-3, 1e-6, 1e-9] {
+        let small3: Vec<VecF64<3>> = source3.iter().map(|p| p * unit).collect();
+        let small2: Vec<VecF64<2>> = source2.iter().map(|p| p * unit).collect();
+
+        let target3: Vec<VecF64<3>> = small3.iter().map(|p| rot3.transform(p)).collect();
+        let result = align_rotation3(&small3[1..5], &target3[1..5], None).unwrap();
+        assert_relative_eq!(result.matrix(), rot3.matrix(), epsilon = 1e-9);
+
+        let small_sim3 = Sim3::<f64, 1>::exp(&VecF64::<7>::from_f64_array([
+            0.5 * unit,
+            -0.2 * unit,
+            unit,
+            0.3,
+            -0.4,
+            2.8,
+            0.2,
+        ]));
+        let target3: Vec<VecF64<3>> = small3.iter().map(|p| small_sim3.transform(p)).collect();
+        let result = align_sim3(&small3, &target3, None).unwrap();
+        assert_relative_eq!(result.matrix(), small_sim3.matrix(), epsilon = 1e-9);
+
+        let target2: Vec<VecF64<2>> = small2.iter().map(|p| rot2.transform(p)).collect();
+        let result = align_rotation2(&small2[1..2], &target2[1..2], None).unwrap();
+        assert_relative_eq!(result.matrix(), rot2.matrix(), epsilon = 1e-9);
+
+        let small_sim2 = Sim2::<f64, 1>::exp(&VecF64::<4>::new(0.5 * unit, -0.2 * unit, 2.5, -0.3));
+        let target2: Vec<VecF64<2>> = small2.iter().map(|p| small_sim2.transform(p)).collect();
+        let result = align_sim2(&small2, &target2, None).unwrap();
+        assert_relative_eq!(result.matrix(), small_sim2.matrix(), epsilon = 1e-9);
+    }
+
+    // errors
+    assert_eq!(
+        align_isometry3(&source3, &source3[1..], None).unwrap_err(),
+        PointAlignmentError::MismatchedLengths {
+            num_source: 5,
+            num_target: 4,
+            num_weights: 5
+        }
+    );
+    assert_eq!(
+        align_isometry3(&source3[0..2], &source3[0..2], None).unwrap_err(),
+        PointAlignmentError::NotEnoughPoints {
+            num_points: 2,
+            min_points: 3
+        }
+    );
+    assert_eq!(
+        align_isometry2(&source2, &source2, Some(&[1.0, 1.0, -1.0, 1.0, 1.0])).unwrap_err(),
+        PointAlignmentError::InvalidWeights
+    );
+    assert_eq!(
+        align_sim2(&source2, &source2, Some(&[0.0; 5])).unwrap_err(),
+        PointAlignmentError::InvalidWeights
+    );
+    let collinear: Vec<VecF64<3>> = (0..4)
+        .map(|i| VecF64::<3>::new(1.0, 2.0, -1.0) * i as f64)
+        .collect();
+    assert_eq!(
+        align_isometry3(&collinear, &collinear, None).unwrap_err(),
+        PointAlignmentError::DegenerateConfiguration
+    );
+    let coincident = vec![VecF64::<2>::new(1.0, 2.0); 3];
+    assert_eq!(
+        align_sim2(&coincident, &coincident, None).unwrap_err(),
+        PointAlignmentError::DegenerateConfiguration
+    );
+}
@@ -2,24 +2,53 @@ use super::traits::IsLieGroupImpl;
 use super::traits::IsRealLieGroupImpl;
 use crate::lie_group::LieGroup;
 use crate::prelude::*;
+#[cfg(feature = "std")]
+use crate::ExtendedIsometry3;
+#[cfg(feature = "std")]
 use crate::Isometry2;
+#[cfg(feature = "std")]
 use crate::Isometry3;
+#[cfg(feature = "std")]
+use crate::Isometry3xIsometry3;
+#[cfg(feature = "std")]
 use crate::Rotation2;
+#[cfg(feature = "std")]
 use crate::Rotation3;
+#[cfg(feature = "std")]
+use crate::Rotation3xTranslation3;
+#[cfg(feature = "std")]
+use crate::RxSO2;
+#[cfg(feature = "std")]
+use crate::RxSO3;
+#[cfg(feature = "std")]
+use crate::Sim2;
+#[cfg(feature = "std")]
+use crate::Sim3;
+#[cfg(feature = "std")]
+use crate::SpecialLinear3;
+#[cfg(feature = "std")]
+use crate::Translation2;
+#[cfg(feature = "std")]
+use crate::Translation3;
+#[cfg(feature = "std")]
 use approx::assert_relative_eq;
 use nalgebra::SVector;
+#[cfg(feature = "std")]
 use sophus_core::calculus::dual::DualScalar;
+#[cfg(feature = "std")]
 use sophus_core::calculus::maps::MatrixValuedMapFromVector;
+#[cfg(feature = "std")]
 use sophus_core::calculus::maps::VectorValuedMapFromMatrix;
+#[cfg(feature = "std")]
 use sophus_core::calculus::maps::VectorValuedMapFromVector;
 
-#[cfg(feature = "simd")]
+#[cfg(feature = "std")]
 use sophus_core::calculus::dual::dual_batch_scalar::DualBatchScalar;
-#[cfg(feature = "simd")]
+#[cfg(feature = "std")]
 use sophus_core::linalg::BatchScalarF64;
 
-use std::fmt::Display;
-use std::fmt::Formatter;
+use core::fmt::Display;
+use core::fmt::Formatter;
 
 impl<
         S: IsRealScalar<BATCH_SIZE, RealScalar = S>,
@@ -59,6 +88,7 @@ where
     }
 
     /// dual representation of the group
+    #[cfg(feature = "alloc")]
     pub fn to_dual_c(
         self,
     ) -> LieGroup<S::DualScalar, DOF, PARAMS, POINT, AMBIENT, BATCH_SIZE, G::DualG> {
@@ -99,17 +129,19 @@ impl<
         G: IsLieGroupImpl<S, DOF, PARAMS, POINT, AMBIENT, BATCH_SIZE>,
     > Display for LieGroup<S, DOF, PARAMS, POINT, AMBIENT, BATCH_SIZE, G>
 {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         write!(f, "{:?}", self.compact())
     }
 }
 
 /// A trait for Lie groups.
+#[cfg(feature = "std")]
 pub trait RealLieGroupTest {
     /// Run all tests.
     fn run_real_tests() {
         Self::adjoint_jacobian_tests();
         Self::exp_log_jacobians_tests();
+        Self::left_right_jacobians_tests();
         Self::hat_jacobians_tests();
         Self::mul_jacobians_tests();
     }
@@ -125,11 +157,15 @@ pub trait RealLieGroupTest {
 
     /// exp_log_jacobians_tests
     fn exp_log_jacobians_tests();
+
+    /// Test left and right Jacobians of the exponential map, and their inverses.
+    fn left_right_jacobians_tests();
 }
 
 macro_rules! def_real_group_test_template {
     ($scalar:ty, $dual_scalar:ty, $group: ty, $dual_group: ty, $batch:literal
 ) => {
+        #[cfg(feature = "std")]
         impl RealLieGroupTest for $group {
 
             fn adjoint_jacobian_tests() {
@@ -393,6 +429,53 @@ macro_rules! def_real_group_test_template {
         }
 
 
+            fn left_right_jacobians_tests() {
+                use crate::traits::IsLieGroup;
+                use sophus_core::manifold::traits::TangentImpl;
+                const DOF: usize = <$group>::DOF;
+
+                for t in <$group>::tangent_examples() {
+                    let exp_t = Self::exp(&t);
+
+                    // exp(t + δ) = exp(jl(t)·δ) · exp(t)
+                    let jl_num_diff = VectorValuedMapFromVector::static_sym_diff_quotient(
+                        |d: <$scalar as IsScalar<$batch>>::Vector<DOF>| {
+                            Self::exp(&(t + d)).group_mul(&exp_t.inverse()).log()
+                        },
+                        <$scalar as IsScalar<$batch>>::Vector::zeros(),
+                        0.0001,
+                    );
+                    let jl = Self::jl(&t);
+                    assert_relative_eq!(jl, jl_num_diff, epsilon = 0.001);
+
+                    // exp(t + δ) = exp(t) · exp(jr(t)·δ)
+                    let jr_num_diff = VectorValuedMapFromVector::static_sym_diff_quotient(
+                        |d: <$scalar as IsScalar<$batch>>::Vector<DOF>| {
+                            exp_t.inverse().group_mul(&Self::exp(&(t + d))).log()
+                        },
+                        <$scalar as IsScalar<$batch>>::Vector::zeros(),
+                        0.0001,
+                    );
+                    let jr = Self::jr(&t);
+                    assert_relative_eq!(jr, jr_num_diff, epsilon = 0.001);
+
+                    // jl(t) = adj(exp(t)) · jr(t)
+                    assert_relative_eq!(jl, exp_t.adj().mat_mul(jr), epsilon = 0.0001);
+
+                    let identity = <$scalar as IsScalar<$batch>>::Matrix::<DOF, DOF>::identity();
+                    assert_relative_eq!(
+                        Self::jl_inv(&t).mat_mul(jl),
+                        identity,
+                        epsilon = 0.0001
+                    );
+                    assert_relative_eq!(
+                        Self::jr_inv(&t).mat_mul(jr),
+                        identity,
+                        epsilon = 0.0001
+                    );
+                }
+            }
+
             fn hat_jacobians_tests() {
                 use crate::traits::IsLieGroup;
                 use sophus_core::manifold::traits::TangentImpl;
@@ -517,7 +600,6 @@ macro_rules! def_real_group_test_template {
 }
 
 def_real_group_test_template!(f64, DualScalar, Rotation2<f64, 1>, Rotation2<DualScalar, 1>,  1);
-#[cfg(feature = "simd")]
 def_real_group_test_template!(
     BatchScalarF64<8>,
     DualBatchScalar<8>,
@@ -527,7 +609,6 @@ def_real_group_test_template!(
 );
 
 def_real_group_test_template!(f64, DualScalar, Isometry2<f64, 1>, Isometry2<DualScalar, 1>,  1);
-#[cfg(feature = "simd")]
 def_real_group_test_template!(
     BatchScalarF64<8>,
     DualBatchScalar<8>,
@@ -537,7 +618,6 @@ def_real_group_test_template!(
 );
 
 def_real_group_test_template!(f64, DualScalar, Rotation3<f64, 1>, Rotation3<DualScalar, 1>,  1);
-#[cfg(feature = "simd")]
 def_real_group_test_template!(
     BatchScalarF64<8>,
     DualBatchScalar<8>,
@@ -547,7 +627,6 @@ def_real_group_test_template!(
 );
 
 def_real_group_test_template!(f64, DualScalar, Isometry3<f64, 1>, Isometry3<DualScalar, 1>,  1);
-#[cfg(feature = "simd")]
 def_real_group_test_template!(
     BatchScalarF64<8>,
     DualBatchScalar<8>,
@@ -555,3 +634,196 @@ def_real_group_test_template!(
     Isometry3<DualBatchScalar<8>, 8>,
     8
 );
+
+def_real_group_test_template!(f64, DualScalar, RxSO2<f64, 1>, RxSO2<DualScalar, 1>,  1);
+def_real_group_test_template!(
+    BatchScalarF64<8>,
+    DualBatchScalar<8>,
+    RxSO2<BatchScalarF64<8>, 8>,
+    RxSO2<DualBatchScalar<8>, 8>,
+    8
+);
+
+def_real_group_test_template!(f64, DualScalar, RxSO3<f64, 1>, RxSO3<DualScalar, 1>,  1);
+def_real_group_test_template!(
+    BatchScalarF64<8>,
+    DualBatchScalar<8>,
+    RxSO3<BatchScalarF64<8>, 8>,
+    RxSO3<DualBatchScalar<8>, 8>,
+    8
+);
+
+def_real_group_test_template!(f64, DualScalar, Sim2<f64, 1>, Sim2<DualScalar, 1>,  1);
+def_real_group_test_template!(
+    BatchScalarF64<8>,
+    DualBatchScalar<8>,
+    Sim2<BatchScalarF64<8>, 8>,
+    Sim2<DualBatchScalar<8>, 8>,
+    8
+);
+
+def_real_group_test_template!(f64, DualScalar, Sim3<f64, 1>, Sim3<DualScalar, 1>,  1);
+def_real_group_test_template!(
+    BatchScalarF64<8>,
+    DualBatchScalar<8>,
+    Sim3<BatchScalarF64<8>, 8>,
+    Sim3<DualBatchScalar<8>, 8>,
+    8
+);
+
+def_real_group_test_template!(
+    f64,
+    DualScalar,
+    ExtendedIsometry3<f64, 1>,
+    ExtendedIsometry3<DualScalar, 1>,
+    1
+);
+def_real_group_test_template!(
+    BatchScalarF64<8>,
+    DualBatchScalar<8>,
+    ExtendedIsometry3<BatchScalarF64<8>, 8>,
+    ExtendedIsometry3<DualBatchScalar<8>, 8>,
+    8
+);
+
+def_real_group_test_template!(
+    f64,
+    DualScalar,
+    SpecialLinear3<f64, 1>,
+    SpecialLinear3<DualScalar, 1>,
+    1
+);
+def_real_group_test_template!(
+    BatchScalarF64<8>,
+    DualBatchScalar<8>,
+    SpecialLinear3<BatchScalarF64<8>, 8>,
+    SpecialLinear3<DualBatchScalar<8>, 8>,
+    8
+);
+
+def_real_group_test_template!(f64, DualScalar, Translation2<f64, 1>, Translation2<DualScalar, 1>, 1);
+def_real_group_test_template!(
+    BatchScalarF64<8>,
+    DualBatchScalar<8>,
+    Translation2<BatchScalarF64<8>, 8>,
+    Translation2<DualBatchScalar<8>, 8>,
+    8
+);
+
+def_real_group_test_template!(f64, DualScalar, Translation3<f64, 1>, Translation3<DualScalar, 1>, 1);
+def_real_group_test_template!(
+    BatchScalarF64<8>,
+    DualBatchScalar<8>,
+    Translation3<BatchScalarF64<8>, 8>,
+    Translation3<DualBatchScalar<8>, 8>,
+    8
+);
+
+def_real_group_test_template!(f64, DualScalar, Rotation3xTranslation3<f64, 1>, Rotation3xTranslation3<DualScalar, 1>, 1);
+def_real_group_test_template!(
+    BatchScalarF64<8>,
+    DualBatchScalar<8>,
+    Rotation3xTranslation3<BatchScalarF64<8>, 8>,
+    Rotation3xTranslation3<DualBatchScalar<8>, 8>,
+    8
+);
+
+def_real_group_test_template!(f64, DualScalar, Isometry3xIsometry3<f64, 1>, Isometry3xIsometry3<DualScalar, 1>, 1);
+def_real_group_test_template!(
+    BatchScalarF64<8>,
+    DualBatchScalar<8>,
+    Isometry3xIsometry3<BatchScalarF64<8>, 8>,
+    Isometry3xIsometry3<DualBatchScalar<8>, 8>,
+    8
+);
+
+#[test]
+fn jet_scalar_drop_in_tests() {
+    use sophus_core::calculus::dual::DualVector;
+    use sophus_core::calculus::dual::JetScalar;
+    use sophus_core::calculus::dual::JetVector;
+    use sophus_core::linalg::VecF64;
+    use sophus_core::manifold::traits::TangentImpl;
+
+    for t in Isometry3::<f64, 1>::tangent_examples() {
+        // dx exp(x)
+        let dual_exp_t = VectorValuedMapFromVector::<DualScalar, 1>::static_fw_autodiff(
+            |vv: DualVector<6>| Isometry3::<DualScalar, 1>::exp(&vv).params().clone(),
+            t,
+        );
+        let jet_exp_t = VectorValuedMapFromVector::<JetScalar<6>, 1>::static_fw_autodiff(
+            |vv: JetVector<6, 6>| *Isometry3::<JetScalar<6>, 1>::exp(&vv).params(),
+            t,
+        );
+        assert_relative_eq!(dual_exp_t, jet_exp_t, epsilon = 1e-9);
+        assert_relative_eq!(Isometry3::<f64, 1>::dx_exp(&t), jet_exp_t, epsilon = 0.001);
+
+        // d/db a * b
+        let a = Isometry3::<f64, 1>::exp(&t);
+        let b = Isometry3::<f64, 1>::exp(&VecF64::<6>::new(0.1, -0.2, 0.3, 0.2, 0.1, -0.4));
+        let jet_a =
+            Isometry3::<JetScalar<7>, 1>::from_params(&JetVector::from_real_vector(*a.params()));
+        let jet_mul = VectorValuedMapFromVector::<JetScalar<7>, 1>::static_fw_autodiff(
+            |vv: JetVector<7, 7>| *jet_a.group_mul(&LieGroup::from_params(&vv)).params(),
+            *b.params(),
+        );
+        assert_relative_eq!(
+            Isometry3::<f64, 1>::db_a_mul_b(&a, &b),
+            jet_mul,
+            epsilon = 0.001
+        );
+    }
+
+    for t in Rotation3::<f64, 1>::tangent_examples() {
+        // dx exp(x) * p
+        let p = VecF64::<3>::new(1.0, -2.0, 0.5);
+        let dual_transform = VectorValuedMapFromVector::<DualScalar, 1>::static_fw_autodiff(
+            |vv: DualVector<3>| {
+                Rotation3::<DualScalar, 1>::exp(&vv).transform(&DualVector::from_real_vector(p))
+            },
+            t,
+        );
+        let jet_transform = VectorValuedMapFromVector::<JetScalar<3>, 1>::static_fw_autodiff(
+            |vv: JetVector<3, 3>| {
+                Rotation3::<JetScalar<3>, 1>::exp(&vv).transform(&JetVector::from_real_vector(p))
+            },
+            t,
+        );
+        assert_relative_eq!(dual_transform, jet_transform, epsilon = 1e-9);
+    }
+}
+
+#[test]
+fn tape_scalar_drop_in_tests() {
+    use sophus_core::calculus::maps::ScalarValuedMapFromVector;
+    use sophus_core::calculus::tape::TapeScalar;
+    use sophus_core::linalg::VecF64;
+    use sophus_core::manifold::traits::TangentImpl;
+
+    // sum of squared distances between transformed points and targets, and the log of the pose
+    fn cost<S: IsSingleScalar + IsScalar<1>>(a: &Isometry3<f64, 1>, tangent: S::Vector<6>) -> S {
+        let a = Isometry3::<S, 1>::from_params(&S::Vector::<7>::from_f64_array(
+            core::array::from_fn(|i| a.params()[i]),
+        ));
+        let pose = a.group_mul(&Isometry3::<S, 1>::exp(&tangent));
+        let mut cost = pose.log().squared_norm();
+        for i in 0..20 {
+            let t = 0.05 * (i as f64);
+            let point = S::Vector::<3>::from_f64_array([t, 1.0 - t, 0.5 * t - 0.2]);
+            let target = S::Vector::<3>::from_f64_array([0.3 - t, t, 1.0]);
+            cost += (pose.transform(&point) - target).squared_norm();
+        }
+        cost
+    }
+
+    let a = Isometry3::<f64, 1>::exp(&VecF64::<6>::new(0.1, -0.2, 0.3, 0.2, 0.1, -0.4));
+    for t in Isometry3::<f64, 1>::tangent_examples() {
+        let tape_grad =
+            ScalarValuedMapFromVector::<TapeScalar, 1>::gradient(|x| cost::<TapeScalar>(&a, x), t);
+        let dual_grad = ScalarValuedMapFromVector::<DualScalar, 1>::fw_autodiff(
+            |x| cost::<DualScalar>(&a, x),
+            t,
+        );
+        assert_relative_eq!(tape_grad, dual_grad, epsilon = 1e-9, max_relative = 1e-9);
+    }
+}
@@ -1,7 +1,11 @@
 use crate::prelude::*;
+#[cfg(feature = "alloc")]
+use alloc::borrow::ToOwned;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::fmt::Debug;
 use sophus_core::manifold::traits::TangentImpl;
 use sophus_core::params::ParamsImpl;
-use std::fmt::Debug;
 
 /// Lie Group implementation trait
 ///
@@ -26,6 +30,7 @@ pub trait IsLieGroupImpl<
     /// Real scalar
     type RealG: IsLieGroupImpl<S::RealScalar, DOF, PARAMS, POINT, AMBIENT, BATCH_SIZE>;
     /// DualScalar scalar - for automatic differentiation
+    #[cfg(feature = "alloc")]
     type DualG: IsLieGroupImpl<S::DualScalar, DOF, PARAMS, POINT, AMBIENT, BATCH_SIZE>;
 
     /// is transformation origin preserving?
@@ -44,6 +49,14 @@ pub trait IsLieGroupImpl<
     /// identity parameters
     fn identity_params() -> S::Vector<PARAMS>;
 
+    /// human readable description of the constraint checked by are_params_valid
+    ///
+    /// Used to report invalid parameters, see [crate::lie_group_error::LieGroupError].
+    #[cfg(feature = "alloc")]
+    fn params_constraint() -> String {
+        "valid group parameters".to_owned()
+    }
+
     // Manifold / Lie Group concepts
 
     /// group adjoint
@@ -64,6 +77,30 @@ pub trait IsLieGroupImpl<
     /// vee operator
     fn vee(hat: &S::Matrix<AMBIENT, AMBIENT>) -> S::Vector<DOF>;
 
+    /// left Jacobian of the exponential map
+    ///
+    /// For small δ: exp(x + δ) ≈ exp(jl(x)·δ) · exp(x)
+    fn jl(tangent: &S::Vector<DOF>) -> S::Matrix<DOF, DOF>;
+
+    /// inverse of the left Jacobian
+    ///
+    /// For small δ: log(exp(δ) · exp(x)) ≈ x + jl_inv(x)·δ
+    fn jl_inv(tangent: &S::Vector<DOF>) -> S::Matrix<DOF, DOF>;
+
+    /// right Jacobian of the exponential map
+    ///
+    /// For small δ: exp(x + δ) ≈ exp(x) · exp(jr(x)·δ)
+    fn jr(tangent: &S::Vector<DOF>) -> S::Matrix<DOF, DOF> {
+        Self::jl(&-tangent.clone())
+    }
+
+    /// inverse of the right Jacobian
+    ///
+    /// For small δ: log(exp(x) · exp(δ)) ≈ x + jr_inv(x)·δ
+    fn jr_inv(tangent: &S::Vector<DOF>) -> S::Matrix<DOF, DOF> {
+        Self::jl_inv(&-tangent.clone())
+    }
+
     // group operations
 
     /// group multiplication
@@ -141,6 +178,7 @@ pub trait IsLieFactorGroupImpl<
     /// Real scalar
     type RealFactorG: IsLieFactorGroupImpl<S::RealScalar, DOF, PARAMS, POINT, BATCH_SIZE>;
     /// DualScalar scalar - for automatic differentiation
+    #[cfg(feature = "alloc")]
     type DualFactorG: IsLieFactorGroupImpl<S::DualScalar, DOF, PARAMS, POINT, BATCH_SIZE>;
 
     /// V matrix - used by semi-direct product exponential
@@ -201,6 +239,7 @@ pub trait IsLieGroup<
     /// Lie Group implementation- with real scalar
     type RealG: IsLieGroupImpl<S::RealScalar, DOF, PARAMS, POINT, AMBIENT, BATCH_SIZE>;
     /// Lie Group implementation with dual scalar - for automatic differentiation
+    #[cfg(feature = "alloc")]
     type DualG: IsLieGroupImpl<S::DualScalar, DOF, PARAMS, POINT, AMBIENT, BATCH_SIZE>;
 
     /// degree of freedom
@@ -224,6 +263,7 @@ pub trait IsLieGroup<
     /// Lie Group with real scalar
     type RealGroup: IsLieGroup<S::RealScalar, DOF, PARAMS, POINT, AMBIENT, BATCH_SIZE>;
     /// Lie Group with dual scalar - for automatic differentiation
+    #[cfg(feature = "alloc")]
     type DualGroup: IsLieGroup<S::DualScalar, DOF, PARAMS, POINT, AMBIENT, BATCH_SIZE>;
 }
 
@@ -4,12 +4,14 @@ use crate::prelude::*;
 use crate::robust_kernel;
 use crate::term::MakeTerm;
 use crate::term::Term;
+use crate::variables::check_param_count;
 use crate::variables::IsVariable;
 use crate::variables::VarKind;
 use sophus_core::calculus::dual::DualScalar;
 use sophus_core::calculus::dual::DualVector;
 use sophus_core::calculus::maps::VectorValuedMapFromVector;
 use sophus_core::linalg::VecF64;
+use sophus_image::ImageSize;
 use sophus_lie::Isometry3;
 use sophus_sensor::PinholeCamera;
 
@@ -24,6 +26,30 @@ impl IsVariable for PinholeCamera<f64, 1> {
         let new_params = *self.params() + delta;
         self.set_params(&new_params);
     }
+
+    fn var_type() -> String {
+        "PinholeCamera".to_owned()
+    }
+
+    /// parameters [fx, fy, cx, cy, width, height], i.e. followed by the image size
+    fn param_vec(&self) -> Option<Vec<f64>> {
+        let mut params = self.params().as_slice().to_vec();
+        params.push(self.image_size().width as f64);
+        params.push(self.image_size().height as f64);
+        Some(params)
+    }
+
+    fn try_from_param_vec(params: &[f64]) -> Result<Self, String> {
+        check_param_count(params, 6)?;
+        let (width, height) = (params[4], params[5]);
+        if width < 0.0 || height < 0.0 || width.fract() != 0.0 || height.fract() != 0.0 {
+            return Err(format!("invalid image size {} x {}", width, height));
+        }
+        Ok(PinholeCamera::new(
+            &VecF64::<4>::from_column_slice(&params[0..4]),
+            ImageSize::new(width as usize, height as usize),
+        ))
+    }
 }
 
 fn res_fn<Scalar: IsSingleScalar + IsScalar<1>>(
@@ -138,3 +164,176 @@ impl IsResidualFn<13, 3, (PinholeCamera<f64, 1>, Isometry3<f64, 1>, VecF64<3>),
             .make_term(var_kinds, residual, robust_kernel, None)
     }
 }
+
+#[cfg(test)]
+struct ReprojectionJacobianTestCase {
+    intrinsics: PinholeCamera<f64, 1>,
+    world_from_camera_pose: Isometry3<f64, 1>,
+    point_in_world: VecF64<3>,
+    uv_in_image: VecF64<2>,
+}
+
+#[cfg(test)]
+type ReprojectionJacobians = (
+    sophus_core::linalg::MatF64<2, 4>,
+    sophus_core::linalg::MatF64<2, 6>,
+    sophus_core::linalg::MatF64<2, 3>,
+);
+
+#[cfg(test)]
+impl ReprojectionJacobianTestCase {
+    fn new() -> Self {
+        Self {
+            intrinsics: PinholeCamera::<f64, 1>::new(
+                &VecF64::<4>::new(600.0, 610.0, 319.5, 239.5),
+                ImageSize::new(640, 480),
+            ),
+            world_from_camera_pose: Isometry3::<f64, 1>::exp(&VecF64::<6>::new(
+                0.1, -0.2, 0.3, 0.05, -0.1, 0.2,
+            )),
+            point_in_world: VecF64::<3>::new(0.5, -0.3, 4.0),
+            uv_in_image: VecF64::<2>::new(400.0, 200.0),
+        }
+    }
+
+    /// jacobians with respect to intrinsics, pose and point using DualScalar
+    fn dual_jacobians(&self) -> ReprojectionJacobians {
+        let dx_intrinsics = VectorValuedMapFromVector::<DualScalar, 1>::static_fw_autodiff(
+            |x: DualVector<4>| {
+                res_fn(
+                    PinholeCamera::<DualScalar, 1>::from_params_and_size(
+                        &x,
+                        self.intrinsics.image_size(),
+                    ),
+                    self.world_from_camera_pose.to_dual_c(),
+                    DualVector::from_real_vector(self.point_in_world),
+                    DualVector::from_real_vector(self.uv_in_image),
+                )
+            },
+            *self.intrinsics.params(),
+        );
+        let dx_pose = VectorValuedMapFromVector::<DualScalar, 1>::static_fw_autodiff(
+            |x: DualVector<6>| {
+                res_fn(
+                    PinholeCamera::<DualScalar, 1>::from_params_and_size(
+                        &DualVector::from_real_vector(*self.intrinsics.params()),
+                        self.intrinsics.image_size(),
+                    ),
+                    Isometry3::<DualScalar, 1>::exp(&x)
+                        .group_mul(&self.world_from_camera_pose.to_dual_c()),
+                    DualVector::from_real_vector(self.point_in_world),
+                    DualVector::from_real_vector(self.uv_in_image),
+                )
+            },
+            VecF64::<6>::zeros(),
+        );
+        let dx_point = VectorValuedMapFromVector::<DualScalar, 1>::static_fw_autodiff(
+            |x: DualVector<3>| {
+                res_fn(
+                    PinholeCamera::<DualScalar, 1>::from_params_and_size(
+                        &DualVector::from_real_vector(*self.intrinsics.params()),
+                        self.intrinsics.image_size(),
+                    ),
+                    self.world_from_camera_pose.to_dual_c(),
+                    x,
+                    DualVector::from_real_vector(self.uv_in_image),
+                )
+            },
+            self.point_in_world,
+        );
+        (dx_intrinsics, dx_pose, dx_point)
+    }
+
+    /// jacobians with respect to intrinsics, pose and point using JetScalar
+    fn jet_jacobians(&self) -> ReprojectionJacobians {
+        use sophus_core::calculus::dual::JetScalar;
+        use sophus_core::calculus::dual::JetVector;
+
+        fn jet_pose<const N: usize>(pose: &Isometry3<f64, 1>) -> Isometry3<JetScalar<N>, 1> {
+            Isometry3::from_params(&JetVector::from_real_vector(*pose.params()))
+        }
+
+        let dx_intrinsics = VectorValuedMapFromVector::<JetScalar<4>, 1>::static_fw_autodiff(
+            |x: JetVector<4, 4>| {
+                res_fn(
+                    PinholeCamera::<JetScalar<4>, 1>::from_params_and_size(
+                        &x,
+                        self.intrinsics.image_size(),
+                    ),
+                    jet_pose(&self.world_from_camera_pose),
+                    JetVector::from_real_vector(self.point_in_world),
+                    JetVector::from_real_vector(self.uv_in_image),
+                )
+            },
+            *self.intrinsics.params(),
+        );
+        let dx_pose = VectorValuedMapFromVector::<JetScalar<6>, 1>::static_fw_autodiff(
+            |x: JetVector<6, 6>| {
+                res_fn(
+                    PinholeCamera::<JetScalar<6>, 1>::from_params_and_size(
+                        &JetVector::from_real_vector(*self.intrinsics.params()),
+                        self.intrinsics.image_size(),
+                    ),
+                    Isometry3::<JetScalar<6>, 1>::exp(&x)
+                        .group_mul(&jet_pose(&self.world_from_camera_pose)),
+                    JetVector::from_real_vector(self.point_in_world),
+                    JetVector::from_real_vector(self.uv_in_image),
+                )
+            },
+            VecF64::<6>::zeros(),
+        );
+        let dx_point = VectorValuedMapFromVector::<JetScalar<3>, 1>::static_fw_autodiff(
+            |x: JetVector<3, 3>| {
+                res_fn(
+                    PinholeCamera::<JetScalar<3>, 1>::from_params_and_size(
+                        &JetVector::from_real_vector(*self.intrinsics.params()),
+                        self.intrinsics.image_size(),
+                    ),
+                    jet_pose(&self.world_from_camera_pose),
+                    x,
+                    JetVector::from_real_vector(self.uv_in_image),
+                )
+            },
+            self.point_in_world,
+        );
+        (dx_intrinsics, dx_pose, dx_point)
+    }
+}
+
+#[test]
+fn reprojection_jet_tests() {
+    let test_case = ReprojectionJacobianTestCase::new();
+    let (dual_d0, dual_d1, dual_d2) = test_case.dual_jacobians();
+    let (jet_d0, jet_d1, jet_d2) = test_case.jet_jacobians();
+    approx::assert_abs_diff_eq!(dual_d0, jet_d0, epsilon = 1e-9);
+    approx::assert_abs_diff_eq!(dual_d1, jet_d1, epsilon = 1e-9);
+    approx::assert_abs_diff_eq!(dual_d2, jet_d2, epsilon = 1e-9);
+}
+
+#[test]
+#[ignore = "timing comparison, run in release mode with --ignored"]
+fn reprojection_jet_timing_tests() {
+    use std::time::Instant;
+
+    const NUM_ITER: usize = 1000;
+    let test_case = ReprojectionJacobianTestCase::new();
+
+    let now = Instant::now();
+    for _ in 0..NUM_ITER {
+        std::hint::black_box(test_case.dual_jacobians());
+    }
+    let dual_elapsed = now.elapsed();
+    let now = Instant::now();
+    for _ in 0..NUM_ITER {
+        std::hint::black_box(test_case.jet_jacobians());
+    }
+    let jet_elapsed = now.elapsed();
+
+    assert!(
+        jet_elapsed < dual_elapsed,
+        "reprojection jacobians x{}: JetScalar {:.2?} not faster than DualScalar {:.2?}",
+        NUM_ITER,
+        jet_elapsed,
+        dual_elapsed
+    );
+}
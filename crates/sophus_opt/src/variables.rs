@@ -1,14 +1,20 @@
 use crate::prelude::*;
 use dyn_clone::DynClone;
 use sophus_core::linalg::VecF64;
+use sophus_lie::traits::IsLieGroupImpl;
 use sophus_lie::Isometry2;
 use sophus_lie::Isometry3;
+use sophus_lie::Isometry3xIsometry3;
+use sophus_lie::LieGroup;
+use sophus_lie::Rotation3xTranslation3;
+use sophus_lie::SpecialLinear3;
 use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::fmt::Debug;
 
 /// Variable kind
 #[derive(PartialEq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum VarKind {
     /// free variable (will be updated during optimization)
     Free,
@@ -25,6 +31,58 @@ pub trait IsVariable: Clone + Debug {
 
     /// update the variable in-place (called during optimization)
     fn update(&mut self, delta: nalgebra::DVectorView<f64>);
+
+    /// name of the variable type, e.g. "Isometry3" - identifies the type of a stored family
+    ///
+    /// Defaults to the Rust type name, which is not guaranteed to be stable across compiler
+    /// versions. Override it for variable types which are stored.
+    fn var_type() -> String {
+        std::any::type_name::<Self>().to_owned()
+    }
+
+    /// parameters of the variable as flat array
+    ///
+    /// Returns None by default, i.e. the variable cannot be stored.
+    fn param_vec(&self) -> Option<Vec<f64>> {
+        None
+    }
+
+    /// create variable from a flat parameter array
+    ///
+    /// Returns an error if the number of parameters is wrong or the parameters are invalid. Returns
+    /// an error by default, i.e. the variable cannot be restored.
+    fn try_from_param_vec(_params: &[f64]) -> Result<Self, String> {
+        Err(format!(
+            "variable type {} cannot be created from parameters",
+            Self::var_type()
+        ))
+    }
+}
+
+/// check the number of parameters of a variable
+pub(crate) fn check_param_count(params: &[f64], expected: usize) -> Result<(), String> {
+    if params.len() != expected {
+        return Err(format!(
+            "expected {} parameters, got {}",
+            expected,
+            params.len()
+        ));
+    }
+    Ok(())
+}
+
+fn lie_group_from_param_vec<
+    const DOF: usize,
+    const PARAMS: usize,
+    const POINT: usize,
+    const AMBIENT: usize,
+    G: IsLieGroupImpl<f64, DOF, PARAMS, POINT, AMBIENT, 1>,
+>(
+    params: &[f64],
+) -> Result<LieGroup<f64, DOF, PARAMS, POINT, AMBIENT, 1, G>, String> {
+    check_param_count(params, PARAMS)?;
+    LieGroup::try_from_params(&VecF64::<PARAMS>::from_column_slice(params))
+        .map_err(|err| err.to_string())
 }
 
 /// Tuple of variables (one for each argument of the cost function)
@@ -130,6 +188,19 @@ impl<const N: usize> IsVariable for VecF64<N> {
             self[d] += delta[d];
         }
     }
+
+    fn var_type() -> String {
+        format!("VecF64<{}>", N)
+    }
+
+    fn param_vec(&self) -> Option<Vec<f64>> {
+        Some(self.as_slice().to_vec())
+    }
+
+    fn try_from_param_vec(params: &[f64]) -> Result<Self, String> {
+        check_param_count(params, N)?;
+        Ok(VecF64::<N>::from_column_slice(params))
+    }
 }
 
 impl IsVariable for Isometry2<f64, 1> {
@@ -145,6 +216,18 @@ impl IsVariable for Isometry2<f64, 1> {
                 .params(),
         );
     }
+
+    fn var_type() -> String {
+        "Isometry2".to_owned()
+    }
+
+    fn param_vec(&self) -> Option<Vec<f64>> {
+        Some(self.params().as_slice().to_vec())
+    }
+
+    fn try_from_param_vec(params: &[f64]) -> Result<Self, String> {
+        lie_group_from_param_vec(params)
+    }
 }
 
 impl IsVariable for Isometry3<f64, 1> {
@@ -160,6 +243,108 @@ impl IsVariable for Isometry3<f64, 1> {
                 .params(),
         );
     }
+
+    fn var_type() -> String {
+        "Isometry3".to_owned()
+    }
+
+    fn param_vec(&self) -> Option<Vec<f64>> {
+        Some(self.params().as_slice().to_vec())
+    }
+
+    fn try_from_param_vec(params: &[f64]) -> Result<Self, String> {
+        lie_group_from_param_vec(params)
+    }
+}
+
+impl IsVariable for SpecialLinear3<f64, 1> {
+    const DOF: usize = 8;
+
+    fn update(&mut self, delta: nalgebra::DVectorView<f64>) {
+        let mut delta_vec = VecF64::<8>::zeros();
+        for d in 0..<Self as IsVariable>::DOF {
+            delta_vec[d] = delta[d];
+        }
+        self.set_params(
+            (SpecialLinear3::<f64, 1>::group_mul(
+                &SpecialLinear3::<f64, 1>::exp(&delta_vec),
+                &self.clone(),
+            ))
+            .params(),
+        );
+    }
+
+    fn var_type() -> String {
+        "SpecialLinear3".to_owned()
+    }
+
+    fn param_vec(&self) -> Option<Vec<f64>> {
+        Some(self.params().as_slice().to_vec())
+    }
+
+    fn try_from_param_vec(params: &[f64]) -> Result<Self, String> {
+        lie_group_from_param_vec(params)
+    }
+}
+
+impl IsVariable for Rotation3xTranslation3<f64, 1> {
+    const DOF: usize = 6;
+
+    fn update(&mut self, delta: nalgebra::DVectorView<f64>) {
+        let mut delta_vec = VecF64::<6>::zeros();
+        for d in 0..<Self as IsVariable>::DOF {
+            delta_vec[d] = delta[d];
+        }
+        self.set_params(
+            (Rotation3xTranslation3::<f64, 1>::group_mul(
+                &Rotation3xTranslation3::<f64, 1>::exp(&delta_vec),
+                &self.clone(),
+            ))
+            .params(),
+        );
+    }
+
+    fn var_type() -> String {
+        "Rotation3xTranslation3".to_owned()
+    }
+
+    fn param_vec(&self) -> Option<Vec<f64>> {
+        Some(self.params().as_slice().to_vec())
+    }
+
+    fn try_from_param_vec(params: &[f64]) -> Result<Self, String> {
+        lie_group_from_param_vec(params)
+    }
+}
+
+impl IsVariable for Isometry3xIsometry3<f64, 1> {
+    const DOF: usize = 12;
+
+    fn update(&mut self, delta: nalgebra::DVectorView<f64>) {
+        let mut delta_vec = VecF64::<12>::zeros();
+        for d in 0..<Self as IsVariable>::DOF {
+            delta_vec[d] = delta[d];
+        }
+        self.set_params(
+            (Isometry3xIsometry3::<f64, 1>::group_mul(
+                &Isometry3xIsometry3::<f64, 1>::exp(&delta_vec),
+                &self.clone(),
+            ))
+            .params(),
+        );
+    }
+
+    fn var_type() -> String {
+        "Isometry3xIsometry3".to_owned()
+    }
+
+    fn param_vec(&self) -> Option<Vec<f64>> {
+        Some(self.params().as_slice().to_vec())
+    }
+
+    fn try_from_param_vec(params: &[f64]) -> Result<Self, String> {
+        lie_group_from_param_vec(params)
+    }
 }
 
 /// A generic family of variables
@@ -199,6 +384,58 @@ impl<Var: IsVariable> VarFamily<Var> {
     fn c(&self) -> VarKind {
         self.kind
     }
+
+    /// create a variable family from its type-erased parameters
+    ///
+    /// Returns an error if the variable type does not match, or a member cannot be created.
+    pub fn try_from_family_params(family_params: &VarFamilyParams) -> Result<Self, String> {
+        if family_params.var_type != Var::var_type() {
+            return Err(format!(
+                "expected variable type {}, got {}",
+                Var::var_type(),
+                family_params.var_type
+            ));
+        }
+        let mut members = Vec::with_capacity(family_params.members.len());
+        for (i, params) in family_params.members.iter().enumerate() {
+            members.push(
+                Var::try_from_param_vec(params)
+                    .map_err(|err| format!("{} member {}: {}", Var::var_type(), i, err))?,
+            );
+        }
+        let mut constant_members = HashMap::new();
+        for &i in family_params.constant_members.iter() {
+            if i >= members.len() {
+                return Err(format!(
+                    "constant member {} out of range, family has {} members",
+                    i,
+                    members.len()
+                ));
+            }
+            constant_members.insert(i, ());
+        }
+        Ok(Self::new_with_const_ids(
+            family_params.kind,
+            members,
+            constant_members,
+        ))
+    }
+}
+
+/// Type-erased parameters of a variable family
+///
+/// This is the stored representation of a family, e.g. when serializing a [VarPool].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VarFamilyParams {
+    /// variable type, see [IsVariable::var_type]
+    pub var_type: String,
+    /// variable kind
+    pub kind: VarKind,
+    /// parameters of each member, see [IsVariable::param_vec]
+    pub members: Vec<Vec<f64>>,
+    /// sorted indices of the constant members
+    pub constant_members: Vec<usize>,
 }
 
 /// A family of variables
@@ -234,6 +471,13 @@ pub trait IsVarFamily: as_any::AsAny + Debug + DynClone {
 
     /// variable kind (free, conditioned, ...)
     fn get_var_kind(&self) -> VarKind;
+
+    /// type-erased parameters of the family
+    ///
+    /// Returns an error if the variables cannot be stored, see [IsVariable::param_vec].
+    fn family_params(&self) -> Result<VarFamilyParams, String> {
+        Err("variable family cannot be stored".to_owned())
+    }
 }
 
 impl<Var: IsVariable + 'static> IsVarFamily for VarFamily<Var> {
@@ -337,10 +581,88 @@ impl<Var: IsVariable + 'static> IsVarFamily for VarFamily<Var> {
     fn update_i(&mut self, i: usize, delta: nalgebra::DVector<f64>) {
         self.members[i].update(delta.as_view());
     }
+
+    fn family_params(&self) -> Result<VarFamilyParams, String> {
+        let members = self
+            .members
+            .iter()
+            .map(|m| m.param_vec())
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| format!("variable type {} cannot be stored", Var::var_type()))?;
+        let mut constant_members: Vec<usize> = self.constant_members.keys().copied().collect();
+        constant_members.sort();
+        Ok(VarFamilyParams {
+            var_type: Var::var_type(),
+            kind: self.kind,
+            members,
+            constant_members,
+        })
+    }
 }
 
 dyn_clone::clone_trait_object!(IsVarFamily);
 
+type VarFamilyFactory = fn(&VarFamilyParams) -> Result<Box<dyn IsVarFamily>, String>;
+
+/// Registry of variable types, to restore type-erased variable families
+///
+/// The default registry contains all variable types of this crate: VecF64<1> to VecF64<12>, the
+/// Lie groups and PinholeCamera. Register custom variable types with [VarFamilyRegistry::register].
+#[derive(Debug, Clone)]
+pub struct VarFamilyRegistry {
+    factories: BTreeMap<String, VarFamilyFactory>,
+}
+
+impl Default for VarFamilyRegistry {
+    fn default() -> Self {
+        let mut registry = Self::new();
+        macro_rules! register_vectors {
+            ($($n:literal),*) => {
+                $(registry = registry.register::<VecF64<$n>>();)*
+            };
+        }
+        register_vectors!(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12);
+        registry
+            .register::<Isometry2<f64, 1>>()
+            .register::<Isometry3<f64, 1>>()
+            .register::<SpecialLinear3<f64, 1>>()
+            .register::<Rotation3xTranslation3<f64, 1>>()
+            .register::<Isometry3xIsometry3<f64, 1>>()
+            .register::<sophus_sensor::PinholeCamera<f64, 1>>()
+    }
+}
+
+impl VarFamilyRegistry {
+    /// create an empty registry
+    pub fn new() -> Self {
+        Self {
+            factories: BTreeMap::new(),
+        }
+    }
+
+    /// register a variable type
+    pub fn register<Var: IsVariable + 'static>(mut self) -> Self {
+        self.factories.insert(Var::var_type(), |family_params| {
+            VarFamily::<Var>::try_from_family_params(family_params)
+                .map(|family| Box::new(family) as Box<dyn IsVarFamily>)
+        });
+        self
+    }
+
+    /// restore a type-erased variable family
+    ///
+    /// Returns an error if the variable type is not registered, or the parameters are invalid.
+    pub fn family_from_params(
+        &self,
+        family_params: &VarFamilyParams,
+    ) -> Result<Box<dyn IsVarFamily>, String> {
+        match self.factories.get(&family_params.var_type) {
+            Some(factory) => factory(family_params),
+            None => Err(format!("unknown variable type {}", family_params.var_type)),
+        }
+    }
+}
+
 /// Builder for the variable pool
 #[derive(Debug, Clone)]
 pub struct VarPoolBuilder {
@@ -435,6 +757,38 @@ impl VarPool {
         as_any::Downcast::downcast_ref::<T>(self.families.get(&name).unwrap().as_ref()).unwrap()
     }
 
+    /// type-erased parameters of all families, by family name
+    ///
+    /// Returns an error if a family cannot be stored, see [IsVariable::param_vec].
+    pub fn family_params(&self) -> Result<BTreeMap<String, VarFamilyParams>, String> {
+        self.families
+            .iter()
+            .map(|(name, family)| {
+                family
+                    .family_params()
+                    .map(|params| (name.clone(), params))
+                    .map_err(|err| format!("family {}: {}", name, err))
+            })
+            .collect()
+    }
+
+    /// restore a variable pool from type-erased family parameters
+    ///
+    /// Returns an error if a variable type is not in the registry, or the parameters are invalid.
+    pub fn try_from_family_params(
+        family_params: &BTreeMap<String, VarFamilyParams>,
+        registry: &VarFamilyRegistry,
+    ) -> Result<Self, String> {
+        let mut builder = VarPoolBuilder::new();
+        for (name, params) in family_params.iter() {
+            let family = registry
+                .family_from_params(params)
+                .map_err(|err| format!("family {}: {}", name, err))?;
+            builder.families.insert(name.clone(), family);
+        }
+        Ok(builder.build())
+    }
+
     /// retrieve family members by family name
     ///
     /// Panics if the family does not exist, or the specified type is not correct
@@ -445,3 +799,135 @@ impl VarPool {
             .clone()
     }
 }
+
+/// Serializes the pool as map from family name to [VarFamilyParams].
+#[cfg(feature = "serde")]
+impl serde::Serialize for VarPool {
+    fn serialize<Ser: serde::Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        self.family_params()
+            .map_err(serde::ser::Error::custom)?
+            .serialize(serializer)
+    }
+}
+
+/// Deserializes the pool using the default [VarFamilyRegistry].
+///
+/// Use [VarPool::try_from_family_params] with a custom registry for custom variable types.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for VarPool {
+    fn deserialize<De: serde::Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+        let family_params = BTreeMap::<String, VarFamilyParams>::deserialize(deserializer)?;
+        VarPool::try_from_family_params(&family_params, &VarFamilyRegistry::default())
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// Serializes the family as [VarFamilyParams].
+#[cfg(feature = "serde")]
+impl<Var: IsVariable + 'static> serde::Serialize for VarFamily<Var> {
+    fn serialize<Ser: serde::Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        self.family_params()
+            .map_err(serde::ser::Error::custom)?
+            .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, Var: IsVariable + 'static> serde::Deserialize<'de> for VarFamily<Var> {
+    fn deserialize<De: serde::Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+        let family_params = VarFamilyParams::deserialize(deserializer)?;
+        VarFamily::try_from_family_params(&family_params).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn var_pool_serde_tests() {
+    use sophus_core::linalg::VecF64;
+    use sophus_image::ImageSize;
+    use sophus_sensor::PinholeCamera;
+
+    let poses = vec![
+        Isometry3::<f64, 1>::exp(&VecF64::<6>::new(0.1, 0.2, 0.3, 0.4, 0.5, 0.6)),
+        Isometry3::<f64, 1>::identity(),
+    ];
+    let points = vec![
+        VecF64::<3>::new(1.0, 2.0, 3.0),
+        VecF64::<3>::new(-1.0, 0.0, 4.0),
+    ];
+    let camera = PinholeCamera::<f64, 1>::new(
+        &VecF64::<4>::new(600.0, 600.0, 319.5, 239.5),
+        ImageSize::new(640, 480),
+    );
+    let var_pool = VarPoolBuilder::new()
+        .add_family(
+            "poses",
+            VarFamily::new_with_const_ids(VarKind::Free, poses.clone(), HashMap::from([(1, ())])),
+        )
+        .add_family(
+            "points",
+            VarFamily::new(VarKind::Marginalized, points.clone()),
+        )
+        .add_family("cams", VarFamily::new(VarKind::Conditioned, vec![camera]))
+        .build();
+
+    let json = serde_json::to_string(&var_pool).unwrap();
+    assert!(json.contains(
+        r#""points":{"var_type":"VecF64<3>","kind":"Marginalized","members":[[1.0,2.0,3.0],[-1.0,0.0,4.0]],"constant_members":[]}"#
+    ));
+    let var_pool2: VarPool = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+        var_pool2
+            .family_params()
+            .unwrap()
+            .keys()
+            .collect::<Vec<_>>(),
+        vec!["cams", "points", "poses"]
+    );
+    assert_eq!(
+        var_pool2.family_params().unwrap()["poses"].constant_members,
+        vec![1]
+    );
+    assert_eq!(var_pool2.num_free_params(), var_pool.num_free_params());
+    assert_eq!(var_pool2.get_members::<VecF64<3>>("points".into()), points);
+    let poses2 = var_pool2.get_members::<Isometry3<f64, 1>>("poses".into());
+    for (pose, pose2) in poses.iter().zip(poses2.iter()) {
+        approx::assert_relative_eq!(pose.params(), pose2.params(), epsilon = 1e-12);
+    }
+    let camera2 = &var_pool2.get_members::<PinholeCamera<f64, 1>>("cams".into())[0];
+    assert_eq!(camera2.params(), camera.params());
+    assert_eq!(camera2.image_size(), camera.image_size());
+
+    // variable types must be registered, and parameters must be valid
+    let family = VarFamily::new(VarKind::Free, vec![VecF64::<13>::zeros()]);
+    let json = format!(r#"{{"foo":{}}}"#, serde_json::to_string(&family).unwrap());
+    assert!(serde_json::from_str::<VarPool>(&json).is_err());
+    let family_params = BTreeMap::from([("foo".to_owned(), family.family_params().unwrap())]);
+    let registry = VarFamilyRegistry::default().register::<VecF64<13>>();
+    assert!(VarPool::try_from_family_params(&family_params, &registry).is_ok());
+    assert!(serde_json::from_str::<VarFamily<Isometry2<f64, 1>>>(
+        r#"{"var_type":"Isometry2","kind":"Free","members":[[0.0,0.0,2.0,0.0]],"constant_members":[]}"#
+    )
+    .is_err());
+    assert!(serde_json::from_str::<VarFamily<Isometry2<f64, 1>>>(
+        r#"{"var_type":"Isometry3","kind":"Free","members":[],"constant_members":[]}"#
+    )
+    .is_err());
+
+    // variables without parameters cannot be stored
+    #[derive(Clone, Debug)]
+    struct Scale(f64);
+    impl IsVariable for Scale {
+        const DOF: usize = 1;
+
+        fn update(&mut self, delta: nalgebra::DVectorView<f64>) {
+            self.0 += delta[0];
+        }
+    }
+    let var_pool = VarPoolBuilder::new()
+        .add_family("scales", VarFamily::new(VarKind::Free, vec![Scale(1.0)]))
+        .build();
+    assert!(var_pool.family_params().is_err());
+    assert!(serde_json::to_string(&var_pool).is_err());
+    assert!(Scale::try_from_param_vec(&[1.0]).is_err());
+}
@@ -119,3 +119,58 @@ impl<
         Self::from_params_and_size(&Distort::identity_params(), ImageSize::default())
     }
 }
+
+/// Serialized representation of a camera: image size and parameter array
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct CameraRepr<Params> {
+    image_size: ImageSize,
+    params: Params,
+}
+
+#[cfg(feature = "serde")]
+impl<
+        S: IsScalar<BATCH>,
+        const DISTORT: usize,
+        const PARAMS: usize,
+        const BATCH: usize,
+        Distort: IsCameraDistortionImpl<S, DISTORT, PARAMS, BATCH>,
+        Proj: IsProjection<S, BATCH>,
+    > serde::Serialize for Camera<S, DISTORT, PARAMS, BATCH, Distort, Proj>
+where
+    S::Vector<PARAMS>: serde::Serialize,
+{
+    fn serialize<Ser: serde::Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        CameraRepr {
+            image_size: self.image_size,
+            params: &self.params,
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Fails if the parameters are invalid for the distortion model.
+#[cfg(feature = "serde")]
+impl<
+        'de,
+        S: IsScalar<BATCH>,
+        const DISTORT: usize,
+        const PARAMS: usize,
+        const BATCH: usize,
+        Distort: IsCameraDistortionImpl<S, DISTORT, PARAMS, BATCH>,
+        Proj: IsProjection<S, BATCH>,
+    > serde::Deserialize<'de> for Camera<S, DISTORT, PARAMS, BATCH, Distort, Proj>
+where
+    S::Vector<PARAMS>: serde::Deserialize<'de>,
+{
+    fn deserialize<De: serde::Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+        let repr = CameraRepr::<S::Vector<PARAMS>>::deserialize(deserializer)?;
+        if !Distort::are_params_valid(&repr.params).all() {
+            return Err(serde::de::Error::custom(format!(
+                "invalid camera parameters {:?}",
+                repr.params.real_vector()
+            )));
+        }
+        Ok(Self::from_params_and_size(&repr.params, repr.image_size))
+    }
+}
@@ -4,6 +4,9 @@ use crate::projections::orthographic::OrthographicCamera;
 use sophus_image::ImageSize;
 
 /// Generalized camera enum
+///
+/// With the `serde` feature, it is serialized like [PerspectiveCameraEnum], using the model tag
+/// "orthographic" for orthographic cameras.
 #[derive(Debug, Clone)]
 pub enum GeneralCameraEnum<S: IsScalar<BATCH>, const BATCH: usize> {
     /// Perspective camera enum
@@ -74,3 +77,64 @@ impl<S: IsScalar<BATCH>, const BATCH: usize> IsCameraEnum<S, BATCH>
         }
     }
 }
+
+/// Serialized representation of an orthographic camera, with model tag
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize)]
+#[serde(tag = "model", rename_all = "snake_case")]
+enum OrthographicCameraRef<'a, Ortho> {
+    Orthographic(&'a Ortho),
+}
+
+/// Serialized representation of a generalized camera, with flat model tag
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(tag = "model", rename_all = "snake_case")]
+enum GeneralCameraRepr<Pinhole, KannalaBrandt, Ortho> {
+    Pinhole(Pinhole),
+    KannalaBrandt(KannalaBrandt),
+    Orthographic(Ortho),
+}
+
+#[cfg(feature = "serde")]
+impl<S: IsScalar<BATCH>, const BATCH: usize> serde::Serialize for GeneralCameraEnum<S, BATCH>
+where
+    S::Vector<4>: serde::Serialize,
+    S::Vector<8>: serde::Serialize,
+{
+    fn serialize<Ser: serde::Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        match self {
+            GeneralCameraEnum::Perspective(camera) => camera.serialize(serializer),
+            GeneralCameraEnum::Orthographic(camera) => {
+                OrthographicCameraRef::Orthographic(camera).serialize(serializer)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, S: IsScalar<BATCH>, const BATCH: usize> serde::Deserialize<'de>
+    for GeneralCameraEnum<S, BATCH>
+where
+    S::Vector<4>: serde::Deserialize<'de>,
+    S::Vector<8>: serde::Deserialize<'de>,
+{
+    fn deserialize<De: serde::Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+        Ok(
+            match GeneralCameraRepr::<
+                crate::PinholeCamera<S, BATCH>,
+                crate::KannalaBrandtCamera<S, BATCH>,
+                OrthographicCamera<S, BATCH>,
+            >::deserialize(deserializer)?
+            {
+                GeneralCameraRepr::Pinhole(camera) => {
+                    Self::Perspective(PerspectiveCameraEnum::Pinhole(camera))
+                }
+                GeneralCameraRepr::KannalaBrandt(camera) => {
+                    Self::Perspective(PerspectiveCameraEnum::KannalaBrandt(camera))
+                }
+                GeneralCameraRepr::Orthographic(camera) => Self::Orthographic(camera),
+            },
+        )
+    }
+}
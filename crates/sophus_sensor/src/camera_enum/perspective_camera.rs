@@ -13,7 +13,23 @@ pub type KannalaBrandtCamera<S, const BATCH: usize> =
     Camera<S, 4, 8, BATCH, KannalaBrandtDistortionImpl<S, BATCH>, PerspectiveProjectionImpl>;
 
 /// Perspective camera enum
+///
+/// With the `serde` feature, it is serialized as a map with the model tag, the image size and
+/// the parameter array, e.g. {"model": "pinhole", "image_size": {..}, "params": [fx, fy, cx, cy]}.
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(
+        tag = "model",
+        rename_all = "snake_case",
+        bound(
+            serialize = "S::Vector<4>: serde::Serialize, S::Vector<8>: serde::Serialize",
+            deserialize = "S::Vector<4>: serde::Deserialize<'de>, \
+                           S::Vector<8>: serde::Deserialize<'de>"
+        )
+    )
+)]
 pub enum PerspectiveCameraEnum<S: IsScalar<BATCH>, const BATCH: usize> {
     /// Pinhole camera
     Pinhole(PinholeCamera<S, BATCH>),
@@ -1,7 +1,11 @@
 use crate::prelude::*;
 use crate::traits::IsCameraDistortionImpl;
+#[cfg(feature = "alloc")]
+use alloc::vec;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::marker::PhantomData;
 use sophus_core::params::ParamsImpl;
-use std::marker::PhantomData;
 
 /// Affine "distortion" implementation
 ///
@@ -18,10 +22,12 @@ impl<S: IsScalar<BATCH>, const BATCH: usize> ParamsImpl<S, 4, BATCH>
         S::Mask::all_true()
     }
 
+    #[cfg(feature = "alloc")]
     fn params_examples() -> Vec<S::Vector<4>> {
         vec![S::Vector::<4>::from_f64_array([1.0, 1.0, 0.0, 0.0])]
     }
 
+    #[cfg(feature = "alloc")]
     fn invalid_params_examples() -> Vec<S::Vector<4>> {
         vec![
             S::Vector::<4>::from_f64_array([0.0, 1.0, 0.0, 0.0]),
@@ -4,13 +4,21 @@ use crate::prelude::*;
 use sophus_image::ImageSize;
 
 /// Dynamic camera facade
+///
+/// With the `serde` feature, it is serialized as the underlying camera enum.
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct DynCameraFacade<
     S: IsScalar<BATCH>,
     const BATCH: usize,
     CameraType: IsCameraEnum<S, BATCH>,
 > {
     camera_type: CameraType,
+    #[cfg_attr(feature = "serde", serde(skip))]
     phantom: std::marker::PhantomData<S>,
 }
 
@@ -272,3 +280,189 @@ fn dyn_camera_tests() {
         }
     }
 }
+
+#[cfg(feature = "serde")]
+#[test]
+fn dyn_camera_serde_tests() {
+    use crate::camera_enum::GeneralCameraEnum;
+    use crate::dyn_camera::DynGeneralCamera;
+    use crate::projections::orthographic::OrthographicCamera;
+    use sophus_core::linalg::VecF64;
+
+    let image_size = ImageSize::new(640, 480);
+
+    let pinhole =
+        DynCamera::<f64, 1>::new_pinhole(&VecF64::<4>::new(600.0, 610.0, 319.5, 239.5), image_size);
+    let json = serde_json::to_string(&pinhole).unwrap();
+    assert_eq!(
+        json,
+        r#"{"model":"pinhole","image_size":{"width":640,"height":480},"params":[600.0,610.0,319.5,239.5]}"#
+    );
+    let pinhole2: DynCamera<f64, 1> = serde_json::from_str(&json).unwrap();
+    assert_eq!(pinhole2.image_size(), image_size);
+    assert_eq!(pinhole2.pinhole_params(), pinhole.pinhole_params());
+
+    let kb_params =
+        VecF64::<8>::from_vec(vec![1000.0, 1000.0, 320.0, 280.0, 0.1, 0.01, 0.001, 0.0001]);
+    let kb = DynCamera::<f64, 1>::new_kannala_brandt(&kb_params, image_size);
+    let json = serde_json::to_string(&kb).unwrap();
+    assert!(json.starts_with(r#"{"model":"kannala_brandt","#));
+    let kb2: DynCamera<f64, 1> = serde_json::from_str(&json).unwrap();
+    let point_in_camera = VecF64::<3>::new(0.1, -0.2, 1.5);
+    assert_eq!(
+        kb2.cam_proj(&point_in_camera),
+        kb.cam_proj(&point_in_camera)
+    );
+
+    // a generalized camera reads perspective cameras, and tags orthographic ones
+    let general: DynGeneralCamera<f64, 1> = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+        general.cam_proj(&point_in_camera),
+        kb.cam_proj(&point_in_camera)
+    );
+    let ortho = DynGeneralCamera::<f64, 1>::from_model(GeneralCameraEnum::Orthographic(
+        OrthographicCamera::new(&VecF64::<4>::new(2.0, 2.0, 320.0, 240.0), image_size),
+    ));
+    let json = serde_json::to_string(&ortho).unwrap();
+    assert!(json.starts_with(r#"{"model":"orthographic","#));
+    let ortho2: DynGeneralCamera<f64, 1> = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+        ortho2.cam_proj(&point_in_camera),
+        ortho.cam_proj(&point_in_camera)
+    );
+    assert!(serde_json::from_str::<DynCamera<f64, 1>>(&json).is_err());
+
+    // wrong number of parameters for the model
+    assert!(serde_json::from_str::<DynCamera<f64, 1>>(
+        r#"{"model":"pinhole","image_size":{"width":640,"height":480},"params":[600.0,610.0]}"#
+    )
+    .is_err());
+}
+
+#[test]
+fn dyn_camera_tape_tests() {
+    use sophus_core::calculus::dual::DualScalar;
+    use sophus_core::calculus::maps::scalar_valued_maps::ScalarValuedMapFromVector;
+    use sophus_core::calculus::tape::TapeScalar;
+    use sophus_core::linalg::VecF64;
+
+    // sum of squared reprojection errors, as a function of the Kannala-Brandt parameters
+    fn cost<S: IsSingleScalar + IsScalar<1>>(params: S::Vector<8>) -> S {
+        let camera = DynCamera::<S, 1>::new_kannala_brandt(&params, ImageSize::new(640, 480));
+        let mut cost = S::from_f64(0.0);
+        for i in 0..10 {
+            let t = 0.1 * (i as f64);
+            let point_in_camera = S::Vector::<3>::from_f64_array([t - 0.5, 0.3 - 0.5 * t, 1.0 + t]);
+            let uv = S::Vector::<2>::from_f64_array([300.0 + 10.0 * t, 250.0 - 5.0 * t]);
+            cost += (camera.cam_proj(&point_in_camera) - uv).squared_norm();
+        }
+        cost
+    }
+
+    let params =
+        VecF64::<8>::from_vec(vec![1000.0, 1000.0, 320.0, 280.0, 0.1, 0.01, 0.001, 0.0001]);
+    let tape_grad =
+        ScalarValuedMapFromVector::<TapeScalar, 1>::gradient(cost::<TapeScalar>, params);
+    let dual_grad =
+        ScalarValuedMapFromVector::<DualScalar, 1>::fw_autodiff(cost::<DualScalar>, params);
+    approx::assert_relative_eq!(tape_grad, dual_grad, max_relative = 1e-9);
+}
+
+#[test]
+fn dyn_camera_batch_tests() {
+    use sophus_core::linalg::BatchScalarF64;
+    use sophus_core::linalg::BatchVecF64;
+    use sophus_core::linalg::VecF64;
+
+    // each lane of the batch camera agrees with the corresponding single camera
+    const BATCH: usize = 4;
+    let image_size = ImageSize::new(640, 480);
+    let lane_params: [VecF64<8>; BATCH] = core::array::from_fn(|lane| {
+        let s = lane as f64;
+        VecF64::<8>::from_vec(vec![
+            1000.0 + 10.0 * s,
+            1000.0 - 5.0 * s,
+            320.0,
+            280.0 + s,
+            0.1,
+            0.01 * s,
+            0.001,
+            0.0001,
+        ])
+    });
+    let batch_params = BatchVecF64::<8, BATCH>::from_fn(|r, _| {
+        BatchScalarF64::<BATCH>::from_real_array(core::array::from_fn(|lane| lane_params[lane][r]))
+    });
+    let batch_camera =
+        DynCamera::<BatchScalarF64<BATCH>, BATCH>::new_kannala_brandt(&batch_params, image_size);
+
+    for i in 0..10 {
+        let t = 0.1 * (i as f64);
+        let lane_points: [VecF64<3>; BATCH] = core::array::from_fn(|lane| {
+            VecF64::<3>::new(t - 0.1 * lane as f64, 0.3 - 0.5 * t, 1.0 + t)
+        });
+        let batch_point = BatchVecF64::<3, BATCH>::from_fn(|r, _| {
+            BatchScalarF64::<BATCH>::from_real_array(core::array::from_fn(|lane| {
+                lane_points[lane][r]
+            }))
+        });
+
+        let batch_pixel = batch_camera.cam_proj(&batch_point);
+        let batch_unproj = batch_camera.cam_unproj_with_z(&batch_pixel, batch_point[2]);
+        for lane in 0..BATCH {
+            let camera = DynCamera::<f64, 1>::new_kannala_brandt(&lane_params[lane], image_size);
+            let pixel = camera.cam_proj(&lane_points[lane]);
+            for r in 0..2 {
+                approx::assert_relative_eq!(
+                    batch_pixel[r].extract_single(lane),
+                    pixel[r],
+                    epsilon = 1e-9
+                );
+            }
+            for r in 0..3 {
+                approx::assert_abs_diff_eq!(
+                    batch_unproj[r].extract_single(lane),
+                    lane_points[lane][r],
+                    epsilon = 1e-6
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn dyn_camera_f32_tests() {
+    use sophus_core::linalg::VecF32;
+    use sophus_core::linalg::VecF64;
+
+    // single precision cameras agree with the double precision ones, up to f32 rounding
+    let image_size = ImageSize::new(640, 480);
+    let pinhole_params = VecF64::<4>::new(1000.0, 1000.0, 320.0, 280.0);
+    let kb_params =
+        VecF64::<8>::from_vec(vec![1000.0, 1000.0, 320.0, 280.0, 0.1, 0.01, 0.001, 0.0001]);
+    let camera_pairs = [
+        (
+            DynCamera::<f32, 1>::new_pinhole(&pinhole_params.cast(), image_size),
+            DynCamera::<f64, 1>::new_pinhole(&pinhole_params, image_size),
+        ),
+        (
+            DynCamera::<f32, 1>::new_kannala_brandt(&kb_params.cast(), image_size),
+            DynCamera::<f64, 1>::new_kannala_brandt(&kb_params, image_size),
+        ),
+    ];
+
+    for (camera_f32, camera_f64) in camera_pairs {
+        for i in 0..10 {
+            let t = 0.1 * (i as f64);
+            let point = VecF64::<3>::new(t, 0.3 - 0.5 * t, 1.0 + t);
+            let point_f32: VecF32<3> = point.cast();
+
+            let pixel_f32 = camera_f32.cam_proj(&point_f32);
+            let pixel = camera_f64.cam_proj(&point);
+            approx::assert_relative_eq!(pixel_f32, pixel.cast(), max_relative = 1e-5);
+
+            let unproj_f32 = camera_f32.cam_unproj_with_z(&pixel_f32, point_f32[2]);
+            approx::assert_abs_diff_eq!(unproj_f32, point_f32, epsilon = f32::test_tolerance(1e-6));
+        }
+    }
+}
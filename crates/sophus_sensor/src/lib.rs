@@ -1,22 +1,37 @@
+#![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(feature = "simd", feature(portable_simd))]
 #![deny(missing_docs)]
 
 //! Sensor (aka camera) crate - part of the sophus-rs project
+//!
+//! Without the default `std` feature, the crate is `no_std`: the projection and distortion
+//! models are always available, while the camera types, which carry an image size, need `std`.
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 /// Distortion lookup table
+#[cfg(feature = "std")]
 pub mod distortion_table;
 
 /// A type-erased camera struct
+#[cfg(feature = "std")]
 pub mod dyn_camera;
+#[cfg(feature = "std")]
 pub use crate::dyn_camera::DynCamera;
 
 /// A generic camera model
+#[cfg(feature = "std")]
 pub mod camera;
+#[cfg(feature = "std")]
 pub use crate::camera::Camera;
 
 /// Projection models
+#[cfg(feature = "std")]
 pub mod camera_enum;
+#[cfg(feature = "std")]
 pub use crate::camera_enum::perspective_camera::KannalaBrandtCamera;
+#[cfg(feature = "std")]
 pub use crate::camera_enum::perspective_camera::PinholeCamera;
 
 /// Projection models
@@ -30,7 +45,9 @@ pub mod traits;
 
 /// sophus sensor prelude
 pub mod prelude {
+    #[cfg(feature = "std")]
     pub use crate::traits::IsCameraEnum;
+    #[cfg(feature = "std")]
     pub use crate::traits::IsPerspectiveCameraEnum;
     pub use crate::traits::IsProjection;
     pub use sophus_core::prelude::*;
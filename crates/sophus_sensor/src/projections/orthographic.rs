@@ -1,9 +1,11 @@
+#[cfg(feature = "std")]
 use crate::camera::Camera;
+#[cfg(feature = "std")]
 use crate::distortions::affine::AffineDistortionImpl;
 use crate::traits::IsProjection;
+use core::marker::PhantomData;
 use sophus_core::linalg::scalar::IsScalar;
 use sophus_core::linalg::vector::IsVector;
-use std::marker::PhantomData;
 
 /// Orthographic projection implementation
 #[derive(Debug, Clone)]
@@ -32,5 +34,6 @@ impl<S: IsScalar<BATCH>, const BATCH: usize> IsProjection<S, BATCH>
 }
 
 /// Orthographic camera
+#[cfg(feature = "std")]
 pub type OrthographicCamera<S, const BATCH: usize> =
     Camera<S, 0, 4, BATCH, AffineDistortionImpl<S, BATCH>, OrthographisProjectionImpl<S, BATCH>>;
@@ -1,5 +1,6 @@
 use crate::prelude::*;
 use sophus_core::params::ParamsImpl;
+#[cfg(feature = "std")]
 use sophus_image::ImageSize;
 
 /// Camera distortion implementation trait
@@ -47,6 +48,7 @@ pub trait IsProjection<S: IsScalar<BATCH>, const BATCH: usize> {
 }
 
 /// Camera trait
+#[cfg(feature = "std")]
 pub trait IsCameraEnum<S: IsScalar<BATCH>, const BATCH: usize> {
     /// Creates a new pinhole camera
     fn new_pinhole(params: &S::Vector<4>, image_size: ImageSize) -> Self;
@@ -69,6 +71,7 @@ pub trait IsCameraEnum<S: IsScalar<BATCH>, const BATCH: usize> {
 }
 
 /// Dynamic camera trait
+#[cfg(feature = "std")]
 pub trait IsPerspectiveCameraEnum<S: IsScalar<BATCH>, const BATCH: usize> {
     /// Return the first four parameters: fx, fy, cx, cy
     fn pinhole_params(&self) -> S::Vector<4>;